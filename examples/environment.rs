@@ -0,0 +1,18 @@
+use std::{collections::BTreeMap, env};
+
+use colprint::{FormattableItem, Table};
+
+fn main() {
+    // Collect the environment in key order
+    let vars: BTreeMap<String, String> = env::vars().collect();
+
+    let mut table = Table::new("{} = {}").with_headers(["Variable", "Value"]);
+    for (key, value) in &vars {
+        table.push_row(&[FormattableItem::from(key), FormattableItem::from(value)]);
+    }
+
+    // Show the longest values first, falling back to key order for values of equal length
+    table.sort_rows_by(|a, b| b[1].len().cmp(&a[1].len()).then_with(|| a[0].cmp(&b[0])));
+
+    print!("{table}");
+}
@@ -0,0 +1,30 @@
+use std::io::{Write as _, stdout};
+
+use colprint::{ColumnFormatter, FormattableItem, IndentWriter};
+
+fn main() {
+    let services = "api\nworker\nscheduler";
+    let hosts = "10.0.0.1\n10.0.0.2\n10.0.0.3";
+    let states = "running\nrunning\nstopped";
+
+    // A three-column layout, nested under a bullet of a list indented by the writer
+    let layout = ColumnFormatter::new(
+        "{} | {} | {}",
+        [
+            FormattableItem::DisplayItem(&services),
+            FormattableItem::DisplayItem(&hosts),
+            FormattableItem::DisplayItem(&states),
+        ],
+    );
+
+    let mut out = stdout().lock();
+    writeln!(out, "Deployments").unwrap();
+    writeln!(out, "- production").unwrap();
+
+    let mut nested = IndentWriter::new(&mut out, "    ");
+    write!(nested, "  - ").unwrap();
+    layout.write_to(&mut nested).unwrap();
+    drop(nested);
+
+    writeln!(out, "- staging (no services)").unwrap();
+}
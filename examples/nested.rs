@@ -0,0 +1,22 @@
+use colprint::{ColumnFormatter, FormattableItem, colprint};
+
+fn main() {
+    let keys = "host\nport\nuser";
+    let values = "example.com\n8080\nadmin";
+
+    // A small two-column layout, used as the right-hand cell of the outer layout
+    let details = ColumnFormatter::new(
+        "{} : {}",
+        [FormattableItem::DisplayItem(&keys), FormattableItem::DisplayItem(&values)],
+    );
+
+    println!("Nested formatter with the colprint! macro");
+    colprint!("{} | {} | {}", "Server\nconfiguration", details, "online");
+
+    println!("\nNested formatter with an explicit item");
+    let outer = ColumnFormatter::new(
+        "{} || {}",
+        [FormattableItem::DisplayItem(&"Details"), FormattableItem::Nested(&details)],
+    );
+    print!("{outer}");
+}
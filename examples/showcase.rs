@@ -56,7 +56,7 @@ fn main() {
     colprint!("{:?} | {:?}", stats, person);
 
     println!("\nExample 4: Pretty debug formatting with border separator");
-    colprint!("{:#?:50} | {:#?:50}", stats, person);
+    colprint!("{:#?}:50 | {:#?}:50", stats, person);
 
     println!("\nExample 5: Mixed formatting with custom separator");
     colprint!("{} => {:?}", person, stats);
@@ -83,4 +83,7 @@ fn main() {
 
     println!("\nExample 6: Three columns with different separators");
     colprint!("{} -> {:?} => {:#?}", person, stats, task);
+
+    println!("\nExample 7: Inline formatting without an intermediate String");
+    colprint!("{} | {}", format_args!("{} ({})", person.name, person.age), task.status);
 }
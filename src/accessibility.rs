@@ -0,0 +1,66 @@
+//! Defines how output signals what it leaves out, for readers who cannot see visual cues.
+//!
+//! This module contains the `Accessibility` enum. Visual cues, such as the `…` truncation marker
+//! and coloured backgrounds, are lost on screen readers:
+//! - `Visual`: Rely on visual cues (the default).
+//! - `Verbose`: Spell out what is left out in plain text. Truncated cells end with `[truncated]`,
+//!   cells cut to their line cap end with a `[12 lines omitted]` line, hidden columns are announced as
+//!   `[column 'notes' hidden]`, and coloured signals such as heatmaps and row styles gain textual markers.
+//!
+//! The annotations are part of the cells before columns are measured, or replace the end of a cut line,
+//! so the layout stays consistent. The default can be set with the `COLPRINT_ACCESSIBILITY` environment variable.
+
+use crate::slice::{ESCAPE, split, visible_width};
+use crate::style::RESET;
+
+/// The annotation ending a truncated cell line.
+const TRUNCATED: &str = " [truncated]";
+
+/// Policy for signalling omitted content.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Accessibility {
+    /// Rely on visual cues such as truncation markers and colour.
+    #[default]
+    Visual,
+    /// Annotate omissions in plain text.
+    Verbose,
+}
+
+impl Accessibility {
+    /// Cut a line wider than its column so it ends with the truncation annotation, if annotating.
+    ///
+    /// Returns `None` when the line fits or cues are visual, leaving the line to be fitted as usual.
+    #[must_use]
+    #[inline]
+    pub(crate) fn cut(self, line: &str, column_width: usize) -> Option<String> {
+        if self == Self::Visual || visible_width(line) <= column_width {
+            return None;
+        }
+
+        let reset = if line.contains(ESCAPE) { RESET } else { "" };
+        let annotation_width = visible_width(TRUNCATED);
+        if column_width < annotation_width {
+            return Some(split(TRUNCATED.trim_start(), column_width).0.to_owned());
+        }
+        Some(format!(
+            "{}{reset}{TRUNCATED}",
+            split(line, column_width - annotation_width).0
+        ))
+    }
+
+    /// The line announcing lines dropped from a cell.
+    #[must_use]
+    #[inline]
+    pub(crate) fn omitted(lines: usize) -> String {
+        let plural = if lines == 1 { "" } else { "s" };
+        format!("[{lines} line{plural} omitted]")
+    }
+
+    /// The line announcing a hidden column, given by its quoted header or its index.
+    #[must_use]
+    #[inline]
+    pub(crate) fn hidden(label: &str) -> String {
+        format!("[column {label} hidden]")
+    }
+}
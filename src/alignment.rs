@@ -0,0 +1,35 @@
+//! Defines the horizontal alignment of content within a column.
+//!
+//! This module contains the `Alignment` enum, which controls on which side of a cell the
+//! padding is placed when a line is narrower than its column:
+//! - `Left`: Content starts at the left edge of the column (the default).
+//! - `Right`: Content ends at the right edge of the column, which suits numeric values.
+//! - `Center`: Content is centred, with any odd padding character placed on the right.
+//!
+//! An alignment can be chosen per column with a `=<`, `=^`, or `=>` suffix in the format string.
+//! Two characters, such as `=^>`, align a table's header by the first and its other rows by the second.
+
+/// Horizontal alignment of a column.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Alignment {
+    /// Pad on the right.
+    #[default]
+    Left,
+    /// Pad on the left.
+    Right,
+    /// Pad evenly on both sides.
+    Center,
+}
+
+impl Alignment {
+    /// Parse a character of a `=` suffix in the format string, or an alignment inside a specifier's braces.
+    pub(crate) const fn from_suffix(suffix: u8) -> Option<Self> {
+        match suffix {
+            b'<' => Some(Self::Left),
+            b'>' => Some(Self::Right),
+            b'^' => Some(Self::Center),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,19 @@
+//! Defines what happens when content has already passed a column's anchor.
+//!
+//! This module contains the `AnchorPolicy` enum. A column with an anchor (`{@40}` in the
+//! format string) starts at that character offset from the left margin, like a tab stop.
+//! When the earlier columns and separators are already wider than the anchor, the policy
+//! decides between:
+//! - `Push`: Start the column straight after the earlier content, later than its anchor (the default).
+//! - `Truncate`: Cut the earlier content back so the column starts exactly at its anchor.
+
+/// Policy for columns whose anchor has already been passed.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum AnchorPolicy {
+    /// Start the column after the earlier content.
+    #[default]
+    Push,
+    /// Cut the earlier content back to the anchor.
+    Truncate,
+}
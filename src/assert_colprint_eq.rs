@@ -0,0 +1,36 @@
+//! Provides the `assert_colprint_eq!` macro for comparing rendered output against the expected text.
+//!
+//! This module contains the `assert_colprint_eq!` macro, a test helper which renders a `ColumnFormatter`
+//! or `Table` and panics with a line-by-line diff if the output differs from the expected text.
+
+/// Macro asserting that a formatter or table renders exactly the expected text.
+///
+/// Anything implementing `Display` can be compared, including `ColumnFormatter` and `Table`. On failure, the panic
+/// message is a diff of the expected and actual lines, with trailing spaces shown as `·`, tabs as `→` and the escape
+/// character as `␛`. A caret marks the first differing column of each changed line, and differences that would be
+/// invisible in a terminal, such as trailing whitespace or ANSI escape codes, are called out. The newline ending the
+/// last line may be left out of the expected text.
+///
+/// # Examples
+///
+/// ```
+/// use colprint::{ColumnFormatter, assert_colprint_eq};
+///
+/// let formatter = ColumnFormatter::new("{} | {}", [&"alpha", &"beta"]);
+/// assert_colprint_eq!(formatter, "alpha | beta");
+/// ```
+///
+/// ```should_panic
+/// use colprint::{ColumnFormatter, assert_colprint_eq};
+///
+/// let formatter = ColumnFormatter::new("{} | {}", [&"alpha", &"beta"]);
+/// assert_colprint_eq!(formatter, "alpha | beta ");
+/// ```
+#[macro_export]
+macro_rules! assert_colprint_eq {
+    ($rendered:expr, $expected:expr $(,)?) => {
+        if let Some(diff) = $crate::macro_support::render_diff(&$expected, &$rendered.to_string()) {
+            panic!("assertion failed: rendered output matches expected\n{}", diff);
+        }
+    };
+}
@@ -0,0 +1,34 @@
+//! Provides the `assert_fits!` macro for checking rendered output against a maximum width.
+//!
+//! This module contains the `assert_fits!` macro, a test helper which renders a `ColumnFormatter`
+//! or `Table` and panics if any line is wider than the given width. Outside of tests, the
+//! `check_fits` methods it calls return a `FitError` instead of panicking.
+
+/// Macro asserting that no rendered line of a formatter or table is wider than the given width.
+///
+/// Lines are measured by their visible characters, ignoring ANSI escape sequences. On failure, the
+/// panic message gives the offending line number and width, with a caret marking the overflow.
+///
+/// # Examples
+///
+/// ```
+/// use colprint::{ColumnFormatter, assert_fits};
+///
+/// let formatter = ColumnFormatter::new("{} | {}", [&"alpha", &"beta"]);
+/// assert_fits!(formatter, 12);
+/// ```
+///
+/// ```should_panic
+/// use colprint::{ColumnFormatter, assert_fits};
+///
+/// let formatter = ColumnFormatter::new("{} | {}", [&"alpha", &"beta"]);
+/// assert_fits!(formatter, 10);
+/// ```
+#[macro_export]
+macro_rules! assert_fits {
+    ($rendered:expr, $max_width:expr $(,)?) => {
+        if let Err(err) = $rendered.check_fits($max_width) {
+            panic!("assertion failed: output fits in {} characters\n{}", $max_width, err);
+        }
+    };
+}
@@ -0,0 +1,55 @@
+//! Renders fractions as text progress bars, such as `[██████----]  60%`.
+//!
+//! A bar fills the width it is given: the brackets and the percentage take what they need, and the
+//! rest is split between the filled and empty parts, rounding to the nearest character. When the
+//! width is too narrow for the percentage it is left out, then the brackets. Fractions outside
+//! `0..=1` are clamped, and NaN is drawn as a bracketed `?`.
+//!
+//! Bars are drawn at `DEFAULT_WIDTH` to measure their column, then drawn again at the width of the
+//! column, so fitting a layout to the terminal resizes them rather than cutting them.
+
+use crate::{BarGlyphs, slice::content_width};
+
+/// The width of a bar when measuring its column, with ten characters between the brackets.
+pub const DEFAULT_WIDTH: usize = 17;
+
+/// Draw the fraction as a bar exactly `width` characters wide.
+#[must_use]
+#[inline]
+pub fn render(fraction: f64, width: usize, glyphs: BarGlyphs) -> String {
+    let brackets = format!("{}{}", glyphs.open, glyphs.close);
+    if fraction.is_nan() {
+        let inner = content_width(width, &brackets);
+        if inner == 0 {
+            return format!("{:^width$}", "?");
+        }
+        return format!("{}{:^inner$}{}", glyphs.open, "?", glyphs.close);
+    }
+
+    let clamped = fraction.clamp(0.0, 1.0);
+    let label = format!(" {:>3.0}%", clamped * 100.0);
+    let labelled = content_width(width, &format!("{brackets}{label}"));
+    let bracketed = content_width(width, &brackets);
+    if labelled > 0 {
+        format!("{}{}{}{label}", glyphs.open, fill(clamped, labelled, glyphs), glyphs.close)
+    } else if bracketed > 0 {
+        format!("{}{}{}", glyphs.open, fill(clamped, bracketed, glyphs), glyphs.close)
+    } else {
+        fill(clamped, width, glyphs)
+    }
+}
+
+/// Draw the filled and empty parts of a bar `width` characters wide, for a fraction in `0..=1`.
+#[expect(
+    clippy::as_conversions,
+    clippy::cast_possible_truncation,
+    clippy::cast_precision_loss,
+    clippy::cast_sign_loss,
+    reason = "The fraction is clamped, so the rounded count lies between zero and the width."
+)]
+fn fill(fraction: f64, width: usize, glyphs: BarGlyphs) -> String {
+    let filled = ((fraction * width as f64).round() as usize).min(width);
+    let mut bar = glyphs.filled.to_string().repeat(filled);
+    bar.push_str(&glyphs.empty.to_string().repeat(width - filled));
+    bar
+}
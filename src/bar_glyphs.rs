@@ -0,0 +1,52 @@
+//! Defines the characters progress bars are drawn with.
+//!
+//! This module contains the `BarGlyphs` struct, which configures the bars of `{:bar}` columns:
+//! - `filled` and `empty`: The characters for the completed and remaining parts of the bar.
+//! - `open` and `close`: The brackets around the bar.
+
+/// The characters a progress bar is drawn with.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct BarGlyphs {
+    /// Character for the completed part of the bar.
+    pub filled: char,
+    /// Character for the remaining part of the bar.
+    pub empty: char,
+    /// Bracket before the bar.
+    pub open: char,
+    /// Bracket after the bar.
+    pub close: char,
+}
+
+impl BarGlyphs {
+    /// Hashes for the completed part and dashes for the rest, between square brackets, drawn in place of the
+    /// default glyphs when only ASCII is written.
+    pub const ASCII: Self = Self::new('#', '-', '[', ']');
+
+    /// Construct a new `BarGlyphs`.
+    #[must_use]
+    #[inline]
+    pub const fn new(filled: char, empty: char, open: char, close: char) -> Self {
+        Self {
+            filled,
+            empty,
+            open,
+            close,
+        }
+    }
+
+    /// Check whether every glyph is ASCII.
+    #[must_use]
+    #[inline]
+    pub const fn is_ascii(&self) -> bool {
+        self.filled.is_ascii() && self.empty.is_ascii() && self.open.is_ascii() && self.close.is_ascii()
+    }
+}
+
+impl Default for BarGlyphs {
+    /// Full blocks for the completed part and dashes for the rest, between square brackets.
+    #[inline]
+    fn default() -> Self {
+        Self::new('\u{2588}', '-', '[', ']')
+    }
+}
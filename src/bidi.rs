@@ -0,0 +1,68 @@
+//! Fitting of cell lines containing right-to-left text.
+//!
+//! Cells are padded and truncated in logical order, but terminals display right-to-left
+//! scripts such as Hebrew and Arabic in visual order. This module fits each line according to
+//! its base direction, taken from its first strong character:
+//! - Left-to-right lines are fitted as usual.
+//! - Right-to-left lines have their alignment mirrored, so a left-aligned column is flush with
+//!   the visual start of the text, on the right. Truncation keeps the logical start, so the cut
+//!   and any `…` marker land at the visual end of the line, on the left.
+//!
+//! Any line containing right-to-left characters is wrapped in a first-strong isolate
+//! (`U+2068` to `U+2069`), so the terminal cannot reorder it with the padding and separators
+//! around it. The isolate marks take up no space on screen, but count as characters when the
+//! output is measured.
+//!
+//! This module is only available with the `bidi` feature.
+
+use unicode_bidi::{BidiClass, Direction, bidi_class, get_base_direction};
+
+use crate::{
+    Alignment,
+    column_format::ColumnFormat,
+    column_formatter::{self, pad_line, split_padding},
+    row_role::RowRole,
+};
+
+/// Mark opening a first-strong isolate.
+const FIRST_STRONG_ISOLATE: char = '\u{2068}';
+
+/// Mark closing an isolate.
+const POP_DIRECTIONAL_ISOLATE: char = '\u{2069}';
+
+/// Truncate or pad a line to exactly fit the column width, following the visual direction of its text.
+#[expect(clippy::single_call_fn, reason = "Keeps the bidirectional rules in one module.")]
+#[must_use]
+#[inline]
+pub fn fit_line(line: &str, column_width: usize, format: &ColumnFormat) -> String {
+    if !line.chars().any(|ch| matches!(bidi_class(ch), BidiClass::R | BidiClass::AL)) {
+        return column_formatter::fit_line(line, column_width, format, RowRole::Data);
+    }
+
+    let alignment = match (get_base_direction(line), format.alignment) {
+        (Direction::Rtl, Alignment::Left) => Alignment::Right,
+        (Direction::Rtl, Alignment::Right) => Alignment::Left,
+        (_, alignment) => alignment,
+    };
+
+    // Truncate the text alone, then pad around its isolate
+    let line_len = line.chars().count();
+    let text = if line_len > column_width {
+        pad_line(
+            line,
+            column_width,
+            alignment,
+            (format.truncate_side, &format.truncate_marker, format.truncate_marker_style),
+            format.fill,
+        )
+    } else {
+        line.to_owned()
+    };
+    let (left, right) = split_padding(column_width.saturating_sub(line_len), alignment);
+    let fill = format.fill.to_string();
+    format!(
+        "{}{FIRST_STRONG_ISOLATE}{text}{POP_DIRECTIONAL_ISOLATE}{}",
+        fill.repeat(left),
+        fill.repeat(right)
+    )
+}
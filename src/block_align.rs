@@ -0,0 +1,22 @@
+//! Defines the alignment of the whole rendered block within a wider target width.
+//!
+//! This module contains the `BlockAlign` enum, which controls where the `ColumnFormatter`
+//! places its output when the target width is wider than the output itself:
+//! - `Left`: Leave the output against the left edge (the default).
+//! - `Center`: Pad every line so the block sits in the middle of the target width.
+//! - `Right`: Pad every line so the block ends at the right edge of the target width.
+//!
+//! The whole block moves as one, so the columns stay aligned with each other.
+
+/// Placement of the rendered block within the target width.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum BlockAlign {
+    /// Keep the block against the left edge.
+    #[default]
+    Left,
+    /// Center the block, rounding towards the left.
+    Center,
+    /// Move the block against the right edge.
+    Right,
+}
@@ -0,0 +1,62 @@
+//! Defines cells which are formatted once and laid out many times.
+//!
+//! This module contains the `Cell` struct, which holds the lines of an item already rendered
+//! in a `FormatType`, along with its natural width. Rendering, such as pretty-printing a large
+//! `Debug` value, is usually the most expensive part of laying out columns, so a `Cell` lets the
+//! same rendered item be placed in many layouts, or frame after frame, while paying that cost once.
+//!
+//! Cells are placed in a `ColumnFormatter` or `Table` through `FormattableItem::Cell`, which
+//! supports every format type and is placed as it is, whatever its column requests.
+
+use crate::{FormatType, FormattableItem, slice::visible_width};
+
+/// The rendered lines of an item, ready to be laid out.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Cell {
+    /// The rendered lines.
+    lines: Vec<String>,
+    /// The width of the widest line, in visible characters.
+    width: usize,
+}
+
+impl Cell {
+    /// Render an item in the given format type, such as `FormatType::PrettyDebug`, splitting it into lines.
+    ///
+    /// Items lacking the requested representation fall back to the one they have. Custom formats are rendered
+    /// as `Display`, as their handlers are registered with a formatter.
+    #[must_use]
+    #[inline]
+    pub fn render(item: &FormattableItem<'_>, format_type: FormatType) -> Self {
+        Self::from_text(&item.render(format_type))
+    }
+
+    /// Construct a `Cell` from text which is already rendered, splitting it into lines.
+    #[must_use]
+    #[inline]
+    pub fn from_text(text: &str) -> Self {
+        let lines: Vec<String> = text.lines().map(ToOwned::to_owned).collect();
+        let width = lines.iter().map(|line| visible_width(line)).max().unwrap_or(0);
+        Self { lines, width }
+    }
+
+    /// The rendered lines.
+    #[must_use]
+    #[inline]
+    pub fn lines(&self) -> &[String] {
+        &self.lines
+    }
+
+    /// The width of the widest line, in visible characters, ignoring escape sequences.
+    #[must_use]
+    #[inline]
+    pub const fn width(&self) -> usize {
+        self.width
+    }
+}
+
+impl<'a> From<&'a Cell> for FormattableItem<'a> {
+    #[inline]
+    fn from(cell: &'a Cell) -> Self {
+        Self::Cell(cell)
+    }
+}
@@ -0,0 +1,88 @@
+//! Provides the `colformat!` macro for formatting data in columns into a `String`.
+//!
+//! This module contains the `colformat!` macro, which is to `colprint!` what `format!` is to
+//! `print!`. It binds the items, selects the representation each column asks for, and renders
+//! them with a `ColumnFormatter`, returning the lines instead of printing them.
+
+/// Macro for formatting items in columns into a `String`, using a format string.
+///
+/// The format string is the same as for `colprint!`. Every line of the result, including the last,
+/// ends with a newline.
+///
+/// # Examples
+///
+/// ```
+/// use colprint::{colformat, doctest_support::Person};
+///
+/// let alice = Person::ALICE;
+/// assert_eq!(colformat!("{} | {:?}", alice.name, alice.age), "Alice | 30\n");
+/// assert_eq!(colformat!("{} | {}", alice, "online"), "Name: Alice | online\nAge: 30     |       \n");
+/// ```
+#[macro_export]
+macro_rules! colformat {
+    // Borrow each item in turn, so that temporaries live until the formatter has been used by the body
+    (@bind [$($literal:literal)?] $fmt:expr; $formatter:ident => $body:expr; [$($bound:ident)*]; $head:expr, $($rest:expr,)*) => {
+        match &$head {
+            item => $crate::colformat!(@bind [$($literal)?] $fmt; $formatter => $body; [$($bound)* item]; $($rest,)*),
+        }
+    };
+    (@bind [$($literal:literal)?] $fmt:expr; $formatter:ident => $body:expr; [$($item:ident)*];) => {
+        {
+            $crate::colformat!(@check [$($literal)?]; 0; $($item)*);
+            let fmt_str = $fmt;
+            let mut items = Vec::new();
+
+            // Find the format type of each column (e.g., "{}", "{:?}", "{:dur}") with the shared parser
+            let format_types = fmt_str.column_types();
+
+            // Create FormattableItems providing the representation each column asks for
+            #[allow(unused_imports)]
+            use $crate::macro_support::{
+                ViaBoth as _, ViaCell as _, ViaDebug as _, ViaNested as _, ViaDisplay as _, ViaDuration as _, ViaDurationFallback as _,
+                ViaFallback as _, ViaNanos as _, ViaSize as _, ViaSizeFallback as _, ViaTime as _, ViaEpochSeconds as _, ViaTimeFallback as _, ViaVerbatim as _,
+                ViaVerbatimFallback as _, ViaDisplayLines as _, ViaDebugLines as _, ViaLinesFallback as _, ViaBytes as _, ViaBytesFallback as _,
+                ViaBar as _, ViaBarFallback as _,
+            };
+            use $crate::macro_support::LayoutSource as _;
+            let mut format_types = format_types.into_iter();
+            $(
+                let wrap = $crate::macro_support::Wrap($item);
+                match format_types.next() {
+                    Some($crate::macro_support::FormatType::Duration) => items.push((&&&wrap).duration_item()),
+                    Some($crate::macro_support::FormatType::Size) => items.push((&&wrap).size_item()),
+                    Some($crate::macro_support::FormatType::Time) => items.push((&&&wrap).time_item()),
+                    Some($crate::macro_support::FormatType::Verbatim) => items.push((&&wrap).verbatim_item()),
+                    Some($crate::macro_support::FormatType::Hex) => items.push((&&wrap).bytes_item()),
+                    Some($crate::macro_support::FormatType::Bar) => items.push((&&wrap).bar_item()),
+                    Some($crate::macro_support::FormatType::Lines) => items.push((&&&wrap).display_lines_item()),
+                    Some($crate::macro_support::FormatType::DebugLines) => items.push((&&&wrap).debug_lines_item()),
+                    Some(_) => items.push((&&&&&wrap).formattable_item()),
+                    None => {}
+                }
+            )*
+
+            // Create the formatter and hand it to the body
+            let $formatter = fmt_str.column_formatter(items);
+            $body
+        }
+    };
+    // Check each item against its column when the format string is a literal, so that an item which does not fit
+    // fails to compile
+    (@check [$fmt:literal]; $index:expr; $head:ident $($rest:ident)*) => {
+        #[allow(unused_imports)]
+        use $crate::macro_support::{FitsCell as _, FitsDuration as _, FitsNested as _};
+        (&&$crate::macro_support::Check::<
+            $crate::macro_support::Spec<{ $crate::macro_support::column_kind::<{ $fmt.len() }>($fmt, $index) }>,
+            _,
+        >::new($head))
+            .fits();
+        $crate::colformat!(@check [$fmt]; $index + 1; $($rest)*);
+    };
+    (@check [$($fmt:literal)?]; $index:expr; $($item:ident)*) => {};
+    ($fmt:literal, $($item:expr),* $(,)?) => {
+        $crate::colformat!(@bind [$fmt] $fmt; formatter => formatter.to_string(); []; $($item,)*)
+    };
+    ($fmt:expr, $($item:expr),* $(,)?) => {
+        $crate::colformat!(@bind [] $fmt; formatter => formatter.to_string(); []; $($item,)*)
+    };
+}
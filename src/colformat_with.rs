@@ -0,0 +1,24 @@
+//! Provides the `colformat_with!` macro for formatting data in columns laid out by a `ColumnTemplate`.
+//!
+//! This module contains the `colformat_with!` macro, which is to `colformat!` what a parsed template
+//! is to a format string: the items are bound and given the representation their column asks for in
+//! the same way, but the columns come from the template, which is never parsed again.
+
+/// Macro for formatting items in columns into a `String`, laid out by a `ColumnTemplate`.
+///
+/// The template can be a `ColumnTemplate`, or anything dereferencing to one, such as a `static` `LazyLock`.
+///
+/// # Examples
+///
+/// ```
+/// use colprint::{ColumnTemplate, colformat_with};
+///
+/// let template = ColumnTemplate::parse("{:>4} | {:?}").unwrap();
+/// assert_eq!(colformat_with!(template, 7, "seven"), "   7 | \"seven\"\n");
+/// ```
+#[macro_export]
+macro_rules! colformat_with {
+    ($template:expr, $($item:expr),* $(,)?) => {
+        $crate::colformat!(@bind [] &$template; formatter => formatter.to_string(); []; $($item,)*)
+    };
+}
@@ -0,0 +1,113 @@
+//! Defines the terminal colours available to styles.
+//!
+//! This module contains the `Color` enum, which a `Style` uses for its foreground and background:
+//! one of the eight standard ANSI colours, a colour of the 256-colour palette, or a 24-bit colour.
+//!
+//! Colours can be parsed from configuration with `Color::parse`, from a common name such as `red` or `grey`, or from
+//! a hex string such as `#ff8800` or `#f80`.
+
+/// A terminal colour.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Color {
+    /// Black.
+    Black,
+    /// Red.
+    Red,
+    /// Green.
+    Green,
+    /// Yellow.
+    Yellow,
+    /// Blue.
+    Blue,
+    /// Magenta.
+    Magenta,
+    /// Cyan.
+    Cyan,
+    /// White.
+    White,
+    /// A colour of the 256-colour palette, by index.
+    Indexed(u8),
+    /// A 24-bit colour, by its red, green, and blue components.
+    Rgb(u8, u8, u8),
+}
+
+impl Color {
+    /// Parse a colour from a common name, such as `red` or `grey`, or from a hex string, such as `#ff8800` or `#f80`.
+    #[must_use]
+    #[inline]
+    pub fn parse(text: &str) -> Option<Self> {
+        let trimmed = text.trim();
+        if trimmed.starts_with('#') {
+            Self::from_hex(trimmed)
+        } else {
+            Self::from_name(trimmed)
+        }
+    }
+
+    /// Find the colour with a common name, ignoring case, such as `red`, `Magenta`, or `grey`.
+    ///
+    /// The eight ANSI colours are known by their names, along with `purple` for magenta and `gray` or `grey` for the
+    /// bright black of the palette.
+    #[must_use]
+    #[inline]
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name.to_ascii_lowercase().as_str() {
+            "black" => Some(Self::Black),
+            "red" => Some(Self::Red),
+            "green" => Some(Self::Green),
+            "yellow" => Some(Self::Yellow),
+            "blue" => Some(Self::Blue),
+            "magenta" | "purple" => Some(Self::Magenta),
+            "cyan" => Some(Self::Cyan),
+            "white" => Some(Self::White),
+            "gray" | "grey" => Some(Self::Indexed(8)),
+            _ => None,
+        }
+    }
+
+    /// Parse a 24-bit colour from a hex string of six or three digits, with or without a leading `#`.
+    #[must_use]
+    #[inline]
+    pub fn from_hex(hex: &str) -> Option<Self> {
+        let digits = hex.strip_prefix('#').unwrap_or(hex);
+        if !digits.is_ascii() {
+            return None;
+        }
+        let component = |range: &str| u8::from_str_radix(range, 16).ok();
+        match digits.len() {
+            6 => Some(Self::Rgb(
+                component(digits.get(0..2)?)?,
+                component(digits.get(2..4)?)?,
+                component(digits.get(4..6)?)?,
+            )),
+            3 => {
+                let doubled = |range: &str| component(range).map(|value| value * 17);
+                Some(Self::Rgb(
+                    doubled(digits.get(0..1)?)?,
+                    doubled(digits.get(1..2)?)?,
+                    doubled(digits.get(2..3)?)?,
+                ))
+            }
+            _ => None,
+        }
+    }
+
+    /// The SGR parameters selecting this colour for the foreground, or for the background.
+    pub(crate) fn sgr(self, background: bool) -> String {
+        let base: u8 = if background { 40 } else { 30 };
+        let standard = |offset: u8| (base + offset).to_string();
+        match self {
+            Self::Black => standard(0),
+            Self::Red => standard(1),
+            Self::Green => standard(2),
+            Self::Yellow => standard(3),
+            Self::Blue => standard(4),
+            Self::Magenta => standard(5),
+            Self::Cyan => standard(6),
+            Self::White => standard(7),
+            Self::Indexed(index) => format!("{};5;{index}", base + 8),
+            Self::Rgb(red, green, blue) => format!("{};2;{red};{green};{blue}", base + 8),
+        }
+    }
+}
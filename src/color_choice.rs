@@ -0,0 +1,67 @@
+//! Defines when styled output is emitted.
+//!
+//! This module contains the `ColorChoice` enum, which decides whether styles are written as
+//! escape sequences:
+//! - `Auto`: Only when standard output is a terminal and `NO_COLOR` is not set (the default).
+//! - `Always`: Always, for example when piping into a pager which understands colours.
+//! - `Never`: Never, giving plain text output.
+//!
+//! Styled text is written through `ColorChoice::paint`, so that this decision is made in one place.
+//!
+//! On Windows consoles, enabling the `terminal` feature lets `Auto` switch on escape sequence
+//! support, falling back to plain text output when the console does not allow it.
+
+use std::{
+    env,
+    io::{IsTerminal as _, stdout},
+};
+
+use crate::{Style, terminal};
+
+/// Policy for emitting styled output.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ColorChoice {
+    /// Style output only when writing to a terminal and `NO_COLOR` is not set.
+    #[default]
+    Auto,
+    /// Always style output.
+    Always,
+    /// Never style output.
+    Never,
+}
+
+impl ColorChoice {
+    /// Wrap text in a style, if styles should be emitted and the style is not empty.
+    pub(crate) fn paint(self, style: Style, text: &str) -> String {
+        if style == Style::new() || !self.enabled() {
+            return text.to_owned();
+        }
+        format!("{}{text}{}", style.render_sgr(), style.render_reset())
+    }
+
+    /// Check whether styles should be emitted.
+    pub(crate) fn enabled(self) -> bool {
+        let no_color = env::var_os("NO_COLOR").is_some_and(|value| !value.is_empty());
+        self.enabled_with(no_color, stdout().is_terminal(), terminal::enable_styles)
+    }
+
+    /// Check whether styles should be emitted, given whether `NO_COLOR` is set, whether standard output is a
+    /// terminal, and a callback preparing the terminal for escape sequences and returning whether it can display them.
+    ///
+    /// The callback is only called when the terminal is used, and `Auto` falls back to plain text when it fails.
+    #[doc(hidden)]
+    #[must_use]
+    #[inline]
+    pub fn enabled_with<F: FnOnce() -> bool>(self, no_color: bool, is_terminal: bool, enable_styles: F) -> bool {
+        match self {
+            Self::Auto => !no_color && is_terminal && enable_styles(),
+            Self::Always => {
+                // Best effort, as the output may not be going to this console
+                let _enabled = enable_styles();
+                true
+            }
+            Self::Never => false,
+        }
+    }
+}
@@ -0,0 +1,104 @@
+//! Defines the colour scales used to shade numeric cells.
+//!
+//! This module contains the `ColorScale` struct, which maps a value normalised to `0..=1` onto
+//! a background colour by blending linearly from a low colour to a high colour. Backgrounds are
+//! given as `Style`s with 24-bit colours, or with the nearest colour of the 256-colour palette for
+//! terminals without true colour support.
+//!
+//! Values which are not finite, such as `NaN` and infinities, have no place on the scale and are
+//! given a style of their own instead.
+
+use crate::{Color, Style};
+
+/// A scale of background colours for values between zero and one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct ColorScale {
+    /// The red, green, and blue components of the background for zero.
+    pub low: [u8; 3],
+    /// The red, green, and blue components of the background for one.
+    pub high: [u8; 3],
+    /// Whether backgrounds are written as 24-bit colours, rather than from the 256-colour palette.
+    pub true_color: bool,
+    /// The style of values which are not finite, such as `NaN` and infinities.
+    pub non_finite: Style,
+}
+
+impl ColorScale {
+    /// A scale from green for the lowest values to red for the highest.
+    pub const GREEN_TO_RED: Self = Self::new([40, 130, 60], [180, 40, 40]);
+
+    /// Construct a new `ColorScale` blending from the low colour to the high colour.
+    ///
+    /// Non-finite values are shown in bold magenta.
+    #[must_use]
+    #[inline]
+    pub const fn new(low: [u8; 3], high: [u8; 3]) -> Self {
+        Self {
+            low,
+            high,
+            true_color: true,
+            non_finite: Style::new().fg(Color::Magenta).bold(),
+        }
+    }
+
+    /// Set whether backgrounds are written as 24-bit colours, or else from the 256-colour palette.
+    #[must_use]
+    #[inline]
+    pub const fn with_true_color(mut self, true_color: bool) -> Self {
+        self.true_color = true_color;
+        self
+    }
+
+    /// Set the style of values which are not finite, such as `NaN` and infinities.
+    #[must_use]
+    #[inline]
+    pub const fn with_non_finite(mut self, style: Style) -> Self {
+        self.non_finite = style;
+        self
+    }
+
+    /// The red, green, and blue components of the background for a value, which is clamped to `0..=1`.
+    #[must_use]
+    #[inline]
+    #[expect(
+        clippy::as_conversions,
+        clippy::cast_possible_truncation,
+        clippy::cast_sign_loss,
+        reason = "Blended components are rounded and always lie between the two colours."
+    )]
+    pub fn rgb(&self, value: f64) -> [u8; 3] {
+        let fraction = if value.is_nan() { 0.0 } else { value.clamp(0.0, 1.0) };
+        let mut rgb = self.low;
+        for (component, &high) in rgb.iter_mut().zip(&self.high) {
+            let low = f64::from(*component);
+            *component = (f64::from(high) - low).mul_add(fraction, low).round() as u8;
+        }
+        rgb
+    }
+
+    /// The style with the background for a value, or the non-finite style.
+    #[must_use]
+    #[inline]
+    pub fn style(&self, value: f64) -> Style {
+        if !value.is_finite() {
+            return self.non_finite;
+        }
+
+        let [red, green, blue] = self.rgb(value);
+        if self.true_color {
+            return Style::new().bg(Color::Rgb(red, green, blue));
+        }
+
+        // Map each component onto the six levels of the palette's colour cube
+        let level = |component: u8| u8::try_from((u16::from(component) * 5 + 127).div_euclid(255)).unwrap_or(5);
+        Style::new().bg(Color::Indexed(16 + 36 * level(red) + 6 * level(green) + level(blue)))
+    }
+
+    /// The SGR sequence which switches on the background for a value, or the non-finite style.
+    #[must_use]
+    #[inline]
+    pub fn render_sgr(&self, value: f64) -> String {
+        self.style(value).render_sgr()
+    }
+}
@@ -2,7 +2,8 @@
 //!
 //! This module contains the implementation of the `colprint!` macro, which is the primary
 //! interface for users of this crate. The macro processes format strings to determine column
-//! layouts and formatting options, then delegates to the `ColumnFormatter` for actual rendering.
+//! layouts and formatting options, then delegates to `colformat!` and the `ColumnFormatter` for
+//! actual rendering.
 //!
 //! The macro supports various formatting options including regular Display format (`{}`),
 //! Debug format (`{:?}`), and Pretty Debug format (`{:#?}`), with optional width specifications
@@ -14,75 +15,154 @@
 /// - `{}` for regular Display
 /// - `{:?}` for Debug
 /// - `{:#?}` for pretty Debug
+/// - `{:dur}` for a human-readable duration (a `Duration`, or an integer number of nanoseconds)
+/// - `{:size}` for a human-readable byte size (an integer number of bytes)
+/// - `{:time(%Y-%m-%d %H:%M)}` for a UTC timestamp following a strftime-like pattern (a `SystemTime`, or an
+///   integer number of seconds since the Unix epoch), or `{:time}` for an ISO 8601 timestamp
+/// - `{:raw}` for pre-rendered text (a `String` or `&str`), inserted without any quoting or escaping
 ///
-/// You can also specify a width for each column by adding a colon and a number after the format:
-/// - `{:80}` for Display with width 80
-/// - `{:?:60}` for Debug with width 60
-/// - `{:#?:100}` for pretty Debug with width 100
+/// Items only need the trait their column asks for, so a type implementing only `Debug` can be
+/// printed in `{:?}` and `{:#?}` columns. When the format string is a literal, it is also read when
+/// the macro is compiled, so an item which does not fit its `{}`, `{:?}`, `{:dur}`, or `{:size}` column
+/// fails to compile, with an error naming the spec of the column and the missing trait: a `Debug`-only
+/// type in a `{}` column is reported as not implementing `Display`, as required by
+/// `Check::<'_, DisplaySpec, T>::fits`.
+///
+/// A format string built at run time is only read when the items are laid out. In a `{}` column a
+/// `Debug`-only type then falls back to its `Debug` output, an item implementing neither trait is
+/// printed as `<not formattable>`, and a value which cannot be humanized is printed as a placeholder
+/// such as `<not a duration>`. In either case, items past the last column are left out.
+///
+/// Ending a format with `n` (e.g. `{:n}` or `{:?n}`) inserts a `,` every three digits of
+/// standalone numbers, so `count=6789012` is printed as `count=6,789,012`.
+///
+/// Inside the braces, specifiers follow the `std::fmt` grammar, so each value can be given a fill,
+/// alignment, sign, minimum width, and precision exactly as with `format!`:
+/// - `{:.3}` for Display with three decimal places
+/// - `{:>8.2?}` for Debug right-aligned to at least 8 characters, with two decimal places
+///
+/// You can also specify a width for each column by adding a colon and a number after the closing brace:
+/// - `{}:80` for Display with width 80
+/// - `{:?}:60` for Debug with width 60
+/// - `{:#?}:100` for pretty Debug with width 100
+/// - `{:.3}:12` for Display with three decimal places, in a column 12 characters wide
+///
+/// The width inside the braces is the minimum width of each value, while the width after them is the
+/// width of the whole column.
+///
+/// Ending the width with `%` makes it a percentage of the total, maximum, or terminal width,
+/// such as `{}:40%`. Percentages are rounded down and scaled back together if they leave no
+/// room for the separators.
+///
+/// A priority can follow the format (and width) with an exclamation mark and a number:
+/// - `{}!2` for Display with priority 2
+/// - `{:?}:60!5` for Debug with width 60 and priority 5
+///
+/// When the formatter uses `Overflow::DropColumns`, lower priority columns are hidden first.
+///
+/// A truncation side can follow the priority with a tilde and `l`, `m`, or `r`:
+/// - `{}:40~l` keeps the end of over-long lines, such as the file name of a path
+/// - `{}:40~m` keeps the start and end of over-long lines
+///
+/// Ending a format with `@` and a number anchors the column at that character offset from the
+/// left margin, like a tab stop:
+/// - `{} {@40}` starts the second column at offset 40, however wide the first column is
+/// - `{:?@40}` anchors a Debug column at offset 40
 ///
 /// Any text between format specifications will be used as column separators:
 /// - `{} | {}` will print a pipe with spaces between columns
 /// - `{}  {}` will print two spaces between columns
 /// - `{:?} -> {:#?}` will print an arrow between columns
 ///
+/// Items are borrowed for the whole print, so temporaries such as `format_args!` values can
+/// be passed directly: `colprint!("{} | {}", format_args!("{host}:{port}"), status)`.
+///
+/// Write `{{` and `}}` for literal braces in separators. Other braces which do not form a
+/// specifier, such as those in `{"key": 1}`, are printed as they are, as is a specifier left
+/// open at the end of the format string. Use `ColumnFormatter::try_new` to reject those instead.
+///
+/// Every line is printed with a newline, so no blank line follows the columns. Use `colformat!`
+/// to collect the lines in a `String` instead.
+///
+/// Standard output is locked once for the whole block, so lines printed by other threads never
+/// land between its lines.
+///
 /// # Examples
 ///
 /// ```
+/// use colprint::{colformat, colprint, doctest_support::Person};
+///
+/// let alice = Person::ALICE;
+/// let bob = Person::BOB;
+///
 /// // Basic usage with Display
-/// colprint!("{}{}", item1, item2);
+/// colprint!("{}{}", alice, bob);
+/// assert_eq!(colformat!("{}{}", alice, bob), "Name: AliceName: Bob\nAge: 30    Age: 25  \n");
 ///
 /// // Using Debug format with separators
-/// colprint!("{:?} | {:?}", item1, item2);
+/// assert_eq!(colformat!("{:?} | {:?}", alice.name, bob.age), "\"Alice\" | 25\n");
 ///
-/// // Using pretty Debug with specific widths and separators
-/// colprint!("{:#?:80} || {:#?:60}", item1, item2);
+/// // Using Display with specific widths and separators
+/// assert_eq!(colformat!("{}:8 || {}:4", alice.name, bob.age), "Alice    || 25  \n");
 ///
 /// // Mixed formats with decorative separators
-/// colprint!("{} -> {:?} => {:#?}", item1, item2, item3);
+/// assert_eq!(
+///     colformat!("{} -> {:?} => {:#?}", alice.name, bob.name, alice.age),
+///     "Alice -> \"Bob\" => 30\n"
+/// );
+/// ```
+///
+/// A type implementing only `Debug` can be printed in `{:?}` columns, but not in `{}` columns:
+///
+/// ```
+/// use colprint::colformat;
+///
+/// #[derive(Debug)]
+/// struct Point(i32, i32);
+///
+/// assert_eq!(colformat!("{:?}", Point(1, 2)), "Point(1, 2)\n");
+/// ```
+///
+/// ```compile_fail,E0277
+/// use colprint::colformat;
+///
+/// #[derive(Debug)]
+/// struct Point(i32, i32);
+///
+/// // `Point` doesn't implement `std::fmt::Display`
+/// colformat!("{}", Point(1, 2));
+/// ```
+///
+/// `{:dur}` and `{:size}` columns of a literal format string only accept the values they can humanize,
+/// so text in them fails to compile rather than being printed as a placeholder:
+///
+/// ```
+/// use colprint::colformat;
+/// use std::time::Duration;
+///
+/// assert_eq!(colformat!("{:dur} {:size}", Duration::from_millis(1500), 2048_u64), "1.5 s 2 KiB\n");
+/// ```
+///
+/// ```compile_fail,E0277
+/// use colprint::colformat;
+///
+/// // `&str` can't be converted into a `Duration` or a number of nanoseconds
+/// colformat!("{:dur}", "soon");
+/// ```
+///
+/// ```compile_fail,E0277
+/// use colprint::colformat;
+///
+/// // `&str` can't be converted into a number of bytes
+/// colformat!("{:size}", "big");
 /// ```
 #[macro_export]
 macro_rules! colprint {
+    // Build the formatter with colformat!, which also borrows the items, and stream the lines to standard output
+    ($fmt:literal, $($item:expr),* $(,)?) => {
+        $crate::colformat!(@bind [$fmt] $fmt; formatter => $crate::macro_support::print_locked(&formatter); []; $($item,)*)
+    };
     ($fmt:expr, $($item:expr),* $(,)?) => {
-        {
-            let fmt_str = $fmt;
-            let mut items = Vec::new();
-            let mut format_specs = Vec::new();
-
-            // Extract all format specifiers (e.g., "{}", "{:?}", "{:#?}")
-            let mut in_format = false;
-            let mut start = 0;
-
-            for (i, c) in fmt_str.char_indices() {
-                if c == '{' && !in_format {
-                    // Start of a format specifier
-                    start = i;
-                    in_format = true;
-                } else if c == '}' && in_format {
-                    // End of a format specifier
-                    let end = i + 1;
-                    let fmt_spec = &fmt_str[start..end];
-                    format_specs.push(fmt_spec);
-                    in_format = false;
-                }
-            }
-
-            // Create FormattableItems based on format specs
-            let mut idx = 0;
-            $(
-                if idx < format_specs.len() {
-                    let spec = format_specs[idx];
-                    if spec.contains(":#?") || spec.contains(":?") {
-                        items.push($crate::FormattableItem::DebugItem(&$item));
-                    } else {
-                        items.push($crate::FormattableItem::DisplayItem(&$item));
-                    }
-                    idx += 1;
-                }
-            )*
-
-            // Create and use the formatter
-            let formatter = $crate::ColumnFormatter::new(fmt_str, items);
-            println!("{}", formatter);
-        }
+        $crate::colformat!(@bind [] $fmt; formatter => $crate::macro_support::print_locked(&formatter); []; $($item,)*)
     };
 }
@@ -0,0 +1,58 @@
+//! Provides the `colprint_kv!` macro for printing key/value pairs joined by dotted leaders.
+//!
+//! This module contains the `colprint_kv!` macro, which lays out pairs as a two-column `Table`
+//! for settings dumps and similar screens. Each key is followed by a run of dots reaching past
+//! the widest key, so every value starts in the same column:
+//!
+//! ```text
+//! host ........ example.com
+//! port ........ 8080
+//! user_name ... admin
+//! ```
+
+/// Macro for printing key/value pairs with dotted leaders between them.
+///
+/// Keys can be anything implementing `Display`. Values are formatted with `Display` by default,
+/// or with the specifier given before the pairs, such as `{:?}` or `{:#?}`.
+///
+/// # Examples
+///
+/// ```
+/// use colprint::colprint_kv;
+///
+/// let host = "example.com";
+/// let port = 8080;
+///
+/// // Values formatted with Display
+/// colprint_kv!([("host", host), ("port", port)]);
+///
+/// // Values formatted with Debug
+/// colprint_kv!("{:?}", [("host", host), ("port", port)]);
+/// ```
+#[macro_export]
+macro_rules! colprint_kv {
+    ([$(($key:expr, $value:expr)),* $(,)?]) => {
+        $crate::colprint_kv!("{}", [$(($key, $value)),*])
+    };
+    ($value_fmt:expr, [$(($key:expr, $value:expr)),* $(,)?]) => {
+        {
+            // Leave room for a space and a leader of at least three dots after the widest key
+            let keys = [$(format!("{} ", $key)),*];
+            let key_width = keys.iter().map(|key| key.chars().count()).max().unwrap_or(0) + 3;
+            let mut table = $crate::Table::new(&format!("{{}}:{key_width} {}", $value_fmt)).with_fill(0, '.');
+
+            #[allow(unused_imports)]
+            use $crate::macro_support::{ViaBoth as _, ViaCell as _, ViaDebug as _, ViaDisplay as _, ViaFallback as _, ViaNested as _};
+            let mut keys = keys.iter();
+            $(
+                if let Some(key) = keys.next() {
+                    let value = &$value;
+                    let wrap = $crate::macro_support::Wrap(&value);
+                    table.push_row(&[$crate::FormattableItem::DisplayItem(key), (&&&&&wrap).formattable_item()]);
+                }
+            )*
+
+            $crate::macro_support::print_locked(&table);
+        }
+    };
+}
@@ -0,0 +1,28 @@
+//! Provides the `colprint_with!` macro for printing data in columns laid out by a `ColumnTemplate`.
+//!
+//! This module contains the `colprint_with!` macro, which prints the rows `colformat_with!` formats,
+//! so that call sites sharing a template always print the same layout.
+
+/// Macro for printing items in columns laid out by a `ColumnTemplate`.
+///
+/// The template can be a `ColumnTemplate`, or anything dereferencing to one, such as a `static` `LazyLock`.
+/// Each item is given the representation its column asks for, as with `colprint!`.
+///
+/// # Examples
+///
+/// ```
+/// use std::sync::LazyLock;
+///
+/// use colprint::{ColumnTemplate, colprint_with};
+///
+/// static TEMPLATE: LazyLock<ColumnTemplate> = LazyLock::new(|| ColumnTemplate::parse("{:>8} | {} | {:.2}:12").unwrap());
+///
+/// colprint_with!(TEMPLATE, 42, "answer", 0.5);
+/// colprint_with!(TEMPLATE, 7, "lucky", 0.25);
+/// ```
+#[macro_export]
+macro_rules! colprint_with {
+    ($template:expr, $($item:expr),* $(,)?) => {
+        $crate::colformat!(@bind [] &$template; formatter => $crate::macro_support::print_locked(&formatter); []; $($item,)*)
+    };
+}
@@ -0,0 +1,25 @@
+//! Provides the `colrule!` macro for printing a divider as wide as a block of columns.
+//!
+//! This module contains the `colrule!` macro, which prints `ColumnFormatter::rule` on a line of its
+//! own, such as between a block and a summary below it.
+
+/// Macro for printing a horizontal rule as wide as the output of a `ColumnFormatter`.
+///
+/// The rule is printed on a line of its own, placed like the formatter's output.
+///
+/// # Examples
+///
+/// ```
+/// use colprint::{ColumnFormatter, colrule};
+///
+/// let formatter = ColumnFormatter::new("{} | {}", [&"name", &"Alice"]);
+/// print!("{formatter}");
+/// colrule!('-', formatter);
+/// assert_eq!(formatter.rule('-'), "------------");
+/// ```
+#[macro_export]
+macro_rules! colrule {
+    ($ch:expr, $formatter:expr $(,)?) => {
+        $crate::macro_support::print_locked(&format_args!("{}\n", $formatter.rule($ch)))
+    };
+}
@@ -1,20 +1,224 @@
 //! Defines the format specification for individual columns.
 //!
 //! This module contains the `ColumnFormat` struct, which encapsulates the formatting rules
-//! for a single column, including the type of formatting to use (`Display`, `Debug`, or `PrettyDebug`),
-//! an optional width constraint, and an optional separator to print after the column.
+//! for a single column, including the type of formatting to use (`Display`, `Debug`, or `PrettyDebug`) and the
+//! `std::fmt` options applied to each value, the pattern of time columns, the bytes per line of hexdump columns, the scale and glyphs of progress bars,
+//! an optional width constraint (absolute or a percentage), optional digit grouping, the alignment of its content,
+//! optional alignments for the header and footer of a table, an optional separator to print after the column and which lines of a row show it, the column's priority when space runs out, an optional offset the column is anchored to, and the
+//! side from which over-long lines are truncated, whether only ASCII is written, whether its separator may be squeezed, the character
+//! used to pad its lines, the text of missing cells in table rows, its priority for absorbing wrapping, how its automatic width is derived,
+//! and an optional callback computing the replacement for over-long lines.
 //!
 //! `ColumnFormat` instances are typically created internally by parsing format strings
-//! and are used by the `ColumnFormatter` to control the output appearance.
+//! and are used by the `ColumnFormatter` to control the output appearance. They can be inspected
+//! through `ColumnFormatter::formats` when debugging a layout.
 
-use crate::format_type::FormatType;
+use std::{
+    fmt::{Debug, Formatter, Result as FmtResult},
+    sync::Arc,
+};
+
+use crate::{
+    Alignment, BarGlyphs, FormattableItem, Separator, SeparatorFill, Style, TruncateSide, WidthHeuristic, bar,
+    format_type::FormatType, group_digits::group_digits, hexdump, humanize, row_role::RowRole, slice::visible_width, timestamp,
+    value_spec::ValueSpec, width::Width,
+};
+
+/// Callback computing the text of an over-long line from the whole line and the width available.
+type Truncator = dyn Fn(&str, usize) -> String + Send + Sync;
 
 /// Describes the format for a single column.
+#[derive(Clone)]
+#[non_exhaustive]
+#[expect(
+    clippy::struct_excessive_bools,
+    reason = "Each flag switches an independent part of the column's rendering."
+)]
 pub struct ColumnFormat {
-    /// The type of formatting to use
+    /// The type of formatting to use.
     pub format_type: FormatType,
-    /// Optional width for the column
-    pub width: Option<usize>,
-    /// Optional separator to print after this column
+    /// Optional name of the custom format requested by the specifier, such as `money` for `{:money}`.
+    pub custom_name: Option<String>,
+    /// Optional `std::fmt` options applied to each value, such as the precision and minimum width of `{:>8.3}`.
+    pub value_spec: Option<ValueSpec>,
+    /// Optional strftime-like pattern for rendering times, such as `%Y-%m-%d` for `{:time(%Y-%m-%d)}`.
+    pub time_pattern: Option<String>,
+    /// The number of bytes on each line of hexdumps, such as `8` for `{:hex(8)}`.
+    pub bytes_per_row: usize,
+    /// The value of a full progress bar, such as `100` for `{:bar(100)}`.
+    pub bar_scale: f64,
+    /// The characters progress bars are drawn with.
+    pub bar_glyphs: BarGlyphs,
+    /// Optional width for the column, in characters or as a percentage of the available width.
+    pub width: Option<Width>,
+    /// Optional separator inserted every three digits of standalone numbers.
+    pub group_digits: Option<char>,
+    /// Alignment of the content within the column.
+    pub alignment: Alignment,
+    /// Whether the alignment was chosen explicitly, by a suffix or a builder, rather than by default.
+    pub explicit_alignment: bool,
+    /// Optional alignment of the header of a table, instead of the column's alignment.
+    pub header_alignment: Option<Alignment>,
+    /// Optional alignment of the footer of a table, instead of the column's alignment.
+    pub footer_alignment: Option<Alignment>,
+    /// Optional separator to print after this column.
     pub separator: Option<String>,
+    /// Which lines of a multi-line row show the separator.
+    pub separator_fill: SeparatorFill,
+    /// Which piece of the separator is written on each line of a row.
+    pub positioned_separator: Separator,
+    /// Importance of the column; lower priority columns are dropped first when space runs out.
+    pub priority: u8,
+    /// Optional character offset from the left margin at which the column starts.
+    pub anchor: Option<usize>,
+    /// Which part of an over-long line is cut away.
+    pub truncate_side: TruncateSide,
+    /// Text marking where an over-long line is cut, unless the cut is at the end.
+    pub truncate_marker: String,
+    /// Style of the truncation marker in lines which are already styled.
+    pub truncate_marker_style: Style,
+    /// Whether only ASCII is written, drawing progress bars with `BarGlyphs::ASCII` unless their glyphs are ASCII,
+    /// and writing microseconds as `us`.
+    pub ascii: bool,
+    /// Whether the spaces around the separator may be trimmed when space runs out.
+    pub squeezable: bool,
+    /// Character used to pad lines shorter than the column.
+    pub fill: char,
+    /// Text of the cell in table rows with no item for the column.
+    pub default_cell: String,
+    /// Whether `Debug` output in the column is syntax highlighted.
+    pub highlight: bool,
+    /// Whether line breaks in the rendered text are written as the escapes `\n` and `\r`, keeping each cell on one line.
+    pub escape_newlines: bool,
+    /// Optional priority for being narrowed and word-wrapped; higher priorities wrap first.
+    pub wrap_priority: Option<u8>,
+    /// How the width is derived from the lines when no width is given.
+    pub width_heuristic: WidthHeuristic,
+    /// Optional callback computing the text of over-long lines from the whole line and the width available,
+    /// instead of cutting them at the truncation side.
+    pub truncator: Option<Arc<Truncator>>,
+}
+
+impl ColumnFormat {
+    /// Render an item as text according to this column's format.
+    #[must_use]
+    #[inline]
+    pub fn render(&self, item: &FormattableItem<'_>) -> String {
+        let formatted = match (item, self.time_pattern.as_deref()) {
+            (&FormattableItem::TimeItem(time), Some(pattern)) => timestamp::format(time, pattern),
+            (&FormattableItem::Bytes(bytes), _) => hexdump::lines(bytes, self.bytes_per_row, None).join("\n"),
+            (&FormattableItem::BarItem(value), _) => self.bar(value, bar::DEFAULT_WIDTH),
+            (&FormattableItem::DurationItem(duration), _) => {
+                let text = humanize::duration(duration, self.ascii);
+                if let Some(value_spec) = self.value_spec.as_ref() {
+                    value_spec.pad(text)
+                } else {
+                    text
+                }
+            }
+            _ => self
+                .value_spec
+                .as_ref()
+                .map_or_else(|| item.render(self.format_type), |value_spec| value_spec.render(item)),
+        };
+        let grouped = match self.group_digits {
+            Some(separator) => group_digits(&formatted, separator),
+            None => formatted,
+        };
+        if self.escape_newlines && grouped.contains(['\n', '\r']) {
+            return grouped.replace('\r', "\\r").replace('\n', "\\n");
+        }
+        grouped
+    }
+
+    /// Use a separator whose piece depends on the line, measuring and writing its `middle` piece as the plain
+    /// separator and protecting it from squeezing.
+    #[expect(clippy::ref_patterns, reason = "Only the middle piece needs to be borrowed.")]
+    #[inline]
+    pub(crate) fn set_separator(&mut self, separator: Separator) {
+        if let Separator::PerPosition { ref middle, .. } = separator {
+            self.separator = Some(middle.clone());
+            self.squeezable = false;
+        }
+        self.positioned_separator = separator;
+    }
+
+    /// Draw a value as a progress bar of the given width, scaled by the value of a full bar.
+    #[must_use]
+    #[inline]
+    pub(crate) fn bar(&self, value: f64, width: usize) -> String {
+        let glyphs = if self.ascii && !self.bar_glyphs.is_ascii() {
+            BarGlyphs::ASCII
+        } else {
+            self.bar_glyphs
+        };
+        bar::render(value / self.bar_scale, width, glyphs)
+    }
+
+    /// The narrowest width in which the column can still show its decorations: the truncation marker, unless
+    /// lines are cut from the right, which draws no marker.
+    #[must_use]
+    #[inline]
+    pub(crate) fn min_width(&self) -> usize {
+        match self.truncate_side {
+            TruncateSide::Right => 0,
+            TruncateSide::Left | TruncateSide::Middle => visible_width(&self.truncate_marker),
+        }
+    }
+
+    /// The alignment of the column's lines in a row with the given role.
+    #[must_use]
+    #[inline]
+    pub(crate) fn alignment_for(&self, role: RowRole) -> Alignment {
+        self.role_alignment(role).unwrap_or(self.alignment)
+    }
+
+    /// The alignment given to rows with the given role in particular, if any, overriding the column's alignment.
+    #[must_use]
+    #[inline]
+    pub(crate) const fn role_alignment(&self, role: RowRole) -> Option<Alignment> {
+        match role {
+            RowRole::Header => self.header_alignment,
+            RowRole::Data => None,
+            RowRole::Footer => self.footer_alignment,
+        }
+    }
+}
+
+impl Debug for ColumnFormat {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.debug_struct("ColumnFormat")
+            .field("format_type", &self.format_type)
+            .field("custom_name", &self.custom_name)
+            .field("value_spec", &self.value_spec)
+            .field("time_pattern", &self.time_pattern)
+            .field("bytes_per_row", &self.bytes_per_row)
+            .field("bar_scale", &self.bar_scale)
+            .field("bar_glyphs", &self.bar_glyphs)
+            .field("width", &self.width)
+            .field("group_digits", &self.group_digits)
+            .field("alignment", &self.alignment)
+            .field("explicit_alignment", &self.explicit_alignment)
+            .field("header_alignment", &self.header_alignment)
+            .field("footer_alignment", &self.footer_alignment)
+            .field("separator", &self.separator)
+            .field("separator_fill", &self.separator_fill)
+            .field("positioned_separator", &self.positioned_separator)
+            .field("priority", &self.priority)
+            .field("anchor", &self.anchor)
+            .field("truncate_side", &self.truncate_side)
+            .field("truncate_marker", &self.truncate_marker)
+            .field("truncate_marker_style", &self.truncate_marker_style)
+            .field("ascii", &self.ascii)
+            .field("squeezable", &self.squeezable)
+            .field("fill", &self.fill)
+            .field("default_cell", &self.default_cell)
+            .field("highlight", &self.highlight)
+            .field("escape_newlines", &self.escape_newlines)
+            .field("wrap_priority", &self.wrap_priority)
+            .field("width_heuristic", &self.width_heuristic)
+            .field("truncator", &self.truncator.as_ref().map(|_| "Fn(&str, usize) -> String"))
+            .finish()
+    }
 }
@@ -5,18 +5,54 @@
 //! - Applying the appropriate formatting to each item.
 //! - Handling column width calculations (both automatic and specified).
 //! - Aligning and printing items in columns with proper separators.
-//! - Managing multi-line content in columns.
+//! - Managing multi-line content in columns, word-wrapping narrowed columns when asked to.
+//! - Placing the whole block within a wider target width.
 //!
 //! The `ColumnFormatter` serves as the engine behind the `colprint!` macro, translating
 //! high-level formatting directives into properly formatted columnar output.
 
 use std::{
+    borrow::Cow,
+    cell::Cell as Counter,
     cmp::min,
-    fmt::{self, Display, Formatter, Result as FmtResult},
+    collections::HashMap,
+    fmt::{self, Debug, Display, Formatter, Result as FmtResult},
     io::{self, Write},
+    iter,
+    ops::Range,
+    sync::Arc,
 };
 
-use crate::{FormattableItem, column_format::ColumnFormat, format_part::FormatPart, format_type::FormatType};
+#[cfg(feature = "unicode")]
+use crate::Normalization;
+#[cfg(feature = "bidi")]
+use crate::bidi;
+use crate::{
+    Accessibility, Alignment, AnchorPolicy, BarGlyphs, BlockAlign, ColorChoice, ColumnFormat, Dimensions, EnvConfig, FitError,
+    FormatError, FormattableItem, HScroll, LineEnding, Overflow, Prepared, RenderOptions, Separator, SeparatorFill, Squeeze,
+    Strictness, Style, TruncateSide, WidthHeuristic, WrapPolicy,
+    format_part::FormatPart,
+    format_type::FormatType,
+    hexdump,
+    highlight::highlight_debug,
+    panic_guard,
+    parser::{normalize_spec, parse_parts, split_anchor, split_grouping, unterminated_spec},
+    reader,
+    row_role::RowRole,
+    slice::{ESCAPE, clean_invisible, content_width, slice, split, visible_width},
+    style::RESET,
+    terminal, timestamp,
+    value_spec::ValueSpec,
+    width::{self, Width},
+    width_resolution::WidthResolution,
+    wrap::wrap,
+};
+
+/// The range of output lines covering the whole layout.
+const ALL_LINES: Range<usize> = 0..usize::MAX;
+
+/// The cell rendered by a strict formatter in place of a mismatched item.
+const MISMATCH: &str = "<format mismatch>";
 
 /// A formatter for creating columnar output.
 pub struct ColumnFormatter<'a> {
@@ -24,99 +60,1101 @@ pub struct ColumnFormatter<'a> {
     formats: Vec<ColumnFormat>,
     /// The items to format.
     items: Vec<FormattableItem<'a>>,
+    /// Optional maximum width of each output line.
+    max_width: Option<usize>,
+    /// Optional exact width shared between the columns and separators.
+    total_width: Option<usize>,
+    /// The layout policies.
+    options: RenderOptions,
+    /// Byte offset of a specifier left open at the end of the format string, if any.
+    unterminated: Option<usize>,
+    /// Optional width the block is aligned within, instead of the terminal width.
+    block_width: Option<usize>,
+    /// Optional Unicode normalization applied to each cell before measurement.
+    #[cfg(feature = "unicode")]
+    normalization: Option<Normalization>,
+    /// Optional text framing the left and right sides of every line.
+    frame: Option<(String, String)>,
+    /// When styles, such as highlighting, are emitted.
+    color: ColorChoice,
+    /// How omitted content is signalled.
+    accessibility: Accessibility,
+    /// Pairs of lines of the first two columns which land on the same row, ordered on both sides.
+    row_links: Vec<(usize, usize)>,
+    /// How words wider than their column are split when wrapping.
+    wrap_policy: WrapPolicy,
+    /// Handlers rendering the custom formats, by name.
+    custom_formats: HashMap<&'static str, Box<CustomFormat>>,
+    /// Optional glyph, with its style, drawn in the last column of lines clipped on the right.
+    overflow_indicator: Option<(char, Style)>,
+    /// Style drawn around separators and the sides of the frame.
+    separator_style: Style,
+    /// Style drawn around the lines added below a cell to match a taller neighbour.
+    padding_style: Style,
+    /// Whether cells whose formatting panics are rendered as error cells instead of unwinding.
+    catch_panics: bool,
+    /// The number of cells whose formatting panicked during the latest render.
+    panicked_cells: Counter<usize>,
+    /// Whether cell lines are aligned and truncated following the direction of their text.
+    #[cfg(feature = "bidi")]
+    bidi: bool,
+    /// The terminator of each line written by `write_to`.
+    line_ending: LineEnding,
+    /// The settings the environment can seed which were given explicitly.
+    explicit: Explicit,
+}
+
+/// Handler rendering a custom format from the `Display` text of an item.
+type CustomFormat = dyn Fn(&str) -> String;
+
+/// Which of the settings the environment can seed were given explicitly, by a builder, so options which do not read
+/// the environment leave them as they are.
+#[derive(Debug, Default, Clone, Copy)]
+#[expect(clippy::struct_excessive_bools, reason = "Each flag tracks an independent setting.")]
+struct Explicit {
+    /// Whether the maximum width was given.
+    max_width: bool,
+    /// Whether the color choice was given.
+    color: bool,
+    /// Whether the accessibility was given.
+    accessibility: bool,
+    /// Whether the truncation marker was given.
+    truncate_marker: bool,
+    /// Whether the choice to only write ASCII was given.
+    ascii: bool,
 }
 
-impl<'a> ColumnFormatter<'a> {
-    /// Construct a new `ColumnFormatter` instance.
+impl<'a> ColumnFormatter<'a> {
+    /// Construct a new `ColumnFormatter` instance.
+    ///
+    /// The items can be any collection of `FormattableItem`s, such as a `Vec`, an array, or a slice,
+    /// or of references to values implementing both `Display` and `Debug`.
+    #[must_use]
+    #[inline]
+    pub fn new<I>(format_str: &str, items: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<FormattableItem<'a>>,
+    {
+        Self {
+            formats: Self::parse_format_string(format_str),
+            items: items.into_iter().map(Into::into).collect(),
+            max_width: EnvConfig::get().width,
+            total_width: None,
+            options: RenderOptions::default(),
+            unterminated: unterminated_spec(format_str),
+            block_width: None,
+            #[cfg(feature = "unicode")]
+            normalization: None,
+            frame: None,
+            color: EnvConfig::get().color.unwrap_or_default(),
+            accessibility: EnvConfig::get().accessibility.unwrap_or_default(),
+            row_links: Vec::new(),
+            wrap_policy: WrapPolicy::default(),
+            custom_formats: HashMap::new(),
+            overflow_indicator: None,
+            separator_style: Style::new(),
+            padding_style: Style::new(),
+            catch_panics: false,
+            panicked_cells: Counter::new(0),
+            #[cfg(feature = "bidi")]
+            bidi: false,
+            line_ending: LineEnding::Lf,
+            explicit: Explicit::default(),
+        }
+    }
+
+    /// Construct a new strict `ColumnFormatter`, checking that every item can provide its column's representation.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FormatError::UnterminatedSpec` if a specifier is left open at the end of the format string,
+    /// `FormatError::InvalidTimePattern` if a time pattern has an unsupported directive, or
+    /// `FormatError::FormatMismatch` for the first item which cannot be formatted as its column requests, such as
+    /// a `Display`-only item in a `{:?}` column.
+    #[inline]
+    pub fn try_new<I>(format_str: &str, items: I) -> Result<Self, FormatError>
+    where
+        I: IntoIterator,
+        I::Item: Into<FormattableItem<'a>>,
+    {
+        let formatter = Self::new(format_str, items).with_strictness(Strictness::Strict);
+        formatter.check().map(|()| formatter)
+    }
+
+    /// Use the given layout policies, such as the frozen `RenderOptions::v1` preset.
+    ///
+    /// Builder methods called afterwards override individual policies. Options which do not read the environment,
+    /// such as `v1`, undo the defaults the `COLPRINT_*` variables or `with_env` seeded, keeping the settings given
+    /// explicitly by builders, whether they were called before or after this.
+    #[must_use]
+    #[inline]
+    pub fn with_options(mut self, options: RenderOptions) -> Self {
+        self.options = options;
+        if options.read_env {
+            return self;
+        }
+
+        // Settings which were seeded rather than given go back to their plain defaults
+        let (empty, explicit) = (EnvConfig::empty(), self.explicit);
+        if !explicit.max_width {
+            self.max_width = empty.width;
+        }
+        if !explicit.color {
+            self.color = empty.color.unwrap_or_default();
+        }
+        if !explicit.accessibility {
+            self.accessibility = empty.accessibility.unwrap_or_default();
+        }
+        for format in &mut self.formats {
+            if !explicit.truncate_marker {
+                format.truncate_marker.clone_from(&empty.truncate_marker);
+            }
+            if !explicit.ascii {
+                format.ascii = empty.ascii;
+            }
+        }
+        self
+    }
+
+    /// Seed the maximum width, color choice, accessibility, truncation marker, and choice to only write ASCII from the
+    /// given defaults instead of the environment, such as `EnvConfig::empty()` for output which never depends on it.
+    ///
+    /// This replaces all five settings, so call it before the builders setting them.
+    #[must_use]
+    #[inline]
+    pub fn with_env(mut self, env: &EnvConfig) -> Self {
+        self.max_width = env.width;
+        self.color = env.color.unwrap_or_default();
+        self.accessibility = env.accessibility.unwrap_or_default();
+        for format in &mut self.formats {
+            format.truncate_marker.clone_from(&env.truncate_marker);
+            format.ascii = env.ascii;
+        }
+        self.explicit = Explicit::default();
+        self
+    }
+
+    /// Cut lines of each cell at the given number of characters before layout, protecting the layout from huge
+    /// cells.
+    ///
+    /// The cap applies before any measurement, padding, or truncation, so over-long lines cost no more than the
+    /// cap. It defaults to 4096 characters, and is off in `RenderOptions::v1`.
+    #[must_use]
+    #[inline]
+    pub const fn with_max_cell_width(mut self, max_cell_width: usize) -> Self {
+        self.options.max_cell_width = Some(max_cell_width);
+        self
+    }
+
+    /// Keep at most the given number of lines of each cell, protecting the layout from huge cells.
+    ///
+    /// Dropped lines never cost padding lines in the other columns. The cap defaults to 10000 lines, and is off
+    /// in `RenderOptions::v1`.
+    #[must_use]
+    #[inline]
+    pub const fn with_max_lines_per_cell(mut self, max_lines_per_cell: usize) -> Self {
+        self.options.max_lines_per_cell = Some(max_lines_per_cell);
+        self
+    }
+
+    /// Set whether trailing whitespace is removed from each line of a cell before it is measured and padded.
+    ///
+    /// Trimming is on by default, so lines written with trailing spaces, such as by `writeln!`, do not widen their
+    /// columns, and off in `RenderOptions::v1`. Turn it off for whitespace-significant content such as diffs. Values
+    /// padded by a width inside their specifier, such as `{:<8}`, are never trimmed.
+    #[must_use]
+    #[inline]
+    pub const fn with_trim_trailing_whitespace(mut self, trim: bool) -> Self {
+        self.options.trim_trailing_whitespace = trim;
+        self
+    }
+
+    /// Set whether invisible characters are cleaned from each line of a cell before it is measured.
+    ///
+    /// Cleaning replaces non-breaking spaces with plain spaces, so that wrapping may break lines at them, and removes
+    /// zero-width spaces. It is off by default, as zero-width characters already take up no width.
+    #[must_use]
+    #[inline]
+    pub const fn clean_invisible(mut self, clean: bool) -> Self {
+        self.options.clean_invisible = clean;
+        self
+    }
+
+    /// Set whether the lines added below a cell to match a taller neighbour are drawn with the column's fill.
+    ///
+    /// Alignment and fill only apply to the lines of a cell's content. The lines added to match a taller cell in
+    /// another column are plain spaces by default, whatever the fill, and become a full line of the fill when set.
+    #[must_use]
+    #[inline]
+    pub const fn fill_blank_lines(mut self, fill: bool) -> Self {
+        self.options.fill_blank_lines = fill;
+        self
+    }
+
+    /// Set whether a cell whose `Display` or `Debug` implementation panics is rendered as
+    /// `<format panicked: message>` instead of unwinding through the whole render.
+    ///
+    /// Off by default. The items are borrowed as trait objects, which are not `UnwindSafe`, so they are treated as
+    /// unwind safe: an item left inconsistent by its own panic is still rendered as it is wherever else it appears.
+    /// The panic hook still runs. The number of failed cells is reported by `panicked_cells`.
+    #[must_use]
+    #[inline]
+    pub const fn catch_panics(mut self, catch_panics: bool) -> Self {
+        self.catch_panics = catch_panics;
+        self
+    }
+
+    /// The number of cells whose formatting panicked during the latest render, when catching panics.
+    #[must_use]
+    #[inline]
+    pub const fn panicked_cells(&self) -> usize {
+        self.panicked_cells.get()
+    }
+
+    /// Set the maximum width of each output line.
+    #[must_use]
+    #[inline]
+    pub const fn with_max_width(mut self, max_width: usize) -> Self {
+        self.max_width = Some(max_width);
+        self.explicit.max_width = true;
+        self
+    }
+
+    /// Lay the output out in exactly the given number of characters.
+    ///
+    /// Columns without an explicit width evenly share whatever remains after the explicit widths
+    /// and separators, with any remainder going to the leftmost columns. If the explicit widths and
+    /// separators are already too wide, the widest columns are shrunk until the layout fits.
+    /// No emitted line is ever wider than the total width.
+    #[must_use]
+    #[inline]
+    pub const fn with_total_width(mut self, total_width: usize) -> Self {
+        self.total_width = Some(total_width);
+        self
+    }
+
+    /// Lay the output out in exactly the given number of characters, as `with_total_width`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FormatError::WidthBudgetExceeded` if the explicit column widths and separators
+    /// are wider than the total width.
+    #[inline]
+    pub fn try_with_total_width(self, total_width: usize) -> Result<Self, FormatError> {
+        let columns: Vec<usize> = (0..self.formats.len()).collect();
+        let explicit_widths: Vec<usize> = self
+            .formats
+            .iter()
+            .map(|fmt| fmt.width.and_then(Width::chars).unwrap_or(0))
+            .collect();
+        let required = self.line_width(&columns, &explicit_widths, &self.separators());
+        if required > total_width {
+            return Err(FormatError::WidthBudgetExceeded {
+                required,
+                available: total_width,
+            });
+        }
+        Ok(self.with_total_width(total_width))
+    }
+
+    /// Align lines of the first two columns, so each pair of a left line index and a right line index lands on the
+    /// same row, such as a key next to the line of a `{:#?}` dump where its field appears.
+    ///
+    /// Blank lines are inserted into either column to push lines down, keeping the order of the lines. Pairs may be
+    /// given in any order, and pairs past the end of a cell are ignored. Line indices count the lines of each cell
+    /// as formatted, before any wrapping, which may move lines apart again.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FormatError::CrossingRows` if two pairs cross, such as `(1, 5)` and `(2, 3)`, or share a line on
+    /// one side only.
+    #[inline]
+    pub fn align_rows(mut self, pairs: &[(usize, usize)]) -> Result<Self, FormatError> {
+        let mut row_links = pairs.to_vec();
+        row_links.sort_unstable();
+        row_links.dedup();
+        if let Some(crossing) = row_links
+            .iter()
+            .zip(row_links.iter().skip(1))
+            .find(|adjacent| adjacent.0.0 == adjacent.1.0 || adjacent.0.1 >= adjacent.1.1)
+        {
+            return Err(FormatError::CrossingRows {
+                first: *crossing.0,
+                second: *crossing.1,
+            });
+        }
+
+        self.row_links = row_links;
+        Ok(self)
+    }
+
+    /// Frame the left and right sides of every line, such as with `"| "` and `" |"`.
+    ///
+    /// The content between the sides is padded to a common width, so the right side always lands in the same
+    /// column, including on the hidden-columns notice. The frame counts towards the total and maximum widths.
+    #[must_use]
+    #[inline]
+    pub fn frame_sides(mut self, left: &str, right: &str) -> Self {
+        self.frame = Some((left.to_owned(), right.to_owned()));
+        self
+    }
+
+    /// Render columns with the named custom format, such as `{:money}`, using the handler.
+    ///
+    /// The handler receives the `Display` text of each item, after any digit grouping, and returns the text
+    /// to place in the cell. Columns naming a format which is never registered are rendered with `Display`.
+    #[must_use]
+    #[inline]
+    pub fn register_format<F>(mut self, name: &'static str, handler: F) -> Self
+    where
+        F: Fn(&str) -> String + 'static,
+    {
+        for format in &mut self.formats {
+            if format.custom_name.as_deref() == Some(name) {
+                format.format_type = FormatType::Custom(name);
+            }
+        }
+        self.custom_formats.insert(name, Box::new(handler));
+        self
+    }
+
+    /// Set the policy applied when the output is wider than the maximum width.
+    #[must_use]
+    #[inline]
+    pub const fn with_overflow(mut self, overflow: Overflow) -> Self {
+        self.options.overflow = overflow;
+        self
+    }
+
+    /// Set which part of over-long lines in a column is cut away, overriding any `~` suffix in the format string.
+    #[must_use]
+    #[inline]
+    pub fn with_truncate_side(mut self, column: usize, truncate_side: TruncateSide) -> Self {
+        if let Some(format) = self.formats.get_mut(column) {
+            format.truncate_side = truncate_side;
+        }
+        self
+    }
+
+    /// Highlight the syntax of a `{:?}` or `{:#?}` column, colouring field names, literals, and punctuation.
+    ///
+    /// Highlighting is off by default, and only emitted when the formatter's `ColorChoice` allows styles.
+    #[must_use]
+    #[inline]
+    pub fn with_highlight(mut self, column: usize, highlight: bool) -> Self {
+        if let Some(format) = self.formats.get_mut(column) {
+            format.highlight = highlight;
+        }
+        self
+    }
+
+    /// Set when styles, such as highlighting, are emitted.
+    #[must_use]
+    #[inline]
+    pub const fn with_color(mut self, color: ColorChoice) -> Self {
+        self.color = color;
+        self.explicit.color = true;
+        self
+    }
+
+    /// Set how each line written by `write_to` and `to_text` ends, such as `LineEnding::CrLf` for reports opened
+    /// on Windows.
+    ///
+    /// `Display` always ends lines with `\n`, so `format!` and `to_string` are unaffected.
+    #[must_use]
+    #[inline]
+    pub const fn with_line_ending(mut self, line_ending: LineEnding) -> Self {
+        self.line_ending = line_ending;
+        self
+    }
+
+    /// Set how omitted content is signalled, such as `Accessibility::Verbose` to annotate it in plain text for
+    /// screen readers.
+    #[must_use]
+    #[inline]
+    pub const fn with_accessibility(mut self, accessibility: Accessibility) -> Self {
+        self.accessibility = accessibility;
+        self.explicit.accessibility = true;
+        self
+    }
+
+    /// Set the style of the truncation marker, such as `Style::new().dim()`, in every column.
+    ///
+    /// The marker is unstyled by default. The style is only drawn in lines which are already styled, such as
+    /// pre-rendered `{:raw}` text containing escape sequences, so plain output stays plain.
+    #[must_use]
+    #[inline]
+    pub fn with_truncate_marker_style(mut self, style: Style) -> Self {
+        for format in &mut self.formats {
+            format.truncate_marker_style = style;
+        }
+        self
+    }
+
+    /// Draw the glyph, such as `>` or `▶`, in the last character of every line clipped on the right, signalling
+    /// that there is more content to the right.
+    ///
+    /// Lines are clipped at the total width, at the edge of a `render_window`, and at the edge of a `render_rect`. The
+    /// glyph takes the place of the last character kept, so clipped lines stay within the width, and lines which
+    /// fit are left as they are. It is separate from the truncation marker of cells cut within their column. The
+    /// style, such as `Style::new().dim()`, is only drawn when styles are emitted.
+    #[must_use]
+    #[inline]
+    pub const fn with_overflow_indicator(mut self, glyph: char, style: Style) -> Self {
+        self.overflow_indicator = Some((glyph, style));
+        self
+    }
+
+    /// Draw separators and the sides of the frame in the given style, such as `Style::new().dim()`, so the cells
+    /// stand out from the structure around them.
+    ///
+    /// Each separator is reset after its style, so cell content keeps its own styles. The style is only drawn when
+    /// styles are emitted, so the output is otherwise unchanged.
+    #[must_use]
+    #[inline]
+    pub const fn separator_style(mut self, style: Style) -> Self {
+        self.separator_style = style;
+        self
+    }
+
+    /// Draw the lines added below a cell to match a taller neighbour in the given style, such as `Style::new().dim()`.
+    ///
+    /// The style matters for lines drawn with a fill or background, and is only drawn when styles are emitted.
+    #[must_use]
+    #[inline]
+    pub const fn padding_style(mut self, style: Style) -> Self {
+        self.padding_style = style;
+        self
+    }
+
+    /// Mark where over-long lines are cut with the given text instead of `…`, in every column.
+    ///
+    /// The marker is used when truncating from the left or the middle; cuts at the end are never marked.
+    #[must_use]
+    #[inline]
+    pub fn with_truncate_marker(mut self, marker: &str) -> Self {
+        for format in &mut self.formats {
+            marker.clone_into(&mut format.truncate_marker);
+        }
+        self.explicit.truncate_marker = true;
+        self
+    }
+
+    /// Set whether only ASCII is written, drawing progress bars with `BarGlyphs::ASCII` unless their glyphs are
+    /// ASCII, and writing microseconds as `us` rather than `µs`, in every column.
+    ///
+    /// It defaults to whether `COLPRINT_ASCII` is `1`. The truncation marker is set separately, with
+    /// `with_truncate_marker`.
+    #[must_use]
+    #[inline]
+    pub fn with_ascii(mut self, ascii: bool) -> Self {
+        for format in &mut self.formats {
+            format.ascii = ascii;
+        }
+        self.explicit.ascii = true;
+        self
+    }
+
+    /// Replace over-long lines in a column with the text computed by the callback, instead of cutting them.
+    ///
+    /// The callback receives the whole line and the width of the column, such as to show how much was dropped with
+    /// `…(+123ch)` or to keep the end of a hash. Text it returns which is still too wide is cut at the end.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use colprint::ColumnFormatter;
+    ///
+    /// let formatter = ColumnFormatter::new("{}:8|{}", [&"0123456789abcdef", &"hash"])
+    /// #     .with_env(&colprint::EnvConfig::empty())
+    ///     .with_truncator(0, |line, width| format!("…{}", &line[line.len() - (width - 1)..]));
+    /// assert_eq!(formatter.to_string(), "…9abcdef|hash\n");
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn with_truncator<F>(mut self, column: usize, truncator: F) -> Self
+    where
+        F: Fn(&str, usize) -> String + Send + Sync + 'static,
+    {
+        if let Some(format) = self.formats.get_mut(column) {
+            format.truncator = Some(Arc::new(truncator));
+        }
+        self
+    }
+
+    /// Draw the progress bars of a `{:bar}` column with the given characters.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use colprint::{BarGlyphs, ColumnFormatter, FormattableItem};
+    ///
+    /// let formatter = ColumnFormatter::new("{:bar}:13", [FormattableItem::BarItem(0.5)])
+    ///     .with_bar_glyphs(0, BarGlyphs::new('#', '.', '|', '|'));
+    /// assert_eq!(formatter.to_string(), "|###...|  50%\n");
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn with_bar_glyphs(mut self, column: usize, glyphs: BarGlyphs) -> Self {
+        if let Some(format) = self.formats.get_mut(column) {
+            format.bar_glyphs = glyphs;
+        }
+        self
+    }
+
+    /// Write line breaks in the rendered text of a column as the escapes `\n` and `\r`, so each item stays on one
+    /// line, as `{:?}` does for strings.
+    ///
+    /// The escapes are part of the cell, so they are counted when measuring its width. This is off by default.
+    #[must_use]
+    #[inline]
+    pub fn escape_newlines(mut self, column: usize, escape: bool) -> Self {
+        if let Some(format) = self.formats.get_mut(column) {
+            format.escape_newlines = escape;
+        }
+        self
+    }
+
+    /// Set which lines of multi-line rows show the separator after a column.
+    ///
+    /// With `SeparatorFill::FirstLineStyled`, continuation lines get a run of the `rest` character as wide
+    /// as the separator instead, so the columns stay lined up.
+    #[must_use]
+    #[inline]
+    pub fn with_separator_fill(mut self, column: usize, separator_fill: SeparatorFill) -> Self {
+        if let Some(format) = self.formats.get_mut(column) {
+            format.separator_fill = separator_fill;
+        }
+        self
+    }
+
+    /// Set the separator after a column to one whose piece depends on the position of the line within the block.
+    ///
+    /// With `Separator::PerPosition`, the separator from the format string is replaced by the `middle` piece for
+    /// measuring, and is protected from squeezing. The `first` and `last` pieces are written on the first and last
+    /// lines of the block, such as `┌` and `└` around a gutter of `│`.
+    #[must_use]
+    #[inline]
+    pub fn with_separator(mut self, column: usize, separator: Separator) -> Self {
+        if let Some(format) = self.formats.get_mut(column) {
+            format.set_separator(separator);
+        }
+        self
+    }
+
+    /// Pad the lines of a column with the given character instead of spaces, for example `.` for dotted leaders.
+    #[must_use]
+    #[inline]
+    pub fn with_fill(mut self, column: usize, fill: char) -> Self {
+        if let Some(format) = self.formats.get_mut(column) {
+            format.fill = fill;
+        }
+        self
+    }
+
+    /// Set how the automatic width of a column is derived from the lengths of its lines.
+    ///
+    /// With `WidthHeuristic::Percentile`, lines longer than the chosen percentile are truncated, or wrapped
+    /// under `Overflow::Wrap`. Columns with an explicit width ignore the heuristic.
+    #[must_use]
+    #[inline]
+    pub fn with_width_heuristic(mut self, column: usize, width_heuristic: WidthHeuristic) -> Self {
+        if let Some(format) = self.formats.get_mut(column) {
+            format.width_heuristic = width_heuristic;
+        }
+        self
+    }
+
+    /// Set the priority of a column for absorbing wrapping under `Overflow::Wrap`.
+    ///
+    /// When the output is too wide, the column with the highest wrap priority is narrowed first, down to the
+    /// minimum width, then the next, and only then columns without a wrap priority. Columns of equal priority are
+    /// narrowed widest first.
+    #[must_use]
+    #[inline]
+    pub fn with_wrap_priority(mut self, column: usize, wrap_priority: u8) -> Self {
+        if let Some(format) = self.formats.get_mut(column) {
+            format.wrap_priority = Some(wrap_priority);
+        }
+        self
+    }
+
+    /// Set how words wider than their column are split under `Overflow::Wrap`.
+    ///
+    /// By default, such words are split after the last `/`, `?`, `&`, or `-` which fits, so URLs and paths break
+    /// at natural points, or else at the column width.
+    #[must_use]
+    #[inline]
+    pub const fn with_wrap_policy(mut self, wrap_policy: WrapPolicy) -> Self {
+        self.wrap_policy = wrap_policy;
+        self
+    }
+
+    /// Set the policy for trimming the spaces around separators when the output is too wide.
+    ///
+    /// Squeezing happens before any columns are dropped or shrunk.
+    #[must_use]
+    #[inline]
+    pub const fn with_squeeze(mut self, squeeze: Squeeze) -> Self {
+        self.options.squeeze = squeeze;
+        self
+    }
+
+    /// Set the number of characters that squeezing always leaves between adjacent columns, counting the padding on
+    /// either side of the separator and the separator itself.
+    ///
+    /// Separators already narrower than the gap are left as they are. Defaults to one character, so squeezing never
+    /// fuses two columns together; columns are dropped or shrunk by the overflow policy instead.
+    #[must_use]
+    #[inline]
+    pub const fn with_min_gap(mut self, min_gap: usize) -> Self {
+        self.options.min_gap = min_gap;
+        self
+    }
+
+    /// Protect the separator after a column from being squeezed, for decorative separators which must not change.
+    #[must_use]
+    #[inline]
+    pub fn with_fixed_separator(mut self, column: usize) -> Self {
+        if let Some(format) = self.formats.get_mut(column) {
+            format.squeezable = false;
+        }
+        self
+    }
+
+    /// Set the policy for items which cannot provide their column's representation.
+    #[must_use]
+    #[inline]
+    pub const fn with_strictness(mut self, strictness: Strictness) -> Self {
+        self.options.strictness = strictness;
+        self
+    }
+
+    /// Insert the given number of spaces between adjacent columns with no separator text between them, such as the
+    /// columns of `"{}{}"`, which are otherwise jammed together.
+    ///
+    /// The gap is 0 by default. Columns joined with `with_no_gap` stay concatenated, and the gap is a plain separator
+    /// from then on, so it can be squeezed like any other.
+    #[must_use]
+    #[inline]
+    pub fn with_default_gap(mut self, gap: usize) -> Self {
+        let last = self.formats.len().saturating_sub(1);
+        for format in self.formats.iter_mut().take(last) {
+            if format.separator.is_none() {
+                format.separator = Some(" ".repeat(gap));
+            }
+        }
+        self
+    }
+
+    /// Keep a column concatenated with the next, with no gap between them, even if a default gap is set.
+    #[must_use]
+    #[inline]
+    pub fn with_no_gap(mut self, column: usize) -> Self {
+        if let Some(format) = self.formats.get_mut(column) {
+            format.separator = Some(String::new());
+        }
+        self
+    }
+
+    /// Add the given number of spaces on each side of every separator, and after the last column.
+    ///
+    /// Padding is added on top of the column widths, so it never affects how much content a column holds.
+    #[must_use]
+    #[inline]
+    pub const fn with_padding(mut self, padding: usize) -> Self {
+        self.options.padding = padding;
+        self
+    }
+
+    /// Indent every line of the block by the given number of spaces.
+    ///
+    /// Column anchors are measured from the end of the margin.
+    #[must_use]
+    #[inline]
+    pub const fn with_margin_left(mut self, margin_left: usize) -> Self {
+        self.options.margin_left = margin_left;
+        self
+    }
+
+    /// Start a column at the given character offset from the left margin, overriding any `@n` anchor in the format string.
+    #[must_use]
+    #[inline]
+    pub fn with_anchor(mut self, column: usize, offset: usize) -> Self {
+        if let Some(format) = self.formats.get_mut(column) {
+            format.anchor = Some(offset);
+        }
+        self
+    }
+
+    /// Set the policy applied when earlier content has passed a column's anchor.
+    #[must_use]
+    #[inline]
+    pub const fn with_anchor_policy(mut self, anchor_policy: AnchorPolicy) -> Self {
+        self.options.anchor_policy = anchor_policy;
+        self
+    }
+
+    /// Place the whole block within the target width, for example to center a title card.
+    ///
+    /// The target width is set with `with_block_width`, falling back to the terminal width from the
+    /// `COLUMNS` environment variable. Without either, or when the block is at least as wide as the
+    /// target, the block is left where it is.
+    #[must_use]
+    #[inline]
+    pub const fn with_block_align(mut self, block_align: BlockAlign) -> Self {
+        self.options.block_align = block_align;
+        self
+    }
+
+    /// Set the width the block is aligned within, instead of the terminal width.
+    #[must_use]
+    #[inline]
+    pub const fn with_block_width(mut self, block_width: usize) -> Self {
+        self.block_width = Some(block_width);
+        self
+    }
+
+    /// Normalize the text of each cell into the given Unicode form before it is measured.
+    #[cfg(feature = "unicode")]
+    #[must_use]
+    #[inline]
+    pub const fn with_normalization(mut self, normalization: Normalization) -> Self {
+        self.normalization = Some(normalization);
+        self
+    }
+
+    /// Align and truncate right-to-left cell lines, such as Hebrew or Arabic text, on the visually correct side.
+    ///
+    /// Lines containing right-to-left text are also isolated, so the terminal cannot reorder them with the
+    /// separators around them. This is off by default.
+    #[cfg(feature = "bidi")]
+    #[must_use]
+    #[inline]
+    pub const fn with_bidi(mut self, bidi: bool) -> Self {
+        self.bidi = bidi;
+        self
+    }
+
+    /// Insert the separator every three digits of standalone numbers in a column.
+    ///
+    /// This overrides the `,` separator used by the `{:n}` flag in the format string.
+    #[must_use]
+    #[inline]
+    pub fn with_digit_grouping(mut self, column: usize, separator: char) -> Self {
+        if let Some(format) = self.formats.get_mut(column) {
+            format.group_digits = Some(separator);
+        }
+        self
+    }
+
+    /// Set the priority of a column, overriding any `!n` suffix in the format string.
+    ///
+    /// Lower priority columns are dropped first by `Overflow::DropColumns`.
+    #[must_use]
+    #[inline]
+    pub fn with_priority(mut self, column: usize, priority: u8) -> Self {
+        if let Some(format) = self.formats.get_mut(column) {
+            format.priority = priority;
+        }
+        self
+    }
+
+    /// Use column formats parsed in advance, such as those of a `ColumnTemplate`, instead of the format string's.
+    #[must_use]
+    #[inline]
+    pub(crate) fn with_formats(mut self, formats: Vec<ColumnFormat>) -> Self {
+        self.formats = formats;
+        self
+    }
+
+    /// The format of each column, as parsed from the format string and adjusted by the builders.
+    #[must_use]
+    #[inline]
+    pub fn formats(&self) -> &[ColumnFormat] {
+        &self.formats
+    }
+
+    /// The number of items to format.
+    #[must_use]
+    #[inline]
+    pub const fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    /// Check whether there are no items to format.
+    #[must_use]
+    #[inline]
+    pub const fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    /// Check that no line of the output is wider than the maximum width, as a guard against overflowing terminals.
+    ///
+    /// Lines are measured by their visible characters, ignoring ANSI escape sequences. The `assert_fits!` macro
+    /// wraps this check for tests.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `FitError` describing the first line wider than the maximum width.
+    #[inline]
+    pub fn check_fits(&self, max_width: usize) -> Result<(), FitError> {
+        FitError::check(self.aligned_lines().iter().map(String::as_str), max_width)
+    }
+
+    /// Write the output to the writer.
+    ///
+    /// Every line ends with the line ending, so the output composes with an `IndentWriter` to nest it under other
+    /// text.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FormatError::Io` if the output cannot be written. A strict formatter writes the output, with a
+    /// `<format mismatch>` marker in place of each mismatched item, then returns `FormatError::UnterminatedSpec`
+    /// or `FormatError::FormatMismatch` as `try_new` would.
+    #[inline]
+    pub fn write_to<W: Write>(&self, writer: &mut W) -> Result<(), FormatError> {
+        self.format_columns(writer, self.line_ending)
+            .map_err(|err| FormatError::Io { kind: err.kind() })?;
+
+        if self.options.strictness == Strictness::Strict {
+            self.check()
+        } else {
+            Ok(())
+        }
+    }
+
+    /// The output as `write_to` writes it, with each line ending in the line ending.
+    ///
+    /// `to_string` and `Display` always end lines with `\n`.
+    #[must_use]
+    #[inline]
+    pub fn to_text(&self) -> String {
+        self.line_ending.apply(&self.to_string()).into_owned()
+    }
+
+    /// The output as the text of a cell in another formatter, with trailing padding removed from each line.
+    pub(crate) fn nested_text(&self) -> String {
+        self.aligned_lines()
+            .iter()
+            .map(|line| line.trim_end())
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    /// Render a horizontally scrolled window onto the output, keeping the first columns in view.
+    ///
+    /// The first `frozen_columns` visible columns, with the separator after them, are always rendered in full.
+    /// The window then shows the rest of the layout from the scroll offset past them, clipped so each line is at
+    /// most as wide as the scroll viewport. Clipping never breaks ANSI escape sequences or splits characters.
+    /// Block alignment is not applied, as the window is placed by the caller.
+    ///
+    /// The scroll state records the width of the layout, and its offset is clamped so the window never scrolls
+    /// past the last column.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use colprint::{ColumnFormatter, HScroll};
+    ///
+    /// let formatter = ColumnFormatter::new("{} | {}", [&"key", &"0123456789"]);
+    /// let mut scroll = HScroll::new(10);
+    /// assert_eq!(formatter.render_window(&mut scroll, 1), "key | 0123");
+    ///
+    /// scroll.scroll_right(100);
+    /// assert_eq!(formatter.render_window(&mut scroll, 1), "key | 6789");
+    /// assert!(!scroll.can_scroll_right());
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn render_window(&self, scroll: &mut HScroll, frozen_columns: usize) -> String {
+        let (lines, frozen_width) = self.layout(frozen_columns, self.width_limit(), ALL_LINES);
+        let width = scroll.viewport();
+        let room = width.saturating_sub(frozen_width);
+        let layout_width = lines.iter().map(|line| visible_width(line)).max().unwrap_or(0);
+        scroll.measure(layout_width.saturating_sub(frozen_width), room);
+        lines
+            .iter()
+            .map(|line| {
+                let (frozen, scrolled) = split(line, frozen_width);
+                let mut window = slice(frozen, 0, width);
+                window.push_str(&self.clip(scrolled, scroll.offset(), room));
+                window
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    /// Render the output into a rectangle of exactly `height` lines, each exactly `width` characters wide.
+    ///
+    /// The columns are fitted within the width, or the maximum or total width if narrower, by the usual squeeze and
+    /// overflow policies. Lines still too wide are clipped, narrower lines are padded with spaces, and the output is
+    /// clipped or padded with blank lines to the height. Lines with escape sequences end with a reset before their
+    /// padding, so no style leaks from one line to the next. Block alignment is not applied, as the rectangle is placed
+    /// by the caller, such as a cell of a TUI frame.
+    #[must_use]
+    #[inline]
+    pub fn render_rect(&self, width: usize, height: usize) -> Vec<String> {
+        let max_width = self.width_limit().map_or(width, |limit| limit.min(width));
+        let (lines, _) = self.layout(0, Some(max_width), ALL_LINES);
+        let blank = " ".repeat(width);
+        lines
+            .iter()
+            .map(|line| {
+                let mut clipped = self.clip(line, 0, width);
+                let padding = " ".repeat(width - visible_width(&clipped));
+                if clipped.contains(ESCAPE) && !clipped.ends_with(RESET) {
+                    clipped.push_str(RESET);
+                }
+                clipped.push_str(&padding);
+                clipped
+            })
+            .chain(iter::repeat(blank))
+            .take(height)
+            .collect()
+    }
+
+    /// Render only the given range of output lines, such as the lines in view of a scrolling viewer.
+    ///
+    /// The lines are the same as the matching lines of the full output. Every cell is still formatted and measured to
+    /// find the column widths, but only the requested lines are assembled and padded. Ranges reaching past the end of
+    /// the output return fewer lines, or none.
     #[must_use]
     #[inline]
-    pub fn new(format_str: &str, items: Vec<FormattableItem<'a>>) -> Self {
-        Self {
-            formats: Self::parse_format_string(format_str),
-            items,
-        }
+    pub fn render_lines(&self, range: Range<usize>) -> Vec<String> {
+        self.aligned(self.layout(0, self.width_limit(), range).0)
     }
 
-    /// Parse a format string like "{} | {:?} | {:#?:80}" into column formats.
-    #[expect(clippy::single_call_fn, reason = "This function makes initialisation logic cleaner.")]
-    fn parse_format_string(format_str: &str) -> Vec<ColumnFormat> {
-        let mut formats = Vec::new();
-        let mut parts = Vec::new();
-
-        // First, split the format string into parts (format specifiers and separators)
-        let mut in_format = false;
-        let mut start_byte_idx = 0;
-
-        // Use char_indices to safely navigate UTF-8 characters
-        for (i, c) in format_str.char_indices() {
-            if c == '{' && !in_format {
-                // Start of a format specifier
-                if i > start_byte_idx {
-                    // There's a separator before this format specifier
-                    if let Some(separator) = format_str.get(start_byte_idx..i) {
-                        parts.push(FormatPart::Separator(separator));
-                    }
-                }
-                start_byte_idx = i;
-                in_format = true;
-            } else if c == '}' && in_format {
-                // End of a format specifier
-                in_format = false;
-                let end_byte_idx = i + c.len_utf8(); // Properly account for character length
-
-                // Check for width specification after the format
-                let mut width_end_byte_idx = end_byte_idx;
-                let format_bytes = format_str.as_bytes();
-
-                // Safely check for colon
-                if end_byte_idx < format_str.len() && format_bytes[end_byte_idx] == b':' {
-                    width_end_byte_idx = end_byte_idx + 1;
-
-                    // Safely collect width digits
-                    while width_end_byte_idx < format_str.len() && format_bytes[width_end_byte_idx].is_ascii_digit() {
-                        width_end_byte_idx += 1;
-                    }
-
-                    // Ensure we're at UTF-8 boundaries before slicing
-                    let start_str = format_str.get(start_byte_idx..end_byte_idx).unwrap_or_default();
-                    let width_str = format_str.get(end_byte_idx + 1..width_end_byte_idx).unwrap_or_default();
+    /// A horizontal rule of the character, as wide as the output and placed like it.
+    ///
+    /// The rule spans every column, separator, and frame side, starting after the margin, and matches the
+    /// width after fitting to the maximum or terminal width, so it never overflows. It has no trailing newline.
+    #[must_use]
+    #[inline]
+    pub fn rule(&self, ch: char) -> String {
+        let block_width = self.column_lines().iter().map(|line| visible_width(line)).max().unwrap_or(0);
+        let margin = self.options.margin_left.min(block_width);
+        let rule = format!("{}{}", " ".repeat(margin), ch.to_string().repeat(block_width - margin));
+        self.aligned(vec![rule]).concat()
+    }
 
-                    parts.push(FormatPart::Format(start_str, Some(width_str)));
-                } else {
-                    let format_slice = format_str.get(start_byte_idx..end_byte_idx).unwrap_or_default();
-                    parts.push(FormatPart::Format(format_slice, None));
-                }
+    /// Format and measure the cells and resolve the column widths, without assembling any lines.
+    ///
+    /// The prepared cells can be read directly, to emit them through another backend, or assembled into the same
+    /// lines as the full output with `Prepared::lines`. Preparing once and assembling several ranges of lines avoids
+    /// formatting every cell again for each range.
+    #[must_use]
+    #[inline]
+    pub fn prepare(&self) -> Prepared<'_, 'a> {
+        self.prepared_within(self.width_limit())
+    }
 
-                start_byte_idx = width_end_byte_idx;
-            }
+    /// Measure the output without writing it.
+    ///
+    /// This runs the same layout as printing, so the dimensions always match the printed output,
+    /// including any hidden-columns notice and block alignment.
+    #[must_use]
+    #[inline]
+    pub fn measure(&self) -> Dimensions {
+        let lines = self.aligned_lines();
+        Dimensions {
+            lines: lines.len(),
+            width: lines.iter().map(|line| visible_width(line)).max().unwrap_or(0),
         }
+    }
 
-        // Add any trailing separator
-        if start_byte_idx < format_str.len() {
-            if let Some(trailing) = format_str.get(start_byte_idx..) {
-                parts.push(FormatPart::Separator(trailing));
-            }
-        }
+    /// Describe how the width of each column was resolved, for debugging a layout or reporting a bug.
+    ///
+    /// Each column is listed with its format type, the width requested in the format string, the width it was
+    /// resolved to and the rule which decided it, and its separator, quoted so its whitespace can be seen. A final
+    /// line compares the width of the output with the width it was fitted within. The report is built from the same
+    /// steps as the render, so it always matches the printed output.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use colprint::ColumnFormatter;
+    ///
+    /// let formatter = ColumnFormatter::new("{}:8 | {:?}", [&"name", &"value"]).with_max_width(20);
+    /// assert_eq!(
+    ///     formatter.explain(),
+    ///     "column 0: Display, requested 8, resolved 8 (explicit), separator \" | \"\n\
+    ///      column 1: Debug, requested auto, resolved 7 (content)\n\
+    ///      total width 18 of 20 (maximum width)\n"
+    /// );
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn explain(&self) -> String {
+        let num_items = min(self.formats.len(), self.items.len());
+        let width_limit = self.width_limit();
+        let resolution = self.resolve_widths(&self.formatted_cells(num_items), width_limit);
+        let line_width = self.line_width(&resolution.visible, &resolution.widths, &resolution.separators);
+        let budget = match (self.total_width, self.max_width) {
+            (Some(total_width), _) => Some((total_width, "total width")),
+            (None, Some(max_width)) => Some((max_width, "maximum width")),
+            (None, None) => None,
+        };
+        resolution.report(&self.formats, line_width, budget)
+    }
+
+    /// Parse a format string like "{} | {:?} | {:#?}:80" into column formats.
+    pub(crate) fn parse_format_string(format_str: &str) -> Vec<ColumnFormat> {
+        let mut formats = Vec::new();
+        let parts = parse_parts(format_str);
 
         // Now process the parts to create column formats
         for (i, part) in parts.iter().enumerate() {
-            if let FormatPart::Format(fmt_str, width_str) = *part {
+            if let FormatPart::Format(raw_spec, width_str, priority_str, side_str, alignment_str) = *part {
+                // Remove stray whitespace between the tokens of the specifier
+                let spec = normalize_spec(raw_spec);
+                let spec_str: &str = &spec;
+                #[cfg(feature = "log")]
+                if spec_str != raw_spec {
+                    log::warn!("colprint: reading specifier `{raw_spec}` as `{spec_str}`");
+                }
+
+                // Split off any anchor, then check for the digit grouping flag at the end of the specifier
+                let (body, anchor) = split_anchor(spec_str);
+                let (fmt_str, grouped) = split_grouping(body);
+                let group_digits = grouped.then_some(',');
+
                 // Determine format type
-                let format_type = if fmt_str.contains(":#?") {
-                    FormatType::PrettyDebug
-                } else if fmt_str.contains(":?") {
-                    FormatType::Debug
-                } else {
-                    FormatType::Display
-                };
+                let format_type = FormatType::from_spec(fmt_str);
+                let custom_name = FormatType::custom_name(fmt_str).map(ToOwned::to_owned);
+                let time_pattern = FormatType::time_pattern(fmt_str).map(ToOwned::to_owned);
+                let bytes_per_row = FormatType::bytes_per_row(fmt_str).unwrap_or(hexdump::DEFAULT_BYTES_PER_ROW);
+                let bar_scale = FormatType::bar_scale(fmt_str).unwrap_or(1.0);
+                let value_spec = ValueSpec::parse(fmt_str).filter(|value_spec| !value_spec.is_plain());
 
                 // Parse width if specified
-                let width = width_str.and_then(|w| w.parse::<usize>().ok());
+                let width = width_str.and_then(Width::parse);
+                #[cfg(feature = "log")]
+                if width.is_none()
+                    && let Some(w) = width_str
+                {
+                    log::warn!("colprint: ignoring unparsable width `{w}` after specifier `{spec_str}`");
+                }
+
+                // Parse priority if specified
+                let parsed_priority = priority_str.and_then(|p| p.parse::<u8>().ok());
+                #[cfg(feature = "log")]
+                if parsed_priority.is_none()
+                    && let Some(p) = priority_str
+                {
+                    log::warn!("colprint: ignoring unparsable priority `{p}` after specifier `{spec_str}`");
+                }
+                let priority = parsed_priority.unwrap_or_default();
+
+                // Parse truncation side if specified
+                let truncate_side = side_str.and_then(TruncateSide::from_suffix).unwrap_or_default();
 
                 // Check for separator after this format
                 let separator = if i + 1 < parts.len() {
                     if let FormatPart::Separator(sep) = parts[i + 1] {
-                        Some(sep.to_owned())
+                        // Escaped braces are printed as single braces
+                        Some(sep.replace("{{", "{").replace("}}", "}"))
                     } else {
                         None
                     }
@@ -124,10 +1162,56 @@ impl<'a> ColumnFormatter<'a> {
                     None
                 };
 
+                // Humanized values are right-aligned by default, unless a suffix aligns the column
+                let suffix_alignments: Vec<Alignment> = alignment_str
+                    .map(|suffix| suffix.bytes().filter_map(Alignment::from_suffix).collect())
+                    .unwrap_or_default();
+                let header_alignment = (suffix_alignments.len() == 2).then(|| suffix_alignments[0]);
+                let default_alignment = match format_type {
+                    FormatType::Duration | FormatType::Size | FormatType::Time => Alignment::Right,
+                    FormatType::Display
+                    | FormatType::Debug
+                    | FormatType::PrettyDebug
+                    | FormatType::Verbatim
+                    | FormatType::Lines
+                    | FormatType::DebugLines
+                    | FormatType::Hex
+                    | FormatType::Bar
+                    | FormatType::Custom(_) => Alignment::Left,
+                };
+                let alignment = suffix_alignments.last().copied().unwrap_or(default_alignment);
+
                 formats.push(ColumnFormat {
                     format_type,
+                    custom_name,
+                    time_pattern,
+                    bytes_per_row,
+                    bar_scale,
+                    bar_glyphs: BarGlyphs::default(),
+                    value_spec,
                     width,
+                    group_digits,
+                    alignment,
+                    explicit_alignment: !suffix_alignments.is_empty(),
+                    header_alignment,
+                    footer_alignment: None,
                     separator,
+                    separator_fill: SeparatorFill::Repeat,
+                    positioned_separator: Separator::Plain,
+                    priority,
+                    anchor,
+                    truncate_side,
+                    truncate_marker: EnvConfig::get().truncate_marker.clone(),
+                    truncate_marker_style: Style::new(),
+                    ascii: EnvConfig::get().ascii,
+                    highlight: false,
+                    escape_newlines: false,
+                    squeezable: true,
+                    fill: ' ',
+                    default_cell: String::new(),
+                    wrap_priority: None,
+                    width_heuristic: WidthHeuristic::Max,
+                    truncator: None,
                 });
             }
         }
@@ -135,104 +1219,892 @@ impl<'a> ColumnFormatter<'a> {
         formats
     }
 
-    /// Format items into columns and write to a buffer.
-    #[expect(clippy::match_same_arms, reason = "Clippy /may/ be incorrect here.")]
-    #[expect(clippy::pattern_type_mismatch, reason = "Priority of arms is important.")]
-    fn format_columns(&self, writer: &mut impl Write) -> io::Result<()> {
-        // Ensure we have the same number of formatters and items
-        let num_items = min(self.formats.len(), self.items.len());
+    /// Format items into columns and write to a buffer, ending each line with the line ending.
+    fn format_columns(&self, writer: &mut impl Write, line_ending: LineEnding) -> io::Result<()> {
+        for line in self.aligned_lines() {
+            write!(writer, "{line}{}", line_ending.as_str())?;
+        }
+        Ok(())
+    }
 
-        if num_items == 0 {
-            return Ok(());
+    /// Format items into lines, with the whole block placed within the target width.
+    fn aligned_lines(&self) -> Vec<String> {
+        self.aligned(self.column_lines())
+    }
+
+    /// Take the visible characters of a line from `start`, up to `width` of them, drawing the overflow indicator in
+    /// place of the last one if the line's content continues past them.
+    fn clip(&self, line: &str, start: usize, width: usize) -> String {
+        // Only lines with more than padding past the edge are marked
+        let continues = visible_width(line.trim_end()) > start + width;
+        let Some((glyph, style)) = self.overflow_indicator.filter(|_| width > 0 && continues) else {
+            return slice(line, start, width);
+        };
+
+        let mut clipped = slice(line, start, width - 1);
+        if clipped.contains(ESCAPE) {
+            clipped.push_str(RESET);
         }
+        clipped.push_str(&self.color.paint(style, &glyph.to_string()));
+        clipped
+    }
 
-        // Format each item according to its format type
-        let formatted_items: Vec<Vec<String>> = self
+    /// Place a block of lines within the target width.
+    pub(crate) fn aligned(&self, lines: Vec<String>) -> Vec<String> {
+        // Only a centred or right-aligned block needs its width, and the terminal's
+        let free = || {
+            let target_width = self.block_width.or_else(terminal::width);
+            let block_width = lines.iter().map(|line| visible_width(line)).max().unwrap_or(0);
+            target_width.map_or(0, |target| target.saturating_sub(block_width))
+        };
+        let indent = match self.options.block_align {
+            BlockAlign::Left => return lines,
+            BlockAlign::Center => free().div_euclid(2),
+            BlockAlign::Right => free(),
+        };
+
+        let padding = " ".repeat(indent);
+        lines.into_iter().map(|line| format!("{padding}{line}")).collect()
+    }
+
+    /// Format items into lines of columns.
+    fn column_lines(&self) -> Vec<String> {
+        self.layout(0, self.width_limit(), ALL_LINES).0
+    }
+
+    /// Format the first `num_items` items into the lines of their cells, with the linked rows aligned.
+    fn formatted_cells(&self, num_items: usize) -> Vec<Vec<String>> {
+        self.panicked_cells.set(0);
+        let mut formatted_items: Vec<Vec<String>> = self
             .formats
             .iter()
             .zip(self.items.iter())
             .take(num_items)
             .map(|(fmt, item)| {
-                let formatted = match (item, &fmt.format_type) {
-                    (FormattableItem::DisplayItem(i), FormatType::Display) => {
-                        format!("{i}")
-                    }
-                    (FormattableItem::DebugItem(i), FormatType::Debug) => {
-                        format!("{i:?}")
-                    }
-                    (FormattableItem::DebugItem(i), FormatType::PrettyDebug) => {
-                        format!("{i:#?}")
-                    }
-                    // Fallback cases - use what we have
-                    (FormattableItem::DisplayItem(i), _) => {
-                        format!("{i}")
-                    }
-                    (FormattableItem::DebugItem(i), FormatType::Display) => {
-                        format!("{i:?}") // Use debug format as fallback
-                    }
+                // Cells are already rendered and split into lines
+                if let FormattableItem::Cell(cell) = *item {
+                    return self.capped_lines(cell.lines().iter().map(String::as_str), self.options.trim_trailing_whitespace);
+                }
+
+                // Hexdumps announce the bytes beyond the cap rather than the lines
+                if let FormattableItem::Bytes(bytes) = *item {
+                    let cap = self.options.max_lines_per_cell.map(|max| (max, fmt.truncate_marker.as_str()));
+                    let lines = hexdump::lines(bytes, fmt.bytes_per_row, cap);
+                    return self.capped_lines(lines.iter().map(String::as_str), false);
+                }
+
+                // Readers are streamed, reading one line past the cap so dropped lines are still detected
+                if let FormattableItem::Reader(source) = *item {
+                    let max_lines = self.options.max_lines_per_cell.map(|max| max.saturating_add(1));
+                    let lines = reader::read_lines(source, max_lines, self.options.max_cell_width);
+                    return self.capped_lines(lines.iter().map(String::as_str), self.options.trim_trailing_whitespace);
+                }
+
+                let formatted = if self.options.strictness == Strictness::Strict && !item.supports(fmt.format_type) {
+                    MISMATCH.to_owned()
+                } else {
+                    self.render(fmt, item)
                 };
 
-                formatted.lines().map(ToOwned::to_owned).collect()
+                // Values padded to a minimum width inside the specifier keep their padding
+                let trim =
+                    self.options.trim_trailing_whitespace && fmt.value_spec.is_none_or(|value_spec| value_spec.width.is_none());
+
+                #[cfg(feature = "unicode")]
+                if let Some(normalization) = self.normalization {
+                    return self.capped_lines(normalization.apply(&formatted).lines(), trim);
+                }
+
+                self.capped_lines(formatted.lines(), trim)
+            })
+            .collect();
+
+        // Push lines of the first two columns down until each linked pair shares a row
+        if let Some((left, rest)) = formatted_items.split_first_mut()
+            && let Some(right) = rest.first_mut()
+        {
+            self.link_rows(left, right);
+        }
+
+        formatted_items
+    }
+
+    /// Insert blank lines into the left and right cells so each linked pair of lines lands on the same row.
+    fn link_rows(&self, left: &mut Vec<String>, right: &mut Vec<String>) {
+        let mut shift = (0, 0);
+        for &(left_idx, right_idx) in &self.row_links {
+            if left_idx >= left.len() || right_idx >= right.len() {
+                break;
+            }
+
+            let left_row = left_idx + shift.0;
+            let right_row = right_idx + shift.1;
+            if left_row < right_row {
+                let blanks = right_row - left_row;
+                left.splice(left_row..left_row, vec![String::new(); blanks]);
+                shift.0 += blanks;
+            } else {
+                let blanks = left_row - right_row;
+                right.splice(right_row..right_row, vec![String::new(); blanks]);
+                shift.1 += blanks;
+            }
+        }
+    }
+
+    /// Collect the lines of a formatted cell, keeping at most the maximum number of lines, each cut to the maximum
+    /// cell width, so huge cells cost no more than the caps for the rest of the layout.
+    ///
+    /// With the `log` feature, a warning is logged whenever a cell is capped. With `Accessibility::Verbose`, the
+    /// last line kept announces how many lines were dropped instead. When trimming, trailing whitespace is removed
+    /// from each line first, so it never widens the column.
+    fn capped_lines<'l, I>(&self, lines: I, trim: bool) -> Vec<String>
+    where
+        I: Iterator<Item = &'l str> + Clone,
+    {
+        let max_lines = self.options.max_lines_per_cell.unwrap_or(usize::MAX);
+        let max_width = self.options.max_cell_width.unwrap_or(usize::MAX);
+        let announced = self.accessibility == Accessibility::Verbose && lines.clone().nth(max_lines).is_some();
+        let kept = if announced { max_lines.saturating_sub(1) } else { max_lines };
+
+        #[cfg(feature = "log")]
+        {
+            let num_lines = lines.clone().count();
+            if num_lines > max_lines {
+                log::warn!(
+                    "colprint: dropping {} line(s) of a cell beyond the cap of {max_lines}",
+                    num_lines - max_lines
+                );
+            }
+        }
+
+        let mut capped: Vec<String> = lines
+            .clone()
+            .take(kept)
+            .map(|line| {
+                let cleaned = if self.options.clean_invisible {
+                    Cow::Owned(clean_invisible(line))
+                } else {
+                    Cow::Borrowed(line)
+                };
+                let source = if trim { cleaned.trim_end() } else { &cleaned };
+                #[cfg(feature = "log")]
+                if visible_width(source) > max_width {
+                    log::warn!("colprint: cutting a line of a cell at the cap of {max_width} characters");
+                }
+                split(source, max_width).0.to_owned()
             })
             .collect();
+        if announced {
+            capped.push(Accessibility::omitted(lines.count() - kept));
+        }
+        capped
+    }
+
+    /// Format items into lines of columns, also finding where the first `frozen_columns` visible columns end.
+    ///
+    /// The returned offset, in characters, includes the margin and the padding and separator after the last
+    /// frozen column, and is only found when the range of lines assembled starts at the first.
+    fn layout(&self, frozen_columns: usize, width_limit: Option<usize>, range: Range<usize>) -> (Vec<String>, usize) {
+        self.assemble(&self.prepared_within(width_limit), frozen_columns, range)
+    }
+
+    /// Format and measure the cells, resolving the column widths within the width limit.
+    fn prepared_within(&self, width_limit: Option<usize>) -> Prepared<'_, 'a> {
+        // Ensure we have the same number of formatters and items
+        let num_items = min(self.formats.len(), self.items.len());
+
+        #[cfg(feature = "log")]
+        if self.options.strictness == Strictness::Lenient {
+            self.warn_recovered();
+        }
+
+        if num_items == 0 {
+            return Prepared::new(self, Vec::new(), Vec::new(), Vec::new(), Vec::new());
+        }
+
+        // Format each item according to its format type
+        let mut formatted_items = self.formatted_cells(num_items);
+
+        // Calculate column widths, then wrap lines wider than their columns if the overflow policy requires it
+        let WidthResolution {
+            separators,
+            visible,
+            widths: column_widths,
+            ..
+        } = self.resolve_widths(&formatted_items, width_limit);
+        // Bars are drawn again to fill their columns, rather than being cut
+        for &idx in &visible {
+            if let Some(&FormattableItem::BarItem(value)) = self.items.get(idx) {
+                formatted_items[idx] = vec![self.formats[idx].bar(value, column_widths[idx])];
+            }
+        }
+        if matches!(self.options.overflow, Overflow::Wrap { .. }) {
+            for &idx in &visible {
+                formatted_items[idx] = formatted_items[idx]
+                    .iter()
+                    .flat_map(|line| wrap(line, column_widths[idx], self.wrap_policy))
+                    .collect();
+            }
+        }
+
+        Prepared::new(self, formatted_items, column_widths, visible, separators)
+    }
+
+    /// Assemble prepared cells into lines of columns, also finding where the first `frozen_columns` visible columns
+    /// end.
+    pub(crate) fn assemble(
+        &self,
+        prepared: &Prepared<'_, '_>,
+        frozen_columns: usize,
+        range: Range<usize>,
+    ) -> (Vec<String>, usize) {
+        let mut lines = Vec::new();
+        let (frame_left, frame_right) = self.frame_widths();
+        let mut frozen_width = self.options.margin_left + frame_left;
+        let formatted_items = prepared.cells();
+        let column_widths = prepared.widths();
+        let visible = prepared.visible_columns();
+        let num_items = formatted_items.len();
 
         // Find the max number of lines
-        let max_lines = formatted_items.iter().map(Vec::len).max().unwrap_or(0);
+        let max_lines = prepared.height();
 
-        // Calculate column widths (use specified width or auto-calculate)
-        let column_widths: Vec<usize> = self
-            .formats
+        // For each line, concatenate the corresponding line from each visible item
+        let padding = " ".repeat(self.options.padding);
+        let highlighting = self.formats.iter().any(|fmt| fmt.highlight) && self.color.enabled();
+        for line_idx in range.start.min(max_lines)..range.end.min(max_lines) {
+            let mut output_line = " ".repeat(self.options.margin_left);
+            output_line.push_str(&" ".repeat(frame_left));
+            for (pos, &item_idx) in visible.iter().enumerate() {
+                let item_lines = &formatted_items[item_idx];
+                let column_width = *column_widths.get(item_idx).unwrap_or(&0);
+
+                let format = &self.formats[item_idx];
+                let line = item_lines.get(line_idx).map_or_else(
+                    || {
+                        let blank = blank_line(format, column_width, self.options.fill_blank_lines);
+                        self.color.paint(self.padding_style, &blank)
+                    },
+                    |line| self.fit_line(line, column_width, format, highlighting),
+                );
+
+                // Move anchored columns to their offset
+                if let Some(offset) = self.formats[item_idx].anchor {
+                    let anchor = self.options.margin_left + frame_left + offset;
+                    let current = visible_width(&output_line);
+                    if current < anchor {
+                        output_line.push_str(&" ".repeat(anchor - current));
+                    } else if self.options.anchor_policy == AnchorPolicy::Truncate {
+                        output_line = slice(&output_line, 0, anchor);
+                    }
+                }
+
+                output_line.push_str(&line);
+
+                // Add padding and separator if not the last visible column, or if the separator holds an unterminated specifier
+                let trailing_text = self.unterminated.is_some() && item_idx == self.formats.len() - 1;
+                if pos < visible.len() - 1 || trailing_text {
+                    output_line.push_str(&padding);
+                    if let Some(separator) = prepared.separator(item_idx) {
+                        let piece = format.positioned_separator.render(separator, line_idx, max_lines);
+                        let filled = format.separator_fill.render(piece, line_idx);
+                        output_line.push_str(&self.color.paint(self.separator_style, &filled));
+                    }
+                }
+                output_line.push_str(&padding);
+
+                if line_idx == 0 && pos < frozen_columns {
+                    frozen_width = visible_width(&output_line);
+                }
+            }
+
+            // Never exceed the total width, even if the separators alone are too wide
+            if let Some(total_width) = self.total_width {
+                output_line = self.clip(&output_line, 0, total_width.saturating_sub(frame_right));
+            }
+            lines.push(output_line);
+        }
+
+        // Report any columns hidden by the overflow policy
+        let notice_start = range.start.saturating_sub(max_lines);
+        let notice_end = range.end.saturating_sub(max_lines);
+        lines.extend(
+            self.hidden_notice(num_items, visible)
+                .into_iter()
+                .skip(notice_start)
+                .take(notice_end.saturating_sub(notice_start)),
+        );
+
+        (self.framed(lines), frozen_width)
+    }
+
+    /// The notice reporting columns hidden by the overflow policy, if there are any and it is enabled.
+    ///
+    /// With `Accessibility::Verbose`, each hidden column is always announced on a line of its own. When framed, the
+    /// notice is indented to leave space for the margin and the left side of the frame.
+    fn hidden_notice(&self, num_items: usize, visible: &[usize]) -> Vec<String> {
+        let hidden: Vec<usize> = (0..num_items).filter(|idx| !visible.contains(idx)).collect();
+        let verbose = self.accessibility == Accessibility::Verbose;
+        if hidden.is_empty() || !(verbose || matches!(self.options.overflow, Overflow::DropColumns { notice: true })) {
+            return Vec::new();
+        }
+
+        let indent = if self.frame.is_some() {
+            " ".repeat(self.options.margin_left + self.frame_widths().0)
+        } else {
+            String::new()
+        };
+        if verbose {
+            return hidden
+                .iter()
+                .map(|idx| format!("{indent}{}", Accessibility::hidden(&idx.to_string())))
+                .collect();
+        }
+
+        let plural = if hidden.len() == 1 { "" } else { "s" };
+        vec![format!("{indent}({} column{plural} hidden)", hidden.len())]
+    }
+
+    /// Draw the frame sides, if any, into the space left for them at the start and end of each line.
+    ///
+    /// Lines are padded to a common width first, so the right side always lands in the same column.
+    fn framed(&self, lines: Vec<String>) -> Vec<String> {
+        let Some(frame) = self.frame.as_ref() else {
+            return lines;
+        };
+        let (left, right) = (
+            self.color.paint(self.separator_style, &frame.0),
+            self.color.paint(self.separator_style, &frame.1),
+        );
+
+        let margin = " ".repeat(self.options.margin_left);
+        let start = self.options.margin_left + frame.0.chars().count();
+        let width = lines.iter().map(|line| visible_width(line)).max().unwrap_or(0);
+        lines
+            .iter()
+            .map(|line| {
+                let (_, content) = split(line, start);
+                let padding = " ".repeat(width.saturating_sub(visible_width(line).max(start)));
+                format!("{margin}{left}{content}{padding}{right}")
+            })
+            .collect()
+    }
+
+    /// Render an item as text according to its column's format, passing custom formats through their handler.
+    fn render(&self, fmt: &ColumnFormat, item: &FormattableItem<'_>) -> String {
+        if self.catch_panics {
+            return panic_guard::guarded(|| self.render_unguarded(fmt, item)).unwrap_or_else(|error| {
+                self.panicked_cells.set(self.panicked_cells.get() + 1);
+                error
+            });
+        }
+        self.render_unguarded(fmt, item)
+    }
+
+    /// Render an item as text according to its column's format, letting panics unwind.
+    fn render_unguarded(&self, fmt: &ColumnFormat, item: &FormattableItem<'_>) -> String {
+        let text = fmt.render(item);
+        if let FormatType::Custom(name) = fmt.format_type
+            && let Some(handler) = self.custom_formats.get(name)
+        {
+            return handler(&text);
+        }
+        text
+    }
+
+    /// Truncate or pad a line to exactly fit its column, following the direction of its text if asked to.
+    ///
+    /// Verbose accessibility annotates cut lines. When highlighting, lines of highlighted `Debug` columns are coloured once they fit.
+    fn fit_line(&self, line: &str, column_width: usize, format: &ColumnFormat, highlighting: bool) -> String {
+        let cut = self.accessibility.cut(line, column_width);
+        let annotated = cut.as_deref().unwrap_or(line);
+
+        #[cfg(feature = "bidi")]
+        let fitted = if self.bidi {
+            bidi::fit_line(annotated, column_width, format)
+        } else {
+            fit_line(annotated, column_width, format, RowRole::Data)
+        };
+        #[cfg(not(feature = "bidi"))]
+        let fitted = fit_line(annotated, column_width, format, RowRole::Data);
+
+        if highlighting
+            && format.highlight
+            && matches!(
+                format.format_type,
+                FormatType::Debug | FormatType::PrettyDebug | FormatType::DebugLines
+            )
+        {
+            return highlight_debug(&fitted);
+        }
+        fitted
+    }
+
+    /// Check the format string and items for the errors reported in strict mode.
+    fn check(&self) -> Result<(), FormatError> {
+        if let Some(offset) = self.unterminated {
+            return Err(FormatError::UnterminatedSpec { offset });
+        }
+        if let Some(column) = self.invalid_time_pattern() {
+            return Err(FormatError::InvalidTimePattern { column });
+        }
+        self.first_mismatch()
+            .map_or(Ok(()), |column| Err(FormatError::FormatMismatch { column }))
+    }
+
+    /// Emit a warning for each problem a lenient formatter recovers from while rendering.
+    #[cfg(feature = "log")]
+    fn warn_recovered(&self) {
+        if let Some(offset) = self.unterminated {
+            log::warn!("colprint: treating the unterminated specifier at byte {offset} as separator text");
+        }
+        if self.items.len() > self.formats.len() {
+            log::warn!(
+                "colprint: dropping {} item(s) beyond the {} specifier(s) in the format string",
+                self.items.len() - self.formats.len(),
+                self.formats.len()
+            );
+        } else if self.items.len() < self.formats.len() {
+            log::warn!(
+                "colprint: dropping {} column(s) with no item, as only {} item(s) were given",
+                self.formats.len() - self.items.len(),
+                self.items.len()
+            );
+        }
+        for (column, fmt) in self.formats.iter().enumerate() {
+            if let Some(name) = fmt.custom_name.as_deref()
+                && fmt.format_type == FormatType::Display
+            {
+                log::warn!("colprint: column {column} requests the unregistered format `{{:{name}}}`; using Display");
+            }
+        }
+        if let Some(column) = self.invalid_time_pattern() {
+            log::warn!("colprint: column {column} has an unsupported time directive, which is printed as it is");
+        }
+        for (column, (fmt, item)) in self.formats.iter().zip(&self.items).enumerate() {
+            if !item.supports(fmt.format_type) {
+                log::warn!(
+                    "colprint: column {column} requests {:?} formatting, which its item lacks; falling back",
+                    fmt.format_type
+                );
+            }
+        }
+    }
+
+    /// Find the first time column whose pattern has an unsupported directive.
+    fn invalid_time_pattern(&self) -> Option<usize> {
+        self.formats.iter().position(|fmt| {
+            fmt.time_pattern
+                .as_deref()
+                .is_some_and(|pattern| !timestamp::is_valid(pattern))
+        })
+    }
+
+    /// Find the first column whose item cannot provide the column's representation.
+    fn first_mismatch(&self) -> Option<usize> {
+        self.formats
+            .iter()
+            .zip(&self.items)
+            .position(|(fmt, item)| !item.supports(fmt.format_type))
+    }
+
+    /// Resolve the width of each column from the formatted cells, recording every step for `explain`.
+    fn resolve_widths(&self, formatted_items: &[Vec<String>], width_limit: Option<usize>) -> WidthResolution<'_> {
+        // Use specified width or calculate based on content
+        let explicit = self.explicit_widths(formatted_items.len(), width_limit);
+        let natural: Vec<usize> = explicit
             .iter()
-            .take(num_items)
             .enumerate()
-            .map(|(idx, fmt)| {
-                // Use specified width or calculate based on content
-                fmt.width.unwrap_or_else(|| {
+            .map(|(idx, explicit_width)| {
+                explicit_width.unwrap_or_else(|| {
                     formatted_items.get(idx).map_or(0, |item_lines| {
-                        item_lines.iter().map(|line| line.chars().count()).max().unwrap_or(0)
+                        let lengths = item_lines.iter().map(|line| visible_width(line)).collect();
+                        self.formats[idx].width_heuristic.width(lengths)
                     })
                 })
             })
             .collect();
 
-        // For each line, concatenate the corresponding line from each item
-        for line_idx in 0..max_lines {
-            for (item_idx, item_lines) in formatted_items.iter().enumerate().take(num_items) {
-                let column_width = *column_widths.get(item_idx).unwrap_or(&0);
+        // Trim separators first, if the squeeze policy allows it
+        let separators = self.squeezed_separators(&natural, width_limit);
 
-                let line = if line_idx < item_lines.len() {
-                    // Truncate or pad the line to fit the column width
-                    let mut line = item_lines[line_idx].clone();
-                    let line_len = line.chars().count();
+        // Decide which columns are shown, dropping columns if the overflow policy requires it
+        let visible = self.visible_columns(&natural, &separators, width_limit);
 
-                    if line_len > column_width {
-                        // Truncate to column width (handling Unicode)
-                        let mut chars = line.chars().collect::<Vec<_>>();
-                        chars.truncate(column_width);
-                        line = chars.into_iter().collect();
-                    } else {
-                        // Pad to column width
-                        line.push_str(&" ".repeat(column_width - line_len));
-                    }
-                    line
-                } else {
-                    // Empty line if this item doesn't have this many lines
-                    " ".repeat(column_width)
-                };
+        // Share out the total width, if one was given
+        let distributed = self.distribute_total_width(natural.clone(), &visible, &separators);
 
-                write!(writer, "{line}")?;
+        // Narrow columns for wrapping, if the overflow policy requires it
+        let widths = self.wrap_widths(distributed.clone(), &visible, &separators, width_limit);
+
+        WidthResolution {
+            explicit,
+            natural,
+            separators,
+            visible,
+            distributed,
+            widths,
+        }
+    }
+
+    /// The separator after each column, as given in the format string.
+    fn separators(&self) -> Vec<Option<&str>> {
+        self.formats.iter().map(|fmt| fmt.separator.as_deref()).collect()
+    }
+
+    /// The separator after each column, with spaces trimmed until the columns fit if the squeeze policy allows it.
+    ///
+    /// Each pass removes one leading and one trailing space from each squeezable separator in turn,
+    /// stopping as soon as the line fits within the total or maximum width.
+    fn squeezed_separators(&self, column_widths: &[usize], width_limit: Option<usize>) -> Vec<Option<&str>> {
+        let mut separators = self.separators();
+
+        let Some(max_width) = width_limit else {
+            return separators;
+        };
+        if self.options.squeeze != Squeeze::Separators {
+            return separators;
+        }
 
-                // Add separator if not the last column
-                if item_idx < num_items - 1 {
-                    if let Some(separator) = &self.formats[item_idx].separator {
-                        write!(writer, "{separator}")?;
+        let columns: Vec<usize> = (0..column_widths.len()).collect();
+        let mut squeezed = true;
+        while squeezed && self.line_width(&columns, column_widths, &separators) > max_width {
+            squeezed = false;
+            for idx in 0..columns.len().saturating_sub(1) {
+                let Some(separator) = separators[idx].filter(|_| self.formats[idx].squeezable) else {
+                    continue;
+                };
+                let without_leading = separator.strip_prefix(' ').unwrap_or(separator);
+                let trimmed = without_leading.strip_suffix(' ').unwrap_or(without_leading);
+                // Trim one side only if trimming both would close the minimum gap
+                let keeps_gap = |candidate: &&str| visible_width(candidate) + 2 * self.options.padding >= self.options.min_gap;
+                if let Some(narrower) = [trimmed, without_leading]
+                    .into_iter()
+                    .filter(|candidate| candidate.len() < separator.len())
+                    .find(keeps_gap)
+                {
+                    separators[idx] = Some(narrower);
+                    squeezed = true;
+                    if self.line_width(&columns, column_widths, &separators) <= max_width {
+                        break;
                     }
                 }
             }
-            writeln!(writer)?;
         }
 
-        Ok(())
+        separators
+    }
+
+    /// Select the indices of the columns to render, in order.
+    ///
+    /// Under `Overflow::DropColumns` the lowest-priority columns are removed (rightmost first on ties)
+    /// until the columns and the separators between them fit within the total or maximum width.
+    /// At least one column is always kept.
+    fn visible_columns(&self, column_widths: &[usize], separators: &[Option<&str>], width_limit: Option<usize>) -> Vec<usize> {
+        let mut visible: Vec<usize> = (0..column_widths.len()).collect();
+
+        let Some(max_width) = width_limit else {
+            return visible;
+        };
+        if !matches!(self.options.overflow, Overflow::DropColumns { .. }) {
+            return visible;
+        }
+
+        while visible.len() > 1 && self.line_width(&visible, column_widths, separators) > max_width {
+            let drop_pos = visible
+                .iter()
+                .enumerate()
+                .min_by_key(|&(pos, &idx)| (self.formats[idx].priority, usize::MAX - pos))
+                .map_or(0, |(pos, _)| pos);
+            let _dropped = visible.remove(drop_pos);
+        }
+
+        visible
+    }
+
+    /// The width the output is fitted within, as the total width if one was given, or else the maximum width.
+    const fn width_limit(&self) -> Option<usize> {
+        match self.total_width {
+            Some(total_width) => Some(total_width),
+            None => self.max_width,
+        }
+    }
+
+    /// Resolve the widths given in the format string, including percentages of the total, maximum, or terminal width.
+    ///
+    /// The percentages share whatever the margin, separators, padding, and absolute widths leave over.
+    fn explicit_widths(&self, num_items: usize, width_limit: Option<usize>) -> Vec<Option<usize>> {
+        let widths: Vec<Option<Width>> = self.formats.iter().take(num_items).map(|fmt| fmt.width).collect();
+        let columns: Vec<usize> = (0..num_items).collect();
+        let absolute: Vec<usize> = widths.iter().map(|width| width.and_then(Width::chars).unwrap_or(0)).collect();
+        let used = self.line_width(&columns, &absolute, &self.separators());
+        let available = width_limit.or_else(terminal::width);
+        width::resolve(&widths, available, used)
+    }
+
+    /// Resolve the visible column widths so that the line exactly fills the total width.
+    ///
+    /// Columns without an explicit width share the remaining space evenly, remainder to the leftmost.
+    /// If the explicit widths and separators are too wide, the widest columns are shrunk one character
+    /// at a time until the layout fits.
+    fn distribute_total_width(
+        &self,
+        mut column_widths: Vec<usize>,
+        visible: &[usize],
+        separators: &[Option<&str>],
+    ) -> Vec<usize> {
+        let Some(total_width) = self.total_width else {
+            return column_widths;
+        };
+
+        // Auto-width columns take no space until the remainder is known
+        let auto_columns: Vec<usize> = visible
+            .iter()
+            .copied()
+            .filter(|&idx| self.formats[idx].width.is_none())
+            .collect();
+        for &idx in &auto_columns {
+            column_widths[idx] = 0;
+        }
+
+        let used = self.line_width(visible, &column_widths, separators);
+        if used <= total_width {
+            let remaining = total_width - used;
+            let count = auto_columns.len().max(1);
+            for (pos, &idx) in auto_columns.iter().enumerate() {
+                column_widths[idx] = remaining.div_euclid(count) + usize::from(pos < remaining.rem_euclid(count));
+            }
+            return column_widths;
+        }
+
+        // Shrink the widest columns until the layout fits
+        let mut excess = used - total_width;
+        while excess > 0 {
+            let Some(&widest) = visible
+                .iter()
+                .filter(|&&idx| column_widths[idx] > 0)
+                .max_by_key(|&&idx| column_widths[idx])
+            else {
+                break;
+            };
+            column_widths[widest] -= 1;
+            excess -= 1;
+        }
+
+        column_widths
+    }
+
+    /// Narrow the visible columns until the line fits within the total or maximum width, under `Overflow::Wrap`.
+    ///
+    /// Columns with a wrap priority are narrowed first, highest priority first, followed by the other columns.
+    /// Columns of equal priority are narrowed widest first, and columns of equal width leftmost first. No column
+    /// is narrowed below the minimum width, nor below the width of its truncation marker.
+    fn wrap_widths(
+        &self,
+        mut column_widths: Vec<usize>,
+        visible: &[usize],
+        separators: &[Option<&str>],
+        width_limit: Option<usize>,
+    ) -> Vec<usize> {
+        let Overflow::Wrap { min_width } = self.options.overflow else {
+            return column_widths;
+        };
+        let Some(max_width) = width_limit else {
+            return column_widths;
+        };
+
+        let mut excess = self.line_width(visible, &column_widths, separators).saturating_sub(max_width);
+
+        let mut order: Vec<usize> = visible.to_vec();
+        order.sort_by_key(|&idx| {
+            let format = &self.formats[idx];
+            (
+                format.wrap_priority.is_none(),
+                u8::MAX - format.wrap_priority.unwrap_or_default(),
+                usize::MAX - column_widths[idx],
+            )
+        });
+        for idx in order {
+            let floor = min_width.max(self.formats[idx].min_width());
+            let cut = min(column_widths[idx].saturating_sub(floor), excess);
+            column_widths[idx] -= cut;
+            excess -= cut;
+        }
+
+        column_widths
+    }
+
+    /// Calculate the width of a line containing the margin, the given columns, and the separators and padding between them.
+    fn line_width(&self, visible: &[usize], column_widths: &[usize], separators: &[Option<&str>]) -> usize {
+        let columns: usize = visible.iter().map(|&idx| column_widths[idx]).sum();
+        let separator_width: usize = visible
+            .iter()
+            .take(visible.len().saturating_sub(1))
+            .filter_map(|&idx| separators[idx])
+            .map(|separator| separator.chars().count())
+            .sum();
+        let padding = (2 * visible.len()).saturating_sub(1) * self.options.padding;
+        let (frame_left, frame_right) = self.frame_widths();
+        self.options.margin_left + frame_left + columns + separator_width + padding + frame_right
+    }
+
+    /// The widths of the left and right frame sides, which are zero without a frame.
+    fn frame_widths(&self) -> (usize, usize) {
+        self.frame
+            .as_ref()
+            .map_or((0, 0), |frame| (frame.0.chars().count(), frame.1.chars().count()))
+    }
+}
+
+/// Truncate or pad a line to exactly fit the column width, following the column's alignment for the role of its row,
+/// truncation, and fill.
+pub fn fit_line(line: &str, column_width: usize, format: &ColumnFormat, role: RowRole) -> String {
+    fit_line_aligned(line, column_width, format, format.alignment_for(role))
+}
+
+/// Fill the line added below a cell to match a taller neighbour: plain spaces, or the column's fill when filling.
+pub fn blank_line(format: &ColumnFormat, column_width: usize, fill: bool) -> String {
+    let ch = if fill { format.fill } else { ' ' };
+    ch.to_string().repeat(column_width)
+}
+
+/// Truncate or pad a line to exactly fit the column width, following the column's truncation and fill but the given
+/// alignment.
+///
+/// Over-long lines are replaced by the column's truncator, if it has one, and its text is cut at the end if it is
+/// still too wide.
+pub fn fit_line_aligned(line: &str, column_width: usize, format: &ColumnFormat, alignment: Alignment) -> String {
+    if let Some(truncator) = format.truncator.as_ref()
+        && visible_width(line) > column_width
+    {
+        let replaced = truncator(line, column_width);
+        return pad_line(
+            &replaced,
+            column_width,
+            alignment,
+            (TruncateSide::Right, "", Style::new()),
+            format.fill,
+        );
+    }
+    pad_line(
+        line,
+        column_width,
+        alignment,
+        (format.truncate_side, &format.truncate_marker, format.truncate_marker_style),
+        format.fill,
+    )
+}
+
+/// Truncate or pad a line to exactly fit the column width, padding with the fill character.
+///
+/// Lines are measured by their visible characters, ignoring ANSI escape sequences, and truncated on the given side,
+/// with the given marker wherever the cut is not at the end.
+pub fn pad_line(
+    line: &str,
+    column_width: usize,
+    alignment: Alignment,
+    truncation: (TruncateSide, &str, Style),
+    fill: char,
+) -> String {
+    let line_len = visible_width(line);
+
+    if line_len > column_width {
+        // Truncate to column width (handling Unicode)
+        return truncate(line, line_len, column_width, truncation);
+    }
+
+    // Pad to column width
+    let (left, right) = split_padding(column_width - line_len, alignment);
+    let fill_str = fill.to_string();
+    format!("{}{line}{}", fill_str.repeat(left), fill_str.repeat(right))
+}
+
+/// Split the padding of a line into the amounts before and after it, following the alignment.
+pub const fn split_padding(padding: usize, alignment: Alignment) -> (usize, usize) {
+    match alignment {
+        Alignment::Left => (0, padding),
+        Alignment::Right => (padding, 0),
+        Alignment::Center => (padding - padding.div_ceil(2), padding.div_ceil(2)),
+    }
+}
+
+/// Shorten a line of `line_len` characters to the column width, marking any cut other than at the end.
+///
+/// Columns narrower than the marker keep the same side of the line without it, rather than a cut marker.
+///
+/// In styled lines, which contain escape sequences, all styling is reset before the marker, which is drawn in
+/// the marker style, and the style in effect where the kept end starts is restored after it. Truncated styled
+/// lines always end with a reset, so their styles never reach the separator or the next column.
+#[expect(clippy::single_call_fn, reason = "Keeps the truncation sides apart from padding.")]
+fn truncate(
+    line: &str,
+    line_len: usize,
+    column_width: usize,
+    (truncate_side, marker, marker_style): (TruncateSide, &str, Style),
+) -> String {
+    let styled = line.contains(ESCAPE);
+    let reset = if styled { RESET } else { "" };
+
+    // Characters kept around the marker
+    let shown = if visible_width(marker) > column_width { "" } else { marker };
+    let kept = content_width(column_width, shown);
+    let (head, tail) = match truncate_side {
+        TruncateSide::Right => return format!("{}{reset}", split(line, column_width).0),
+        TruncateSide::Left => (0, kept),
+        TruncateSide::Middle => (kept.div_ceil(2), kept.div_euclid(2)),
+    };
+
+    let start = split(line, head).0;
+    let (skipped, end) = split(line, line_len - tail);
+    if !styled {
+        return format!("{start}{shown}{end}");
+    }
+
+    // Restore the style of the kept end by replaying the escape sequences before it
+    let state = slice(skipped, 0, 0);
+    let styled_marker = if shown.is_empty() {
+        String::new()
+    } else {
+        format!("{}{shown}{}", marker_style.render_sgr(), marker_style.render_reset())
+    };
+    format!("{start}{RESET}{styled_marker}{state}{end}{RESET}")
+}
+
+impl Debug for ColumnFormatter<'_> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        let mut custom_formats: Vec<&str> = self.custom_formats.keys().copied().collect();
+        custom_formats.sort_unstable();
+
+        let mut debug = f.debug_struct("ColumnFormatter");
+        debug
+            .field("formats", &self.formats)
+            .field("items", &self.items)
+            .field("max_width", &self.max_width)
+            .field("total_width", &self.total_width)
+            .field("options", &self.options)
+            .field("unterminated", &self.unterminated)
+            .field("block_width", &self.block_width);
+        #[cfg(feature = "unicode")]
+        debug.field("normalization", &self.normalization);
+        debug
+            .field("frame", &self.frame)
+            .field("color", &self.color)
+            .field("accessibility", &self.accessibility)
+            .field("row_links", &self.row_links)
+            .field("wrap_policy", &self.wrap_policy)
+            .field("custom_formats", &custom_formats)
+            .field("overflow_indicator", &self.overflow_indicator)
+            .field("separator_style", &self.separator_style)
+            .field("padding_style", &self.padding_style)
+            .field("catch_panics", &self.catch_panics)
+            .field("panicked_cells", &self.panicked_cells.get());
+        #[cfg(feature = "bidi")]
+        debug.field("bidi", &self.bidi);
+        debug
+            .field("line_ending", &self.line_ending)
+            .field("explicit", &self.explicit)
+            .finish()
     }
 }
 
@@ -243,7 +2115,7 @@ impl Display for ColumnFormatter<'_> {
         let mut buffer = Vec::new();
 
         // Format the items into columns
-        if self.format_columns(&mut buffer).is_err() {
+        if self.format_columns(&mut buffer, LineEnding::Lf).is_err() {
             return Err(fmt::Error);
         }
 
@@ -0,0 +1,97 @@
+//! Defines the settings of one column in a saved table layout.
+//!
+//! This module contains the `ColumnSpec` struct, one entry of a `LayoutSpec`. Every setting is
+//! optional and written as plain text, so that layouts read naturally in TOML or JSON:
+//! - `header`: The text of the column's header cell.
+//! - `width`: A number of characters, such as `12`, or a percentage of the available width, such as `40%`.
+//! - `align`: One of `left`, `right`, or `center`.
+//! - `separator`: The text printed after the column.
+//! - `truncate`: The side cut from over-long lines, one of `right`, `left`, or `middle`.
+//! - `truncate_marker`: The text marking where an over-long line is cut.
+//!
+//! Settings which are left out keep the value given by the format string.
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// The saved settings of one column of a table.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize), serde(default, deny_unknown_fields))]
+#[non_exhaustive]
+pub struct ColumnSpec {
+    /// Optional text of the header cell.
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub header: Option<String>,
+    /// Optional width, in characters or as a percentage such as `40%`.
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub width: Option<String>,
+    /// Optional alignment, one of `left`, `right`, or `center`.
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub align: Option<String>,
+    /// Optional separator printed after the column.
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub separator: Option<String>,
+    /// Optional side cut from over-long lines, one of `right`, `left`, or `middle`.
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub truncate: Option<String>,
+    /// Optional text marking where an over-long line is cut.
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub truncate_marker: Option<String>,
+}
+
+impl ColumnSpec {
+    /// Create a column which keeps every setting of the format string.
+    #[must_use]
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the text of the header cell.
+    #[must_use]
+    #[inline]
+    pub fn header<S: Into<String>>(mut self, header: S) -> Self {
+        self.header = Some(header.into());
+        self
+    }
+
+    /// Set the width, such as `12` or `40%`.
+    #[must_use]
+    #[inline]
+    pub fn width<S: Into<String>>(mut self, width: S) -> Self {
+        self.width = Some(width.into());
+        self
+    }
+
+    /// Set the alignment, one of `left`, `right`, or `center`.
+    #[must_use]
+    #[inline]
+    pub fn align<S: Into<String>>(mut self, align: S) -> Self {
+        self.align = Some(align.into());
+        self
+    }
+
+    /// Set the separator printed after the column.
+    #[must_use]
+    #[inline]
+    pub fn separator<S: Into<String>>(mut self, separator: S) -> Self {
+        self.separator = Some(separator.into());
+        self
+    }
+
+    /// Set the side cut from over-long lines, one of `right`, `left`, or `middle`.
+    #[must_use]
+    #[inline]
+    pub fn truncate<S: Into<String>>(mut self, truncate: S) -> Self {
+        self.truncate = Some(truncate.into());
+        self
+    }
+
+    /// Set the text marking where an over-long line is cut.
+    #[must_use]
+    #[inline]
+    pub fn truncate_marker<S: Into<String>>(mut self, truncate_marker: S) -> Self {
+        self.truncate_marker = Some(truncate_marker.into());
+        self
+    }
+}
@@ -0,0 +1,218 @@
+//! Defines layouts parsed once and reused across call sites.
+//!
+//! This module contains the `ColumnTemplate` struct, which holds the column formats parsed from a
+//! format string, optional headers, and the layout policies applied whenever it is used. Templates
+//! are `Send` and `Sync`, so one can be kept in a `static` `LazyLock` and shared by every call site
+//! printing the same layout, either through its methods or with the `colprint_with!` macro. Using a
+//! template copies its formats, but never parses the format string again.
+
+use std::iter;
+
+use crate::{
+    ColorChoice, ColumnFormat, ColumnFormatter, FormatError, FormatType, FormattableItem, RenderOptions,
+    macro_support::{self, print_locked},
+    row_role::RowRole,
+};
+
+/// A layout parsed from a format string once, for formatting rows at many call sites.
+///
+/// # Examples
+///
+/// ```
+/// use std::sync::LazyLock;
+///
+/// use colprint::{ColumnTemplate, colformat_with};
+///
+/// static TEMPLATE: LazyLock<ColumnTemplate> =
+///     LazyLock::new(|| ColumnTemplate::parse("{}:5 | {:.2}:6").unwrap().with_headers(["Name", "Ratio"]));
+///
+/// assert_eq!(TEMPLATE.header(), "Name  | Ratio \n");
+/// assert_eq!(colformat_with!(TEMPLATE, "Alice", 0.5), "Alice | 0.50  \n");
+/// ```
+#[derive(Debug, Clone)]
+pub struct ColumnTemplate {
+    /// The format of each column, parsed from the format string.
+    formats: Vec<ColumnFormat>,
+    /// The format type of each column, as the macros read them from the format string.
+    format_types: Vec<FormatType>,
+    /// Optional header cells, rendered with `header`.
+    headers: Option<Vec<String>>,
+    /// The layout policies.
+    options: RenderOptions,
+    /// Optional maximum width of each output line, instead of the configured width.
+    max_width: Option<usize>,
+    /// Optional choice of when styles are emitted, instead of the configured choice.
+    color: Option<ColorChoice>,
+}
+
+impl ColumnTemplate {
+    /// Parse a format string into a template.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FormatError::UnterminatedSpec` if a specifier is left open at the end of the format string, or
+    /// `FormatError::InvalidTimePattern` if a time pattern has an unsupported directive.
+    #[inline]
+    pub fn parse(format_str: &str) -> Result<Self, FormatError> {
+        let formatter = ColumnFormatter::try_new(format_str, iter::empty::<FormattableItem<'_>>())?;
+        Ok(Self {
+            formats: formatter.formats().to_vec(),
+            format_types: macro_support::format_types(format_str),
+            headers: None,
+            options: RenderOptions::default(),
+            max_width: None,
+            color: None,
+        })
+    }
+
+    /// Use the given header cells, one per column, rendered by `header` and `print_header`.
+    #[must_use]
+    #[inline]
+    pub fn with_headers<I, S>(mut self, headers: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.headers = Some(headers.into_iter().map(Into::into).collect());
+        self
+    }
+
+    /// Use the given layout policies for every row formatted with the template.
+    #[must_use]
+    #[inline]
+    pub const fn with_options(mut self, options: RenderOptions) -> Self {
+        self.options = options;
+        self
+    }
+
+    /// Limit every row formatted with the template to the given width.
+    #[must_use]
+    #[inline]
+    pub const fn with_max_width(mut self, max_width: usize) -> Self {
+        self.max_width = Some(max_width);
+        self
+    }
+
+    /// Set when styles are emitted in every row formatted with the template.
+    #[must_use]
+    #[inline]
+    pub const fn with_color(mut self, color: ColorChoice) -> Self {
+        self.color = Some(color);
+        self
+    }
+
+    /// The format of each column, as parsed from the format string.
+    #[must_use]
+    #[inline]
+    pub fn formats(&self) -> &[ColumnFormat] {
+        &self.formats
+    }
+
+    /// The format type of each column, as the macros read them from the format string.
+    #[must_use]
+    #[inline]
+    pub fn format_types(&self) -> &[FormatType] {
+        &self.format_types
+    }
+
+    /// The header cells, if any.
+    #[must_use]
+    #[inline]
+    pub fn headers(&self) -> Option<&[String]> {
+        self.headers.as_deref()
+    }
+
+    /// Create a formatter for the items, laid out by the template.
+    ///
+    /// Builder methods called on the formatter override the template's settings for this call only.
+    #[must_use]
+    #[inline]
+    pub fn formatter<'a, I>(&self, items: I) -> ColumnFormatter<'a>
+    where
+        I: IntoIterator,
+        I::Item: Into<FormattableItem<'a>>,
+    {
+        self.formatter_with(self.formats.clone(), items)
+    }
+
+    /// Format the items as a block of lines laid out by the template.
+    #[must_use]
+    #[inline]
+    pub fn format<'a, I>(&self, items: I) -> String
+    where
+        I: IntoIterator,
+        I::Item: Into<FormattableItem<'a>>,
+    {
+        self.formatter(items).to_string()
+    }
+
+    /// Print the items to standard output, laid out by the template.
+    ///
+    /// # Panics
+    ///
+    /// Panics if writing to standard output fails, as `print!` does.
+    #[inline]
+    pub fn print<'a, I>(&self, items: I)
+    where
+        I: IntoIterator,
+        I::Item: Into<FormattableItem<'a>>,
+    {
+        print_locked(&self.formatter(items));
+    }
+
+    /// Format the header cells as a block of lines laid out by the template, or an empty string without headers.
+    ///
+    /// Headers are inserted as they are, whatever their column's format, and take the column's header alignment.
+    /// They only line up with the rows in columns with an explicit width, as each block is measured on its own.
+    #[must_use]
+    #[inline]
+    pub fn header(&self) -> String {
+        let Some(headers) = self.headers.as_ref() else {
+            return String::new();
+        };
+        let formats = self
+            .formats
+            .iter()
+            .map(|format| ColumnFormat {
+                format_type: FormatType::Verbatim,
+                custom_name: None,
+                value_spec: None,
+                time_pattern: None,
+                group_digits: None,
+                alignment: format.alignment_for(RowRole::Header),
+                highlight: false,
+                ..format.clone()
+            })
+            .collect();
+        self.formatter_with(formats, headers.iter().map(|header| FormattableItem::Verbatim(header)))
+            .to_string()
+    }
+
+    /// Print the header cells to standard output, laid out by the template, if there are any.
+    ///
+    /// # Panics
+    ///
+    /// Panics if writing to standard output fails, as `print!` does.
+    #[inline]
+    pub fn print_header(&self) {
+        print_locked(&self.header());
+    }
+
+    /// Create a formatter for the items with the given column formats and the template's settings.
+    fn formatter_with<'a, I>(&self, formats: Vec<ColumnFormat>, items: I) -> ColumnFormatter<'a>
+    where
+        I: IntoIterator,
+        I::Item: Into<FormattableItem<'a>>,
+    {
+        let mut formatter = ColumnFormatter::new("", items)
+            .with_formats(formats)
+            .with_options(self.options);
+        if let Some(max_width) = self.max_width {
+            formatter = formatter.with_max_width(max_width);
+        }
+        if let Some(color) = self.color {
+            formatter = formatter.with_color(color);
+        }
+        formatter
+    }
+}
@@ -0,0 +1,44 @@
+//! Provides column-major input for tables.
+//!
+//! This module contains the `Columns` trait, implemented for tuples of up to eight collections, such as
+//! `(names, ages, scores)` where each is a `Vec`. `Table::from_columns` renders every value of each
+//! collection in its column's format and zips the cells into rows, saving a transpose at the call site.
+
+use std::fmt::{Debug, Display};
+
+use crate::FormattableItem;
+
+/// Collections of values, one per column, which can be rendered column by column.
+pub trait Columns {
+    /// Render each value of each column, given the column's index and the value, returning the cells of each column.
+    fn cells(self, render: &mut dyn FnMut(usize, &FormattableItem<'_>) -> String) -> Vec<Vec<String>>;
+}
+
+/// Implement `Columns` for a tuple of collections, given the index and type of each element.
+macro_rules! impl_columns {
+    ($($idx:tt $column:ident),+) => {
+        impl<$($column),+> Columns for ($($column,)+)
+        where
+            $($column: IntoIterator, $column::Item: Display + Debug,)+
+        {
+            #[inline]
+            fn cells(self, render: &mut dyn FnMut(usize, &FormattableItem<'_>) -> String) -> Vec<Vec<String>> {
+                vec![$(
+                    self.$idx
+                        .into_iter()
+                        .map(|value| render($idx, &FormattableItem::Both(&value, &value)))
+                        .collect(),
+                )+]
+            }
+        }
+    };
+}
+
+impl_columns!(0 A);
+impl_columns!(0 A, 1 B);
+impl_columns!(0 A, 1 B, 2 C);
+impl_columns!(0 A, 1 B, 2 C, 3 D);
+impl_columns!(0 A, 1 B, 2 C, 3 D, 4 E);
+impl_columns!(0 A, 1 B, 2 C, 3 D, 4 E, 5 F);
+impl_columns!(0 A, 1 B, 2 C, 3 D, 4 E, 5 F, 6 G);
+impl_columns!(0 A, 1 B, 2 C, 3 D, 4 E, 5 F, 6 G, 7 H);
@@ -0,0 +1,136 @@
+//! Dumps the fields of any `Debug` value as an aligned table of names and values.
+//!
+//! This module contains the `debug_fields` function, which parses the top level of a value's
+//! pretty `Debug` output, `{:#?}`, into its entries. Entries are split by tracking the depth of
+//! brackets outside string and character literals, so nested structures stay whole:
+//! - Structs and struct variants give `name: value` pairs, headed by the type or variant name.
+//! - Maps give `key: value` pairs, with the keys as they are printed.
+//! - Tuple structs and tuple variants are named by the index of each field.
+//!
+//! Nested values keep their pretty `Debug` lines, dedented to the start of their cell. Output which
+//! does not have this shape, such as a unit variant or a list, is dumped as it is.
+
+use std::fmt::Debug;
+
+use crate::{FormattableItem, Table};
+
+/// The indentation pretty `Debug` output adds at each level.
+const INDENT: &str = "    ";
+
+/// Format the fields of a value as a table of names and values, headed by its type or variant name.
+///
+/// # Examples
+///
+/// ```
+/// use colprint::{debug_fields, doctest_support::Person};
+///
+/// assert_eq!(debug_fields(&Person::ALICE), "Person\nname  \"Alice\"\nage   30     \n");
+/// ```
+#[must_use]
+#[inline]
+pub fn debug_fields<T: Debug + ?Sized>(value: &T) -> String {
+    let dump = format!("{value:#?}");
+    let Some((title, entries)) = parse(&dump) else {
+        return format!("{dump}\n");
+    };
+
+    // Verbatim cells are inserted as they are, so the names and values are never quoted again
+    let mut table = Table::new("{}  {}");
+    for entry in &entries {
+        table.push_row(&[FormattableItem::Verbatim(&entry.0), FormattableItem::Verbatim(&entry.1)]);
+    }
+    if title.is_empty() {
+        table.to_string()
+    } else {
+        format!("{title}\n{table}")
+    }
+}
+
+/// Split pretty `Debug` output into the name before its outer brackets and its entries, as names and values.
+#[expect(clippy::single_call_fn, reason = "Keeps the parsing apart from the rendering.")]
+fn parse(dump: &str) -> Option<(&str, Vec<(String, String)>)> {
+    let (first, rest) = dump.split_once('\n')?;
+    let (title, close, named) = if let Some(title) = first.strip_suffix('{') {
+        (title.trim_end(), "}", true)
+    } else if let Some(title) = first.strip_suffix('(') {
+        (title, ")", false)
+    } else {
+        return None;
+    };
+    let body = rest.strip_suffix(close)?;
+
+    let entries = split_entries(body)?;
+    let named_entries = entries
+        .into_iter()
+        .enumerate()
+        .map(|(idx, entry)| {
+            if named {
+                let (name, value) = split_name(&entry)?;
+                Some((name.to_owned(), value.to_owned()))
+            } else {
+                Some((idx.to_string(), entry))
+            }
+        })
+        .collect::<Option<Vec<_>>>()?;
+    Some((title, named_entries))
+}
+
+/// Split the indented lines between the outer brackets into entries, dedented and without their trailing commas.
+#[expect(
+    clippy::single_call_fn,
+    reason = "Keeps the bracket tracking apart from naming the entries."
+)]
+fn split_entries(body: &str) -> Option<Vec<String>> {
+    let mut entries = Vec::new();
+    let mut current: Vec<&str> = Vec::new();
+    let mut depth = 0_usize;
+    for line in body.lines() {
+        current.push(line.strip_prefix(INDENT)?);
+        depth = scan(line, depth)?.0;
+        if depth == 0 {
+            entries.push(current.join("\n").strip_suffix(',')?.to_owned());
+            current.clear();
+        }
+    }
+    current.is_empty().then_some(entries)
+}
+
+/// Scan text from the given depth of brackets, skipping string and character literals, returning the depth at
+/// its end and whether it ends inside a literal.
+///
+/// Returns `None` if a bracket closes more than was opened.
+fn scan(text: &str, mut depth: usize) -> Option<(usize, bool)> {
+    let mut quote = None;
+    let mut escaped = false;
+    for ch in text.chars() {
+        match (quote, ch) {
+            (Some(_), _) if escaped => escaped = false,
+            (Some(_), '\\') => escaped = true,
+            (Some(open), _) if ch == open => quote = None,
+            (None, '"' | '\'') => quote = Some(ch),
+            (None, '{' | '[' | '(') => depth += 1,
+            (None, '}' | ']' | ')') => depth = depth.checked_sub(1)?,
+            _ => {}
+        }
+    }
+    Some((depth, quote.is_some()))
+}
+
+/// Split an entry at the first `: ` outside any literal or bracket into its name and value.
+#[expect(
+    clippy::single_call_fn,
+    reason = "Keeps the search for the name apart from splitting the entries."
+)]
+fn split_name(entry: &str) -> Option<(&str, &str)> {
+    let (first, _) = entry.split_once('\n').unwrap_or((entry, ""));
+    let mut start = 0;
+    while let Some(pos) = first.get(start..)?.find(": ") {
+        let split = start + pos;
+        let (name, value) = (entry.get(..split)?, entry.get(split + 2..)?);
+        if scan(name, 0) == Some((0, false)) {
+            return Some((name, value));
+        }
+        start = split + 2;
+    }
+    None
+}
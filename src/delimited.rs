@@ -0,0 +1,156 @@
+//! Shared backend for tables delimited by `|` characters.
+//!
+//! This module renders the Markdown and Org output styles. Both dialects write every row as
+//! `| cell | cell |` with cells padded to a common width, and differ only in how `|` is escaped
+//! inside cells, how the rule under the header is drawn, and whether alignment hints are written:
+//! - Markdown escapes `|` as `\|` and draws `| --- | --: |`, encoding alignment in the rule.
+//! - Org escapes `|` as `\vert{}` and draws `|-----+-----|`, with an optional `| <l> | <r> |` row.
+//!
+//! The header can be repeated every few rows of long tables. Org repeats the rule under it too,
+//! while Markdown, which allows only one rule, repeats just the header row.
+//!
+//! Multi-line cells are joined onto one line with a configurable delimiter, as neither dialect
+//! supports line breaks within a row.
+
+use std::fmt::{Result as FmtResult, Write};
+
+use crate::{Alignment, OutputStyle, Style, TruncateSide, column_formatter::pad_line};
+
+/// Minimum column width, fitting Org alignment cookies and Markdown rules such as `:-:`.
+const COOKIE_WIDTH: usize = 3;
+
+/// A table delimited by `|` characters, in the Markdown or Org dialect.
+pub struct Delimited<'a> {
+    /// The output style, which selects the dialect.
+    pub style: OutputStyle,
+    /// Alignment of each column.
+    pub alignments: &'a [Alignment],
+    /// Text used to join the lines of multi-line cells.
+    pub line_join: &'a str,
+    /// Optional number of rows after which the header is written again.
+    pub repeat_header_every: Option<usize>,
+}
+
+impl Delimited<'_> {
+    /// Write the headers, rows, and footers.
+    #[inline]
+    pub fn write<W: Write>(
+        &self,
+        writer: &mut W,
+        headers: Option<&[String]>,
+        rows: &[&[String]],
+        footers: Option<&[String]>,
+    ) -> FmtResult {
+        let header_cells = headers.map(|cells| self.cells(cells));
+        let row_cells: Vec<Vec<String>> = rows.iter().map(|cells| self.cells(cells)).collect();
+        let footer_cells = footers.map(|cells| self.cells(cells));
+
+        // Calculate column widths over every cell that will be written
+        let alignment_row = matches!(self.style, OutputStyle::Org { alignment_row: true });
+        let minimum = if alignment_row || self.style == OutputStyle::Markdown {
+            COOKIE_WIDTH
+        } else {
+            1
+        };
+        let column_widths: Vec<usize> = (0..self.alignments.len())
+            .map(|idx| {
+                row_cells
+                    .iter()
+                    .chain(header_cells.as_ref())
+                    .chain(footer_cells.as_ref())
+                    .filter_map(|cells| cells.get(idx))
+                    .map(|cell| cell.chars().count())
+                    .fold(minimum, usize::max)
+            })
+            .collect();
+
+        if let Some(cells) = header_cells.as_ref() {
+            self.write_row(writer, cells, &column_widths)?;
+            self.write_rule(writer, &column_widths)?;
+        }
+        if alignment_row {
+            let cookies: Vec<String> = self
+                .alignments
+                .iter()
+                .map(|alignment| {
+                    match *alignment {
+                        Alignment::Left => "<l>",
+                        Alignment::Right => "<r>",
+                        Alignment::Center => "<c>",
+                    }
+                    .to_owned()
+                })
+                .collect();
+            self.write_row(writer, &cookies, &column_widths)?;
+        }
+        for (idx, cells) in row_cells.iter().enumerate() {
+            if let Some(header) = header_cells.as_ref()
+                && self.repeat_header_every.is_some_and(|every| idx > 0 && idx % every == 0)
+            {
+                self.write_row(writer, header, &column_widths)?;
+                if self.style != OutputStyle::Markdown {
+                    self.write_rule(writer, &column_widths)?;
+                }
+            }
+            self.write_row(writer, cells, &column_widths)?;
+        }
+        if let Some(cells) = footer_cells.as_ref() {
+            if self.style != OutputStyle::Markdown {
+                self.write_rule(writer, &column_widths)?;
+            }
+            self.write_row(writer, cells, &column_widths)?;
+        }
+
+        Ok(())
+    }
+
+    /// Escape each cell and join its lines onto a single line.
+    fn cells(&self, cells: &[String]) -> Vec<String> {
+        (0..self.alignments.len())
+            .map(|idx| {
+                let cell = cells.get(idx).map_or("", String::as_str);
+                let escape = if self.style == OutputStyle::Markdown {
+                    "\\|"
+                } else {
+                    "\\vert{}"
+                };
+                cell.lines().collect::<Vec<_>>().join(self.line_join).replace('|', escape)
+            })
+            .collect()
+    }
+
+    /// Write a single row of cells.
+    fn write_row(&self, writer: &mut impl Write, cells: &[String], column_widths: &[usize]) -> FmtResult {
+        write!(writer, "|")?;
+        for ((cell, &column_width), &alignment) in cells.iter().zip(column_widths).zip(self.alignments) {
+            write!(
+                writer,
+                " {} |",
+                pad_line(cell, column_width, alignment, (TruncateSide::Right, "", Style::new()), ' ')
+            )?;
+        }
+        writeln!(writer)
+    }
+
+    /// Write the rule separating the header from the rows.
+    fn write_rule(&self, writer: &mut impl Write, column_widths: &[usize]) -> FmtResult {
+        if self.style == OutputStyle::Markdown {
+            write!(writer, "|")?;
+            for (&column_width, &alignment) in column_widths.iter().zip(self.alignments) {
+                let rule = match alignment {
+                    Alignment::Left => "-".repeat(column_width),
+                    Alignment::Right => format!("{}:", "-".repeat(column_width - 1)),
+                    Alignment::Center => format!(":{}:", "-".repeat(column_width - 2)),
+                };
+                write!(writer, " {rule} |")?;
+            }
+            return writeln!(writer);
+        }
+
+        let segments: Vec<String> = column_widths
+            .iter()
+            .map(|&column_width| "-".repeat(column_width + 2))
+            .collect();
+        writeln!(writer, "|{}|", segments.join("+"))
+    }
+}
@@ -0,0 +1,15 @@
+//! Defines the measured size of rendered output.
+//!
+//! This module contains the `Dimensions` struct, returned by `ColumnFormatter::measure`, which
+//! reports how many lines the output occupies and how wide its widest line is, without writing
+//! anything.
+
+/// The size of rendered output.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct Dimensions {
+    /// The number of lines written.
+    pub lines: usize,
+    /// The width of the widest line, in characters.
+    pub width: usize,
+}
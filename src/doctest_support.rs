@@ -0,0 +1,31 @@
+//! Sample types for the examples in the documentation.
+//!
+//! This module is hidden from the documentation. It gives every doc example the same small values
+//! to print, so the examples stay short, compile, and run as doctests asserting their exact output.
+
+use std::fmt::{Display, Formatter, Result as FmtResult};
+
+/// A person, with two-line `Display` output and distinct `Debug` output.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct Person {
+    /// The person's name.
+    pub name: &'static str,
+    /// The person's age in years.
+    pub age: u32,
+}
+
+impl Person {
+    /// A sample person named Alice.
+    pub const ALICE: Self = Self { name: "Alice", age: 30 };
+
+    /// A sample person named Bob.
+    pub const BOB: Self = Self { name: "Bob", age: 25 };
+}
+
+impl Display for Person {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "Name: {}\nAge: {}", self.name, self.age)
+    }
+}
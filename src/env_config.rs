@@ -0,0 +1,159 @@
+//! Defaults read from the environment, so output can be tuned without code changes.
+//!
+//! The environment is read once, the first time a formatter or table is created:
+//! - `COLPRINT_WIDTH`: A maximum width for every `ColumnFormatter`, as with `with_max_width`.
+//! - `COLPRINT_COLOR`: `auto`, `always`, or `never`, the `ColorChoice` of every formatter and table.
+//! - `COLPRINT_ASCII`: `1` to only write ASCII, using `...` in place of the `…` truncation marker, `#` in place of
+//!   the `█` of progress bars, and `us` in place of `µs` in durations.
+//! - `COLPRINT_TRUNCATE_MARKER`: The text marking where over-long lines are cut, instead of `…`.
+//! - `COLPRINT_ACCESSIBILITY`: `visual` or `verbose`, the `Accessibility` of every formatter and table.
+//!
+//! These only seed the defaults, so every setting can still be overridden in code for each
+//! formatter or table. Invalid values are ignored, with a warning when the `log` feature is on.
+//!
+//! Formatters using the frozen `RenderOptions::v1` preset ignore the environment. Any formatter or table can also be
+//! given another `EnvConfig` with `with_env`, such as `EnvConfig::empty()`, so tests do not depend on the environment
+//! they run in.
+
+use std::{env, sync::OnceLock};
+
+use crate::{Accessibility, ColorChoice};
+
+/// The truncation marker used by default.
+const MARKER: &str = "\u{2026}";
+
+/// The truncation marker used when only ASCII is written.
+const ASCII_MARKER: &str = "...";
+
+/// Defaults read from the environment.
+///
+/// # Examples
+///
+/// ```
+/// use colprint::{ColumnFormatter, EnvConfig};
+///
+/// let env = EnvConfig::from_lookup(|name| (name == "COLPRINT_ASCII").then(|| "1".to_owned()));
+/// assert_eq!(env.truncate_marker, "...");
+///
+/// let formatter = ColumnFormatter::new("{}:8~m", [&"a long line"]).with_env(&env);
+/// assert_eq!(formatter.to_string(), "a l...ne\n");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct EnvConfig {
+    /// Optional maximum width of each output line.
+    pub width: Option<usize>,
+    /// Optional policy for emitting styled output.
+    pub color: Option<ColorChoice>,
+    /// The text marking where over-long lines are cut.
+    pub truncate_marker: String,
+    /// Whether only ASCII is written.
+    pub ascii: bool,
+    /// Optional policy for signalling omitted content.
+    pub accessibility: Option<Accessibility>,
+}
+
+impl EnvConfig {
+    /// The defaults read from the environment, which is only read the first time.
+    #[must_use]
+    #[inline]
+    pub fn get() -> &'static Self {
+        static CONFIG: OnceLock<EnvConfig> = OnceLock::new();
+        CONFIG.get_or_init(Self::read)
+    }
+
+    /// No defaults, as if none of the variables were set.
+    #[must_use]
+    #[inline]
+    pub fn empty() -> Self {
+        Self {
+            width: None,
+            color: None,
+            truncate_marker: MARKER.to_owned(),
+            ascii: false,
+            accessibility: None,
+        }
+    }
+
+    /// Read the defaults through the given lookup of variables by name, ignoring invalid values.
+    ///
+    /// Unset and empty variables are treated alike.
+    #[must_use]
+    #[inline]
+    pub fn from_lookup<F>(lookup: F) -> Self
+    where
+        F: Fn(&str) -> Option<String>,
+    {
+        let var = |name: &str| lookup(name).filter(|value| !value.is_empty());
+
+        let width = var("COLPRINT_WIDTH").and_then(|value| {
+            let width = value.trim().parse().ok();
+            warn_invalid("COLPRINT_WIDTH", &value, width.is_none());
+            width
+        });
+
+        let color = var("COLPRINT_COLOR").and_then(|value| {
+            let color = match value.trim().to_ascii_lowercase().as_str() {
+                "auto" => Some(ColorChoice::Auto),
+                "always" => Some(ColorChoice::Always),
+                "never" => Some(ColorChoice::Never),
+                _ => None,
+            };
+            warn_invalid("COLPRINT_COLOR", &value, color.is_none());
+            color
+        });
+
+        let ascii = var("COLPRINT_ASCII").is_some_and(|value| {
+            let flag = value.trim();
+            warn_invalid("COLPRINT_ASCII", &value, !matches!(flag, "0" | "1"));
+            flag == "1"
+        });
+        let default_marker = if ascii { ASCII_MARKER } else { MARKER };
+        let truncate_marker = var("COLPRINT_TRUNCATE_MARKER")
+            .filter(|marker| !ascii || marker.is_ascii())
+            .unwrap_or_else(|| default_marker.to_owned());
+
+        let accessibility = var("COLPRINT_ACCESSIBILITY").and_then(|value| {
+            let accessibility = match value.trim().to_ascii_lowercase().as_str() {
+                "visual" => Some(Accessibility::Visual),
+                "verbose" => Some(Accessibility::Verbose),
+                _ => None,
+            };
+            warn_invalid("COLPRINT_ACCESSIBILITY", &value, accessibility.is_none());
+            accessibility
+        });
+
+        Self {
+            width,
+            color,
+            truncate_marker,
+            ascii,
+            accessibility,
+        }
+    }
+
+    /// Read the defaults from the environment, treating non-Unicode values as unset.
+    #[expect(clippy::single_call_fn, reason = "Keeps reading the environment apart from caching it.")]
+    fn read() -> Self {
+        Self::from_lookup(|name| env::var(name).ok())
+    }
+}
+
+impl Default for EnvConfig {
+    #[inline]
+    fn default() -> Self {
+        Self::empty()
+    }
+}
+
+/// Warn that an environment variable holds an invalid value, which is ignored.
+#[cfg(feature = "log")]
+fn warn_invalid(name: &str, value: &str, invalid: bool) {
+    if invalid {
+        log::warn!("colprint: ignoring invalid value `{value}` for {name}");
+    }
+}
+
+/// Invalid values are ignored silently without the `log` feature.
+#[cfg(not(feature = "log"))]
+const fn warn_invalid(_name: &str, _value: &str, _invalid: bool) {}
@@ -0,0 +1,17 @@
+//! Defines which rows determine column widths when a table is filtered.
+//!
+//! This module contains the `FilterWidths` enum, used by `Table::render_filtered` to decide
+//! whether automatic column widths are measured over only the rows that pass the filter
+//! (giving the tightest output) or over every row in the table (keeping widths stable
+//! between different filters).
+
+/// Source of automatic column widths for filtered tables.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum FilterWidths {
+    /// Measure only the rows that pass the filter.
+    #[default]
+    Filtered,
+    /// Measure every row in the table.
+    AllRows,
+}
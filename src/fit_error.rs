@@ -0,0 +1,72 @@
+//! Defines the error reported when rendered output is wider than allowed.
+//!
+//! This module contains the `FitError` struct, returned by `ColumnFormatter::check_fits` and
+//! `Table::check_fits` for the first line wider than the maximum width, and shown by the
+//! `assert_fits!` macro. Lines are measured by their visible characters, ignoring ANSI escape
+//! sequences. Its message points at the overflow with a caret under the first character past
+//! the maximum width:
+//!
+//! ```text
+//! line 1 is 12 characters wide, exceeding the maximum of 10:
+//! beta | 12345
+//!           ^
+//! ```
+
+use std::{
+    error::Error,
+    fmt::{Display, Formatter, Result as FmtResult},
+};
+
+use crate::slice::visible_width;
+
+/// The first line of rendered output which is wider than the maximum width.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct FitError {
+    /// Line number of the offending line, counting from one.
+    pub line: usize,
+    /// Width of the offending line, in visible characters.
+    pub width: usize,
+    /// The maximum width which was exceeded.
+    pub max_width: usize,
+    /// The offending line.
+    pub text: String,
+}
+
+impl FitError {
+    /// Check that no line is wider than the maximum width, reporting the first which is.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `FitError` describing the first line wider than the maximum width.
+    pub(crate) fn check<'a>(lines: impl IntoIterator<Item = &'a str>, max_width: usize) -> Result<(), Self> {
+        lines
+            .into_iter()
+            .enumerate()
+            .map(|(idx, line)| (idx, line, visible_width(line)))
+            .find(|&(_, _, width)| width > max_width)
+            .map_or(Ok(()), |(idx, line, width)| {
+                Err(Self {
+                    line: idx + 1,
+                    width,
+                    max_width,
+                    text: line.to_owned(),
+                })
+            })
+    }
+}
+
+impl Display for FitError {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        writeln!(
+            f,
+            "line {} is {} characters wide, exceeding the maximum of {}:",
+            self.line, self.width, self.max_width
+        )?;
+        writeln!(f, "{}", self.text)?;
+        write!(f, "{}^", " ".repeat(self.max_width))
+    }
+}
+
+impl Error for FitError {}
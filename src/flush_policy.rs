@@ -0,0 +1,20 @@
+//! Defines when a `TableWriter` flushes the rows it has written.
+//!
+//! This module contains the `FlushPolicy` enum, which trades latency against throughput when
+//! streaming rows over slow pipes:
+//! - `EveryRow`: Flush after every row, so interactive consumers see each row at once (the default).
+//! - `EveryN`: Flush after every `n` rows, batching writes for throughput.
+//! - `Manual`: Only flush when `TableWriter::flush` is called, or when the writer is dropped.
+
+/// Policy for flushing streamed rows.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum FlushPolicy {
+    /// Flush after every row.
+    #[default]
+    EveryRow,
+    /// Flush after every given number of rows; zero flushes after every row.
+    EveryN(usize),
+    /// Flush only on request, or when dropped.
+    Manual,
+}
@@ -0,0 +1,140 @@
+//! Defines the errors reported by fallible formatter construction.
+//!
+//! This module contains the `FormatError` enum, returned by the `try_` variants of the
+//! `ColumnFormatter` builder methods and by `ColumnFormatter::align_rows` when a requested layout
+//! cannot be honoured, by `Table::try_push_row` when a row is too long, by `Table::select_columns` when a
+//! selected column does not exist, by `Table::with_layout` when a layout setting is invalid, by `Table::write_to` when a strict
+//! table cannot be written, and by
+//! `ColumnFormatter::write_to` when the output cannot be written.
+
+use std::{
+    error::Error,
+    fmt::{Display, Formatter, Result as FmtResult},
+    io::ErrorKind,
+};
+
+/// Errors arising from an impossible layout.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum FormatError {
+    /// The explicit column widths and separators are wider than the total width.
+    WidthBudgetExceeded {
+        /// Width needed by the explicit columns and the separators.
+        required: usize,
+        /// The total width available.
+        available: usize,
+    },
+    /// A strict formatter was given an item which cannot provide its column's representation.
+    FormatMismatch {
+        /// Index of the first mismatched column.
+        column: usize,
+    },
+    /// A specifier is left open at the end of the format string, such as the `{:?` in `"{} | {:?"`.
+    UnterminatedSpec {
+        /// Byte offset of the opening brace in the format string.
+        offset: usize,
+    },
+    /// A time specifier has a pattern with an unsupported directive, such as the `%Q` in `{:time(%Q)}`.
+    InvalidTimePattern {
+        /// Index of the first column with an invalid pattern.
+        column: usize,
+    },
+    /// A table row has more items than the table has columns.
+    TooManyItems {
+        /// The number of columns in the table.
+        columns: usize,
+        /// The number of items in the row.
+        items: usize,
+    },
+    /// The columns given to a table have different lengths.
+    ColumnLengthMismatch {
+        /// Index of the first column whose length differs from the first column's.
+        column: usize,
+        /// The length of the first column.
+        expected: usize,
+        /// The length of the mismatched column.
+        found: usize,
+    },
+    /// A column selected for rendering is beyond the table's columns.
+    ColumnOutOfRange {
+        /// The index of the selected column.
+        column: usize,
+        /// The number of columns in the table.
+        columns: usize,
+    },
+    /// A header name selected for rendering matches none of the table's headers.
+    UnknownHeader {
+        /// The position of the name in the selection.
+        position: usize,
+    },
+    /// A setting of a table layout is invalid, such as an unknown alignment.
+    InvalidLayout {
+        /// The path of the setting in the layout, such as `columns[1].align`.
+        field: String,
+        /// What is wrong with the setting.
+        message: String,
+    },
+    /// A cell of a strict table in the `Delimited` style contains the delimiter.
+    DelimiterInCell {
+        /// Index of the first column with such a cell.
+        column: usize,
+    },
+    /// Two pairs of lines to align cross, so they cannot both land on the same rows.
+    CrossingRows {
+        /// The earlier pair, as a left line index and a right line index.
+        first: (usize, usize),
+        /// The later pair, which goes back on one side.
+        second: (usize, usize),
+    },
+    /// The output could not be written.
+    Io {
+        /// The kind of the underlying I/O error.
+        kind: ErrorKind,
+    },
+}
+
+impl Display for FormatError {
+    #[expect(clippy::ref_patterns, reason = "Only the layout error holds text, which is borrowed.")]
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match *self {
+            Self::WidthBudgetExceeded { required, available } => write!(
+                f,
+                "explicit column widths and separators need {required} characters but only {available} are available"
+            ),
+            Self::FormatMismatch { column } => {
+                write!(f, "the item in column {column} cannot be formatted as its column requests")
+            }
+            Self::UnterminatedSpec { offset } => {
+                write!(f, "the format specifier starting at byte {offset} is never closed")
+            }
+            Self::InvalidTimePattern { column } => {
+                write!(f, "the time pattern of column {column} has an unsupported directive")
+            }
+            Self::TooManyItems { columns, items } => {
+                write!(f, "a row of {items} items was pushed to a table of {columns} columns")
+            }
+            Self::ColumnLengthMismatch { column, expected, found } => {
+                write!(f, "column {column} has {found} values but the first column has {expected}")
+            }
+            Self::ColumnOutOfRange { column, columns } => {
+                write!(f, "column {column} was selected from a table of {columns} columns")
+            }
+            Self::UnknownHeader { position } => {
+                write!(f, "the header name at position {position} of the selection matches no header")
+            }
+            Self::InvalidLayout { ref field, ref message } => write!(f, "layout field `{field}` {message}"),
+            Self::DelimiterInCell { column } => {
+                write!(f, "a cell of column {column} contains the delimiter of the output")
+            }
+            Self::CrossingRows { first, second } => write!(
+                f,
+                "cannot align lines {} and {} with lines {} and {}, as the pairs cross",
+                first.0, second.0, first.1, second.1
+            ),
+            Self::Io { kind } => write!(f, "failed to write the output: {kind}"),
+        }
+    }
+}
+
+impl Error for FormatError {}
@@ -2,16 +2,17 @@
 //!
 //! This module contains the `FormatPart` enum, which is used during the format string
 //! parsing process to represent different parts of the format string:
-//! - Format specifications like `{}`, `{:?}`, or `{:#?}` with optional width parameters
+//! - Format specifications like `{}`, `{:?}`, or `{:#?}` with optional width, priority, truncation side, and alignment parameters
 //! - Separator text between columns
 //!
 //! These elements are used internally by the `ColumnFormatter` when parsing format
 //! strings to create the appropriate column formats.
 
 /// Helper enum for parsing format strings.
+#[derive(Debug)]
 pub enum FormatPart<'a> {
-    /// Format specifier with optional width
-    Format(&'a str, Option<&'a str>),
-    /// Separator between columns
+    /// Format specifier with optional width, optional priority, optional truncation side, and optional alignment.
+    Format(&'a str, Option<&'a str>, Option<&'a str>, Option<&'a str>, Option<&'a str>),
+    /// Separator between columns.
     Separator(&'a str),
 }
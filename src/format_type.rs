@@ -5,12 +5,26 @@
 //! - `Display`: Standard formatting using the `Display` trait.
 //! - `Debug`: Debug formatting using the `Debug` trait with `{:?}` format.
 //! - `PrettyDebug`: Pretty debug formatting using the `Debug` trait with `{:#?}` format.
+//! - `Duration`: Human-readable durations with `{:dur}` format.
+//! - `Size`: Human-readable byte sizes with `{:size}` format.
+//! - `Time`: Timestamps following a strftime-like pattern with `{:time(%Y-%m-%d %H:%M)}` format.
+//! - `Verbatim`: Pre-rendered text, inserted untouched, with `{:raw}` format.
+//! - `Lines`: A collection with one element per line, each using `Display`, with `{:lines}` format.
+//! - `Hex`: A classic hexdump of bytes, with `{:hex}` format, or `{:hex(8)}` for 8 bytes per line.
+//! - `DebugLines`: A collection with one element per line, each using `Debug`, with `{:lines?}` format.
+//! - `Bar`: A progress bar filling the column, with `{:bar}` format for fractions, or `{:bar(100)}` for percentages.
+//! - `Custom`: A named format such as `{:money}`, rendered by a handler registered with
+//!   `ColumnFormatter::register_format`.
 //!
 //! The format type is determined by the format specifier used in the format string
-//! and controls how items are rendered in the output.
+//! and controls how items are rendered in the output. The enum is non-exhaustive, so new
+//! format types can be added without breaking code matching on it.
+
+use crate::value_spec::ValueSpec;
 
 /// Different formatting types.
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
 pub enum FormatType {
     /// Custom formatting.
     Display,
@@ -18,4 +32,108 @@ pub enum FormatType {
     Debug,
     /// Pretty debug formatting with `:#?`.
     PrettyDebug,
+    /// Human-readable duration formatting with `:dur`.
+    Duration,
+    /// Human-readable byte size formatting with `:size`.
+    Size,
+    /// Timestamp formatting with `:time(pattern)`, or `:time` for ISO 8601 timestamps.
+    Time,
+    /// Pre-rendered text inserted as it is with `:raw`.
+    Verbatim,
+    /// A collection rendered one element per line, each with `Display`, with `:lines`.
+    Lines,
+    /// A collection rendered one element per line, each with `Debug`, with `:lines?`.
+    DebugLines,
+    /// A hexdump of bytes with `:hex(n)`, or `:hex` for 16 bytes per line.
+    Hex,
+    /// A progress bar with `:bar` for values in `0..=1`, or `:bar(max)` for values in `0..=max`.
+    Bar,
+    /// A named format, rendered by the handler registered under the name.
+    Custom(&'static str),
+}
+
+impl FormatType {
+    /// Determine the format type requested by a specifier, such as `{:?}` or `{:dur}`.
+    ///
+    /// Inside the braces, specifiers follow the `std::fmt` grammar, so `{:>8.3?}` requests `Debug`. Named formats
+    /// such as `{:money}` are reported as `Display`, as they only become `Custom` once
+    /// a handler is registered for them; `custom_name` extracts their name.
+    #[must_use]
+    #[inline]
+    pub fn from_spec(spec: &str) -> Self {
+        let body = spec.strip_suffix('}').unwrap_or(spec);
+        if body == "{:time" || body.starts_with("{:time(") {
+            Self::Time
+        } else if body == "{:hex" || body.starts_with("{:hex(") {
+            Self::Hex
+        } else if body == "{:bar" || body.starts_with("{:bar(") {
+            Self::Bar
+        } else if body == "{:lines" {
+            Self::Lines
+        } else if body == "{:lines?" {
+            Self::DebugLines
+        } else if spec.contains(":dur") {
+            Self::Duration
+        } else if spec.contains(":size") {
+            Self::Size
+        } else if spec.contains(":raw") {
+            Self::Verbatim
+        } else if let Some(value_spec) = ValueSpec::parse(spec) {
+            value_spec.format_type
+        } else if spec.contains(":#?") {
+            Self::PrettyDebug
+        } else if spec.contains(":?") {
+            Self::Debug
+        } else {
+            Self::Display
+        }
+    }
+
+    /// The pattern of a time specifier, such as `%H:%M` for `{:time(%H:%M)}`, if any.
+    #[must_use]
+    #[inline]
+    pub fn time_pattern(spec: &str) -> Option<&str> {
+        let rest = spec.strip_prefix("{:time(")?;
+        rest.strip_suffix('}').unwrap_or(rest).strip_suffix(')')
+    }
+
+    /// The number of bytes per line of a hexdump specifier, such as `8` for `{:hex(8)}`, if any.
+    #[must_use]
+    #[inline]
+    pub fn bytes_per_row(spec: &str) -> Option<usize> {
+        let rest = spec.strip_prefix("{:hex(")?;
+        rest.strip_suffix('}')
+            .unwrap_or(rest)
+            .strip_suffix(')')?
+            .trim()
+            .parse()
+            .ok()
+            .filter(|&bytes| bytes > 0)
+    }
+
+    /// The value of a full bar in a progress bar specifier, such as `100` for `{:bar(100)}`, if any.
+    #[must_use]
+    #[inline]
+    pub fn bar_scale(spec: &str) -> Option<f64> {
+        let rest = spec.strip_prefix("{:bar(")?;
+        rest.strip_suffix('}')
+            .unwrap_or(rest)
+            .strip_suffix(')')?
+            .trim()
+            .parse()
+            .ok()
+            .filter(|&scale: &f64| scale.is_finite() && scale > 0.0)
+    }
+
+    /// The name of the custom format requested by a specifier, such as `money` for `{:money}`, if any.
+    ///
+    /// Names are made of ASCII letters, digits, and underscores, and never match a built-in format or a
+    /// `std::fmt` specifier such as `{:80}`.
+    #[must_use]
+    #[inline]
+    pub fn custom_name(spec: &str) -> Option<&str> {
+        let name = spec.strip_prefix("{:")?.strip_suffix('}').unwrap_or(spec.get(2..)?);
+        let valid = !name.is_empty() && name.bytes().all(|b| b.is_ascii_alphanumeric() || b == b'_');
+        (valid && Self::from_spec(spec) == Self::Display && ValueSpec::parse(spec).is_none()).then_some(name)
+    }
 }
@@ -1,21 +1,177 @@
 //! Provides a wrapper for items that can be formatted.
 //!
 //! This module contains the `FormattableItem` enum, which serves as a type-erased
-//! wrapper for items that implement either the `Display` or `Debug` traits. It allows
+//! wrapper for items that implement the `Display` and/or `Debug` traits. It allows
 //! the `ColumnFormatter` to store and format heterogeneous collections of items.
 //!
 //! The enum variants correspond to the different formatting capabilities:
+//! - `Both`: Wraps an item that implements both traits, so any format type can be honoured
 //! - `DisplayItem`: Wraps an item that implements the `Display` trait
 //! - `DebugItem`: Wraps an item that implements the `Debug` trait
+//! - `DurationItem`: Holds a duration, rendered in a human-readable unit
+//! - `SizeItem`: Holds a number of bytes, rendered in a human-readable binary unit
+//! - `BarItem`: Holds a value, rendered as a progress bar filling its column
+//! - `TimeItem`: Holds a point in time, rendered following its column's pattern
+//! - `Verbatim` and `VerbatimString`: Hold pre-rendered text, which is split into lines and otherwise left untouched
+//! - `Bytes`: Borrows a byte slice, rendered as a classic hexdump with an offset, the bytes in hex, and an ASCII gutter
+//! - `Nested`: Wraps another `ColumnFormatter`, whose lines are placed in the cell without trailing padding
+//! - `Cell`: Wraps a `Cell` rendered in advance, whose lines are placed as they are
+//! - `Reader`: Borrows a `BufRead` source, such as a file or process output, whose lines are streamed
+//!   when the cell is rendered and placed as they are; formatters only read as many lines as they keep
+//!
+//! The `Debug` output of an item shows its variant and the first 40 characters of its rendering.
+//!
+//! Items convert from references to values implementing both traits, giving a `Both`, from
+//! references to `Cell`s, and from references to other items, so collections of either can be passed to `ColumnFormatter::new`.
 //!
 //! This abstraction enables the `colprint!` macro to handle mixed formatting types
-//! within a single output.
+//! within a single output. The representation used is chosen by the column's `FormatType`;
+//! items lacking that representation fall back to the one they have, unless the formatter is strict.
+
+use std::{
+    cell::RefCell,
+    fmt::{Debug, Display, Formatter, Result as FmtResult},
+    io::BufRead,
+    time::{Duration, SystemTime},
+};
+
+use crate::{BarGlyphs, Cell, ColumnFormatter, bar, format_type::FormatType, hexdump, humanize, reader, timestamp};
 
-use std::fmt::{Debug, Display};
+/// The number of characters of the rendering shown by the `Debug` output of an item.
+const PREVIEW_LENGTH: usize = 40;
 
 /// A wrapper that formats both Display and Debug trait objects.
+#[derive(Clone)]
 #[non_exhaustive]
 pub enum FormattableItem<'a> {
+    Both(&'a dyn Display, &'a dyn Debug),
     DisplayItem(&'a dyn Display),
     DebugItem(&'a dyn Debug),
+    DurationItem(Duration),
+    SizeItem(u64),
+    BarItem(f64),
+    TimeItem(SystemTime),
+    Verbatim(&'a str),
+    VerbatimString(String),
+    Bytes(&'a [u8]),
+    Nested(&'a ColumnFormatter<'a>),
+    Cell(&'a Cell),
+    Reader(&'a RefCell<dyn BufRead + 'a>),
+}
+
+impl FormattableItem<'_> {
+    /// Check whether the item can provide the representation requested by the format type.
+    pub(crate) const fn supports(&self, format_type: FormatType) -> bool {
+        matches!(
+            (self, format_type),
+            (
+                Self::Both(..),
+                FormatType::Display
+                    | FormatType::Debug
+                    | FormatType::PrettyDebug
+                    | FormatType::Lines
+                    | FormatType::DebugLines
+                    | FormatType::Custom(_)
+            ) | (
+                Self::DisplayItem(_),
+                FormatType::Display | FormatType::Lines | FormatType::Custom(_)
+            ) | (
+                Self::DebugItem(_),
+                FormatType::Debug | FormatType::PrettyDebug | FormatType::DebugLines
+            ) | (Self::DurationItem(_), FormatType::Duration)
+                | (Self::SizeItem(_), FormatType::Size)
+                | (Self::BarItem(_), FormatType::Bar)
+                | (Self::TimeItem(_), FormatType::Time)
+                | (Self::Bytes(_), FormatType::Hex)
+                | (
+                    Self::Verbatim(_) | Self::VerbatimString(_) | Self::Nested(_) | Self::Cell(_) | Self::Reader(_),
+                    _
+                )
+        )
+    }
+
+    /// Render the item as text according to the requested format type.
+    ///
+    /// Items which cannot provide the requested representation fall back to the one they have.
+    #[expect(clippy::ref_patterns, reason = "Only the owned text needs to be borrowed.")]
+    pub(crate) fn render(&self, format_type: FormatType) -> String {
+        match *self {
+            Self::Both(display, debug) => match format_type {
+                FormatType::Debug | FormatType::DebugLines => format!("{debug:?}"),
+                FormatType::PrettyDebug => format!("{debug:#?}"),
+                FormatType::Display
+                | FormatType::Duration
+                | FormatType::Size
+                | FormatType::Time
+                | FormatType::Verbatim
+                | FormatType::Lines
+                | FormatType::Hex
+                | FormatType::Bar
+                | FormatType::Custom(_) => {
+                    format!("{display}")
+                }
+            },
+            Self::DisplayItem(display) => format!("{display}"),
+            Self::DebugItem(debug) => {
+                if format_type == FormatType::PrettyDebug {
+                    format!("{debug:#?}")
+                } else {
+                    format!("{debug:?}")
+                }
+            }
+            Self::DurationItem(duration) => humanize::duration(duration, false),
+            Self::SizeItem(bytes) => humanize::size(bytes),
+            Self::BarItem(fraction) => bar::render(fraction, bar::DEFAULT_WIDTH, BarGlyphs::default()),
+            Self::TimeItem(time) => timestamp::format(time, timestamp::DEFAULT_PATTERN),
+            Self::Verbatim(text) => text.to_owned(),
+            Self::VerbatimString(ref text) => text.clone(),
+            Self::Bytes(bytes) => hexdump::lines(bytes, hexdump::DEFAULT_BYTES_PER_ROW, None).join("\n"),
+            Self::Nested(formatter) => formatter.nested_text(),
+            Self::Cell(cell) => cell.lines().join("\n"),
+            Self::Reader(reader) => reader::read_lines(reader, None, None).join("\n"),
+        }
+    }
+}
+
+impl Debug for FormattableItem<'_> {
+    /// Show the variant and the start of the item's own rendering, as the wrapped values are opaque.
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        let variant = match *self {
+            Self::Both(..) => "Both",
+            Self::DisplayItem(_) => "DisplayItem",
+            Self::DebugItem(_) => "DebugItem",
+            Self::DurationItem(_) => "DurationItem",
+            Self::SizeItem(_) => "SizeItem",
+            Self::BarItem(_) => "BarItem",
+            Self::TimeItem(_) => "TimeItem",
+            Self::Verbatim(_) => "Verbatim",
+            Self::VerbatimString(_) => "VerbatimString",
+            Self::Bytes(_) => "Bytes",
+            Self::Nested(_) => "Nested",
+            Self::Cell(_) => "Cell",
+            // Previewing a reader would consume its lines
+            Self::Reader(_) => return f.write_str("Reader(..)"),
+        };
+        let rendered = self.render(FormatType::Display);
+        let mut preview: String = rendered.chars().take(PREVIEW_LENGTH).collect();
+        if preview.len() < rendered.len() {
+            preview.push('\u{2026}');
+        }
+        f.debug_tuple(variant).field(&preview).finish()
+    }
+}
+
+impl<'a, T: Display + Debug> From<&'a T> for FormattableItem<'a> {
+    #[inline]
+    fn from(value: &'a T) -> Self {
+        Self::Both(value, value)
+    }
+}
+
+impl From<&Self> for FormattableItem<'_> {
+    #[inline]
+    fn from(item: &Self) -> Self {
+        item.clone()
+    }
 }
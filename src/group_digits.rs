@@ -0,0 +1,79 @@
+//! Insertion of thousands separators into numbers within text.
+//!
+//! This module provides the post-processing step used by columns with digit grouping enabled
+//! (the `{:n}` format flag). The text of a cell is split into words of alphanumeric characters
+//! and underscores, and only words made entirely of digits are treated as standalone numbers:
+//! - `id=12345 count=6789012` becomes `id=12,345 count=6,789,012`.
+//! - Digits inside identifiers and hashes, such as `a1b2c3d4e5` or `build_20240101`, are left untouched.
+//! - Only the integer part of a decimal is grouped, so `1234.5678` becomes `1,234.5678`.
+//! - Numbers joined to other digits by `-`, `:` or `.`, such as `2024-01-01`, `12:30:45` or `10.0.1234`, are dates,
+//!   times and versions rather than quantities, and are left untouched.
+
+/// Insert the separator every three digits in the integer part of each standalone number.
+#[must_use]
+#[inline]
+#[expect(clippy::single_call_fn, reason = "Keeps the tokenizer separate from column rendering.")]
+pub fn group_digits(text: &str, separator: char) -> String {
+    let chars: Vec<char> = text.chars().collect();
+    let mut output = String::with_capacity(text.len());
+
+    let mut i = 0;
+    while i < chars.len() {
+        if !is_word_char(chars[i]) {
+            output.push(chars[i]);
+            i += 1;
+            continue;
+        }
+
+        // Collect the whole word
+        let start = i;
+        while i < chars.len() && is_word_char(chars[i]) {
+            i += 1;
+        }
+        let word = &chars[start..i];
+
+        // Digits joined to earlier digits are a fractional part, or part of a date, time or version
+        let joined_before = start >= 2 && is_joiner(chars[start - 1]) && chars[start - 2].is_ascii_digit();
+        let joined_after = joins_digits(&chars, i) && !(chars[i] == '.' && is_decimal(&chars, i));
+
+        if joined_before || joined_after || !word.iter().all(char::is_ascii_digit) {
+            output.extend(word);
+            continue;
+        }
+
+        for (pos, &digit) in word.iter().enumerate() {
+            if pos > 0 && (word.len() - pos).is_multiple_of(3) {
+                output.push(separator);
+            }
+            output.push(digit);
+        }
+    }
+
+    output
+}
+
+/// Check if the character can be part of a word, such as an identifier or a number.
+fn is_word_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '_'
+}
+
+/// Check if the character can join runs of digits into a date, time, version, or decimal.
+const fn is_joiner(c: char) -> bool {
+    matches!(c, '-' | ':' | '.')
+}
+
+/// Check if the character at the index joins the digits before it to a following digit.
+fn joins_digits(chars: &[char], index: usize) -> bool {
+    chars.get(index).is_some_and(|&c| is_joiner(c)) && chars.get(index + 1).is_some_and(char::is_ascii_digit)
+}
+
+/// Check if the decimal point at the index is followed by a fractional part which is not itself joined to more
+/// digits, as it would be in a version such as `1.2.3`.
+#[expect(clippy::single_call_fn, reason = "Names the check telling decimals from versions.")]
+fn is_decimal(chars: &[char], index: usize) -> bool {
+    let mut end = index + 1;
+    while chars.get(end).is_some_and(|&c| is_word_char(c)) {
+        end += 1;
+    }
+    !joins_digits(chars, end)
+}
@@ -0,0 +1,88 @@
+//! Tracks the horizontal scrolling of a window onto a layout wider than its viewport.
+//!
+//! This module contains the `HScroll` struct, which holds the offset of a `render_window` and the
+//! width of its viewport. Each render measures the layout and records how far it can scroll, so
+//! scrolling is clamped to the layout's width, and `can_scroll_left` and `can_scroll_right` tell
+//! callers when to draw scroll indicators. Before the first render the layout is unknown, so
+//! scrolling right is not clamped until then.
+
+/// The horizontal scroll state of a window onto a layout.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct HScroll {
+    /// Characters scrolled past, after the frozen columns.
+    offset: usize,
+    /// Width of the window, including the frozen columns.
+    viewport: usize,
+    /// The largest offset, once a render has measured the layout.
+    max_offset: Option<usize>,
+}
+
+impl HScroll {
+    /// Construct a new `HScroll` for a window of the given width, scrolled to the start.
+    #[must_use]
+    #[inline]
+    pub const fn new(viewport: usize) -> Self {
+        Self {
+            offset: 0,
+            viewport,
+            max_offset: None,
+        }
+    }
+
+    /// The number of characters scrolled past, after the frozen columns.
+    #[must_use]
+    #[inline]
+    pub const fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// The width of the window, including the frozen columns.
+    #[must_use]
+    #[inline]
+    pub const fn viewport(&self) -> usize {
+        self.viewport
+    }
+
+    /// Change the width of the window, such as when the terminal is resized.
+    ///
+    /// The offset is clamped again by the next render.
+    #[inline]
+    pub const fn set_viewport(&mut self, viewport: usize) {
+        self.viewport = viewport;
+    }
+
+    /// Scroll towards the start of the layout by up to `n` characters.
+    #[inline]
+    pub const fn scroll_left(&mut self, n: usize) {
+        self.offset = self.offset.saturating_sub(n);
+    }
+
+    /// Scroll towards the end of the layout by up to `n` characters, stopping once its last column is in view.
+    #[inline]
+    pub fn scroll_right(&mut self, n: usize) {
+        let offset = self.offset.saturating_add(n);
+        self.offset = self.max_offset.map_or(offset, |max_offset| offset.min(max_offset));
+    }
+
+    /// Check whether any of the layout is hidden to the left of the window.
+    #[must_use]
+    #[inline]
+    pub const fn can_scroll_left(&self) -> bool {
+        self.offset > 0
+    }
+
+    /// Check whether any of the layout is hidden to the right of the window, as of the latest render.
+    #[must_use]
+    #[inline]
+    pub fn can_scroll_right(&self) -> bool {
+        self.max_offset.is_none_or(|max_offset| self.offset < max_offset)
+    }
+
+    /// Record the width of the scrolled part of the layout and the room for it in the window, clamping the offset.
+    #[inline]
+    pub(crate) fn measure(&mut self, scrolled_width: usize, room: usize) {
+        let max_offset = scrolled_width.saturating_sub(room);
+        self.max_offset = Some(max_offset);
+        self.offset = self.offset.min(max_offset);
+    }
+}
@@ -0,0 +1,56 @@
+//! Renders byte slices as classic hexdumps.
+//!
+//! Each line shows the offset of its first byte as eight hex digits, then a fixed number of bytes
+//! in hex, with an extra space after every eighth, then the same bytes as ASCII between bars.
+//! Bytes outside printable ASCII are shown as `.` in the gutter. The last line is padded so its
+//! gutter lines up with the others, so the width of a full line is the natural width of the column.
+//!
+//! When the lines are capped, the last line kept starts with the column's truncation marker and announces how many
+//! bytes were left out.
+
+/// The number of bytes on each line unless a `{:hex(n)}` specifier chooses another.
+pub const DEFAULT_BYTES_PER_ROW: usize = 16;
+
+/// Render the bytes as hexdump lines, keeping at most the given number of lines including the trailer, which
+/// starts with the given marker.
+#[must_use]
+#[inline]
+pub fn lines(bytes: &[u8], row_width: usize, cap: Option<(usize, &str)>) -> Vec<String> {
+    let bytes_per_row = row_width.max(1);
+    let rows = bytes.len().div_ceil(bytes_per_row);
+    let (kept, marker) = match cap {
+        Some((max, marker)) if rows > max => (max.saturating_sub(1), marker),
+        Some(_) | None => (rows, ""),
+    };
+
+    let mut dumped: Vec<String> = bytes
+        .chunks(bytes_per_row)
+        .take(kept)
+        .enumerate()
+        .map(|(row, chunk)| {
+            let hex: String = (0..bytes_per_row)
+                .map(|slot| {
+                    let gap = if slot % 8 == 0 { " " } else { "" };
+                    chunk
+                        .get(slot)
+                        .map_or_else(|| format!("{gap}   "), |byte| format!("{gap}{byte:02x} "))
+                })
+                .collect();
+            let gutter: String = chunk
+                .iter()
+                .map(|&byte| {
+                    if byte == b' ' || byte.is_ascii_graphic() {
+                        char::from(byte)
+                    } else {
+                        '.'
+                    }
+                })
+                .collect();
+            format!("{:08x} {hex} |{gutter}|", row * bytes_per_row)
+        })
+        .collect();
+    if kept < rows {
+        dumped.push(format!("{marker} (+{} bytes)", bytes.len() - kept * bytes_per_row));
+    }
+    dumped
+}
@@ -0,0 +1,81 @@
+//! Syntax highlighting of `Debug` output.
+//!
+//! A small tokenizer colours each line of `{:?}` and `{:#?}` output:
+//! - Field names, such as `name` in `name: "Bob"`, in cyan.
+//! - String and character literals in green.
+//! - Numbers in yellow.
+//! - Punctuation (`{}[](),:`) in blue.
+//!
+//! Type names, variants, and anything unrecognised are left uncoloured. Lines are highlighted one
+//! at a time after being fitted to their column, so a string cut by truncation is coloured to the
+//! end of the line, and the escape sequences never affect the layout.
+
+use std::{iter::Peekable, str::CharIndices};
+
+use crate::{Color, Style};
+
+/// Colour the tokens of a line of `Debug` output.
+#[expect(clippy::single_call_fn, reason = "Keeps the tokenizer apart from the layout pass.")]
+#[must_use]
+#[inline]
+pub fn highlight_debug(line: &str) -> String {
+    let mut highlighted = String::new();
+    let mut chars = line.char_indices().peekable();
+    while let Some(&(start, ch)) = chars.peek() {
+        let color = match ch {
+            '"' | '\'' => {
+                chars.next();
+                skip_literal(&mut chars, ch);
+                Some(Color::Green)
+            }
+            '0'..='9' => {
+                skip_while(&mut chars, |next| next.is_ascii_alphanumeric() || next == '.' || next == '_');
+                Some(Color::Yellow)
+            }
+            '{' | '}' | '[' | ']' | '(' | ')' | ',' | ':' => {
+                chars.next();
+                Some(Color::Blue)
+            }
+            _ if ch.is_alphabetic() || ch == '_' => {
+                skip_while(&mut chars, |next| next.is_alphanumeric() || next == '_');
+                let end = chars.peek().map_or(line.len(), |&(idx, _)| idx);
+                let rest = line.get(end..).unwrap_or_default();
+                (rest.starts_with(':') && !rest.starts_with("::")).then_some(Color::Cyan)
+            }
+            _ => {
+                chars.next();
+                None
+            }
+        };
+
+        let end = chars.peek().map_or(line.len(), |&(idx, _)| idx);
+        let token = line.get(start..end).unwrap_or_default();
+        match color {
+            Some(fg) => {
+                let style = Style::new().fg(fg);
+                highlighted.push_str(&style.render_sgr());
+                highlighted.push_str(token);
+                highlighted.push_str(style.render_reset());
+            }
+            None => highlighted.push_str(token),
+        }
+    }
+    highlighted
+}
+
+/// Skip the rest of a literal up to and including its closing quote, or to the end of the line.
+#[expect(clippy::single_call_fn, reason = "Keeps the literal grammar readable.")]
+fn skip_literal(chars: &mut Peekable<CharIndices<'_>>, quote: char) {
+    while let Some((_, ch)) = chars.next() {
+        if ch == '\\' {
+            chars.next();
+        } else if ch == quote {
+            return;
+        }
+    }
+}
+
+/// Skip characters while they match the predicate.
+fn skip_while(chars: &mut Peekable<CharIndices<'_>>, predicate: impl Fn(char) -> bool) {
+    while chars.next_if(|&(_, ch)| predicate(ch)).is_some() {}
+}
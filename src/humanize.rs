@@ -0,0 +1,75 @@
+//! Human-readable rendering of durations and byte sizes.
+//!
+//! This module provides the functions used by the `Duration` and `Size` format types to turn
+//! raw values into short, readable text such as `1.24 s`, `3 ms`, `1.5 GiB`, or `312 KiB`.
+//! Values are shown with three significant digits, with trailing zeros removed.
+
+use std::time::Duration;
+
+/// Units used for durations, with their length in nanoseconds.
+const DURATION_UNITS: [(&str, f64); 4] = [("s", 1e9), ("ms", 1e6), ("\u{b5}s", 1e3), ("ns", 1.0)];
+
+/// Units used for durations when only ASCII is written, with their length in nanoseconds.
+const ASCII_DURATION_UNITS: [(&str, f64); 4] = [("s", 1e9), ("ms", 1e6), ("us", 1e3), ("ns", 1.0)];
+
+/// Binary units used for byte sizes.
+const SIZE_UNITS: [&str; 7] = ["B", "KiB", "MiB", "GiB", "TiB", "PiB", "EiB"];
+
+/// Render a duration using the largest unit that keeps the value at or above one, writing microseconds as `us`
+/// rather than `µs` if only ASCII is written.
+#[must_use]
+#[inline]
+pub fn duration(value: Duration, ascii: bool) -> String {
+    let nanos = value.as_secs_f64() * 1e9;
+    let units = if ascii { ASCII_DURATION_UNITS } else { DURATION_UNITS };
+    let (unit, scale) = units
+        .iter()
+        .copied()
+        .find(|&(_, scale)| nanos >= scale)
+        .unwrap_or(("ns", 1.0));
+
+    format!("{} {unit}", significant(nanos / scale))
+}
+
+/// Render a number of bytes using the largest binary unit that keeps the value at or above one.
+#[must_use]
+#[inline]
+#[expect(clippy::single_call_fn, reason = "Kept alongside the other humanized formats.")]
+#[expect(
+    clippy::as_conversions,
+    clippy::cast_precision_loss,
+    reason = "Sizes are only shown to three significant digits."
+)]
+pub fn size(bytes: u64) -> String {
+    let mut value = bytes as f64;
+    let mut unit = SIZE_UNITS[0];
+    for next_unit in SIZE_UNITS.iter().skip(1) {
+        if value < 1024.0 {
+            break;
+        }
+        value /= 1024.0;
+        unit = next_unit;
+    }
+
+    if unit == SIZE_UNITS[0] {
+        format!("{bytes} {unit}")
+    } else {
+        format!("{} {unit}", significant(value))
+    }
+}
+
+/// Format a value with three significant digits, removing trailing zeros.
+fn significant(value: f64) -> String {
+    let decimals = match value {
+        v if v < 10.0 => 2,
+        v if v < 100.0 => 1,
+        _ => 0,
+    };
+
+    let text = format!("{value:.decimals$}");
+    if text.contains('.') {
+        text.trim_end_matches('0').trim_end_matches('.').to_owned()
+    } else {
+        text
+    }
+}
@@ -0,0 +1,44 @@
+//! Hyperlinks to local files, written as OSC 8 escape sequences.
+//!
+//! Terminals which support OSC 8 let the linked text be clicked to open the target, and others
+//! show the text alone. The escape sequences take up no space on screen, so links are only added
+//! once a line has been fitted to its column.
+
+use std::{fs, path::Path};
+
+/// Link the text of a line to the file at its path, if the path exists.
+#[expect(clippy::single_call_fn, reason = "Keeps the escape sequence apart from the table layout.")]
+///
+/// Relative paths are joined to the base, if given, or else to the working directory. Padding
+/// around the text is left outside the link.
+#[must_use]
+#[inline]
+pub fn link_path(fitted: &str, line: &str, base: Option<&Path>) -> String {
+    let path = base.map_or_else(|| Path::new(line.trim()).to_path_buf(), |dir| dir.join(line.trim()));
+    let Ok(target) = fs::canonicalize(path) else {
+        return fitted.to_owned();
+    };
+
+    let text = fitted.trim();
+    let start = fitted.len() - fitted.trim_start().len();
+    let (before, rest) = fitted.split_at(start);
+    let after = rest.get(text.len()..).unwrap_or_default();
+    format!(
+        "{before}\x1b]8;;file://{}\x1b\\{text}\x1b]8;;\x1b\\{after}",
+        encode(&target.to_string_lossy())
+    )
+}
+
+/// Percent-encode a path for use in a URL, leaving its separators and unreserved characters.
+#[expect(clippy::single_call_fn, reason = "Keeps URL encoding apart from the escape sequence.")]
+fn encode(path: &str) -> String {
+    path.bytes()
+        .map(|byte| {
+            if byte.is_ascii_alphanumeric() || b"/-._~".contains(&byte) {
+                char::from(byte).to_string()
+            } else {
+                format!("%{byte:02X}")
+            }
+        })
+        .collect()
+}
@@ -0,0 +1,83 @@
+//! Implementation of a writer adapter which indents every line it forwards.
+//!
+//! This module provides the `IndentWriter` struct, which wraps any `io::Write` and inserts a
+//! fixed prefix at the start of each line, so columns can be nested inside the output of code
+//! generators, loggers, and lists which manage their own indentation.
+//!
+//! The prefix is only written once a line has content, so output ending with a newline leaves
+//! no dangling prefix behind it. The first line is not indented unless asked, as callers have
+//! usually written the start of the line themselves, such as a bullet.
+
+use std::io::{Result as IoResult, Write};
+
+/// A writer which inserts a prefix after every newline it forwards.
+#[derive(Debug)]
+pub struct IndentWriter<W: Write> {
+    /// The writer receiving the indented output.
+    inner: W,
+    /// The text inserted at the start of each line.
+    prefix: String,
+    /// Whether the next byte written starts a line which needs the prefix.
+    pending: bool,
+}
+
+impl<W: Write> IndentWriter<W> {
+    /// Construct a new `IndentWriter`, inserting the prefix after every newline written to the inner writer.
+    #[must_use]
+    #[inline]
+    pub fn new(inner: W, prefix: &str) -> Self {
+        Self {
+            inner,
+            prefix: prefix.to_owned(),
+            pending: false,
+        }
+    }
+
+    /// Also insert the prefix before the very first line.
+    ///
+    /// This is off by default.
+    #[must_use]
+    #[inline]
+    pub const fn with_indent_first_line(mut self, indent_first_line: bool) -> Self {
+        self.pending = indent_first_line;
+        self
+    }
+
+    /// A reference to the inner writer.
+    #[must_use]
+    #[inline]
+    pub const fn get_ref(&self) -> &W {
+        &self.inner
+    }
+
+    /// Unwrap the inner writer.
+    #[must_use]
+    #[inline]
+    pub fn into_inner(self) -> W {
+        self.inner
+    }
+}
+
+impl<W: Write> Write for IndentWriter<W> {
+    /// Write the whole buffer, indenting each line which starts in it.
+    ///
+    /// The buffer is always written in full, or not at all if an error occurs first, so the prefix
+    /// is never written twice for one line.
+    #[inline]
+    fn write(&mut self, buf: &[u8]) -> IoResult<usize> {
+        for line in buf.split_inclusive(|&byte| byte == b'\n') {
+            if self.pending {
+                self.inner.write_all(self.prefix.as_bytes())?;
+                self.pending = false;
+            }
+            self.inner.write_all(line)?;
+            self.pending = line.ends_with(b"\n");
+        }
+        Ok(buf.len())
+    }
+
+    #[inline]
+    fn flush(&mut self) -> IoResult<()> {
+        self.inner.flush()
+    }
+}
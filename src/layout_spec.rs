@@ -0,0 +1,234 @@
+//! Defines table layouts which can be saved to and loaded from configuration files.
+//!
+//! This module contains the `LayoutSpec` struct, which describes the order in which a table's
+//! columns are rendered and the settings of each column as a list of `ColumnSpec`s. With the
+//! `serde` feature it implements `Serialize` and `Deserialize`, so that users can tweak a layout
+//! exported by `Table::layout_spec` in a TOML or JSON file and load it back with `Table::with_layout`.
+//!
+//! Columns are matched to the format string by position. A layout is checked in full before any
+//! of it is applied, and the first invalid setting is reported as a `FormatError::InvalidLayout`
+//! naming the field, such as `columns[1].align`.
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{Alignment, ColumnFormat, ColumnSpec, FormatError, TruncateSide, width::Width};
+
+/// The saved layout of a table.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize), serde(default, deny_unknown_fields))]
+#[non_exhaustive]
+pub struct LayoutSpec {
+    /// Optional indices of the columns to render, in order, instead of every column.
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub order: Option<Vec<usize>>,
+    /// Settings of each column, in the order of the format string.
+    pub columns: Vec<ColumnSpec>,
+}
+
+/// The settings of a column once they have been checked.
+struct ResolvedColumn {
+    /// Optional width of the column.
+    width: Option<Width>,
+    /// Optional alignment of the column.
+    alignment: Option<Alignment>,
+    /// Optional side cut from over-long lines.
+    truncate_side: Option<TruncateSide>,
+}
+
+impl LayoutSpec {
+    /// Create a layout which keeps every setting of the format string.
+    #[must_use]
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Render only the given columns, in the given order.
+    #[must_use]
+    #[inline]
+    pub fn order(mut self, order: &[usize]) -> Self {
+        self.order = Some(order.to_vec());
+        self
+    }
+
+    /// Add the settings of the next column.
+    #[must_use]
+    #[inline]
+    pub fn column(mut self, column: ColumnSpec) -> Self {
+        self.columns.push(column);
+        self
+    }
+
+    /// Describe the effective layout of the given columns, headers, and selection.
+    #[expect(clippy::single_call_fn, reason = "Keeps the conversion next to its inverse.")]
+    pub(crate) fn from_parts(formats: &[ColumnFormat], headers: Option<&[String]>, order: Option<&[usize]>) -> Self {
+        let columns = formats
+            .iter()
+            .enumerate()
+            .map(|(index, format)| ColumnSpec {
+                header: headers.and_then(|cells| cells.get(index)).cloned(),
+                width: format.width.map(|width| match width {
+                    Width::Chars(chars) => chars.to_string(),
+                    Width::Percent(percent) => format!("{percent}%"),
+                }),
+                align: Some(
+                    match format.alignment {
+                        Alignment::Left => "left",
+                        Alignment::Right => "right",
+                        Alignment::Center => "center",
+                    }
+                    .to_owned(),
+                ),
+                separator: format.separator.clone(),
+                truncate: Some(
+                    match format.truncate_side {
+                        TruncateSide::Right => "right",
+                        TruncateSide::Left => "left",
+                        TruncateSide::Middle => "middle",
+                    }
+                    .to_owned(),
+                ),
+                truncate_marker: Some(format.truncate_marker.clone()),
+            })
+            .collect();
+        Self {
+            order: order.map(<[usize]>::to_vec),
+            columns,
+        }
+    }
+
+    /// Check the layout against the given columns, then apply it to them and to the headers and selection.
+    pub(crate) fn apply(
+        &self,
+        formats: &mut [ColumnFormat],
+        headers: &mut Option<Vec<String>>,
+        order: &mut Option<Vec<usize>>,
+    ) -> Result<(), FormatError> {
+        let resolved = self.resolve(formats.len())?;
+
+        for ((format, spec), column) in formats.iter_mut().zip(&self.columns).zip(resolved) {
+            if column.width.is_some() {
+                format.width = column.width;
+            }
+            if let Some(alignment) = column.alignment {
+                format.alignment = alignment;
+                format.explicit_alignment = true;
+            }
+            if let Some(separator) = spec.separator.as_ref() {
+                format.separator = Some(separator.clone());
+            }
+            if let Some(truncate_side) = column.truncate_side {
+                format.truncate_side = truncate_side;
+            }
+            if let Some(marker) = spec.truncate_marker.as_ref() {
+                format.truncate_marker.clone_from(marker);
+            }
+        }
+
+        if self.columns.iter().any(|spec| spec.header.is_some()) {
+            let cells = headers.get_or_insert_with(|| vec![String::new(); formats.len()]);
+            for (index, spec) in self.columns.iter().enumerate() {
+                if let Some(header) = spec.header.as_ref() {
+                    if cells.len() <= index {
+                        cells.resize(index + 1, String::new());
+                    }
+                    if let Some(cell) = cells.get_mut(index) {
+                        cell.clone_from(header);
+                    }
+                }
+            }
+        }
+        if let Some(selection) = self.order.as_ref() {
+            *order = Some(selection.clone());
+        }
+        Ok(())
+    }
+
+    /// Check every setting against a table of the given number of columns.
+    fn resolve(&self, columns: usize) -> Result<Vec<ResolvedColumn>, FormatError> {
+        if self.columns.len() > columns {
+            return Err(invalid(
+                format!("columns[{columns}]"),
+                format!("is beyond the {columns} columns of the format string"),
+            ));
+        }
+        if let Some((position, &column)) = self
+            .order
+            .as_ref()
+            .and_then(|order| order.iter().enumerate().find(|&(_, &column)| column >= columns))
+        {
+            return Err(invalid(
+                format!("order[{position}]"),
+                format!("selects column {column} from a table of {columns} columns"),
+            ));
+        }
+
+        self.columns
+            .iter()
+            .enumerate()
+            .map(|(index, spec)| {
+                let width = spec
+                    .width
+                    .as_deref()
+                    .map(|text| {
+                        Width::parse(text.trim())
+                            .filter(|&width| width != Width::Percent(0) && !matches!(width, Width::Percent(101..)))
+                            .ok_or_else(|| {
+                                invalid(
+                                    format!("columns[{index}].width"),
+                                    format!("is `{text}`, expected a number of characters or a percentage up to 100%"),
+                                )
+                            })
+                    })
+                    .transpose()?;
+                let alignment = spec
+                    .align
+                    .as_deref()
+                    .map(|text| {
+                        match text.trim() {
+                            "left" => Some(Alignment::Left),
+                            "right" => Some(Alignment::Right),
+                            "center" => Some(Alignment::Center),
+                            _ => None,
+                        }
+                        .ok_or_else(|| {
+                            invalid(
+                                format!("columns[{index}].align"),
+                                format!("is `{text}`, expected `left`, `right`, or `center`"),
+                            )
+                        })
+                    })
+                    .transpose()?;
+                let truncate_side = spec
+                    .truncate
+                    .as_deref()
+                    .map(|text| {
+                        match text.trim() {
+                            "right" => Some(TruncateSide::Right),
+                            "left" => Some(TruncateSide::Left),
+                            "middle" => Some(TruncateSide::Middle),
+                            _ => None,
+                        }
+                        .ok_or_else(|| {
+                            invalid(
+                                format!("columns[{index}].truncate"),
+                                format!("is `{text}`, expected `right`, `left`, or `middle`"),
+                            )
+                        })
+                    })
+                    .transpose()?;
+                Ok(ResolvedColumn {
+                    width,
+                    alignment,
+                    truncate_side,
+                })
+            })
+            .collect()
+    }
+}
+
+/// The error for an invalid setting of a layout.
+const fn invalid(field: String, message: String) -> FormatError {
+    FormatError::InvalidLayout { field, message }
+}
@@ -114,12 +114,149 @@
 #![allow(clippy::unwrap_in_result, reason = "In some cases unwrap can be guaranteed to succeed.")]
 #![allow(clippy::unwrap_used, reason = "In some cases unwrap can be guaranteed to succeed.")]
 
+mod accessibility;
+mod alignment;
+mod anchor_policy;
+mod assert_colprint_eq;
+mod assert_fits;
+mod bar;
+mod bar_glyphs;
+#[cfg(feature = "bidi")]
+mod bidi;
+mod block_align;
+mod cell;
+mod colformat;
+mod colformat_with;
+mod color;
+mod color_choice;
+mod color_scale;
 mod colprint;
+mod colprint_kv;
+mod colprint_with;
+mod colrule;
 mod column_format;
 mod column_formatter;
+mod column_spec;
+mod column_template;
+mod columns;
+mod debug_fields;
+mod delimited;
+mod dimensions;
+#[doc(hidden)]
+pub mod doctest_support;
+mod env_config;
+mod filter_widths;
+mod fit_error;
+mod flush_policy;
+mod format_error;
 mod format_part;
 mod format_type;
 mod formattable_item;
+mod group_digits;
+mod h_scroll;
+mod hexdump;
+mod highlight;
+mod humanize;
+mod hyperlink;
+mod indent_writer;
+mod layout_spec;
+mod line_ending;
+#[doc(hidden)]
+pub mod macro_support;
+mod measurer;
+#[cfg(feature = "unicode")]
+mod normalization;
+mod output_style;
+mod overflow;
+mod panic_guard;
+mod parser;
+pub mod prelude;
+mod prepared;
+mod reader;
+mod render_column;
+mod render_diff;
+mod render_options;
+mod renderer;
+mod row_role;
+mod separator;
+mod separator_fill;
+mod slice;
+mod squeeze;
+mod strictness;
+mod style;
+mod table;
+mod table_writer;
+mod terminal;
+mod timestamp;
+mod to_debug_item;
+mod to_display_item;
+mod to_formattable_item;
+mod to_lines_item;
+mod truncate_side;
+mod value_spec;
+#[cfg(feature = "ratatui")]
+mod widget;
+mod width;
+mod width_heuristic;
+mod width_resolution;
+mod wrap;
+mod wrap_policy;
 
+pub use accessibility::Accessibility;
+pub use alignment::Alignment;
+pub use anchor_policy::AnchorPolicy;
+pub use bar_glyphs::BarGlyphs;
+pub use block_align::BlockAlign;
+pub use cell::Cell;
+pub use color::Color;
+pub use color_choice::ColorChoice;
+pub use color_scale::ColorScale;
+pub use column_format::ColumnFormat;
 pub use column_formatter::ColumnFormatter;
+pub use column_spec::ColumnSpec;
+pub use column_template::ColumnTemplate;
+pub use columns::Columns;
+pub use debug_fields::debug_fields;
+pub use dimensions::Dimensions;
+pub use env_config::EnvConfig;
+pub use filter_widths::FilterWidths;
+pub use fit_error::FitError;
+pub use flush_policy::FlushPolicy;
+pub use format_error::FormatError;
+pub use format_type::FormatType;
 pub use formattable_item::FormattableItem;
+pub use h_scroll::HScroll;
+pub use indent_writer::IndentWriter;
+pub use layout_spec::LayoutSpec;
+pub use line_ending::LineEnding;
+pub use measurer::CharCount;
+pub use measurer::Measurer;
+#[cfg(feature = "unicode")]
+pub use measurer::SkipZeroWidth;
+#[cfg(feature = "unicode")]
+pub use normalization::Normalization;
+pub use output_style::OutputStyle;
+pub use overflow::Overflow;
+pub use prepared::Prepared;
+pub use render_column::RenderColumn;
+pub use render_options::RenderOptions;
+pub use renderer::PlainRenderer;
+pub use renderer::Renderer;
+pub use separator::Separator;
+pub use separator_fill::SeparatorFill;
+pub use squeeze::Squeeze;
+pub use strictness::Strictness;
+pub use style::Style;
+pub use table::Table;
+pub use table_writer::TableWriter;
+pub use to_debug_item::ToDebugItem;
+pub use to_display_item::ToDisplayItem;
+pub use to_formattable_item::ToFormattableItem;
+pub use to_lines_item::ToLinesItem;
+pub use truncate_side::TruncateSide;
+pub use value_spec::ValueSpec;
+#[cfg(feature = "ratatui")]
+pub use widget::ColprintWidget;
+pub use width::Width;
+pub use width_heuristic::WidthHeuristic;
+pub use wrap_policy::WrapPolicy;
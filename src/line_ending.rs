@@ -0,0 +1,45 @@
+//! Defines how lines written to files and other writers end.
+//!
+//! This module contains the `LineEnding` enum, which chooses the line terminator written by
+//! `write_to` and `to_text`:
+//! - `Lf`: A line feed, `\n`, as on Unix (the default).
+//! - `CrLf`: A carriage return and line feed, `\r\n`, as expected by Windows tools such as Notepad.
+//!
+//! Output is laid out with line feeds and converted as it is emitted, so every line, including
+//! headers, rules, and summaries, ends the same way. `Display`, and so `to_string` and `format!`,
+//! always uses line feeds. Output never starts with a byte order mark, and does not depend on the locale.
+
+use std::borrow::Cow;
+
+/// The terminator of each written line.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum LineEnding {
+    /// End lines with `\n`.
+    #[default]
+    Lf,
+    /// End lines with `\r\n`.
+    CrLf,
+}
+
+impl LineEnding {
+    /// The text ending each line.
+    #[must_use]
+    #[inline]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Lf => "\n",
+            Self::CrLf => "\r\n",
+        }
+    }
+
+    /// Convert text laid out with line feeds to this line ending.
+    #[must_use]
+    #[inline]
+    pub(crate) fn apply(self, text: &str) -> Cow<'_, str> {
+        match self {
+            Self::Lf => Cow::Borrowed(text),
+            Self::CrLf => Cow::Owned(text.replace('\n', self.as_str())),
+        }
+    }
+}
@@ -0,0 +1,657 @@
+//! Support code for the `colprint!` macro.
+//!
+//! The macro chooses how to wrap each item from the format string, but the item's type only
+//! determines which wrappers are possible. This module uses autoref-based specialization to
+//! pick the best available `FormattableItem` for each requested format type:
+//! - `formattable_item`: A `Nested` when the item is a `ColumnFormatter`, a `Both` when the item
+//!   implements `Display` and `Debug`, otherwise whichever of `DisplayItem` or `DebugItem` the item supports.
+//! - `duration_item`: A `DurationItem` when the item converts into a `Duration`, or into an integer number of nanoseconds.
+//! - `size_item`: A `SizeItem` when the item converts into an integer number of bytes.
+//! - `bar_item`: A `BarItem` when the item converts into an `f64`.
+//! - `time_item`: A `TimeItem` when the item converts into a `SystemTime`, or into an integer number of seconds
+//!   since the Unix epoch.
+//! - `verbatim_item`: A `Verbatim` when the item is a string.
+//! - `bytes_item`: A `Bytes` when the item is a byte slice. Arrays and vectors are not matched, so that integer
+//!   literals in other columns are never inferred to be bytes; pass them with `as_slice`.
+//! - `display_lines_item` and `debug_lines_item`: One element per line when the item is a collection
+//!   of `Display` or `Debug` elements.
+//!
+//! `assert_colprint_eq!` builds its failure message with `render_diff`.
+//!
+//! The macros read the columns of their layout through `LayoutSource`, so that a `ColumnTemplate` can stand in
+//! for a format string without being parsed again.
+//!
+//! The macros print through `print_locked`, which holds the lock on standard output for the whole block while
+//! `write_locked` writes it.
+//!
+//! Items which cannot provide the requested representation are rendered as a short placeholder
+//! such as `<not a duration>`. Call each method through enough references to reach the most
+//! specific implementation, as the macro does (for example `(&&&Wrap(&item)).duration_item()`).
+//!
+//! When the format string is a literal, the macros also find the kind of each column with `column_kind` when they
+//! are compiled, and call `fits` on a `Check` of each item with the spec of its column. The last `fits` found for
+//! each spec is an inherent method of `Check` with the weakest bound the spec accepts, so an item which does not fit
+//! its column fails to compile with an error naming the spec and the missing trait, such as
+//! `` `Point` doesn't implement `std::fmt::Display` `` and ``required by a bound in
+//! `Check::<'_, DisplaySpec, T>::fits` ``. Columns of other kinds, and items past the last column, accept any item.
+//!
+//! This module is not part of the public API.
+
+use std::{
+    fmt::{Debug, Display},
+    io::{self, Write},
+    marker::PhantomData,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use crate::{
+    Cell, ColumnFormatter, ColumnTemplate, FormattableItem, ToLinesItem as _,
+    format_part::FormatPart,
+    parser::{normalize_spec, parse_parts},
+};
+pub use crate::{format_type::FormatType, parser::column_format_type, render_diff::render_diff};
+
+/// The format type of each column in the format string, parsed exactly as `ColumnFormatter` does.
+#[must_use]
+#[inline]
+pub fn format_types(format_str: &str) -> Vec<FormatType> {
+    parse_parts(format_str)
+        .into_iter()
+        .filter_map(|part| match part {
+            FormatPart::Format(spec, _, _, _, _) => Some(FormatType::from_spec(&normalize_spec(spec))),
+            FormatPart::Separator(_) => None,
+        })
+        .collect()
+}
+
+/// A layout the macros format items with: a format string, parsed on every use, or a parsed `ColumnTemplate`.
+pub trait LayoutSource {
+    /// The format type of each column.
+    fn column_types(&self) -> Vec<FormatType>;
+
+    /// Create a formatter laying out the items.
+    fn column_formatter<'a>(&self, items: Vec<FormattableItem<'a>>) -> ColumnFormatter<'a>;
+}
+
+impl LayoutSource for str {
+    #[inline]
+    fn column_types(&self) -> Vec<FormatType> {
+        format_types(self)
+    }
+
+    #[inline]
+    fn column_formatter<'a>(&self, items: Vec<FormattableItem<'a>>) -> ColumnFormatter<'a> {
+        ColumnFormatter::new(self, items)
+    }
+}
+
+impl LayoutSource for ColumnTemplate {
+    #[inline]
+    fn column_types(&self) -> Vec<FormatType> {
+        self.format_types().to_vec()
+    }
+
+    #[inline]
+    fn column_formatter<'a>(&self, items: Vec<FormattableItem<'a>>) -> ColumnFormatter<'a> {
+        self.formatter(items)
+    }
+}
+
+/// Print a block to standard output, locking it once so other threads cannot print between its lines.
+///
+/// # Panics
+///
+/// Panics if writing to standard output fails, as `print!` does.
+#[expect(clippy::panic, reason = "Printing fails the same way as `print!`.")]
+#[inline]
+pub fn print_locked<T: Display + ?Sized>(block: &T) {
+    if let Err(err) = write_locked(&mut io::stdout().lock(), block) {
+        panic!("failed printing to stdout: {err}");
+    }
+}
+
+/// Write a block to a writer the caller holds, such as a locked standard output, with every line ending in a newline.
+///
+/// # Errors
+///
+/// Returns any error from the writer.
+#[inline]
+pub fn write_locked<W: Write + ?Sized, T: Display + ?Sized>(writer: &mut W, block: &T) -> io::Result<()> {
+    write!(writer, "{block}")
+}
+
+/// Wrapper around a macro argument, used to select the best `FormattableItem` for it.
+#[expect(clippy::exhaustive_structs, reason = "Constructed by the colprint! macro in user crates.")]
+pub struct Wrap<'a, T>(pub &'a T);
+
+/// Wrap nested formatters.
+pub trait ViaNested<'a> {
+    /// Wrap the formatter so that its lines are placed in the cell.
+    fn formattable_item(&self) -> FormattableItem<'a>;
+}
+
+impl<'a> ViaNested<'a> for &&&&Wrap<'a, ColumnFormatter<'a>> {
+    #[inline]
+    fn formattable_item(&self) -> FormattableItem<'a> {
+        FormattableItem::Nested(self.0)
+    }
+}
+
+/// Wrap cells rendered in advance.
+pub trait ViaCell<'a> {
+    /// Wrap the cell so that its lines are placed as they are.
+    fn formattable_item(&self) -> FormattableItem<'a>;
+}
+
+impl<'a> ViaCell<'a> for &&&&Wrap<'a, Cell> {
+    #[inline]
+    fn formattable_item(&self) -> FormattableItem<'a> {
+        FormattableItem::Cell(self.0)
+    }
+}
+
+/// Wrap items implementing both `Display` and `Debug`.
+pub trait ViaBoth<'a> {
+    /// Wrap the item so that the column's format type chooses the representation.
+    fn formattable_item(&self) -> FormattableItem<'a>;
+}
+
+impl<'a, T: Display + Debug> ViaBoth<'a> for &&&Wrap<'a, T> {
+    #[inline]
+    fn formattable_item(&self) -> FormattableItem<'a> {
+        FormattableItem::Both(self.0, self.0)
+    }
+}
+
+/// Wrap items implementing only `Display`.
+pub trait ViaDisplay<'a> {
+    /// Wrap the item for `Display` formatting.
+    fn formattable_item(&self) -> FormattableItem<'a>;
+}
+
+impl<'a, T: Display> ViaDisplay<'a> for &&Wrap<'a, T> {
+    #[inline]
+    fn formattable_item(&self) -> FormattableItem<'a> {
+        FormattableItem::DisplayItem(self.0)
+    }
+}
+
+/// Wrap items implementing only `Debug`.
+pub trait ViaDebug<'a> {
+    /// Wrap the item for `Debug` formatting.
+    fn formattable_item(&self) -> FormattableItem<'a>;
+}
+
+impl<'a, T: Debug> ViaDebug<'a> for &Wrap<'a, T> {
+    #[inline]
+    fn formattable_item(&self) -> FormattableItem<'a> {
+        FormattableItem::DebugItem(self.0)
+    }
+}
+
+/// Placeholder for items which implement neither `Display` nor `Debug`.
+pub trait ViaFallback<'a> {
+    /// Wrap a placeholder in place of the item.
+    fn formattable_item(&self) -> FormattableItem<'a>;
+}
+
+impl<'a, T> ViaFallback<'a> for Wrap<'a, T> {
+    #[inline]
+    fn formattable_item(&self) -> FormattableItem<'a> {
+        FormattableItem::DisplayItem(&"<not formattable>")
+    }
+}
+
+/// Wrap items convertible into a `Duration`.
+pub trait ViaDuration<'a> {
+    /// Wrap the item as a duration.
+    fn duration_item(&self) -> FormattableItem<'a>;
+}
+
+impl<'a, T: Copy + Into<Duration>> ViaDuration<'a> for &&Wrap<'a, T> {
+    #[inline]
+    fn duration_item(&self) -> FormattableItem<'a> {
+        FormattableItem::DurationItem((*self.0).into())
+    }
+}
+
+/// Wrap integers as a number of nanoseconds.
+pub trait ViaNanos<'a> {
+    /// Wrap the item as a duration in nanoseconds.
+    fn duration_item(&self) -> FormattableItem<'a>;
+}
+
+impl<'a, T: Copy + TryInto<u64>> ViaNanos<'a> for &Wrap<'a, T> {
+    #[inline]
+    fn duration_item(&self) -> FormattableItem<'a> {
+        (*self.0)
+            .try_into()
+            .map_or(FormattableItem::DisplayItem(&"<not a duration>"), |nanos| {
+                FormattableItem::DurationItem(Duration::from_nanos(nanos))
+            })
+    }
+}
+
+/// Placeholder for items which are not durations.
+pub trait ViaDurationFallback<'a> {
+    /// Wrap a placeholder in place of the item.
+    fn duration_item(&self) -> FormattableItem<'a>;
+}
+
+impl<'a, T> ViaDurationFallback<'a> for Wrap<'a, T> {
+    #[inline]
+    fn duration_item(&self) -> FormattableItem<'a> {
+        FormattableItem::DisplayItem(&"<not a duration>")
+    }
+}
+
+/// Wrap integers as a number of bytes.
+pub trait ViaSize<'a> {
+    /// Wrap the item as a byte size.
+    fn size_item(&self) -> FormattableItem<'a>;
+}
+
+impl<'a, T: Copy + TryInto<u64>> ViaSize<'a> for &Wrap<'a, T> {
+    #[inline]
+    fn size_item(&self) -> FormattableItem<'a> {
+        (*self.0)
+            .try_into()
+            .map_or(FormattableItem::DisplayItem(&"<not a size>"), FormattableItem::SizeItem)
+    }
+}
+
+/// Placeholder for items which are not byte sizes.
+pub trait ViaSizeFallback<'a> {
+    /// Wrap a placeholder in place of the item.
+    fn size_item(&self) -> FormattableItem<'a>;
+}
+
+impl<'a, T> ViaSizeFallback<'a> for Wrap<'a, T> {
+    #[inline]
+    fn size_item(&self) -> FormattableItem<'a> {
+        FormattableItem::DisplayItem(&"<not a size>")
+    }
+}
+
+/// Wrap numbers as the value of a progress bar.
+pub trait ViaBar<'a> {
+    /// Wrap the item as the value of a progress bar.
+    fn bar_item(&self) -> FormattableItem<'a>;
+}
+
+impl<'a, T: Copy + Into<f64>> ViaBar<'a> for &Wrap<'a, T> {
+    #[inline]
+    fn bar_item(&self) -> FormattableItem<'a> {
+        FormattableItem::BarItem((*self.0).into())
+    }
+}
+
+/// Placeholder for items which are not numbers.
+pub trait ViaBarFallback<'a> {
+    /// Wrap a placeholder in place of the item.
+    fn bar_item(&self) -> FormattableItem<'a>;
+}
+
+impl<'a, T> ViaBarFallback<'a> for Wrap<'a, T> {
+    #[inline]
+    fn bar_item(&self) -> FormattableItem<'a> {
+        FormattableItem::DisplayItem(&"<not a number>")
+    }
+}
+
+/// Wrap items convertible into a `SystemTime`.
+pub trait ViaTime<'a> {
+    /// Wrap the item as a time.
+    fn time_item(&self) -> FormattableItem<'a>;
+}
+
+impl<'a, T: Copy + Into<SystemTime>> ViaTime<'a> for &&Wrap<'a, T> {
+    #[inline]
+    fn time_item(&self) -> FormattableItem<'a> {
+        FormattableItem::TimeItem((*self.0).into())
+    }
+}
+
+/// Wrap integers as a number of seconds since the Unix epoch.
+pub trait ViaEpochSeconds<'a> {
+    /// Wrap the item as a time in seconds since the Unix epoch.
+    fn time_item(&self) -> FormattableItem<'a>;
+}
+
+impl<'a, T: Copy + TryInto<i64>> ViaEpochSeconds<'a> for &Wrap<'a, T> {
+    #[inline]
+    fn time_item(&self) -> FormattableItem<'a> {
+        let epoch_seconds: Option<i64> = (*self.0).try_into().ok();
+        epoch_seconds
+            .and_then(|seconds| {
+                let offset = Duration::from_secs(seconds.unsigned_abs());
+                if seconds < 0 {
+                    UNIX_EPOCH.checked_sub(offset)
+                } else {
+                    UNIX_EPOCH.checked_add(offset)
+                }
+            })
+            .map_or(FormattableItem::DisplayItem(&"<not a time>"), FormattableItem::TimeItem)
+    }
+}
+
+/// Placeholder for items which are not times.
+pub trait ViaTimeFallback<'a> {
+    /// Wrap a placeholder in place of the item.
+    fn time_item(&self) -> FormattableItem<'a>;
+}
+
+impl<'a, T> ViaTimeFallback<'a> for Wrap<'a, T> {
+    #[inline]
+    fn time_item(&self) -> FormattableItem<'a> {
+        FormattableItem::DisplayItem(&"<not a time>")
+    }
+}
+
+/// Wrap strings as verbatim text.
+pub trait ViaVerbatim<'a> {
+    /// Wrap the item as verbatim text.
+    fn verbatim_item(&self) -> FormattableItem<'a>;
+}
+
+impl<'a, T: AsRef<str>> ViaVerbatim<'a> for &Wrap<'a, T> {
+    #[inline]
+    fn verbatim_item(&self) -> FormattableItem<'a> {
+        FormattableItem::Verbatim(self.0.as_ref())
+    }
+}
+
+/// Placeholder for items which are not strings.
+pub trait ViaVerbatimFallback<'a> {
+    /// Wrap a placeholder in place of the item.
+    fn verbatim_item(&self) -> FormattableItem<'a>;
+}
+
+impl<'a, T> ViaVerbatimFallback<'a> for Wrap<'a, T> {
+    #[inline]
+    fn verbatim_item(&self) -> FormattableItem<'a> {
+        FormattableItem::DisplayItem(&"<not text>")
+    }
+}
+
+/// Wrap collections of `Display` elements as one element per line.
+pub trait ViaDisplayLines<'a> {
+    /// Wrap the item as lines rendered with `Display`.
+    fn display_lines_item(&self) -> FormattableItem<'a>;
+}
+
+impl<'a, T> ViaDisplayLines<'a> for &&Wrap<'a, T>
+where
+    &'a T: IntoIterator,
+    <&'a T as IntoIterator>::Item: Display,
+{
+    #[inline]
+    fn display_lines_item(&self) -> FormattableItem<'a> {
+        self.0.as_display_lines()
+    }
+}
+
+impl<'a, T: Copy + IntoIterator> ViaDisplayLines<'a> for &Wrap<'a, T>
+where
+    T::Item: Display,
+{
+    #[inline]
+    fn display_lines_item(&self) -> FormattableItem<'a> {
+        (*self.0).as_display_lines()
+    }
+}
+
+/// Wrap collections of `Debug` elements as one element per line.
+pub trait ViaDebugLines<'a> {
+    /// Wrap the item as lines rendered with `Debug`.
+    fn debug_lines_item(&self) -> FormattableItem<'a>;
+}
+
+impl<'a, T> ViaDebugLines<'a> for &&Wrap<'a, T>
+where
+    &'a T: IntoIterator,
+    <&'a T as IntoIterator>::Item: Debug,
+{
+    #[inline]
+    fn debug_lines_item(&self) -> FormattableItem<'a> {
+        self.0.as_debug_lines()
+    }
+}
+
+impl<'a, T: Copy + IntoIterator> ViaDebugLines<'a> for &Wrap<'a, T>
+where
+    T::Item: Debug,
+{
+    #[inline]
+    fn debug_lines_item(&self) -> FormattableItem<'a> {
+        (*self.0).as_debug_lines()
+    }
+}
+
+/// Placeholder for items which are not collections of the requested elements.
+pub trait ViaLinesFallback<'a> {
+    /// Wrap a placeholder in place of the item.
+    fn display_lines_item(&self) -> FormattableItem<'a>;
+
+    /// Wrap a placeholder in place of the item.
+    fn debug_lines_item(&self) -> FormattableItem<'a>;
+}
+
+impl<'a, T> ViaLinesFallback<'a> for Wrap<'a, T> {
+    #[inline]
+    fn display_lines_item(&self) -> FormattableItem<'a> {
+        FormattableItem::DisplayItem(&"<not a collection>")
+    }
+
+    #[inline]
+    fn debug_lines_item(&self) -> FormattableItem<'a> {
+        FormattableItem::DisplayItem(&"<not a collection>")
+    }
+}
+
+/// Wrap byte slices as hexdumps.
+pub trait ViaBytes<'a> {
+    /// Wrap the item as bytes.
+    fn bytes_item(&self) -> FormattableItem<'a>;
+}
+
+impl<'a, 'b: 'a> ViaBytes<'a> for &Wrap<'a, &'b [u8]> {
+    #[inline]
+    fn bytes_item(&self) -> FormattableItem<'a> {
+        FormattableItem::Bytes(self.0)
+    }
+}
+
+/// Placeholder for items which are not bytes.
+pub trait ViaBytesFallback<'a> {
+    /// Wrap a placeholder in place of the item.
+    fn bytes_item(&self) -> FormattableItem<'a>;
+}
+
+impl<'a, T> ViaBytesFallback<'a> for Wrap<'a, T> {
+    #[inline]
+    fn bytes_item(&self) -> FormattableItem<'a> {
+        FormattableItem::DisplayItem(&"<not bytes>")
+    }
+}
+
+/// Kind of a column accepting any item, and of items past the last column.
+pub const ANY: u8 = 0;
+/// Kind of a `{}` column, including `std::fmt` formats using `Display`, such as `{:.3}`.
+pub const DISPLAY: u8 = 1;
+/// Kind of a `{:?}` or `{:#?}` column, including `std::fmt` formats using `Debug`, such as `{:>8?}`.
+pub const DEBUG: u8 = 2;
+/// Kind of a `{:dur}` column.
+pub const DURATION: u8 = 3;
+/// Kind of a `{:size}` column.
+pub const SIZE: u8 = 4;
+
+/// Find the kind of a column of a literal format string when the macro is compiled, selecting its spec with `Spec`.
+///
+/// `LEN` must be at least the length of the format string, as for `column_format_type`.
+#[must_use]
+#[inline]
+pub const fn column_kind<const LEN: usize>(format_str: &str, column: usize) -> u8 {
+    match column_format_type::<LEN>(format_str, column) {
+        Some(FormatType::Display) => DISPLAY,
+        Some(FormatType::Debug | FormatType::PrettyDebug) => DEBUG,
+        Some(FormatType::Duration) => DURATION,
+        Some(FormatType::Size) => SIZE,
+        _ => ANY,
+    }
+}
+
+/// A column kind found by `column_kind`, which selects the spec of the column.
+#[expect(clippy::exhaustive_structs, reason = "Named by the colprint! macro in user crates.")]
+pub struct Kind<const KIND: u8>;
+
+/// Selection of the spec of a column from its kind.
+pub trait ColumnKind {
+    /// The spec which items in the column must fit.
+    type Spec;
+}
+
+/// The spec of the column with the given kind.
+pub type Spec<const KIND: u8> = <Kind<KIND> as ColumnKind>::Spec;
+
+/// The spec of a column accepting any item.
+#[expect(clippy::exhaustive_structs, reason = "Named by the colprint! macro in user crates.")]
+pub struct AnySpec;
+
+/// The spec of a column accepting items implementing `Display`.
+#[expect(clippy::exhaustive_structs, reason = "Named by the colprint! macro in user crates.")]
+pub struct DisplaySpec;
+
+/// The spec of a column accepting items implementing `Debug`.
+#[expect(clippy::exhaustive_structs, reason = "Named by the colprint! macro in user crates.")]
+pub struct DebugSpec;
+
+/// The spec of a column accepting durations.
+#[expect(clippy::exhaustive_structs, reason = "Named by the colprint! macro in user crates.")]
+pub struct DurationSpec;
+
+/// The spec of a column accepting byte sizes.
+#[expect(clippy::exhaustive_structs, reason = "Named by the colprint! macro in user crates.")]
+pub struct SizeSpec;
+
+impl ColumnKind for Kind<ANY> {
+    type Spec = AnySpec;
+}
+
+impl ColumnKind for Kind<DISPLAY> {
+    type Spec = DisplaySpec;
+}
+
+impl ColumnKind for Kind<DEBUG> {
+    type Spec = DebugSpec;
+}
+
+impl ColumnKind for Kind<DURATION> {
+    type Spec = DurationSpec;
+}
+
+impl ColumnKind for Kind<SIZE> {
+    type Spec = SizeSpec;
+}
+
+/// Check of a macro argument against the spec of its column, made when the macro is compiled.
+#[expect(clippy::exhaustive_structs, reason = "Constructed by the colprint! macro in user crates.")]
+pub struct Check<'a, S, T>(pub &'a T, pub PhantomData<S>);
+
+impl<'a, S, T> Check<'a, S, T> {
+    /// Check an item in a column with the given spec.
+    #[must_use]
+    #[inline]
+    pub const fn new(item: &'a T) -> Self {
+        Self(item, PhantomData)
+    }
+}
+
+/// Specs accepting any item implementing `Display` or `Debug`, and cells and formatters laid out in advance.
+pub trait GeneralSpec {}
+
+impl GeneralSpec for DisplaySpec {}
+
+impl GeneralSpec for DebugSpec {}
+
+/// Accept cells in `{}` and `{:?}` columns.
+pub trait FitsCell {
+    /// Accept the cell.
+    fn fits(&self);
+}
+
+impl<S: GeneralSpec> FitsCell for &Check<'_, S, Cell> {
+    #[inline]
+    fn fits(&self) {}
+}
+
+/// Accept nested formatters in `{}` and `{:?}` columns.
+pub trait FitsNested {
+    /// Accept the formatter.
+    fn fits(&self);
+}
+
+impl<S: GeneralSpec> FitsNested for &Check<'_, S, ColumnFormatter<'_>> {
+    #[inline]
+    fn fits(&self) {}
+}
+
+/// Accept items convertible into a `Duration` in `{:dur}` columns.
+pub trait FitsDuration {
+    /// Accept the item.
+    fn fits(&self);
+}
+
+impl<T: Copy + Into<Duration>> FitsDuration for &Check<'_, DurationSpec, T> {
+    #[inline]
+    fn fits(&self) {}
+}
+
+// The methods below are found last, and their bounds are the weakest each spec accepts. An item which does not fit
+// its column therefore fails to compile with an error naming the spec, rather than with no method being found.
+
+impl<T> Check<'_, AnySpec, T> {
+    /// Accept any item in a column which is not checked, or past the last column.
+    #[inline]
+    pub const fn fits(&self) {}
+}
+
+impl<T> Check<'_, DisplaySpec, T> {
+    /// Accept an item in a `{}` column, which requires `Display`.
+    #[inline]
+    pub const fn fits(&self)
+    where
+        T: Display,
+    {
+    }
+}
+
+impl<T> Check<'_, DebugSpec, T> {
+    /// Accept an item in a `{:?}` or `{:#?}` column, which requires `Debug`.
+    #[inline]
+    pub const fn fits(&self)
+    where
+        T: Debug,
+    {
+    }
+}
+
+impl<T> Check<'_, DurationSpec, T> {
+    /// Accept an item in a `{:dur}` column, which requires a `Duration` or an integer number of nanoseconds.
+    #[inline]
+    pub const fn fits(&self)
+    where
+        T: Copy + TryInto<u64>,
+    {
+    }
+}
+
+impl<T> Check<'_, SizeSpec, T> {
+    /// Accept an item in a `{:size}` column, which requires an integer number of bytes.
+    #[inline]
+    pub const fn fits(&self)
+    where
+        T: Copy + TryInto<u64>,
+    {
+    }
+}
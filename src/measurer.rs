@@ -0,0 +1,92 @@
+//! Measures the visible width of rendered lines.
+//!
+//! This module contains the `Measurer` trait, the seam through which every line width is computed, and its
+//! implementations. `CharCount` counts one column per `char` and skips ANSI escape sequences. It needs no
+//! dependencies and never allocates, so it stays available whether or not the `unicode` feature is enabled,
+//! and is the measurer the crate uses without it.
+//!
+//! With the `unicode` feature, the crate measures with `SkipZeroWidth` instead, which also gives zero-width
+//! characters, such as joiners and soft hyphens, no width. Neither is a display-width table: wide characters, such
+//! as CJK ideographs and most emoji, are still counted as one column, because lines are sliced and truncated one
+//! `char` at a time.
+
+#[cfg(feature = "unicode")]
+use crate::slice::is_zero_width;
+use crate::slice::{ESCAPE, skip_escape_sequence};
+
+/// Computes the number of terminal columns a line of text occupies.
+pub trait Measurer {
+    /// Measure the visible width of a single line.
+    fn width(&self, line: &str) -> usize;
+}
+
+/// Counts each visible `char` as one column, ignoring ANSI escape sequences.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[expect(
+    clippy::exhaustive_structs,
+    reason = "A unit measurer has no fields to add, and must be constructible by callers."
+)]
+pub struct CharCount;
+
+impl Measurer for CharCount {
+    #[inline]
+    fn width(&self, line: &str) -> usize {
+        // ASCII text without escape sequences takes one column per byte
+        if line.is_ascii() && !line.contains(ESCAPE) {
+            return line.len();
+        }
+        let mut width = 0;
+        let mut chars = line.chars();
+        while let Some(ch) = chars.next() {
+            if ch == ESCAPE {
+                skip_escape_sequence(&mut chars);
+            } else {
+                width += 1;
+            }
+        }
+        width
+    }
+}
+
+/// Counts each visible `char` as one column, except zero-width characters, which take none, ignoring ANSI
+/// escape sequences.
+///
+/// This is a `char` count rather than a display width: wide characters, such as CJK ideographs and most emoji,
+/// are counted as one column even though terminals draw them across two.
+///
+/// This measurer is only available with the `unicode` feature.
+#[cfg(feature = "unicode")]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[expect(
+    clippy::exhaustive_structs,
+    reason = "A unit measurer has no fields to add, and must be constructible by callers."
+)]
+pub struct SkipZeroWidth;
+
+#[cfg(feature = "unicode")]
+impl Measurer for SkipZeroWidth {
+    #[inline]
+    fn width(&self, line: &str) -> usize {
+        if line.is_ascii() && !line.contains(ESCAPE) {
+            return line.len();
+        }
+        let mut width = 0;
+        let mut chars = line.chars();
+        while let Some(ch) = chars.next() {
+            if ch == ESCAPE {
+                skip_escape_sequence(&mut chars);
+            } else if !is_zero_width(ch) {
+                width += 1;
+            }
+        }
+        width
+    }
+}
+
+/// The measurer used for every line the crate lays out.
+#[cfg(feature = "unicode")]
+pub const DEFAULT: SkipZeroWidth = SkipZeroWidth;
+
+/// The measurer used for every line the crate lays out.
+#[cfg(not(feature = "unicode"))]
+pub const DEFAULT: CharCount = CharCount;
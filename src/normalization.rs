@@ -0,0 +1,44 @@
+//! Defines the Unicode normalization forms applied to cell content.
+//!
+//! This module contains the `Normalization` enum, which selects the Unicode normalization
+//! form applied to each cell's text before it is split into lines and measured. Content
+//! from different sources can mix precomposed and decomposed characters (`é` as `U+00E9`
+//! versus `e` followed by `U+0301`), which otherwise leads to inconsistent column widths.
+//!
+//! Truncation counts characters, so normalizing to a composed form (`Nfc` or `Nfkc`) also
+//! stops a base character from being separated from its combining marks when a line is cut,
+//! whenever a precomposed character exists. Decomposed forms (`Nfd` or `Nfkd`) count combining
+//! marks as separate characters and should be avoided when columns are likely to be truncated.
+//!
+//! This module is only available with the `unicode` feature.
+
+use unicode_normalization::UnicodeNormalization as _;
+
+/// Unicode normalization forms.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Normalization {
+    /// Canonical decomposition followed by canonical composition.
+    #[default]
+    Nfc,
+    /// Canonical decomposition.
+    Nfd,
+    /// Compatibility decomposition followed by canonical composition.
+    Nfkc,
+    /// Compatibility decomposition.
+    Nfkd,
+}
+
+impl Normalization {
+    /// Normalize the given text into this form.
+    #[must_use]
+    #[inline]
+    pub fn apply(self, text: &str) -> String {
+        match self {
+            Self::Nfc => text.nfc().collect(),
+            Self::Nfd => text.nfd().collect(),
+            Self::Nfkc => text.nfkc().collect(),
+            Self::Nfkd => text.nfkd().collect(),
+        }
+    }
+}
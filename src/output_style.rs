@@ -0,0 +1,39 @@
+//! Defines the output styles available to tables.
+//!
+//! This module contains the `OutputStyle` enum, which selects how a `Table` is rendered:
+//! - `Plain`: Aligned columns joined by the separators from the format string (the default).
+//! - `Markdown`: A GitHub-flavoured Markdown table.
+//! - `Org`: An Emacs org-mode table, optionally with an alignment row of `<l>`, `<c>`, and `<r>` cookies.
+//! - `Custom`: The `Renderer` registered on the table under the given name.
+//! - `Delimited`: Machine-parsable lines of cells joined by an exact delimiter, with no padding.
+//!
+//! The Markdown and Org styles share a common delimited-table backend: cells are wrapped in
+//! `|` characters, any `|` inside a cell is escaped, and multi-line cells are joined onto a
+//! single line with a configurable delimiter.
+//!
+//! The `Delimited` style suits scripts, such as `awk -F'\t'`, as each field stays in the same position whatever the
+//! width of the content. Unlike CSV, nothing is quoted: multi-line cells are flattened with `\n` escapes, and the
+//! delimiter inside a cell is replaced, or rejected by strict tables.
+
+/// How a table is rendered.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum OutputStyle {
+    /// Aligned columns joined by the format string's separators.
+    #[default]
+    Plain,
+    /// Markdown table with a `| --- |` rule under the header.
+    Markdown,
+    /// Org-mode table with a `|---+---|` rule under the header.
+    Org {
+        /// Emit a row of alignment cookies (`<l>`, `<c>`, `<r>`) above the rows.
+        alignment_row: bool,
+    },
+    /// Rows written by the `Renderer` registered on the table under this name, or the plain style if there is none.
+    Custom(&'static str),
+    /// Cells joined by exactly this delimiter, with no padding, and one line per row.
+    Delimited {
+        /// The text written between cells, such as `\t`.
+        delimiter: &'static str,
+    },
+}
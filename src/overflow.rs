@@ -0,0 +1,32 @@
+//! Defines the policies applied when columns exceed the available width.
+//!
+//! This module contains the `Overflow` enum, which controls what the `ColumnFormatter` does
+//! when the combined width of all columns and separators is larger than the configured
+//! maximum width:
+//! - `Ignore`: Render every column at its resolved width, regardless of the budget.
+//! - `DropColumns`: Hide the least important columns (and their separators) until the rest fit.
+//! - `Wrap`: Narrow columns until the rest fit, word-wrapping their content onto more lines.
+//!
+//! Column importance is controlled by the per-column priority, set either with a `!n` suffix
+//! in the format string or through `ColumnFormatter::with_priority`. Which columns are narrowed
+//! for wrapping is controlled separately, by `ColumnFormatter::with_wrap_priority`, and how words
+//! wider than their column are split by `ColumnFormatter::with_wrap_policy`.
+
+/// Policy for handling output wider than the maximum width.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Overflow {
+    /// Render all columns, even if the output exceeds the maximum width.
+    #[default]
+    Ignore,
+    /// Drop the lowest-priority columns until the remaining columns fit.
+    DropColumns {
+        /// Append a line such as `(2 columns hidden)` when any columns were dropped.
+        notice: bool,
+    },
+    /// Narrow columns, highest wrap priority first, and word-wrap their content.
+    Wrap {
+        /// Width below which no column is narrowed.
+        min_width: usize,
+    },
+}
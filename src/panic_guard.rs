@@ -0,0 +1,25 @@
+//! Guards the rendering of cells against panicking formatting implementations.
+//!
+//! When a formatter or table catches panics, each cell is rendered inside `catch_unwind`. A cell whose
+//! `Display` or `Debug` implementation panics is replaced by `<format panicked: message>`, so the rest of
+//! the output is still printed. The panic hook still runs, so the panic is reported on stderr as usual.
+//!
+//! Items are borrowed through trait objects which are not `UnwindSafe`, so rendering is wrapped in
+//! `AssertUnwindSafe`. Items are only read while rendering, but an item left in an inconsistent state by
+//! its own panicking implementation is rendered as it is by later cells.
+
+use std::panic::{AssertUnwindSafe, catch_unwind};
+
+/// Render a cell, returning the text of an error cell instead if rendering panics.
+#[inline]
+pub fn guarded<F: FnOnce() -> String>(render: F) -> Result<String, String> {
+    catch_unwind(AssertUnwindSafe(render)).map_err(|payload| {
+        // `panic!` payloads are a `&str` for literal messages and a `String` for formatted ones
+        let message = payload
+            .downcast_ref::<&str>()
+            .copied()
+            .or_else(|| payload.downcast_ref::<String>().map(String::as_str))
+            .unwrap_or("unknown payload");
+        format!("<format panicked: {message}>")
+    })
+}
@@ -0,0 +1,555 @@
+//! The single grammar shared by the `colprint!` macro and the `ColumnFormatter`.
+//!
+//! This module splits a format string into `FormatPart`s. Both the macro (which pairs each
+//! item with its specifier) and `ColumnFormatter::parse_format_string` (which builds the
+//! column formats) use this parser, so they always agree on how many columns there are.
+//!
+//! `column_format_type` follows the same grammar with the operations available in constants, so
+//! that the macros can find the format type of each column of a literal format string when they are
+//! compiled. The `format_types` tests check that it agrees with this parser.
+//!
+//! The grammar is:
+//! - A specifier is `{` optionally followed by `:` or `@` and any text without braces, then `}`,
+//!   e.g. `{}`, `{:?}`, `{:#?}`, `{:dur}`, or `{@40}`. Text following the `std::fmt` grammar, such as
+//!   `{:>8.3}`, sets the options of each value, where the width is a per-value minimum width; any other
+//!   text names a format, such as `{:dur}`.
+//! - A specifier may end with an anchor (`@` and one or more digits) just before the closing
+//!   brace, e.g. `{@40}` or `{:?@40}`, which `split_anchor` separates from the rest.
+//! - An empty or `std::fmt` specifier may end with `n` to group the digits of numbers, e.g. `{:n}` or `{:?n}`,
+//!   which `split_grouping` separates from the rest. Format names ending in `n`, such as `{:json}`, are not grouped.
+//! - A specifier may be followed by a column width (`:` and one or more digits, optionally followed
+//!   by `%` for a percentage of the available width), then a priority (`!` and one or more
+//!   digits), and then a truncation side (`~` and one of `l`, `m`, or `r`), e.g. `{:?}:40!2~l`
+//!   or `{:?}:40%`.
+//! - The suffixes may end with an alignment (`=` and one or two of `<`, `^`, or `>`). One character
+//!   aligns the whole column, while two align the header by the first and every other row by the
+//!   second, e.g. `{}:12=^>` for a numeric column with a centred header.
+//! - ASCII whitespace between the tokens of a specifier is tolerated and removed by `normalize_spec`, so
+//!   `{ :#? }` is the same as `{:#?}` and `{ :? : 40 }` the same as `{:?:40}`. Whitespace which means something
+//!   is kept: a space used as the fill of a `std::fmt` specifier, such as `{: >8}`, and spaces inside the
+//!   parentheses of a named format, such as `{:time(%H %M)}`. The suffixes after the closing brace never
+//!   contain whitespace, as text such as `{}: 5 items` is a separator.
+//! - `{{` and `}}` are escaped braces and appear as single braces in separators.
+//! - Any other brace, such as the braces in a JSON-like separator `{"a": 1}`, is treated as
+//!   literal separator text.
+//! - A specifier left open at the end of the string, such as the `{:?` in `"{} | {:?"`, is also
+//!   literal separator text, but `unterminated_spec` reports where it starts so strict callers
+//!   can reject it.
+
+use std::borrow::Cow;
+
+use crate::{Alignment, ValueSpec, format_part::FormatPart, format_type::FormatType};
+
+/// Split a format string into specifiers and separators.
+#[must_use]
+#[inline]
+pub fn parse_parts(format_str: &str) -> Vec<FormatPart<'_>> {
+    scan(format_str).0
+}
+
+/// Find the byte offset of a specifier left open at the end of the format string, if there is one.
+#[expect(clippy::single_call_fn, reason = "Keeps the specifier grammar in one module.")]
+#[must_use]
+#[inline]
+pub fn unterminated_spec(format_str: &str) -> Option<usize> {
+    scan(format_str).1
+}
+
+/// Split a format string into parts, also reporting the offset of any unterminated specifier.
+fn scan(format_str: &str) -> (Vec<FormatPart<'_>>, Option<usize>) {
+    let bytes = format_str.as_bytes();
+    let mut parts = Vec::new();
+    let mut unterminated = None;
+
+    let mut separator_start = 0;
+    let mut i = 0;
+    while i < bytes.len() {
+        // Escaped braces are part of the separator
+        if (bytes[i] == b'{' || bytes[i] == b'}') && bytes.get(i + 1) == Some(&bytes[i]) {
+            i += 2;
+            continue;
+        }
+
+        let found = spec_end(bytes, i);
+        let Some(end) = found else {
+            // A specifier opened with no brace after it can never be closed
+            if bytes[i] == b'{'
+                && matches!(bytes.get(i + 1), Some(&b':' | &b'@'))
+                && !bytes[i + 1..].iter().any(|&b| b == b'{' || b == b'}')
+            {
+                unterminated = unterminated.or(Some(i));
+            }
+            i += 1;
+            continue;
+        };
+
+        // There's a separator before this format specifier
+        if i > separator_start {
+            parts.push(FormatPart::Separator(format_str.get(separator_start..i).unwrap_or_default()));
+        }
+
+        let spec = format_str.get(i..end).unwrap_or_default();
+        let (width, width_end) = width_suffix(format_str, end);
+        let (priority, priority_end) = suffix(format_str, width_end, b'!');
+        let (side, side_end) = side_suffix(format_str, priority_end);
+        let (alignment, alignment_end) = alignment_suffix(format_str, side_end);
+        parts.push(FormatPart::Format(spec, width, priority, side, alignment));
+
+        i = alignment_end;
+        separator_start = i;
+    }
+
+    // Add any trailing separator
+    if separator_start < format_str.len() {
+        parts.push(FormatPart::Separator(format_str.get(separator_start..).unwrap_or_default()));
+    }
+
+    (parts, unterminated)
+}
+
+/// Find the end (exclusive) of the specifier starting at `start`, if there is one.
+const fn spec_end(bytes: &[u8], start: usize) -> Option<usize> {
+    if bytes[start] != b'{' {
+        return None;
+    }
+
+    // Whitespace may come before the first token
+    let mut first = start + 1;
+    while first < bytes.len() && bytes[first].is_ascii_whitespace() {
+        first += 1;
+    }
+    if first == bytes.len() {
+        return None;
+    }
+    match bytes[first] {
+        b'}' => Some(first + 1),
+        b':' | b'@' => {
+            let mut close = first;
+            while close < bytes.len() && bytes[close] != b'{' && bytes[close] != b'}' {
+                close += 1;
+            }
+            if close < bytes.len() && bytes[close] == b'}' {
+                Some(close + 1)
+            } else {
+                None
+            }
+        }
+        _ => None,
+    }
+}
+
+/// Remove the ASCII whitespace between the tokens of a specifier, such as `{ :? : 40 }`, giving `{:?:40}`.
+///
+/// Whitespace is removed after the opening brace, before the closing brace, before a `:` or `@`, and between a
+/// `:` and the digits of a width. Any other whitespace, such as the fill of `{: >8}` or the spaces inside the
+/// parentheses of `{:time(%H %M)}`, is kept.
+#[must_use]
+#[inline]
+pub fn normalize_spec(spec: &str) -> Cow<'_, str> {
+    if !spec.bytes().any(|b| b.is_ascii_whitespace()) {
+        return Cow::Borrowed(spec);
+    }
+
+    let body = spec
+        .strip_prefix('{')
+        .and_then(|inner| inner.strip_suffix('}'))
+        .unwrap_or(spec)
+        .trim_matches(|ch: char| ch.is_ascii_whitespace());
+    let mut normalized = String::from("{");
+    let mut depth = 0_usize;
+    let mut chars = body.chars().peekable();
+    while let Some(ch) = chars.next() {
+        match ch {
+            '(' => depth += 1,
+            ')' => depth = depth.saturating_sub(1),
+            _ if ch.is_ascii_whitespace() && depth == 0 => {
+                while chars.next_if(char::is_ascii_whitespace).is_some() {}
+                let next = chars.peek().copied();
+                let before_token = matches!(next, Some(':' | '@'));
+                let before_width = normalized.ends_with(':') && next.is_some_and(|digit| digit.is_ascii_digit());
+                if before_token || before_width {
+                    continue;
+                }
+            }
+            _ => {}
+        }
+        normalized.push(ch);
+    }
+    normalized.push('}');
+    Cow::Owned(normalized)
+}
+
+/// Split a specifier into its body, without the closing brace, and the anchor at its end, if any.
+#[must_use]
+#[inline]
+pub fn split_anchor(spec: &str) -> (&str, Option<usize>) {
+    let body = spec.strip_suffix('}').unwrap_or(spec);
+    body.rsplit_once('@')
+        .filter(|&(_, offset)| !offset.is_empty() && offset.bytes().all(|b| b.is_ascii_digit()))
+        .and_then(|(head, offset)| Some((head, Some(offset.parse().ok()?))))
+        .unwrap_or((body, None))
+}
+
+/// Split the digit grouping flag `n` off the end of a specifier body, returning the rest and whether it was there.
+///
+/// The flag is only read after an empty or `std::fmt` specifier, such as `{:n` or `{:>8.2?n`, so format names ending
+/// in `n`, such as `{:json` or `{:bin`, are kept whole.
+#[must_use]
+#[inline]
+pub fn split_grouping(body: &str) -> (&str, bool) {
+    match body.strip_suffix('n') {
+        Some(head) if ValueSpec::parse(head).is_some() => (head, true),
+        _ => (body, false),
+    }
+}
+
+/// Parse a `~` followed by a truncation side letter at `start`, returning the letter and the end of the suffix.
+#[expect(clippy::single_call_fn, reason = "Keeps the specifier grammar readable.")]
+fn side_suffix(format_str: &str, start: usize) -> (Option<&str>, usize) {
+    let bytes = format_str.as_bytes();
+    match (bytes.get(start), bytes.get(start + 1)) {
+        (Some(&b'~'), Some(&(b'l' | b'm' | b'r'))) => (format_str.get(start + 1..start + 2), start + 2),
+        _ => (None, start),
+    }
+}
+
+/// Parse a `=` followed by one or two alignment characters at `start`, returning the characters and the end of the suffix.
+#[expect(clippy::single_call_fn, reason = "Keeps the specifier grammar readable.")]
+fn alignment_suffix(format_str: &str, start: usize) -> (Option<&str>, usize) {
+    let bytes = format_str.as_bytes();
+    if bytes.get(start) != Some(&b'=') {
+        return (None, start);
+    }
+
+    let count = bytes[start + 1..]
+        .iter()
+        .take(2)
+        .take_while(|&&b| matches!(b, b'<' | b'^' | b'>'))
+        .count();
+    if count == 0 {
+        return (None, start);
+    }
+
+    let end = start + 1 + count;
+    (format_str.get(start + 1..end), end)
+}
+
+/// Parse a width suffix at `start`, including any trailing `%`, returning the width and the end of the suffix.
+#[expect(clippy::single_call_fn, reason = "Keeps the specifier grammar readable.")]
+fn width_suffix(format_str: &str, start: usize) -> (Option<&str>, usize) {
+    match suffix(format_str, start, b':') {
+        (Some(_), end) if format_str.as_bytes().get(end) == Some(&b'%') => (format_str.get(start + 1..=end), end + 1),
+        parsed => parsed,
+    }
+}
+
+/// Parse a marker followed by one or more digits at `start`, returning the digits and the end of the suffix.
+fn suffix(format_str: &str, start: usize, marker: u8) -> (Option<&str>, usize) {
+    let bytes = format_str.as_bytes();
+    if bytes.get(start) != Some(&marker) {
+        return (None, start);
+    }
+
+    let digits = bytes[start + 1..].iter().take_while(|b| b.is_ascii_digit()).count();
+    if digits == 0 {
+        return (None, start);
+    }
+
+    let end = start + 1 + digits;
+    (format_str.get(start + 1..end), end)
+}
+
+/// Find the format type of a column of a format string, or `None` if it has no such column.
+///
+/// This follows `parse_parts`, `normalize_spec`, and `FormatType::from_spec` with the operations available in
+/// constants, so that the macros can check each item against its column when they are compiled. `LEN` is the size of
+/// the buffer a specifier containing whitespace is normalized into, so the macros pass the length of the format string.
+///
+/// # Panics
+///
+/// Panics if the specifier of the column contains whitespace and is longer than `LEN` bytes.
+#[must_use]
+#[inline]
+pub const fn column_format_type<const LEN: usize>(format_str: &str, column: usize) -> Option<FormatType> {
+    let bytes = format_str.as_bytes();
+    let mut found = 0;
+    let mut i = 0;
+    while i < bytes.len() {
+        // Escaped braces are part of the separator
+        if (bytes[i] == b'{' || bytes[i] == b'}') && i + 1 < bytes.len() && bytes[i + 1] == bytes[i] {
+            i += 2;
+            continue;
+        }
+
+        // The suffixes after a specifier contain no braces, so scanning on over them finds the same next specifier
+        match spec_end(bytes, i) {
+            Some(end) if found == column => return Some(spec_format_type::<LEN>(bytes.split_at(end).0.split_at(i).1)),
+            Some(end) => {
+                found += 1;
+                i = end;
+            }
+            None => i += 1,
+        }
+    }
+    None
+}
+
+/// Find the format type requested by a specifier, normalizing any whitespace in it first.
+#[expect(clippy::single_call_fn, reason = "Keeps the constant grammar readable.")]
+const fn spec_format_type<const LEN: usize>(spec: &[u8]) -> FormatType {
+    let mut has_whitespace = false;
+    let mut i = 0;
+    while i < spec.len() {
+        has_whitespace |= spec[i].is_ascii_whitespace();
+        i += 1;
+    }
+    if !has_whitespace {
+        return normalized_format_type(spec);
+    }
+
+    // Normalizing only removes bytes, so the specifier fits in a buffer as long as the format string
+    let mut normalized = [0_u8; LEN];
+    let len = normalize_into(spec, &mut normalized);
+    normalized_format_type(normalized.split_at(len).0)
+}
+
+/// Write a specifier without the whitespace `normalize_spec` removes, returning the length written.
+#[expect(clippy::single_call_fn, reason = "Kept next to `normalize_spec`, which it follows.")]
+const fn normalize_into(spec: &[u8], normalized: &mut [u8]) -> usize {
+    let (mut start, mut end) = (0, spec.len());
+    if ends_with(spec, b"}") && starts_with(spec, b"{") {
+        (start, end) = (1, spec.len() - 1);
+    }
+    while start < end && spec[start].is_ascii_whitespace() {
+        start += 1;
+    }
+    while end > start && spec[end - 1].is_ascii_whitespace() {
+        end -= 1;
+    }
+
+    normalized[0] = b'{';
+    let mut len = 1;
+    let mut depth = 0_usize;
+    let mut i = start;
+    while i < end {
+        let byte = spec[i];
+        i += 1;
+        match byte {
+            b'(' => depth += 1,
+            b')' => depth = depth.saturating_sub(1),
+            _ if byte.is_ascii_whitespace() && depth == 0 => {
+                while i < end && spec[i].is_ascii_whitespace() {
+                    i += 1;
+                }
+                let next = if i < end { spec[i] } else { 0 };
+                let before_token = next == b':' || next == b'@';
+                let before_width = normalized[len - 1] == b':' && next.is_ascii_digit();
+                if before_token || before_width {
+                    continue;
+                }
+            }
+            _ => {}
+        }
+        normalized[len] = byte;
+        len += 1;
+    }
+    normalized[len] = b'}';
+    len + 1
+}
+
+/// Find the format type requested by a specifier without whitespace, as `FormatType::from_spec` does.
+const fn normalized_format_type(spec: &[u8]) -> FormatType {
+    let body = strip_close(spec);
+    if equals(body, b"{:time") || starts_with(body, b"{:time(") {
+        FormatType::Time
+    } else if equals(body, b"{:hex") || starts_with(body, b"{:hex(") {
+        FormatType::Hex
+    } else if equals(body, b"{:bar") || starts_with(body, b"{:bar(") {
+        FormatType::Bar
+    } else if equals(body, b"{:lines") {
+        FormatType::Lines
+    } else if equals(body, b"{:lines?") {
+        FormatType::DebugLines
+    } else if contains(spec, b":dur") {
+        FormatType::Duration
+    } else if contains(spec, b":size") {
+        FormatType::Size
+    } else if contains(spec, b":raw") {
+        FormatType::Verbatim
+    } else if let Some(format_type) = value_format_type(spec) {
+        format_type
+    } else if contains(spec, b":#?") {
+        FormatType::PrettyDebug
+    } else if contains(spec, b":?") {
+        FormatType::Debug
+    } else {
+        FormatType::Display
+    }
+}
+
+/// Find the format type of a specifier following the `std::fmt` grammar, as `ValueSpec::parse` does.
+const fn value_format_type(spec: &[u8]) -> Option<FormatType> {
+    let body = without_grouping(without_anchor(spec));
+    let mut rest = if starts_with(body, b"{:") {
+        body.split_at(2).1
+    } else if starts_with(body, b"{") {
+        body.split_at(1).1
+    } else {
+        return None;
+    };
+
+    // An alignment, optionally preceded by a fill character, which may take several bytes
+    let mut fill_len = 1;
+    while fill_len < rest.len() && rest[fill_len] & 0xC0 == 0x80 {
+        fill_len += 1;
+    }
+    if fill_len < rest.len() && is_align(rest[fill_len]) {
+        rest = rest.split_at(fill_len + 1).1;
+    } else if !rest.is_empty() && is_align(rest[0]) {
+        rest = rest.split_at(1).1;
+    }
+
+    // Flags, which std parses in this order
+    if starts_with(rest, b"+") || starts_with(rest, b"-") {
+        rest = rest.split_at(1).1;
+    }
+    let alternate = starts_with(rest, b"#");
+    if alternate {
+        rest = rest.split_at(1).1;
+    }
+    if starts_with(rest, b"0") {
+        rest = rest.split_at(1).1;
+    }
+
+    // Width, then precision
+    rest = rest.split_at(leading_digits(rest)).1;
+    if starts_with(rest, b".") {
+        let digits = leading_digits(rest.split_at(1).1);
+        if digits == 0 || !fits_usize(rest.split_at(1 + digits).0.split_at(1).1) {
+            return None;
+        }
+        rest = rest.split_at(1 + digits).1;
+    }
+
+    // Type
+    if rest.is_empty() {
+        Some(FormatType::Display)
+    } else if !equals(rest, b"?") {
+        None
+    } else if alternate {
+        Some(FormatType::PrettyDebug)
+    } else {
+        Some(FormatType::Debug)
+    }
+}
+
+/// Remove the closing brace and any anchor from a specifier, as `split_anchor` does.
+#[expect(clippy::single_call_fn, reason = "Kept next to `split_anchor`, which it follows.")]
+const fn without_anchor(spec: &[u8]) -> &[u8] {
+    let body = strip_close(spec);
+    let mut at = body.len();
+    while at > 0 && body[at - 1] != b'@' {
+        at -= 1;
+    }
+    if at == 0 {
+        return body;
+    }
+
+    let (head, offset) = body.split_at(at);
+    if !offset.is_empty() && leading_digits(offset) == offset.len() && fits_usize(offset) {
+        head.split_at(at - 1).0
+    } else {
+        body
+    }
+}
+
+/// Remove the digit grouping flag from a specifier body, as `split_grouping` does.
+#[expect(clippy::single_call_fn, reason = "Kept next to `split_grouping`, which it follows.")]
+const fn without_grouping(body: &[u8]) -> &[u8] {
+    if !ends_with(body, b"n") {
+        return body;
+    }
+    let head = body.split_at(body.len() - 1).0;
+    if value_format_type(head).is_some() { head } else { body }
+}
+
+/// Remove the closing brace from the end of a specifier, if it is there.
+const fn strip_close(spec: &[u8]) -> &[u8] {
+    if ends_with(spec, b"}") {
+        spec.split_at(spec.len() - 1).0
+    } else {
+        spec
+    }
+}
+
+/// Check whether a byte is an alignment of the `std::fmt` grammar.
+const fn is_align(byte: u8) -> bool {
+    Alignment::from_suffix(byte).is_some()
+}
+
+/// Count the ASCII digits at the start of some bytes.
+const fn leading_digits(bytes: &[u8]) -> usize {
+    let mut count = 0;
+    while count < bytes.len() && bytes[count].is_ascii_digit() {
+        count += 1;
+    }
+    count
+}
+
+/// Check whether some ASCII digits parse as a `usize` without overflowing.
+#[expect(
+    clippy::as_conversions,
+    reason = "`usize::from` cannot be called in constants, and a digit always fits."
+)]
+const fn fits_usize(digits: &[u8]) -> bool {
+    let mut value = 0_usize;
+    let mut i = 0;
+    while i < digits.len() {
+        value = match value.checked_mul(10) {
+            Some(shifted) => match shifted.checked_add((digits[i] - b'0') as usize) {
+                Some(sum) => sum,
+                None => return false,
+            },
+            None => return false,
+        };
+        i += 1;
+    }
+    true
+}
+
+/// Check whether two byte strings are equal.
+const fn equals(bytes: &[u8], other: &[u8]) -> bool {
+    bytes.len() == other.len() && starts_with(bytes, other)
+}
+
+/// Check whether some bytes start with a prefix.
+const fn starts_with(bytes: &[u8], prefix: &[u8]) -> bool {
+    if prefix.len() > bytes.len() {
+        return false;
+    }
+    let mut i = 0;
+    while i < prefix.len() {
+        if bytes[i] != prefix[i] {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
+/// Check whether some bytes end with a suffix.
+const fn ends_with(bytes: &[u8], suffix: &[u8]) -> bool {
+    suffix.len() <= bytes.len() && starts_with(bytes.split_at(bytes.len() - suffix.len()).1, suffix)
+}
+
+/// Check whether some bytes contain a needle.
+const fn contains(bytes: &[u8], needle: &[u8]) -> bool {
+    let mut start = 0;
+    while start + needle.len() <= bytes.len() {
+        if starts_with(bytes.split_at(start).1, needle) {
+            return true;
+        }
+        start += 1;
+    }
+    false
+}
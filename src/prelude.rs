@@ -0,0 +1,34 @@
+//! The items most programs printing columns need, for a single glob import.
+//!
+//! Importing `colprint::prelude::*` brings in the macros, the formatter and table types, the traits turning values
+//! into items, and the options most often passed to their builders. Every item is also re-exported at the crate
+//! root, so the rest can be imported from there by name.
+//!
+//! # Examples
+//!
+//! ```
+//! use colprint::prelude::*;
+//!
+//! let formatter = ColumnFormatter::new("{}:5 | {:?}", [FormattableItem::from(&"Alice"), FormattableItem::from(&30)]).with_color(ColorChoice::Never);
+//! assert_eq!(formatter.to_string(), colformat!("{}:5 | {:?}", "Alice", 30));
+//! ```
+//!
+//! The modules implementing the crate are private, so their internals cannot be named from outside it:
+//!
+//! ```compile_fail
+//! use colprint::format_part::FormatPart;
+//! ```
+//!
+//! ```compile_fail
+//! use colprint::column_formatter::ColumnFormatter;
+//! ```
+//!
+//! ```compile_fail
+//! use colprint::prelude::FormatPart;
+//! ```
+
+pub use crate::{
+    Alignment, Color, ColorChoice, ColumnFormat, ColumnFormatter, ColumnTemplate, Columns, FormatError, FormatType,
+    FormattableItem, Overflow, RenderOptions, Style, Table, TableWriter, ToDebugItem, ToDisplayItem, ToFormattableItem,
+    ToLinesItem, colformat, colformat_with, colprint, colprint_kv, colprint_with, colrule,
+};
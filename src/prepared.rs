@@ -0,0 +1,124 @@
+//! Defines the cells of a `ColumnFormatter` after formatting and measurement, before any line is assembled.
+//!
+//! This module contains the `Prepared` struct, the middle phase of a render. The format string and layout
+//! policies are held by the `ColumnFormatter`, or reused across call sites by a `ColumnTemplate`. Preparing formats
+//! every item into the lines of its cell, resolves the width of each column, drops the columns which do not fit,
+//! and wraps or redraws cells to their final width. The prepared cells are then assembled into lines of text, as
+//! `Display` does, or read directly by a caller emitting them through another backend.
+
+use std::ops::Range;
+
+use crate::{ColumnFormatter, Dimensions, slice::visible_width};
+
+/// The cells of a `ColumnFormatter` formatted and measured, with the widths of their columns resolved.
+///
+/// # Examples
+///
+/// ```
+/// use colprint::ColumnFormatter;
+///
+/// let formatter = ColumnFormatter::new("{} | {}", [&"name", &"Alice"]);
+/// let prepared = formatter.prepare();
+/// assert_eq!(prepared.widths(), [4, 5]);
+/// assert_eq!(prepared.cell(1), Some(&["Alice".to_owned()][..]));
+/// assert_eq!(prepared.lines(), ["name | Alice"]);
+/// ```
+#[derive(Debug)]
+pub struct Prepared<'f, 'a> {
+    /// The formatter the cells were prepared by, which assembles them into lines.
+    formatter: &'f ColumnFormatter<'a>,
+    /// The lines of each cell, by column.
+    cells: Vec<Vec<String>>,
+    /// The resolved width of each column.
+    widths: Vec<usize>,
+    /// The indices of the columns left after any were dropped, in order.
+    visible: Vec<usize>,
+    /// The separator after each column, after any squeezing.
+    separators: Vec<Option<&'f str>>,
+}
+
+impl<'f, 'a> Prepared<'f, 'a> {
+    /// Hold the prepared cells with the formatter which assembles them.
+    pub(crate) const fn new(
+        formatter: &'f ColumnFormatter<'a>,
+        cells: Vec<Vec<String>>,
+        widths: Vec<usize>,
+        visible: Vec<usize>,
+        separators: Vec<Option<&'f str>>,
+    ) -> Self {
+        Self {
+            formatter,
+            cells,
+            widths,
+            visible,
+            separators,
+        }
+    }
+
+    /// The lines of each cell, by column, before they are aligned and padded to the width of their column.
+    #[must_use]
+    #[inline]
+    pub fn cells(&self) -> &[Vec<String>] {
+        &self.cells
+    }
+
+    /// The lines of the cell in the given column, if there is one.
+    #[must_use]
+    #[inline]
+    pub fn cell(&self, column: usize) -> Option<&[String]> {
+        self.cells.get(column).map(Vec::as_slice)
+    }
+
+    /// The resolved width of each column, in characters.
+    #[must_use]
+    #[inline]
+    pub fn widths(&self) -> &[usize] {
+        &self.widths
+    }
+
+    /// The indices of the columns shown, in order, after any were dropped by the overflow policy.
+    #[must_use]
+    #[inline]
+    pub fn visible_columns(&self) -> &[usize] {
+        &self.visible
+    }
+
+    /// The separator after the given column, if it has one after any squeezing.
+    #[must_use]
+    #[inline]
+    pub fn separator(&self, column: usize) -> Option<&str> {
+        self.separators.get(column).copied().flatten()
+    }
+
+    /// The number of lines of the tallest cell, which is the number of lines of columns in the output.
+    #[must_use]
+    #[inline]
+    pub fn height(&self) -> usize {
+        self.cells.iter().map(Vec::len).max().unwrap_or(0)
+    }
+
+    /// Assemble the cells into the lines of the full output, the same as those written by `Display`.
+    #[must_use]
+    #[inline]
+    pub fn lines(&self) -> Vec<String> {
+        self.render_lines(0..usize::MAX)
+    }
+
+    /// Assemble only the given range of output lines, the same as the matching lines of `lines`.
+    #[must_use]
+    #[inline]
+    pub fn render_lines(&self, range: Range<usize>) -> Vec<String> {
+        self.formatter.aligned(self.formatter.assemble(self, 0, range).0)
+    }
+
+    /// Measure the assembled output.
+    #[must_use]
+    #[inline]
+    pub fn measure(&self) -> Dimensions {
+        let lines = self.lines();
+        Dimensions {
+            lines: lines.len(),
+            width: lines.iter().map(|line| visible_width(line)).max().unwrap_or(0),
+        }
+    }
+}
@@ -0,0 +1,69 @@
+//! Reads the lines of cells streamed from `BufRead` sources.
+//!
+//! A `FormattableItem::Reader` cell is read when its formatter renders, pulling lines from the source one
+//! at a time. Only the lines and characters the layout can keep are stored: reading stops after the
+//! maximum number of lines per cell, plus one to detect that lines were dropped, and the rest of each line
+//! beyond the maximum cell width is skipped without being stored. A large log file therefore costs no more
+//! memory than its capped cell.
+//!
+//! An I/O error part way through ends the cell with a `<read error: ...>` line. Sources are read once:
+//! rendering again continues from where the previous render stopped.
+
+use std::{
+    cell::RefCell,
+    io::{BufRead, Result as IoResult},
+};
+
+/// The most bytes a character takes in UTF-8, used to bound the bytes kept of each line.
+const MAX_CHAR_BYTES: usize = 4;
+
+/// Read lines from a source, up to `max_lines` of them and each up to `max_width` characters.
+pub fn read_lines(reader: &RefCell<dyn BufRead + '_>, max_lines: Option<usize>, max_width: Option<usize>) -> Vec<String> {
+    let Ok(mut source) = reader.try_borrow_mut() else {
+        return vec!["<read error: the reader is already being read>".to_owned()];
+    };
+
+    let max_bytes = max_width.map_or(usize::MAX, |width| width.saturating_mul(MAX_CHAR_BYTES));
+    let mut lines = Vec::new();
+    while max_lines.is_none_or(|max| lines.len() < max) {
+        match read_line(&mut *source, max_bytes) {
+            Ok(Some(line)) => lines.push(line),
+            Ok(None) => break,
+            Err(err) => {
+                lines.push(format!("<read error: {}>", err.kind()));
+                break;
+            }
+        }
+    }
+    lines
+}
+
+/// Read a single line, keeping at most `max_bytes` of it and skipping the rest, or `None` at the end of the source.
+#[expect(clippy::single_call_fn, reason = "Keeps the bounded line reading apart from the line cap.")]
+fn read_line(source: &mut dyn BufRead, max_bytes: usize) -> IoResult<Option<String>> {
+    let mut bytes = Vec::new();
+    let mut read_any = false;
+    loop {
+        let buffer = source.fill_buf()?;
+        if buffer.is_empty() {
+            break;
+        }
+        read_any = true;
+
+        let newline = buffer.iter().position(|&byte| byte == b'\n');
+        let chunk = &buffer[..newline.unwrap_or(buffer.len())];
+        let room = max_bytes.saturating_sub(bytes.len());
+        bytes.extend_from_slice(&chunk[..chunk.len().min(room)]);
+
+        let consumed = newline.map_or(buffer.len(), |idx| idx + 1);
+        source.consume(consumed);
+        if newline.is_some() {
+            break;
+        }
+    }
+
+    if bytes.last() == Some(&b'\r') {
+        bytes.pop();
+    }
+    Ok(read_any.then(|| String::from_utf8_lossy(&bytes).into_owned()))
+}
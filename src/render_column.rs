@@ -0,0 +1,18 @@
+//! Defines the layout of a column as seen by a `Renderer`.
+//!
+//! This module contains the `RenderColumn` struct, which gives each renderer the alignment and width of a column,
+//! measured over every cell of the table, and the separator the format string puts after it.
+
+use crate::Alignment;
+
+/// The layout of one column of a table, passed to every `Renderer` call.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct RenderColumn<'a> {
+    /// Alignment of the data in the column.
+    pub alignment: Alignment,
+    /// Width of the widest line of the column's cells, including headers and footers.
+    pub width: usize,
+    /// Optional separator after the column, which is always `None` for the last column.
+    pub separator: Option<&'a str>,
+}
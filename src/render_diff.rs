@@ -0,0 +1,182 @@
+//! Line-by-line diffs of rendered output, for the failure message of `assert_colprint_eq!`.
+//!
+//! Lines are compared exactly and matched up by their longest common subsequence. Each changed line is shown with
+//! whitespace and escape characters made visible, followed by a line of carets under the first differing column,
+//! and a note when the lines would look the same in a terminal.
+
+use std::iter::repeat_n;
+
+use crate::slice::{ESCAPE, strip_escapes};
+
+/// The character shown in place of a trailing space.
+const SPACE: char = '\u{b7}';
+
+/// The character shown in place of a tab.
+const TAB: char = '\u{2192}';
+
+/// The character shown in place of the escape character.
+const SHOWN_ESCAPE: char = '\u{241b}';
+
+/// A line of a diff.
+enum DiffLine<'a> {
+    /// A line found in both the expected and actual output.
+    Same(&'a str),
+    /// A line of the expected output missing from the actual output.
+    Removed(&'a str),
+    /// A line of the actual output missing from the expected output.
+    Added(&'a str),
+}
+
+/// A unified diff of the expected and actual output, or `None` when they are equal.
+///
+/// Rendered output ends every line with a newline, so a single trailing newline on either side is ignored.
+///
+/// Changed lines are prefixed `-` for expected and `+` for actual. Where a removed line is replaced by an added one,
+/// a caret marks the first differing display column of the pair.
+#[must_use]
+#[inline]
+pub fn render_diff(expected: &str, actual: &str) -> Option<String> {
+    let expected_text = expected.strip_suffix('\n').unwrap_or(expected);
+    let actual_text = actual.strip_suffix('\n').unwrap_or(actual);
+    if expected_text == actual_text {
+        return None;
+    }
+    let expected_lines: Vec<&str> = expected_text.split('\n').collect();
+    let actual_lines: Vec<&str> = actual_text.split('\n').collect();
+    let mut diff = String::from("--- expected\n+++ actual\n");
+    let lines = diff_lines(&expected_lines, &actual_lines);
+    let mut idx = 0;
+    while idx < lines.len() {
+        if let DiffLine::Same(line) = lines[idx] {
+            push_line(&mut diff, ' ', line);
+            idx += 1;
+            continue;
+        }
+        let mut removed = Vec::new();
+        let mut added = Vec::new();
+        while let Some(line) = lines.get(idx) {
+            match *line {
+                DiffLine::Same(_) => break,
+                DiffLine::Removed(text) => removed.push(text),
+                DiffLine::Added(text) => added.push(text),
+            }
+            idx += 1;
+        }
+        for pair in 0..removed.len().max(added.len()) {
+            let (old_line, new_line) = (removed.get(pair), added.get(pair));
+            if let Some(old) = old_line {
+                push_line(&mut diff, '-', old);
+            }
+            if let Some(new) = new_line {
+                push_line(&mut diff, '+', new);
+            }
+            if let (Some(old), Some(new)) = (old_line, new_line) {
+                push_carets(&mut diff, old, new);
+            }
+        }
+    }
+    if expected_lines.len() != actual_lines.len() {
+        diff.push_str("expected ");
+        diff.push_str(&expected_lines.len().to_string());
+        diff.push_str(" lines, found ");
+        diff.push_str(&actual_lines.len().to_string());
+        diff.push('\n');
+    }
+    Some(diff)
+}
+
+/// Match up the lines of the expected and actual output by their longest common subsequence.
+#[expect(clippy::single_call_fn, reason = "Keeps the matching of lines apart from their display.")]
+fn diff_lines<'a>(expected: &[&'a str], actual: &[&'a str]) -> Vec<DiffLine<'a>> {
+    // common[i][j] is the length of the longest common subsequence of expected[i..] and actual[j..]
+    let mut common = vec![vec![0_usize; actual.len() + 1]; expected.len() + 1];
+    for i in (0..expected.len()).rev() {
+        for j in (0..actual.len()).rev() {
+            common[i][j] = if expected[i] == actual[j] {
+                common[i + 1][j + 1] + 1
+            } else {
+                common[i + 1][j].max(common[i][j + 1])
+            };
+        }
+    }
+    let (mut i, mut j) = (0, 0);
+    let mut lines = Vec::new();
+    while i < expected.len() || j < actual.len() {
+        if i < expected.len() && j < actual.len() && expected[i] == actual[j] {
+            lines.push(DiffLine::Same(expected[i]));
+            i += 1;
+            j += 1;
+        } else if j < actual.len() && (i == expected.len() || common[i][j + 1] >= common[i + 1][j]) {
+            lines.push(DiffLine::Added(actual[j]));
+            j += 1;
+        } else {
+            lines.push(DiffLine::Removed(expected[i]));
+            i += 1;
+        }
+    }
+    lines
+}
+
+/// Show a line with its trailing spaces, tabs and escape characters made visible.
+fn visible(line: &str) -> String {
+    let content = line.trim_end_matches(' ');
+    let mut shown: String = content
+        .chars()
+        .map(|ch| match ch {
+            '\t' => TAB,
+            ESCAPE => SHOWN_ESCAPE,
+            _ => ch,
+        })
+        .collect();
+    shown.extend(repeat_n(SPACE, line.len() - content.len()));
+    shown
+}
+
+/// Push a line of the diff after its marker.
+fn push_line(diff: &mut String, marker: char, line: &str) {
+    diff.push(marker);
+    diff.push(' ');
+    diff.push_str(&visible(line));
+    diff.push('\n');
+}
+
+/// Push a caret under the first differing column of a changed pair of lines, noting any invisible difference.
+#[expect(clippy::single_call_fn, reason = "Keeps the layout of a changed pair out of the diff loop.")]
+fn push_carets(diff: &mut String, old: &str, new: &str) {
+    let (old_shown, new_shown) = (visible(old), visible(new));
+    let column = old_shown
+        .chars()
+        .zip(new_shown.chars())
+        .take_while(|&(old_ch, new_ch)| old_ch == new_ch)
+        .count();
+    diff.push_str("  ");
+    diff.extend(repeat_n(' ', column));
+    diff.push('^');
+    if let Some(note) = invisible_difference(old, new) {
+        diff.push(' ');
+        diff.push_str(note);
+    }
+    diff.push('\n');
+}
+
+/// Describe the difference between two lines when it would not be visible in a terminal.
+#[expect(clippy::single_call_fn, reason = "Keeps each kind of invisible difference in one place.")]
+fn invisible_difference(old: &str, new: &str) -> Option<&'static str> {
+    let (old_text, new_text) = (strip_escapes(old), strip_escapes(new));
+    if old_text == new_text {
+        return Some("(only ANSI escape codes differ)");
+    }
+    if old_text.trim_end() == new_text.trim_end() {
+        return Some("(only trailing whitespace differs)");
+    }
+    let words = |text: &str| {
+        text.split([' ', '\t'])
+            .filter(|word| !word.is_empty())
+            .collect::<Vec<_>>()
+            .join(" ")
+    };
+    if (old_text.contains('\t') || new_text.contains('\t')) && words(&old_text) == words(&new_text) {
+        return Some("(tabs differ from spaces)");
+    }
+    None
+}
@@ -0,0 +1,96 @@
+//! Defines the presets which fix how the `ColumnFormatter` lays out its output.
+//!
+//! This module contains the `RenderOptions` struct, which gathers the layout policies that
+//! have defaults: overflow handling, separator squeezing, strictness, padding, margins, anchor
+//! handling, block alignment, the caps protecting the layout from pathologically large cells, the trimming of
+//! trailing whitespace from cell lines, the gap squeezing must leave between columns, the cleaning of invisible
+//! characters from cell lines, the filling of blank lines added to match a taller neighbouring cell, and whether the
+//! `COLPRINT_*` environment variables seed the formatter's defaults.
+//!
+//! `RenderOptions::v1` is a frozen preset: its values, and the output they produce, will not
+//! change in later releases, so it is suitable for golden-output tests. New behaviour is only
+//! ever added as options which are off in this preset. `RenderOptions::default` is the same as
+//! `v1`, except that it caps cells at 4096 characters wide and 10000 lines tall, trims trailing whitespace from
+//! their lines, and never squeezes columns closer than one character apart; it may change.
+
+use crate::{AnchorPolicy, BlockAlign, Overflow, Squeeze, Strictness};
+
+/// Number of characters after which lines of a cell are cut by default.
+const DEFAULT_MAX_CELL_WIDTH: usize = 4096;
+
+/// Number of lines after which the lines of a cell are dropped by default.
+const DEFAULT_MAX_LINES_PER_CELL: usize = 10000;
+
+/// The layout policies used by a `ColumnFormatter`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+#[expect(clippy::struct_excessive_bools, reason = "Each policy is switched on independently.")]
+pub struct RenderOptions {
+    /// Policy applied when the output is wider than the maximum width.
+    pub overflow: Overflow,
+    /// Policy for trimming separators before the overflow policy applies.
+    pub squeeze: Squeeze,
+    /// Policy for items which cannot provide their column's representation.
+    pub strictness: Strictness,
+    /// Spaces added on each side of every separator, and after the last column.
+    pub padding: usize,
+    /// Spaces added before every line of the block.
+    pub margin_left: usize,
+    /// Policy applied when earlier content has passed a column's anchor.
+    pub anchor_policy: AnchorPolicy,
+    /// Placement of the whole block within the target width.
+    pub block_align: BlockAlign,
+    /// Optional number of characters after which lines of a cell are cut before layout.
+    pub max_cell_width: Option<usize>,
+    /// Optional number of lines after which the lines of a cell are dropped before layout.
+    pub max_lines_per_cell: Option<usize>,
+    /// Whether trailing whitespace is removed from each line of a cell before it is measured.
+    pub trim_trailing_whitespace: bool,
+    /// Number of characters, counting padding and the separator, that squeezing leaves between adjacent columns.
+    pub min_gap: usize,
+    /// Whether non-breaking spaces in cell lines become spaces and zero-width spaces are removed before measurement.
+    pub clean_invisible: bool,
+    /// Whether lines added below a cell to match a taller neighbour are drawn with the column's fill, not spaces.
+    pub fill_blank_lines: bool,
+    /// Whether the `COLPRINT_*` environment variables seed the maximum width, color choice, accessibility, truncation
+    /// marker, and choice to only write ASCII.
+    pub read_env: bool,
+}
+
+impl RenderOptions {
+    /// The frozen version 1 layout, whose output will stay the same byte for byte in later releases.
+    #[must_use]
+    #[inline]
+    pub const fn v1() -> Self {
+        Self {
+            overflow: Overflow::Ignore,
+            squeeze: Squeeze::None,
+            strictness: Strictness::Lenient,
+            padding: 0,
+            margin_left: 0,
+            anchor_policy: AnchorPolicy::Push,
+            block_align: BlockAlign::Left,
+            max_cell_width: None,
+            max_lines_per_cell: None,
+            trim_trailing_whitespace: false,
+            min_gap: 0,
+            clean_invisible: false,
+            fill_blank_lines: false,
+            read_env: false,
+        }
+    }
+}
+
+impl Default for RenderOptions {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            max_cell_width: Some(DEFAULT_MAX_CELL_WIDTH),
+            max_lines_per_cell: Some(DEFAULT_MAX_LINES_PER_CELL),
+            trim_trailing_whitespace: true,
+            min_gap: 1,
+            read_env: true,
+            ..Self::v1()
+        }
+    }
+}
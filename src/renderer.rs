@@ -0,0 +1,126 @@
+//! Defines the backend trait through which tables are written in custom output styles.
+//!
+//! This module contains the `Renderer` trait and `PlainRenderer`, its implementation for aligned columns. The table
+//! formats every cell, measures the columns, and then calls the renderer once to begin the table, once for each
+//! header, data, and footer row, once for the rule under the header, and once to end the table. Renderers are
+//! registered on a `Table` under a name with `Table::with_renderer`, and selected with `OutputStyle::Custom`, so
+//! new backends such as `AsciiDoc` can be added without changing the crate.
+
+use std::fmt::{Result as FmtResult, Write};
+
+use crate::{Cell, RenderColumn, Style, TruncateSide, column_formatter::pad_line};
+
+/// A backend writing the rows of a table in an output style.
+///
+/// # Examples
+///
+/// ```
+/// use std::fmt::{Result, Write};
+///
+/// use colprint::{Cell, OutputStyle, RenderColumn, Renderer, Table};
+///
+/// struct Csv;
+///
+/// impl Renderer for Csv {
+///     fn data_row(&self, out: &mut dyn Write, _columns: &[RenderColumn<'_>], cells: &[Cell]) -> Result {
+///         let texts: Vec<String> = cells.iter().map(|cell| cell.lines().join(" ")).collect();
+///         writeln!(out, "{}", texts.join(","))
+///     }
+/// }
+///
+/// let table = Table::from_columns("{} | {}", (vec!["Alice", "Bob"], vec![30, 4]))
+///     .with_renderer("csv", Csv)
+///     .with_style(OutputStyle::Custom("csv"));
+/// assert_eq!(table.to_string(), "Alice,30\nBob,4\n");
+/// ```
+pub trait Renderer {
+    /// Write anything preceding the rows, such as an opening tag.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the output cannot be written.
+    #[inline]
+    fn begin_table(&self, _out: &mut dyn Write, _columns: &[RenderColumn<'_>]) -> FmtResult {
+        Ok(())
+    }
+
+    /// Write the header row, which is the same as a data row unless overridden.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the output cannot be written.
+    #[inline]
+    fn header_row(&self, out: &mut dyn Write, columns: &[RenderColumn<'_>], cells: &[Cell]) -> FmtResult {
+        self.data_row(out, columns, cells)
+    }
+
+    /// Write a row of data, with one cell for each column.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the output cannot be written.
+    fn data_row(&self, out: &mut dyn Write, columns: &[RenderColumn<'_>], cells: &[Cell]) -> FmtResult;
+
+    /// Write the footer row, which is the same as a data row unless overridden.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the output cannot be written.
+    #[inline]
+    fn footer_row(&self, out: &mut dyn Write, columns: &[RenderColumn<'_>], cells: &[Cell]) -> FmtResult {
+        self.data_row(out, columns, cells)
+    }
+
+    /// Write the rule between the header row and the data rows, which is nothing unless overridden.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the output cannot be written.
+    #[inline]
+    fn rule(&self, _out: &mut dyn Write, _columns: &[RenderColumn<'_>]) -> FmtResult {
+        Ok(())
+    }
+
+    /// Write anything following the rows, such as a closing tag.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the output cannot be written.
+    #[inline]
+    fn end_table(&self, _out: &mut dyn Write, _columns: &[RenderColumn<'_>]) -> FmtResult {
+        Ok(())
+    }
+}
+
+/// A renderer writing aligned columns joined by their separators, as the plain style does for unstyled tables.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct PlainRenderer;
+
+impl PlainRenderer {
+    /// Construct a `PlainRenderer`.
+    #[must_use]
+    #[inline]
+    pub const fn new() -> Self {
+        Self
+    }
+}
+
+impl Renderer for PlainRenderer {
+    #[inline]
+    fn data_row(&self, out: &mut dyn Write, columns: &[RenderColumn<'_>], cells: &[Cell]) -> FmtResult {
+        let height = cells.iter().map(|cell| cell.lines().len()).max().unwrap_or(0).max(1);
+        for line_idx in 0..height {
+            for (column, cell) in columns.iter().zip(cells) {
+                let line = cell.lines().get(line_idx).map_or("", String::as_str);
+                let truncation = (TruncateSide::Right, "", Style::new());
+                write!(out, "{}", pad_line(line, column.width, column.alignment, truncation, ' '))?;
+                if let Some(separator) = column.separator {
+                    write!(out, "{separator}")?;
+                }
+            }
+            writeln!(out)?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,18 @@
+//! Defines the role a row plays in a table.
+//!
+//! This module contains the `RowRole` enum, which tells the renderer whether a row is the header,
+//! a row of data, or the footer, so each column can align the three differently:
+//! - `Header`: The header row, including repeated headers.
+//! - `Data`: A row of pushed items.
+//! - `Footer`: The footer row, such as a summary.
+
+/// The role of a row in a table.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RowRole {
+    /// The header row.
+    Header,
+    /// A row of data.
+    Data,
+    /// The footer row.
+    Footer,
+}
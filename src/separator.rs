@@ -0,0 +1,63 @@
+//! Defines separators which change with the position of the line within a row.
+//!
+//! This module contains the `Separator` enum, which chooses the text written after a column on each
+//! line of a row:
+//! - `Plain`: Write the separator from the format string on every line (the default).
+//! - `PerPosition`: Write `first` on the first line, `last` on the last line, and `middle` on the lines
+//!   between them, so multi-line cells get bracket-like gutters such as `┌`, `│`, and `└`. Rows of a
+//!   single line use `middle`.
+//!
+//! The three pieces must have the same display width, which `Separator::per_position` checks, so the
+//! columns stay lined up.
+
+use crate::slice::visible_width;
+
+/// The separator written after a column, by the position of the line within its row.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Separator {
+    /// Write the separator from the format string on every line.
+    #[default]
+    Plain,
+    /// Write different pieces on the first, middle, and last lines of a row.
+    PerPosition {
+        /// Piece written on the first line of a row of several lines.
+        first: String,
+        /// Piece written on the lines between the first and the last, and on rows of a single line.
+        middle: String,
+        /// Piece written on the last line of a row of several lines.
+        last: String,
+    },
+}
+
+impl Separator {
+    /// Construct a `PerPosition` separator, or `None` if the pieces have different display widths.
+    #[must_use]
+    #[inline]
+    pub fn per_position<S: Into<String>>(first: S, middle: S, last: S) -> Option<Self> {
+        let pieces = [first.into(), middle.into(), last.into()];
+        let width = visible_width(&pieces[1]);
+        if pieces.iter().any(|piece| visible_width(piece) != width) {
+            return None;
+        }
+        let [first_piece, middle_piece, last_piece] = pieces;
+        Some(Self::PerPosition {
+            first: first_piece,
+            middle: middle_piece,
+            last: last_piece,
+        })
+    }
+
+    /// The text written in place of the plain separator on the given line of a row of `num_lines` lines.
+    #[must_use]
+    #[expect(clippy::ref_patterns, reason = "Only the pieces need to be borrowed.")]
+    #[inline]
+    pub(crate) fn render<'s>(&'s self, separator: &'s str, line_idx: usize, num_lines: usize) -> &'s str {
+        match *self {
+            Self::Plain => separator,
+            Self::PerPosition { ref first, .. } if num_lines > 1 && line_idx == 0 => first,
+            Self::PerPosition { ref last, .. } if num_lines > 1 && line_idx == num_lines - 1 => last,
+            Self::PerPosition { ref middle, .. } => middle,
+        }
+    }
+}
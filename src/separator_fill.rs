@@ -0,0 +1,40 @@
+//! Defines which output lines of a multi-line row show the separators.
+//!
+//! This module contains the `SeparatorFill` enum, which controls what is written in place of a separator
+//! on each line of a row whose cells span several lines:
+//! - `Repeat`: Write the separator on every line (the default).
+//! - `FirstLineStyled`: Write the separator on the first line only, and a run of the `rest` character
+//!   of the same display width on the following lines, keeping the columns lined up.
+
+use std::borrow::Cow;
+
+use crate::slice::visible_width;
+
+/// Policy for writing separators on the lines of a multi-line row.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum SeparatorFill {
+    /// Write the separator on every line.
+    #[default]
+    Repeat,
+    /// Write the separator on the first line, and the `rest` character on the following lines.
+    FirstLineStyled {
+        /// Character repeated to the display width of the separator after the first line.
+        rest: char,
+    },
+}
+
+impl SeparatorFill {
+    /// The text written in place of the separator on the given line of a row.
+    ///
+    /// Escape sequences in the separator take up no width, so styled separators are replaced by
+    /// runs as wide as their visible text.
+    #[must_use]
+    #[inline]
+    pub(crate) fn render(self, separator: &str, line_idx: usize) -> Cow<'_, str> {
+        match self {
+            Self::FirstLineStyled { rest } if line_idx > 0 => Cow::Owned(rest.to_string().repeat(visible_width(separator))),
+            Self::Repeat | Self::FirstLineStyled { .. } => Cow::Borrowed(separator),
+        }
+    }
+}
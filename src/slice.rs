@@ -0,0 +1,187 @@
+//! Horizontal slicing of rendered lines.
+//!
+//! Lines are measured, split, and sliced by visible characters. ANSI escape sequences, both control sequences such as
+//! styles and operating system commands such as OSC 8 hyperlinks, take up no width and are always
+//! kept, whether or not they fall inside the slice, so styles opened before the slice still apply
+//! within it and styles closed after it are still reset. Slices never split a character.
+//!
+//! With the `unicode` feature, zero-width spaces, non-joiners and joiners, word joiners, byte order marks, and soft
+//! hyphens, such as those in text copied from web pages, take up no width either. They stay with the character before
+//! them when a line is sliced. Without it, every other character takes up one column. Non-breaking spaces take up one
+//! column like any other character.
+
+/// The escape character starting an ANSI escape sequence.
+pub const ESCAPE: char = '\x1b';
+
+use crate::measurer::{self, Measurer as _};
+
+/// Whether a character takes up no width in a terminal.
+#[cfg(feature = "unicode")]
+#[must_use]
+#[inline]
+pub const fn is_zero_width(ch: char) -> bool {
+    matches!(ch, '\u{200b}' | '\u{200c}' | '\u{200d}' | '\u{2060}' | '\u{feff}' | '\u{ad}')
+}
+
+/// Whether a character takes up no width in a terminal, which is never the case without the `unicode` feature.
+#[cfg(not(feature = "unicode"))]
+#[must_use]
+#[inline]
+pub const fn is_zero_width(_ch: char) -> bool {
+    false
+}
+
+/// Replace non-breaking spaces with spaces and remove zero-width spaces.
+#[expect(clippy::single_call_fn, reason = "Kept with the definition of zero-width characters.")]
+#[must_use]
+#[inline]
+pub fn clean_invisible(line: &str) -> String {
+    line.chars()
+        .filter(|&ch| ch != '\u{200b}')
+        .map(|ch| if ch == '\u{a0}' { ' ' } else { ch })
+        .collect()
+}
+
+/// Take the visible characters of a line from `start`, up to `width` of them, keeping every escape sequence.
+#[must_use]
+#[inline]
+pub fn slice(line: &str, start: usize, width: usize) -> String {
+    let mut sliced = String::new();
+    let mut column = 0;
+    let mut chars = line.chars();
+    while let Some(ch) = chars.next() {
+        if ch == ESCAPE {
+            sliced.push(ch);
+            escape_sequence(&mut chars, |escaped| sliced.push(escaped));
+            continue;
+        }
+        if is_zero_width(ch) {
+            if column > start && column <= start + width {
+                sliced.push(ch);
+            }
+            continue;
+        }
+        if column >= start && column < start + width {
+            sliced.push(ch);
+        }
+        column += 1;
+    }
+    sliced
+}
+
+/// Count the visible characters of a line, skipping escape sequences.
+#[must_use]
+#[inline]
+pub fn visible_width(line: &str) -> usize {
+    measurer::DEFAULT.width(line)
+}
+
+/// The width left for content in a column of the given width once a decoration, such as a truncation marker,
+/// has been drawn in it, which is zero when the decoration does not fit.
+#[must_use]
+#[inline]
+pub fn content_width(column_width: usize, decoration: &str) -> usize {
+    column_width.saturating_sub(visible_width(decoration))
+}
+
+/// Remove every escape sequence from a line, leaving only its visible characters.
+#[must_use]
+#[inline]
+pub fn strip_escapes(line: &str) -> String {
+    let mut stripped = String::new();
+    let mut chars = line.chars();
+    while let Some(ch) = chars.next() {
+        if ch == ESCAPE {
+            skip_escape_sequence(&mut chars);
+        } else {
+            stripped.push(ch);
+        }
+    }
+    stripped
+}
+
+/// The SGR sequences still in effect after a line, starting from the SGR sequences in effect before it.
+///
+/// A reset, `ESC [ m` or `ESC [ 0 m`, clears the state, and every other SGR sequence is added to it. Other escape
+/// sequences, such as hyperlinks, are not tracked.
+#[expect(clippy::single_call_fn, reason = "Kept with the other escape sequence parsing.")]
+#[must_use]
+#[inline]
+pub fn sgr_state(state: &str, line: &str) -> String {
+    let mut active = state.to_owned();
+    let mut chars = line.chars();
+    while let Some(ch) = chars.next() {
+        if ch != ESCAPE {
+            continue;
+        }
+        let mut sequence = String::from(ESCAPE);
+        escape_sequence(&mut chars, |escaped| sequence.push(escaped));
+        let Some(params) = sequence.strip_prefix("\x1b[").and_then(|rest| rest.strip_suffix('m')) else {
+            continue;
+        };
+        if params.is_empty() || params == "0" {
+            active.clear();
+        } else {
+            active.push_str(&sequence);
+        }
+    }
+    active
+}
+
+/// Split a line before its visible character at `column`, keeping escape sequences with the text that follows them.
+#[must_use]
+#[inline]
+pub fn split(line: &str, column: usize) -> (&str, &str) {
+    let mut visible = 0;
+    let mut chars = line.char_indices().peekable();
+    while let Some(&(idx, ch)) = chars.peek() {
+        if visible == column && !(is_zero_width(ch) && idx > 0) {
+            return line.split_at(idx);
+        }
+        chars.next();
+        if ch == ESCAPE {
+            let mut rest = chars.by_ref().map(|(_, escaped)| escaped);
+            skip_escape_sequence(&mut rest);
+        } else if !is_zero_width(ch) {
+            visible += 1;
+        }
+    }
+    (line, "")
+}
+
+/// Skip the rest of an escape sequence, up to and including its final byte, without copying it.
+#[inline]
+pub fn skip_escape_sequence<I: Iterator<Item = char>>(chars: &mut I) {
+    escape_sequence(chars, |_| {});
+}
+
+/// Pass the rest of an escape sequence, up to and including its final byte, to `push`.
+fn escape_sequence(chars: &mut impl Iterator<Item = char>, mut push: impl FnMut(char)) {
+    let Some(introducer) = chars.next() else {
+        return;
+    };
+    push(introducer);
+    match introducer {
+        // Control sequences are `ESC [`, parameters, then a final byte in `@`..=`~`
+        '[' => {
+            for ch in chars.by_ref() {
+                push(ch);
+                if ('@'..='~').contains(&ch) {
+                    return;
+                }
+            }
+        }
+        // Operating system commands, such as hyperlinks, are `ESC ]`, a payload, then `ESC \` or BEL
+        ']' => {
+            let mut escaped = false;
+            for ch in chars.by_ref() {
+                push(ch);
+                if ch == '\x07' || (escaped && ch == '\\') {
+                    return;
+                }
+                escaped = ch == ESCAPE;
+            }
+        }
+        _ => {}
+    }
+}
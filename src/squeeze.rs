@@ -0,0 +1,22 @@
+//! Defines how separators give up space before columns are shrunk.
+//!
+//! This module contains the `Squeeze` enum, which controls a pre-pass run by the
+//! `ColumnFormatter` when the output is wider than its total or maximum width:
+//! - `None`: Leave separators as they are (the default).
+//! - `Separators`: Trim the spaces around separators, one from each side of each separator at
+//!   a time, until the output fits or the separators have no spaces left. For example `"  |  "`
+//!   becomes `" | "` and then `"|"`.
+//!
+//! Columns are only dropped or shrunk if the output is still too wide after squeezing.
+//! Decorative separators can be protected with `ColumnFormatter::with_fixed_separator`.
+
+/// Policy for reclaiming separator whitespace when the output is too wide.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Squeeze {
+    /// Never alter separators.
+    #[default]
+    None,
+    /// Trim spaces around separators before shrinking columns.
+    Separators,
+}
@@ -0,0 +1,24 @@
+//! Defines how mismatches between items and their column formats are handled.
+//!
+//! This module contains the `Strictness` enum. An item mismatches its column when it cannot
+//! provide the representation the format asks for, such as a `Display`-only item in a `{:?}`
+//! column:
+//! - `Lenient`: Render the item with the representation it does have (the default).
+//! - `Strict`: Render a `<format mismatch>` marker in place of the item, and report a
+//!   `FormatError::FormatMismatch` from the fallible constructors and writers.
+//!
+//! Strict formatters also report a specifier left open at the end of the format string as
+//! `FormatError::UnterminatedSpec`, where lenient formatters print it as separator text, and a time
+//! pattern with an unsupported directive as `FormatError::InvalidTimePattern`, where lenient formatters
+//! print the directive as it is.
+
+/// Policy for items which cannot provide their column's representation.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Strictness {
+    /// Fall back to the representation the item has.
+    #[default]
+    Lenient,
+    /// Mark the mismatch in the output and report it as an error.
+    Strict,
+}
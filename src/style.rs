@@ -0,0 +1,151 @@
+//! Defines the text styles applied to styled output.
+//!
+//! This module contains the `Style` struct, a combination of an optional foreground colour,
+//! an optional background colour, and the bold, dim, italic, and underline text attributes. A style is written to the
+//! terminal as an SGR (Select Graphic Rendition) escape sequence before the styled text, followed by
+//! a reset afterwards.
+//!
+//! Every styling feature, from overflow indicators and group headers to row callbacks and heatmaps, describes its
+//! styles with `Style` and writes them with `render_sgr` and `render_reset`. Styles combine with `patch`, where the
+//! later style's colours win and attributes accumulate, such as a bold row style over a heatmap background.
+
+use crate::Color;
+
+/// The SGR sequence which resets all styling.
+pub const RESET: &str = "\x1b[0m";
+
+/// A text style for terminal output.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+#[expect(
+    clippy::struct_excessive_bools,
+    reason = "Each attribute is switched on independently, as in SGR."
+)]
+pub struct Style {
+    /// Optional foreground colour.
+    pub fg: Option<Color>,
+    /// Optional background colour.
+    pub bg: Option<Color>,
+    /// Whether the text is bold.
+    pub bold: bool,
+    /// Whether the text is dimmed.
+    pub dim: bool,
+    /// Whether the text is italic.
+    pub italic: bool,
+    /// Whether the text is underlined.
+    pub underline: bool,
+}
+
+impl Style {
+    /// Construct a new, unstyled `Style`.
+    #[must_use]
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            fg: None,
+            bg: None,
+            bold: false,
+            dim: false,
+            italic: false,
+            underline: false,
+        }
+    }
+
+    /// Set the foreground colour.
+    #[must_use]
+    #[inline]
+    pub const fn fg(mut self, color: Color) -> Self {
+        self.fg = Some(color);
+        self
+    }
+
+    /// Set the background colour.
+    #[must_use]
+    #[inline]
+    pub const fn bg(mut self, color: Color) -> Self {
+        self.bg = Some(color);
+        self
+    }
+
+    /// Make the text bold.
+    #[must_use]
+    #[inline]
+    pub const fn bold(mut self) -> Self {
+        self.bold = true;
+        self
+    }
+
+    /// Make the text dim.
+    #[must_use]
+    #[inline]
+    pub const fn dim(mut self) -> Self {
+        self.dim = true;
+        self
+    }
+
+    /// Make the text italic.
+    #[must_use]
+    #[inline]
+    pub const fn italic(mut self) -> Self {
+        self.italic = true;
+        self
+    }
+
+    /// Underline the text.
+    #[must_use]
+    #[inline]
+    pub const fn underline(mut self) -> Self {
+        self.underline = true;
+        self
+    }
+
+    /// Combine this style with a later one, whose colours override these and whose attributes are added to these.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use colprint::{Color, Style};
+    ///
+    /// let base = Style::new().fg(Color::Red).bg(Color::Black);
+    /// let patched = base.patch(Style::new().fg(Color::Green).bold());
+    /// assert_eq!(patched, Style::new().fg(Color::Green).bg(Color::Black).bold());
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn patch(self, other: Self) -> Self {
+        Self {
+            fg: if other.fg.is_some() { other.fg } else { self.fg },
+            bg: if other.bg.is_some() { other.bg } else { self.bg },
+            bold: self.bold || other.bold,
+            dim: self.dim || other.dim,
+            italic: self.italic || other.italic,
+            underline: self.underline || other.underline,
+        }
+    }
+
+    /// The SGR sequence which switches this style on, or an empty string for an unstyled `Style`.
+    #[must_use]
+    #[inline]
+    pub fn render_sgr(&self) -> String {
+        let codes: Vec<String> = [(self.bold, "1"), (self.dim, "2"), (self.italic, "3"), (self.underline, "4")]
+            .into_iter()
+            .filter(|&(on, _)| on)
+            .map(|(_, code)| code.to_owned())
+            .chain(self.fg.map(|color| color.sgr(false)))
+            .chain(self.bg.map(|color| color.sgr(true)))
+            .collect();
+
+        if codes.is_empty() {
+            String::new()
+        } else {
+            format!("\x1b[{}m", codes.join(";"))
+        }
+    }
+
+    /// The SGR sequence which switches this style off, or an empty string for an unstyled `Style`.
+    #[must_use]
+    #[inline]
+    pub fn render_reset(&self) -> &'static str {
+        if *self == Self::new() { "" } else { RESET }
+    }
+}
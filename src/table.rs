@@ -0,0 +1,1561 @@
+//! Implementation of tables of rows sharing a single column layout.
+//!
+//! This module provides the `Table` struct, which applies one format string to many rows
+//! of items. Every row is formatted as soon as it is pushed, and column widths are
+//! calculated across all rendered rows so that the columns line up from top to bottom.
+//!
+//! Rows can be reordered after they are pushed with `Table::sort_rows_by`, which compares
+//! the formatted cells with a caller-supplied function.
+//!
+//! Tables can carry optional header and footer rows, which are always rendered and always
+//! count towards the column widths. Each column can align its header, data, and footer
+//! differently, such as a centred header over right-aligned numbers. Rows can be filtered at render time with
+//! `Table::render_filtered` without rebuilding the table.
+//!
+//! Rows can be grouped by the text of a column with `Table::group_by`, rendering a header line before each group
+//! and optionally a subtotal row after it.
+//!
+//! Whole rows can be styled from their contents with `Table::style_rows`, for example to
+//! colour failed rows red, and numeric columns can be shaded like a heatmap with
+//! `Table::heatmap_column`. Styles are only emitted in the plain style, and only when the
+//! table's `ColorChoice` allows it.
+//!
+//! The widths, alignments, headers, separators, truncation, and column order can be exported as a `LayoutSpec`
+//! with `Table::layout_spec` and applied with `Table::with_layout`, for layouts kept in configuration files.
+//!
+//! With the `serde_json` feature, the formatted cells can also be exported as JSON for scripts,
+//! either directly with `Table::to_json` or, for commands offering both, with `Table::to_porcelain`,
+//! which only renders aligned columns when writing to a terminal.
+//!
+//! Besides plain aligned columns, tables can be rendered as Markdown or Org-mode tables, or as unpadded lines of
+//! delimited fields for scripts, by selecting an `OutputStyle`.
+
+#[cfg(feature = "serde_json")]
+use std::io::{IsTerminal as _, stdout};
+use std::{
+    borrow::Cow,
+    cmp::Ordering,
+    fmt::{Display, Formatter, Result as FmtResult, Write},
+    io,
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+
+#[cfg(feature = "serde_json")]
+use serde_json::Value;
+
+use crate::{
+    Accessibility, Alignment, Cell, ColorChoice, ColorScale, ColumnFormatter, Columns, FilterWidths, FitError, FormatError,
+    FormattableItem, LayoutSpec, LineEnding, OutputStyle, RenderColumn, Renderer, Separator, SeparatorFill, Strictness, Style,
+    column_format::ColumnFormat,
+    column_formatter::{blank_line, fit_line_aligned},
+    delimited::Delimited,
+    env_config::EnvConfig,
+    hyperlink::link_path,
+    panic_guard,
+    row_role::RowRole,
+    slice::{ESCAPE, split, visible_width},
+    style::RESET,
+    terminal,
+    width::{self, Width},
+};
+
+/// A table of rows formatted with a shared column layout.
+#[expect(
+    clippy::struct_excessive_bools,
+    reason = "Each rendering option is switched on independently."
+)]
+pub struct Table {
+    /// The kind of format for each column.
+    formats: Vec<ColumnFormat>,
+    /// Optional header cells, rendered above the rows.
+    headers: Option<Vec<String>>,
+    /// Optional footer cells, rendered below the rows.
+    footers: Option<Vec<String>>,
+    /// The formatted cells of each row.
+    rows: Vec<Vec<String>>,
+    /// Which rows determine automatic column widths when filtering.
+    filter_widths: FilterWidths,
+    /// Whether filtered renders report how many rows are shown.
+    show_count: bool,
+    /// How the table is rendered.
+    style: OutputStyle,
+    /// Optional text joining the lines of multi-line cells in delimited styles.
+    line_join: Option<String>,
+    /// Whether columns with only blank cells are left out when rendering.
+    collapse_empty: bool,
+    /// Whether lines added below a cell to match a taller neighbour are drawn with the column's fill.
+    fill_blank_lines: bool,
+    /// Optional number of rows after which the header is rendered again.
+    repeat_header_every: Option<usize>,
+    /// Optional number of output lines after which the lines of every row are dropped.
+    max_row_height: Option<usize>,
+    /// Optional callback choosing the style of each row from its index and formatted cells.
+    row_style: Option<Box<RowStyle>>,
+    /// When styles are emitted.
+    color: ColorChoice,
+    /// How omitted content and coloured signals are announced.
+    accessibility: Accessibility,
+    /// Columns whose existing file paths are hyperlinked, with the directory relative paths are joined to.
+    linked_paths: Vec<(usize, Option<PathBuf>)>,
+    /// Columns whose numeric cells are shaded, with the scale of their backgrounds.
+    heatmaps: Vec<(usize, ColorScale)>,
+    /// Whether cells whose formatting panics are rendered as error cells instead of unwinding.
+    catch_panics: bool,
+    /// Policy for cells which cannot be written in the output style, such as cells containing the delimiter.
+    strictness: Strictness,
+    /// The terminator of each line written by `write_to`.
+    line_ending: LineEnding,
+    /// Optional fraction of numeric non-empty cells above which columns are right-aligned automatically.
+    auto_align: Option<f64>,
+    /// Optional indices of the columns to render, in order, instead of every column.
+    selected_columns: Option<Vec<usize>>,
+    /// Optional column whose text groups the rows under header lines.
+    group_column: Option<usize>,
+    /// Style of the group header lines.
+    group_style: Style,
+    /// Spaces indenting the first rendered column of grouped rows.
+    group_indent: usize,
+    /// Optional callback computing the cells of the subtotal row after each group.
+    group_subtotal: Option<Box<GroupSubtotal>>,
+    /// Renderers selected by `OutputStyle::Custom`, with the names they were registered under.
+    renderers: Vec<(&'static str, Box<dyn Renderer>)>,
+    /// The number of pushed cells whose formatting panicked.
+    panicked_cells: usize,
+}
+
+/// The default fraction of numeric non-empty cells above which `Table::auto_align` right-aligns a column.
+const AUTO_ALIGN_FRACTION: f64 = 0.9;
+
+/// Callback choosing the style of a row from its index and formatted cells.
+type RowStyle = dyn Fn(usize, &[String]) -> Option<Style>;
+
+/// Callback computing the cells of a group's subtotal row from the group's label and formatted rows.
+type GroupSubtotal = dyn Fn(&str, &[&[String]]) -> Vec<String>;
+
+impl Table {
+    /// Construct a new, empty `Table` using the given format string for every row.
+    #[must_use]
+    #[inline]
+    pub fn new(format_str: &str) -> Self {
+        Self {
+            formats: ColumnFormatter::parse_format_string(format_str),
+            headers: None,
+            footers: None,
+            rows: Vec::new(),
+            filter_widths: FilterWidths::Filtered,
+            show_count: false,
+            style: OutputStyle::Plain,
+            line_join: None,
+            collapse_empty: false,
+            fill_blank_lines: false,
+            repeat_header_every: None,
+            max_row_height: None,
+            row_style: None,
+            color: EnvConfig::get().color.unwrap_or_default(),
+            accessibility: EnvConfig::get().accessibility.unwrap_or_default(),
+            linked_paths: Vec::new(),
+            heatmaps: Vec::new(),
+            catch_panics: false,
+            strictness: Strictness::Lenient,
+            line_ending: LineEnding::Lf,
+            panicked_cells: 0,
+            auto_align: None,
+            selected_columns: None,
+            group_column: None,
+            group_style: Style::new(),
+            group_indent: 0,
+            group_subtotal: None,
+            renderers: Vec::new(),
+        }
+    }
+
+    /// Construct a `Table` from column-major data, such as a tuple of `Vec`s, one per column.
+    ///
+    /// The values of each column are rendered in its format and zipped into rows. Columns shorter than the longest
+    /// are padded with their default cell text, which is empty, and columns beyond the format string are dropped.
+    /// Like pushed rows, the cells are rendered immediately, so builders which change how cells are rendered must be
+    /// applied to a table built with `new`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use colprint::Table;
+    ///
+    /// let names = vec!["Alice", "Bob"];
+    /// let ages = vec![30, 4];
+    /// let table = Table::from_columns("{} | {}", (names, ages));
+    /// assert_eq!(table.to_string(), "Alice | 30\nBob   | 4 \n");
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn from_columns<C: Columns>(format_str: &str, columns: C) -> Self {
+        let mut table = Self::new(format_str);
+        let cells = table.column_cells(columns);
+        table.push_columns(&cells);
+        table
+    }
+
+    /// Construct a `Table` from column-major data, rejecting columns of different lengths.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FormatError::TooManyItems` if there are more columns of data than in the format string, or
+    /// `FormatError::ColumnLengthMismatch` for the first column whose length differs from the first column's.
+    #[inline]
+    pub fn try_from_columns<C: Columns>(format_str: &str, columns: C) -> Result<Self, FormatError> {
+        let mut table = Self::new(format_str);
+        let cells = table.column_cells(columns);
+        if cells.len() > table.formats.len() {
+            return Err(FormatError::TooManyItems {
+                columns: table.formats.len(),
+                items: cells.len(),
+            });
+        }
+        let expected = cells.first().map_or(0, Vec::len);
+        if let Some((column, found)) = cells.iter().map(Vec::len).enumerate().find(|&(_, len)| len != expected) {
+            return Err(FormatError::ColumnLengthMismatch { column, expected, found });
+        }
+        table.push_columns(&cells);
+        Ok(table)
+    }
+
+    /// Render the values of each column in the column's format.
+    fn column_cells<C: Columns>(&self, columns: C) -> Vec<Vec<String>> {
+        columns.cells(&mut |idx, item| self.formats.get(idx).map_or_else(String::new, |fmt| fmt.render(item)))
+    }
+
+    /// Zip rendered columns into rows, filling the cells missing from shorter columns with their default cell text.
+    fn push_columns(&mut self, cells: &[Vec<String>]) {
+        let num_rows = cells.iter().map(Vec::len).max().unwrap_or(0);
+        for row_idx in 0..num_rows {
+            let row = self
+                .formats
+                .iter()
+                .enumerate()
+                .map(|(idx, fmt)| {
+                    cells
+                        .get(idx)
+                        .and_then(|column| column.get(row_idx))
+                        .map_or_else(|| fmt.default_cell.clone(), Clone::clone)
+                })
+                .collect();
+            self.rows.push(row);
+        }
+    }
+
+    /// Write line breaks in the cells of a column as the escapes `\n` and `\r`, so each row stays on one line.
+    ///
+    /// Only rows pushed after this is set are escaped. This is off by default.
+    #[must_use]
+    #[inline]
+    pub fn escape_newlines(mut self, column: usize, escape: bool) -> Self {
+        if let Some(format) = self.formats.get_mut(column) {
+            format.escape_newlines = escape;
+        }
+        self
+    }
+
+    /// Pad the cells of a column with the given character instead of spaces, for example `.` for dotted leaders.
+    #[must_use]
+    #[inline]
+    pub fn with_fill(mut self, column: usize, fill: char) -> Self {
+        if let Some(format) = self.formats.get_mut(column) {
+            format.fill = fill;
+        }
+        self
+    }
+
+    /// Replace over-long lines in a column with the text computed by the callback, instead of cutting them.
+    ///
+    /// The callback receives the whole line and the width of the column. Text it returns which is still too wide
+    /// is cut at the end.
+    #[must_use]
+    #[inline]
+    pub fn with_truncator<F>(mut self, column: usize, truncator: F) -> Self
+    where
+        F: Fn(&str, usize) -> String + Send + Sync + 'static,
+    {
+        if let Some(format) = self.formats.get_mut(column) {
+            format.truncator = Some(Arc::new(truncator));
+        }
+        self
+    }
+
+    /// Set the text of the cells in a column for rows pushed with too few items, which is empty by default.
+    #[must_use]
+    #[inline]
+    pub fn with_default_cell<S: Into<String>>(mut self, column: usize, text: S) -> Self {
+        if let Some(format) = self.formats.get_mut(column) {
+            format.default_cell = text.into();
+        }
+        self
+    }
+
+    /// Set whether a cell whose `Display` or `Debug` implementation panics while its row is pushed is stored as
+    /// `<format panicked: message>` instead of unwinding out of `push_row`.
+    ///
+    /// Off by default. The items are treated as unwind safe, as for `ColumnFormatter::catch_panics`. The number of
+    /// failed cells is reported by `panicked_cells`.
+    #[must_use]
+    #[inline]
+    pub const fn catch_panics(mut self, catch_panics: bool) -> Self {
+        self.catch_panics = catch_panics;
+        self
+    }
+
+    /// The number of cells whose formatting panicked while their rows were pushed, when catching panics.
+    #[must_use]
+    #[inline]
+    pub const fn panicked_cells(&self) -> usize {
+        self.panicked_cells
+    }
+
+    /// Set the separator after a column to one whose piece depends on the position of the line within each row.
+    ///
+    /// With `Separator::PerPosition`, the separator from the format string is replaced by the `middle` piece, and
+    /// the `first` and `last` pieces are written on the first and last lines of each multi-line row.
+    #[must_use]
+    #[inline]
+    pub fn with_separator(mut self, column: usize, separator: Separator) -> Self {
+        if let Some(format) = self.formats.get_mut(column) {
+            format.set_separator(separator);
+        }
+        self
+    }
+
+    /// Render only the given columns, in the given order, leaving the rows themselves untouched.
+    ///
+    /// Columns may be repeated to show them more than once. Each column keeps the separator that follows it in the
+    /// format string, which can be replaced with `with_separator`. A column without one, such as the last column,
+    /// takes the separator after the column at its new position instead. Widths are measured over the selected
+    /// columns alone.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FormatError::ColumnOutOfRange` if an index is beyond the table's columns.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use colprint::Table;
+    ///
+    /// let table = Table::from_columns("{} | {} | {}", (vec!["Alice"], vec![30], vec!["Paris"]));
+    /// let table = table.select_columns(&[2, 0]).unwrap();
+    /// assert_eq!(table.to_string(), "Paris | Alice\n");
+    /// ```
+    #[inline]
+    pub fn select_columns(mut self, columns: &[usize]) -> Result<Self, FormatError> {
+        if let Some(&column) = columns.iter().find(|&&column| column >= self.formats.len()) {
+            return Err(FormatError::ColumnOutOfRange {
+                column,
+                columns: self.formats.len(),
+            });
+        }
+        self.selected_columns = Some(columns.to_vec());
+        Ok(self)
+    }
+
+    /// Render only the columns with the given headers, in the given order, as with `select_columns`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FormatError::UnknownHeader` if a name matches none of the headers, or if the table has no headers.
+    #[inline]
+    pub fn select_headers<S: AsRef<str>>(self, names: &[S]) -> Result<Self, FormatError> {
+        let headers = self.headers.as_deref().unwrap_or_default();
+        let columns = names
+            .iter()
+            .enumerate()
+            .map(|(position, name)| {
+                headers
+                    .iter()
+                    .position(|header| header == name.as_ref())
+                    .ok_or(FormatError::UnknownHeader { position })
+            })
+            .collect::<Result<Vec<usize>, FormatError>>()?;
+        self.select_columns(&columns)
+    }
+
+    /// Apply a saved layout, such as one loaded from a configuration file with the `serde` feature.
+    ///
+    /// Columns are matched to the format string by position, and settings left out of the layout keep their
+    /// current values. The layout is checked in full before any of it is applied.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FormatError::InvalidLayout` naming the first invalid setting, such as an unknown alignment, a
+    /// malformed width, or a column beyond the format string.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use colprint::{ColumnSpec, LayoutSpec, Table};
+    ///
+    /// let layout = LayoutSpec::new()
+    ///     .column(ColumnSpec::new().header("Name"))
+    ///     .column(ColumnSpec::new().header("Age").align("right").width("5"));
+    /// let table = Table::from_columns("{} | {}", (vec!["Alice"], vec![30])).with_layout(&layout).unwrap();
+    /// assert_eq!(table.to_string(), "Name  |   Age\nAlice |    30\n");
+    /// ```
+    #[inline]
+    pub fn with_layout(mut self, layout: &LayoutSpec) -> Result<Self, FormatError> {
+        layout.apply(&mut self.formats, &mut self.headers, &mut self.selected_columns)?;
+        Ok(self)
+    }
+
+    /// The effective layout of the table, with every column's settings spelled out, for users to save and tweak.
+    #[must_use]
+    #[inline]
+    pub fn layout_spec(&self) -> LayoutSpec {
+        LayoutSpec::from_parts(&self.formats, self.headers.as_deref(), self.selected_columns.as_deref())
+    }
+
+    /// Set which lines of multi-line cells show the separator after a column.
+    #[must_use]
+    #[inline]
+    pub fn with_separator_fill(mut self, column: usize, separator_fill: SeparatorFill) -> Self {
+        if let Some(format) = self.formats.get_mut(column) {
+            format.separator_fill = separator_fill;
+        }
+        self
+    }
+
+    /// Align the header of a column, which otherwise follows the column's alignment.
+    #[must_use]
+    #[inline]
+    pub fn align_header(mut self, column: usize, alignment: Alignment) -> Self {
+        if let Some(format) = self.formats.get_mut(column) {
+            format.header_alignment = Some(alignment);
+        }
+        self
+    }
+
+    /// Align the rows of a column, which is the column's own alignment followed by its header and footer
+    /// unless they are aligned separately.
+    ///
+    /// This also sets the alignment written to Markdown and Org tables.
+    #[must_use]
+    #[inline]
+    pub fn align_data(mut self, column: usize, alignment: Alignment) -> Self {
+        if let Some(format) = self.formats.get_mut(column) {
+            format.alignment = alignment;
+            format.explicit_alignment = true;
+        }
+        self
+    }
+
+    /// Set whether columns of numbers are right-aligned automatically.
+    ///
+    /// When on, each column is right-aligned at render time if at least 90% of its non-empty data cells, across all
+    /// rows, are numbers: integers, floats, percentages, or values with `,` or `_` digit group separators. Headers
+    /// and footers are not inspected, but follow the column unless aligned separately. Alignment given by a suffix
+    /// in the format string or by `align_data` always wins.
+    #[must_use]
+    #[inline]
+    pub fn auto_align(mut self, auto_align: bool) -> Self {
+        self.auto_align = auto_align.then(|| self.auto_align.unwrap_or(AUTO_ALIGN_FRACTION));
+        self
+    }
+
+    /// Turn on `auto_align` with the fraction of non-empty data cells, from `0.0` to `1.0`, which must be numbers for a
+    /// column to be right-aligned.
+    #[must_use]
+    #[inline]
+    pub const fn auto_align_threshold(mut self, fraction: f64) -> Self {
+        self.auto_align = Some(fraction);
+        self
+    }
+
+    /// Align the footer of a column, which otherwise follows the column's alignment.
+    #[must_use]
+    #[inline]
+    pub fn align_footer(mut self, column: usize, alignment: Alignment) -> Self {
+        if let Some(format) = self.formats.get_mut(column) {
+            format.footer_alignment = Some(alignment);
+        }
+        self
+    }
+
+    /// Set the header cells, rendered above the rows.
+    #[must_use]
+    #[inline]
+    pub fn with_headers<I, S>(mut self, headers: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.headers = Some(headers.into_iter().map(Into::into).collect());
+        self
+    }
+
+    /// Set the footer cells, rendered below the rows.
+    #[must_use]
+    #[inline]
+    pub fn with_footers<I, S>(mut self, footers: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.footers = Some(footers.into_iter().map(Into::into).collect());
+        self
+    }
+
+    /// Set which rows determine automatic column widths when filtering.
+    #[must_use]
+    #[inline]
+    pub const fn with_filter_widths(mut self, filter_widths: FilterWidths) -> Self {
+        self.filter_widths = filter_widths;
+        self
+    }
+
+    /// Append a line like `(showing 12 of 340 rows)` to filtered renders.
+    #[must_use]
+    #[inline]
+    pub const fn with_row_count(mut self, show_count: bool) -> Self {
+        self.show_count = show_count;
+        self
+    }
+
+    /// Set how the table is rendered.
+    #[must_use]
+    #[inline]
+    pub const fn with_style(mut self, style: OutputStyle) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Register a renderer under a name, to write the table when its style is `OutputStyle::Custom` with that name.
+    ///
+    /// A renderer registered later under the same name replaces the earlier one.
+    #[must_use]
+    #[inline]
+    pub fn with_renderer<R: Renderer + 'static>(mut self, name: &'static str, renderer: R) -> Self {
+        self.renderers.push((name, Box::new(renderer)));
+        self
+    }
+
+    /// Set the text joining the lines of multi-line cells in the Markdown, Org, and Delimited styles.
+    ///
+    /// Defaults to `<br>` for Markdown, a single space for Org, and the two characters `\n` for Delimited.
+    #[must_use]
+    #[inline]
+    pub fn with_line_join<S: Into<String>>(mut self, line_join: S) -> Self {
+        self.line_join = Some(line_join.into());
+        self
+    }
+
+    /// Set whether cells which cannot be written in the output style are rejected by `write_to`.
+    ///
+    /// In the `Delimited` style, a lenient table replaces the delimiter inside cells with a space, or with `_` if the
+    /// delimiter contains whitespace, while a strict table refuses to write them. Rendering with `Display` is always
+    /// lenient.
+    #[must_use]
+    #[inline]
+    pub const fn with_strictness(mut self, strictness: Strictness) -> Self {
+        self.strictness = strictness;
+        self
+    }
+
+    /// Set how each line written by `write_to` and `to_text` ends, such as `LineEnding::CrLf` for reports opened
+    /// on Windows.
+    ///
+    /// Headers, rules, group headers, and summaries end the same way as rows. `Display` always ends lines with
+    /// `\n`, so `format!` and `to_string` are unaffected.
+    #[must_use]
+    #[inline]
+    pub const fn with_line_ending(mut self, line_ending: LineEnding) -> Self {
+        self.line_ending = line_ending;
+        self
+    }
+
+    /// Leave out columns whose every rendered cell is empty or whitespace, along with their header and separator.
+    ///
+    /// This is off by default.
+    #[must_use]
+    #[inline]
+    pub const fn collapse_empty_columns(mut self, collapse_empty: bool) -> Self {
+        self.collapse_empty = collapse_empty;
+        self
+    }
+
+    /// Draw the lines added below a cell to match a taller neighbour with the column's fill, instead of spaces.
+    ///
+    /// This is off by default, so the fill only appears on lines with content.
+    #[must_use]
+    #[inline]
+    pub const fn fill_blank_lines(mut self, fill: bool) -> Self {
+        self.fill_blank_lines = fill;
+        self
+    }
+
+    /// Render the header again after every `rows` rows, so long tables keep their context.
+    ///
+    /// The header is never repeated after the last row, and is not counted as a row. Zero turns repetition off.
+    #[must_use]
+    #[inline]
+    pub const fn repeat_header_every(mut self, rows: usize) -> Self {
+        self.repeat_header_every = if rows == 0 { None } else { Some(rows) };
+        self
+    }
+
+    /// Limit every row to the given number of output lines, so one tall row cannot dominate the table.
+    ///
+    /// Cells with more lines are clipped, and the last line kept ends with the column's truncation marker, or is
+    /// replaced by an announcement of the dropped lines with `Accessibility::Verbose`. Dropped lines do not count
+    /// towards the column widths, and rows which fit are left as they are. A height of zero is treated as one. Rows
+    /// are unlimited by default.
+    #[must_use]
+    #[inline]
+    pub const fn max_row_height(mut self, height: usize) -> Self {
+        self.max_row_height = Some(height);
+        self
+    }
+
+    /// Style whole rows based on their contents.
+    ///
+    /// The callback receives the index of each row in the table and its formatted cells.
+    /// The returned style spans every line of the row, including padding and separators.
+    /// Headers and footers are never styled.
+    #[must_use]
+    #[inline]
+    pub fn style_rows<F>(mut self, row_style: F) -> Self
+    where
+        F: Fn(usize, &[String]) -> Option<Style> + 'static,
+    {
+        self.row_style = Some(Box::new(row_style));
+        self
+    }
+
+    /// Group the rows by the text of a column, rendering a line with the group's label before each group.
+    ///
+    /// Groups are ordered by the first appearance of their label, and rows keep their order within each group.
+    /// Group header lines span the table and are cut at its width, so they never widen the columns. Like row
+    /// styles, groups are only rendered in the plain style. Combine with `select_columns` to leave the grouping
+    /// column out of the rows.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use colprint::Table;
+    ///
+    /// let table = Table::from_columns("{} {}", (vec!["api", "db", "api"], vec!["up", "down", "slow"]))
+    ///     .group_by(0)
+    ///     .select_columns(&[1])
+    ///     .unwrap();
+    /// assert_eq!(table.to_string(), "api\nup  \nslow\ndb\ndown\n");
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn group_by(mut self, column: usize) -> Self {
+        self.group_column = Some(column);
+        self
+    }
+
+    /// Set the style of group header lines, such as `Style::new().bold()`.
+    ///
+    /// The lines are unstyled by default, and the style is only emitted when the table's `ColorChoice` allows it.
+    #[must_use]
+    #[inline]
+    pub const fn group_style(mut self, style: Style) -> Self {
+        self.group_style = style;
+        self
+    }
+
+    /// Indent the first rendered column of grouped rows by the given number of spaces, under their group header.
+    #[must_use]
+    #[inline]
+    pub const fn group_indent(mut self, indent: usize) -> Self {
+        self.group_indent = indent;
+        self
+    }
+
+    /// Render a subtotal row after each group, with the cells computed from the group's label and formatted rows.
+    ///
+    /// Subtotal rows are aligned like footers and count towards the column widths.
+    #[must_use]
+    #[inline]
+    pub fn group_subtotals<F>(mut self, subtotal: F) -> Self
+    where
+        F: Fn(&str, &[&[String]]) -> Vec<String> + 'static,
+    {
+        self.group_subtotal = Some(Box::new(subtotal));
+        self
+    }
+
+    /// Seed the color choice, accessibility, truncation marker, and choice to only write ASCII from the given
+    /// defaults instead of the environment, such as `EnvConfig::empty()` for output which never depends on it.
+    ///
+    /// This replaces all four settings, so call it before the builders setting them.
+    #[must_use]
+    #[inline]
+    pub fn with_env(mut self, env: &EnvConfig) -> Self {
+        self.color = env.color.unwrap_or_default();
+        self.accessibility = env.accessibility.unwrap_or_default();
+        for format in &mut self.formats {
+            format.truncate_marker.clone_from(&env.truncate_marker);
+            format.ascii = env.ascii;
+        }
+        self
+    }
+
+    /// Set when styles are emitted.
+    #[must_use]
+    #[inline]
+    pub const fn with_color(mut self, color: ColorChoice) -> Self {
+        self.color = color;
+        self
+    }
+
+    /// Set how omitted content and coloured signals are announced.
+    ///
+    /// With `Accessibility::Verbose`, truncated cells end with `[truncated]`, columns left out as empty are
+    /// announced below the table, styled rows start with `[highlighted]`, and heatmap cells end with their
+    /// position in the column's range, such as `[75%]`, whether or not styles are emitted.
+    #[must_use]
+    #[inline]
+    pub const fn with_accessibility(mut self, accessibility: Accessibility) -> Self {
+        self.accessibility = accessibility;
+        self
+    }
+
+    /// Hyperlink the lines of a column which are paths to existing files, so they can be clicked in terminals.
+    ///
+    /// Relative paths are joined to the base directory, if given, or else to the working directory. Links are
+    /// written as OSC 8 escape sequences, which take no space, in the plain style only, and only when the
+    /// table's `ColorChoice` allows styles.
+    #[must_use]
+    #[inline]
+    pub fn linkify_paths(mut self, column: usize, base: Option<&Path>) -> Self {
+        self.linked_paths.push((column, base.map(Path::to_path_buf)));
+        self
+    }
+
+    /// Shade the background of each numeric cell of a column by where its value lies between the column's
+    /// lowest and highest values.
+    ///
+    /// The range is taken over the rows being rendered. Digit group separators (`,` and `_`) are ignored when
+    /// reading values, cells which are not numbers are left unstyled, and `NaN` and infinities are given the
+    /// scale's non-finite style. Headers and footers are never shaded. Like row styles, backgrounds are only
+    /// written in the plain style, and only when the table's `ColorChoice` allows styles.
+    #[must_use]
+    #[inline]
+    pub fn heatmap_column(mut self, column: usize, scale: ColorScale) -> Self {
+        self.heatmaps.push((column, scale));
+        self
+    }
+
+    /// Check that no line of the rendered table is wider than the maximum width, as a guard against overflowing
+    /// terminals.
+    ///
+    /// Lines are measured by their visible characters, ignoring ANSI escape sequences. The `assert_fits!` macro
+    /// wraps this check for tests.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `FitError` describing the first line wider than the maximum width.
+    #[inline]
+    pub fn check_fits(&self, max_width: usize) -> Result<(), FitError> {
+        FitError::check(self.to_string().lines(), max_width)
+    }
+
+    /// Format a row of items and append it to the table.
+    ///
+    /// Rows may be shorter than the table, in which case the missing trailing cells take their column's default
+    /// cell text. Items beyond the number of columns are dropped, with a warning under the `log` feature.
+    #[inline]
+    pub fn push_row(&mut self, items: &[FormattableItem<'_>]) {
+        #[cfg(feature = "log")]
+        if items.len() > self.formats.len() {
+            log::warn!(
+                "colprint: dropping {} item(s) beyond the {} column(s) of the table",
+                items.len() - self.formats.len(),
+                self.formats.len()
+            );
+        }
+
+        let mut panicked = 0;
+        let row = self
+            .formats
+            .iter()
+            .enumerate()
+            .map(|(idx, fmt)| {
+                items.get(idx).map_or_else(
+                    || fmt.default_cell.clone(),
+                    |item| {
+                        if !self.catch_panics {
+                            return fmt.render(item);
+                        }
+                        panic_guard::guarded(|| fmt.render(item)).unwrap_or_else(|error| {
+                            panicked += 1;
+                            error
+                        })
+                    },
+                )
+            })
+            .collect();
+        self.panicked_cells += panicked;
+        self.rows.push(row);
+    }
+
+    /// Format a row of items and append it to the table, rejecting rows longer than the table.
+    ///
+    /// Short rows are filled with their columns' default cell text, as with `push_row`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FormatError::TooManyItems`, leaving the table unchanged, if there are more items than columns.
+    #[inline]
+    pub fn try_push_row(&mut self, items: &[FormattableItem<'_>]) -> Result<(), FormatError> {
+        if items.len() > self.formats.len() {
+            return Err(FormatError::TooManyItems {
+                columns: self.formats.len(),
+                items: items.len(),
+            });
+        }
+        self.push_row(items);
+        Ok(())
+    }
+
+    /// Write the table to the writer, ending each line with the line ending.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FormatError::Io` if the output cannot be written. A strict table in the `Delimited` style writes
+    /// nothing, and returns `FormatError::DelimiterInCell`, if any cell contains the delimiter.
+    #[inline]
+    pub fn write_to<W: io::Write>(&self, writer: &mut W) -> Result<(), FormatError> {
+        if self.strictness == Strictness::Strict
+            && let Some(column) = self.delimiter_in_cell()
+        {
+            return Err(FormatError::DelimiterInCell { column });
+        }
+        writer
+            .write_all(self.to_text().as_bytes())
+            .map_err(|err| FormatError::Io { kind: err.kind() })
+    }
+
+    /// The table as `write_to` writes it, with each line ending in the line ending.
+    ///
+    /// `to_string` and `Display` always end lines with `\n`.
+    #[must_use]
+    #[inline]
+    pub fn to_text(&self) -> String {
+        self.line_ending.apply(&self.to_string()).into_owned()
+    }
+
+    /// Sort the rows by comparing their formatted cells.
+    ///
+    /// The sort is stable, so rows which compare equal keep their order, and comparing one column and then
+    /// another sorts by both. The rows are sorted once, in place; rendering never re-sorts them, so rows pushed
+    /// later are appended unsorted. Headers and footers are never moved.
+    #[inline]
+    pub fn sort_rows_by<F>(&mut self, mut compare: F)
+    where
+        F: FnMut(&[String], &[String]) -> Ordering,
+    {
+        self.rows.sort_by(|a, b| compare(a, b));
+    }
+
+    /// The number of rows in the table, excluding headers and footers.
+    #[must_use]
+    #[inline]
+    pub const fn len(&self) -> usize {
+        self.rows.len()
+    }
+
+    /// Check if the table has no rows.
+    #[must_use]
+    #[inline]
+    pub const fn is_empty(&self) -> bool {
+        self.rows.is_empty()
+    }
+
+    /// Export the headers and rows as a JSON object like `{"headers": [...], "rows": [[...], ...]}`.
+    ///
+    /// Cells hold their formatted text, before padding or truncation, with multi-line cells kept as strings
+    /// containing `\n`. Without headers, `headers` is an empty array. Footers are left out.
+    #[cfg(feature = "serde_json")]
+    #[must_use]
+    #[inline]
+    pub fn to_json(&self) -> String {
+        let strings = |cells: &[String]| Value::Array(cells.iter().cloned().map(Value::String).collect());
+        let headers = strings(self.headers.as_deref().unwrap_or_default());
+        let rows = Value::Array(self.rows.iter().map(|row| strings(row)).collect());
+        Value::Object(
+            [("headers".to_owned(), headers), ("rows".to_owned(), rows)]
+                .into_iter()
+                .collect(),
+        )
+        .to_string()
+    }
+
+    /// Render the table for a person when standard output is a terminal, and as JSON for scripts otherwise.
+    ///
+    /// This lets a command offer both forms, like a `--porcelain` flag, without checking the terminal itself.
+    #[cfg(feature = "serde_json")]
+    #[must_use]
+    #[inline]
+    pub fn to_porcelain(&self) -> String {
+        if stdout().is_terminal() {
+            self.to_string()
+        } else {
+            self.to_json()
+        }
+    }
+
+    /// Render only the rows whose formatted cells match the predicate.
+    ///
+    /// Headers and footers are always rendered.
+    #[must_use]
+    #[inline]
+    pub fn render_filtered<F>(&self, predicate: F) -> String
+    where
+        F: Fn(&[String]) -> bool,
+    {
+        let (indices, rows): (Vec<usize>, Vec<&[String]>) = self
+            .rows
+            .iter()
+            .map(Vec::as_slice)
+            .enumerate()
+            .filter(|&(_, row)| predicate(row))
+            .unzip();
+        let styles = self.row_styles(&indices, &rows);
+
+        let mut output = String::new();
+        self.write_rows(&mut output, &rows, &styles, self.show_count)
+            .map_or_else(|_| String::new(), |()| output)
+    }
+
+    /// Write the headers, the given rows, the footers, and optionally a count of the rows shown.
+    ///
+    /// With `Accessibility::Verbose`, coloured signals are spelled out in the cells before they are measured.
+    fn write_rows(&self, writer: &mut impl Write, rows: &[&[String]], styles: &[Option<Style>], show_count: bool) -> FmtResult {
+        let columns = self.visible_columns(rows);
+        let cell_styles = self.cell_styles(rows, &columns);
+        let verbose = self.accessibility == Accessibility::Verbose;
+        let annotated_rows = if verbose {
+            self.annotated_rows(rows, styles)
+        } else {
+            Vec::new()
+        };
+        let annotated_row_slices: Vec<&[String]> = annotated_rows.iter().map(Vec::as_slice).collect();
+        let (shown_rows, shown_styles) = if verbose {
+            (
+                annotated_row_slices.as_slice(),
+                if self.color.enabled() { styles } else { &[] },
+            )
+        } else {
+            (rows, styles)
+        };
+
+        let renderer = match self.style {
+            OutputStyle::Custom(name) => self
+                .renderers
+                .iter()
+                .rev()
+                .find(|registered| registered.0 == name)
+                .map(|registered| registered.1.as_ref()),
+            OutputStyle::Plain | OutputStyle::Markdown | OutputStyle::Org { .. } | OutputStyle::Delimited { .. } => None,
+        };
+        if let Some(custom) = renderer {
+            self.write_rendered(writer, custom, shown_rows, &columns)?;
+        } else if let OutputStyle::Delimited { delimiter } = self.style {
+            self.write_delimited(writer, delimiter, shown_rows, &columns)?;
+        } else if matches!(self.style, OutputStyle::Plain | OutputStyle::Custom(_)) {
+            self.write_plain(writer, shown_rows, (shown_styles, &cell_styles), &columns)?;
+        } else {
+            let project = |cells: &[String]| -> Vec<String> {
+                columns
+                    .iter()
+                    .map(|&idx| cells.get(idx).cloned().unwrap_or_default())
+                    .collect()
+            };
+            let headers = self.headers.as_deref().map(project);
+            let footers = self.footers.as_deref().map(project);
+            let projected_rows: Vec<Vec<String>> = shown_rows.iter().map(|cells| project(cells)).collect();
+            let projected_row_slices: Vec<&[String]> = projected_rows.iter().map(Vec::as_slice).collect();
+
+            let data_alignments = self.data_alignments();
+            let alignments: Vec<Alignment> = columns.iter().map(|&idx| data_alignments[idx]).collect();
+            let default_join = if self.style == OutputStyle::Markdown { "<br>" } else { " " };
+            Delimited {
+                style: self.style,
+                alignments: &alignments,
+                line_join: self.line_join.as_deref().unwrap_or(default_join),
+                repeat_header_every: self.repeat_header_every,
+            }
+            .write(writer, headers.as_deref(), &projected_row_slices, footers.as_deref())?;
+        }
+
+        if verbose {
+            self.write_hidden(writer, &columns)?;
+        }
+        if show_count {
+            writeln!(writer, "(showing {} of {} rows)", rows.len(), self.rows.len())?;
+        }
+
+        Ok(())
+    }
+
+    /// Write the headers, the given rows, and the footers through a renderer.
+    fn write_rendered(
+        &self,
+        writer: &mut impl Write,
+        renderer: &dyn Renderer,
+        rows: &[&[String]],
+        columns: &[usize],
+    ) -> FmtResult {
+        let cells = |row: &[String]| -> Vec<Cell> {
+            columns
+                .iter()
+                .map(|&idx| Cell::from_text(row.get(idx).map_or("", String::as_str)))
+                .collect()
+        };
+        let headers = self.headers.as_deref().map(cells);
+        let footers = self.footers.as_deref().map(cells);
+        let data: Vec<Vec<Cell>> = rows.iter().map(|row| cells(row)).collect();
+
+        let alignments = self.data_alignments();
+        let layout: Vec<RenderColumn<'_>> = columns
+            .iter()
+            .enumerate()
+            .map(|(pos, &idx)| RenderColumn {
+                alignment: alignments[idx],
+                width: data
+                    .iter()
+                    .chain(headers.as_ref())
+                    .chain(footers.as_ref())
+                    .map(|row| row[pos].width())
+                    .max()
+                    .unwrap_or(0),
+                separator: if pos + 1 < columns.len() {
+                    self.separator(columns, pos).map(String::as_str)
+                } else {
+                    None
+                },
+            })
+            .collect();
+
+        let out: &mut dyn Write = writer;
+        renderer.begin_table(out, &layout)?;
+        if let Some(header_cells) = headers.as_ref() {
+            renderer.header_row(out, &layout, header_cells)?;
+            renderer.rule(out, &layout)?;
+        }
+        for row in &data {
+            renderer.data_row(out, &layout, row)?;
+        }
+        if let Some(footer_cells) = footers.as_ref() {
+            renderer.footer_row(out, &layout, footer_cells)?;
+        }
+        renderer.end_table(out, &layout)
+    }
+
+    /// Write the headers, the given rows, and the footers as cells joined by the delimiter, with no padding.
+    fn write_delimited(&self, writer: &mut impl Write, delimiter: &str, rows: &[&[String]], columns: &[usize]) -> FmtResult {
+        let line_join = self.line_join.as_deref().unwrap_or("\\n");
+        let replacement = if delimiter.contains(char::is_whitespace) { "_" } else { " " };
+        let fields = |cells: &[String]| -> String {
+            columns
+                .iter()
+                .map(|&idx| {
+                    let cell = cells.get(idx).map_or("", String::as_str);
+                    let flattened = cell.lines().collect::<Vec<_>>().join(line_join);
+                    if delimiter.is_empty() {
+                        flattened
+                    } else {
+                        flattened.replace(delimiter, replacement)
+                    }
+                })
+                .collect::<Vec<_>>()
+                .join(delimiter)
+        };
+
+        for cells in self
+            .headers
+            .iter()
+            .map(Vec::as_slice)
+            .chain(rows.iter().copied())
+            .chain(self.footers.as_deref())
+        {
+            writeln!(writer, "{}", fields(cells))?;
+        }
+        Ok(())
+    }
+
+    /// The first column with a cell containing the delimiter of the `Delimited` style, if any.
+    fn delimiter_in_cell(&self) -> Option<usize> {
+        let OutputStyle::Delimited { delimiter } = self.style else {
+            return None;
+        };
+        if delimiter.is_empty() {
+            return None;
+        }
+        let line_join = self.line_join.as_deref().unwrap_or("\\n");
+        self.headers
+            .iter()
+            .chain(&self.rows)
+            .chain(self.footers.as_ref())
+            .flat_map(|cells| cells.iter().enumerate())
+            .filter(|&(_, cell)| cell.lines().collect::<Vec<_>>().join(line_join).contains(delimiter))
+            .map(|(column, _)| column)
+            .min()
+    }
+
+    /// Announce the selected columns left out of the render, by their quoted headers or else their indices.
+    fn write_hidden(&self, writer: &mut impl Write, columns: &[usize]) -> FmtResult {
+        for idx in self.selected().into_iter().filter(|idx| !columns.contains(idx)) {
+            let label = self
+                .headers
+                .as_ref()
+                .and_then(|headers| headers.get(idx))
+                .map_or_else(|| idx.to_string(), |header| format!("'{header}'"));
+            writeln!(writer, "{}", Accessibility::hidden(&label))?;
+        }
+        Ok(())
+    }
+
+    /// Spell out the coloured signals of the given rows: styled rows are marked, and heatmap cells are given
+    /// their position in their column's range.
+    fn annotated_rows(&self, rows: &[&[String]], styles: &[Option<Style>]) -> Vec<Vec<String>> {
+        let ranges: Vec<Option<(ColorScale, f64, f64)>> =
+            (0..self.formats.len()).map(|idx| self.heatmap_range(rows, idx)).collect();
+
+        rows.iter()
+            .enumerate()
+            .map(|(row_idx, row)| {
+                let mut cells: Vec<String> = row
+                    .iter()
+                    .zip(&ranges)
+                    .map(|(cell, range)| match range.zip(cell_value(cell)) {
+                        Some(((_, min, max), value)) if value.is_finite() => {
+                            let fraction = if max > min { (value - min) / (max - min) } else { 0.0 };
+                            format!("{cell} [{:.0}%]", fraction * 100.0)
+                        }
+                        _ => cell.clone(),
+                    })
+                    .collect();
+                if styles.get(row_idx).copied().flatten().is_some()
+                    && let Some(first) = cells.first_mut()
+                {
+                    first.insert_str(0, "[highlighted] ");
+                }
+                cells
+            })
+            .collect()
+    }
+
+    /// Choose the style of each of the given rows, or none at all if styles are neither emitted nor announced.
+    fn row_styles(&self, indices: &[usize], rows: &[&[String]]) -> Vec<Option<Style>> {
+        match self.row_style.as_ref() {
+            Some(row_style) if self.color.enabled() || self.accessibility == Accessibility::Verbose => {
+                indices.iter().zip(rows).map(|(&idx, row)| row_style(idx, row)).collect()
+            }
+            _ => Vec::new(),
+        }
+    }
+
+    /// The separator after the column at the given position of the render.
+    ///
+    /// A selected column without a separator of its own, such as the last column of the format string, takes the
+    /// separator after the column at its new position.
+    fn separator(&self, columns: &[usize], pos: usize) -> Option<&String> {
+        self.formats[columns[pos]].separator.as_ref().or_else(|| {
+            self.selected_columns.as_ref()?;
+            self.formats.get(pos)?.separator.as_ref()
+        })
+    }
+
+    /// The indices of the selected columns, in order, or of every column if none were selected.
+    fn selected(&self) -> Vec<usize> {
+        self.selected_columns
+            .clone()
+            .unwrap_or_else(|| (0..self.formats.len()).collect())
+    }
+
+    /// Select the indices of the columns to render, in order.
+    ///
+    /// When collapsing empty columns, columns whose every cell in the given rows is blank are left out.
+    fn visible_columns(&self, rows: &[&[String]]) -> Vec<usize> {
+        self.selected()
+            .into_iter()
+            .filter(|&idx| {
+                !self.collapse_empty
+                    || rows
+                        .iter()
+                        .any(|row| row.get(idx).is_some_and(|cell| !cell.trim().is_empty()))
+            })
+            .collect()
+    }
+
+    /// Write the headers, the given rows, and the footers as aligned columns.
+    fn write_plain(
+        &self,
+        writer: &mut impl Write,
+        rows: &[&[String]],
+        (styles, cell_styles): (&[Option<Style>], &[Vec<String>]),
+        columns: &[usize],
+    ) -> FmtResult {
+        let groups = self.groups(rows);
+        let subtotals: Vec<Vec<String>> = self.group_subtotal.as_ref().map_or_else(Vec::new, |subtotal| {
+            groups
+                .iter()
+                .map(|group| {
+                    let group_rows: Vec<&[String]> = group.1.iter().map(|&idx| rows[idx]).collect();
+                    subtotal(group.0, &group_rows)
+                })
+                .collect()
+        });
+        let indented = self.indented(rows, columns);
+        let shown_rows: Vec<&[String]> = if indented.is_empty() {
+            rows.to_vec()
+        } else {
+            indented.iter().map(Vec::as_slice).collect()
+        };
+
+        let all_rows: Vec<&[String]> = self.rows.iter().map(Vec::as_slice).collect();
+        let indented_all = match self.filter_widths {
+            FilterWidths::Filtered => Vec::new(),
+            FilterWidths::AllRows => self.indented(&all_rows, columns),
+        };
+        let mut measured_rows: Vec<&[String]> = match self.filter_widths {
+            FilterWidths::Filtered => shown_rows.clone(),
+            FilterWidths::AllRows if indented_all.is_empty() => all_rows,
+            FilterWidths::AllRows => indented_all.iter().map(Vec::as_slice).collect(),
+        };
+        measured_rows.extend(subtotals.iter().map(Vec::as_slice));
+        let column_widths = self.column_widths(&measured_rows, columns);
+
+        let alignments = self.data_alignments();
+        let layout = (column_widths.as_slice(), alignments.as_slice());
+
+        if let Some(headers) = self.headers.as_ref() {
+            self.write_row(writer, headers, (RowRole::Header, None), columns, layout, &[])?;
+        }
+        let mut written = 0;
+        for (group_idx, group) in groups.iter().enumerate() {
+            if self.group_column.is_some() {
+                self.write_group_header(writer, group.0, columns, &column_widths)?;
+            }
+            for &idx in &group.1 {
+                if let Some(headers) = self.headers.as_ref()
+                    && self
+                        .repeat_header_every
+                        .is_some_and(|every| written > 0 && written % every == 0)
+                {
+                    self.write_row(writer, headers, (RowRole::Header, None), columns, layout, &[])?;
+                }
+                let style = styles.get(idx).copied().flatten();
+                let row_cell_styles = cell_styles.get(idx).map_or(&[][..], Vec::as_slice);
+                self.write_row(
+                    writer,
+                    shown_rows[idx],
+                    (RowRole::Data, style),
+                    columns,
+                    layout,
+                    row_cell_styles,
+                )?;
+                written += 1;
+            }
+            if let Some(subtotal) = subtotals.get(group_idx) {
+                self.write_row(writer, subtotal, (RowRole::Footer, None), columns, layout, &[])?;
+            }
+        }
+        if let Some(footers) = self.footers.as_ref() {
+            self.write_row(writer, footers, (RowRole::Footer, None), columns, layout, &[])?;
+        }
+
+        Ok(())
+    }
+
+    /// Split the indices of the given rows into groups by the text of the grouping column, in order of first
+    /// appearance, or into a single unlabelled group if the rows are not grouped.
+    fn groups<'r>(&self, rows: &[&'r [String]]) -> Vec<(&'r str, Vec<usize>)> {
+        let Some(group_column) = self.group_column else {
+            return vec![("", (0..rows.len()).collect())];
+        };
+        let mut groups: Vec<(&str, Vec<usize>)> = Vec::new();
+        for (idx, row) in rows.iter().enumerate() {
+            let label = row.get(group_column).map_or("", String::as_str);
+            match groups.iter_mut().find(|group| group.0 == label) {
+                Some(group) => group.1.push(idx),
+                None => groups.push((label, vec![idx])),
+            }
+        }
+        groups
+    }
+
+    /// Copy the given rows with every line of their first rendered column indented, or return no rows if grouped
+    /// rows are not indented.
+    fn indented(&self, rows: &[&[String]], columns: &[usize]) -> Vec<Vec<String>> {
+        let Some(&first) = columns.first() else {
+            return Vec::new();
+        };
+        if self.group_column.is_none() || self.group_indent == 0 {
+            return Vec::new();
+        }
+        let indent = " ".repeat(self.group_indent);
+        rows.iter()
+            .map(|row| {
+                let mut cells = row.to_vec();
+                if let Some(cell) = cells.get_mut(first) {
+                    *cell = cell
+                        .lines()
+                        .map(|line| format!("{indent}{line}"))
+                        .collect::<Vec<_>>()
+                        .join("\n");
+                }
+                cells
+            })
+            .collect()
+    }
+
+    /// Write the header line of a group, cut at the width of the table and styled if styles are emitted.
+    fn write_group_header(
+        &self,
+        writer: &mut impl Write,
+        label: &str,
+        columns: &[usize],
+        column_widths: &[usize],
+    ) -> FmtResult {
+        let separators: usize = (0..columns.len().saturating_sub(1))
+            .filter_map(|pos| self.separator(columns, pos))
+            .map(|separator| visible_width(separator))
+            .sum();
+        let table_width = column_widths.iter().sum::<usize>() + separators;
+        let line = split(label, table_width).0;
+        writeln!(writer, "{}", self.color.paint(self.group_style, line))
+    }
+
+    /// Find the SGR sequence switching on the background of each heatmap cell of the given rows and columns.
+    ///
+    /// Cells without a background have an empty sequence, and no rows have any when styles are not emitted.
+    fn cell_styles(&self, rows: &[&[String]], columns: &[usize]) -> Vec<Vec<String>> {
+        if self.heatmaps.is_empty() || !self.color.enabled() {
+            return Vec::new();
+        }
+
+        let scales: Vec<Option<(ColorScale, f64, f64)>> = columns.iter().map(|&idx| self.heatmap_range(rows, idx)).collect();
+
+        rows.iter()
+            .map(|row| {
+                columns
+                    .iter()
+                    .zip(&scales)
+                    .map(|(&idx, scale)| {
+                        scale
+                            .zip(row.get(idx).and_then(|cell| cell_value(cell)))
+                            .map(|((color_scale, min, max), value)| {
+                                let fraction = if max > min { (value - min) / (max - min) } else { 0.0 };
+                                color_scale.render_sgr(if value.is_finite() { fraction } else { value })
+                            })
+                            .unwrap_or_default()
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+
+    /// Find the scale of a heatmap column, with the range of its finite values in the given rows.
+    fn heatmap_range(&self, rows: &[&[String]], idx: usize) -> Option<(ColorScale, f64, f64)> {
+        let scale = self.heatmaps.iter().rev().find(|heatmap| heatmap.0 == idx)?.1;
+        let (min, max) = rows
+            .iter()
+            .filter_map(|row| cell_value(row.get(idx)?))
+            .filter(|value| value.is_finite())
+            .fold((f64::INFINITY, f64::NEG_INFINITY), |range, value| {
+                (range.0.min(value), range.1.max(value))
+            });
+        Some((scale, min, max))
+    }
+
+    /// Calculate the width of each of the given columns (use specified width or auto-calculate)
+    /// over the given rows, headers, and footers.
+    ///
+    /// Percentage widths are resolved against the terminal width, less the separators and absolute widths.
+    fn column_widths(&self, rows: &[&[String]], columns: &[usize]) -> Vec<usize> {
+        let widths: Vec<Option<Width>> = columns.iter().map(|&idx| self.formats[idx].width).collect();
+        let separators: usize = (0..columns.len().saturating_sub(1))
+            .filter_map(|pos| self.separator(columns, pos))
+            .map(|separator| separator.chars().count())
+            .sum();
+        let absolute: usize = widths.iter().filter_map(|width| width.and_then(Width::chars)).sum();
+        let explicit_widths = width::resolve(&widths, terminal::width(), separators + absolute);
+
+        columns
+            .iter()
+            .zip(explicit_widths)
+            .map(|(&idx, explicit)| {
+                explicit.unwrap_or_else(|| {
+                    rows.iter()
+                        .copied()
+                        .chain(self.headers.as_deref())
+                        .chain(self.footers.as_deref())
+                        .filter_map(|row| row.get(idx))
+                        .flat_map(|cell| self.clipped_cell(cell.lines().collect(), usize::MAX, &self.formats[idx]))
+                        .map(|line| visible_width(&line))
+                        .max()
+                        .unwrap_or(0)
+                })
+            })
+            .collect()
+    }
+
+    /// The alignment of the data in each column, right-aligning columns of numbers if `auto_align` is on.
+    fn data_alignments(&self) -> Vec<Alignment> {
+        self.formats
+            .iter()
+            .enumerate()
+            .map(|(idx, format)| match self.auto_align {
+                Some(fraction) if !format.explicit_alignment && self.numeric_fraction(idx) >= fraction => Alignment::Right,
+                _ => format.alignment,
+            })
+            .collect()
+    }
+
+    /// The fraction of the non-empty data cells of a column which are numbers, or zero if all are empty.
+    fn numeric_fraction(&self, idx: usize) -> f64 {
+        let (numeric, non_empty) = self
+            .rows
+            .iter()
+            .filter_map(|row| row.get(idx))
+            .filter(|cell| !cell.trim().is_empty())
+            .fold((0_u32, 0_u32), |(numeric, non_empty), cell| {
+                // Percentages count as numbers, as do values with digit group separators
+                let trimmed = cell.trim();
+                let number = cell_value(trimmed.strip_suffix('%').unwrap_or(trimmed)).is_some_and(f64::is_finite);
+                (numeric + u32::from(number), non_empty + 1)
+            });
+        if non_empty == 0 {
+            0.0
+        } else {
+            f64::from(numeric) / f64::from(non_empty)
+        }
+    }
+
+    /// The number of output lines each row is limited to, or `usize::MAX` when unlimited.
+    fn row_height_limit(&self) -> usize {
+        self.max_row_height.map_or(usize::MAX, |height| height.max(1))
+    }
+
+    /// Keep the lines of a cell within the row height limit, marking the last line kept if any are dropped.
+    fn clipped_cell<'c>(&self, lines: Vec<&'c str>, column_width: usize, format: &ColumnFormat) -> Vec<Cow<'c, str>> {
+        let limit = self.row_height_limit();
+        let num_lines = lines.len();
+        let mut kept: Vec<Cow<'c, str>> = lines.into_iter().take(limit).map(Cow::Borrowed).collect();
+        if num_lines > limit
+            && let Some(last) = kept.last_mut()
+        {
+            // The announcement takes the place of the last line, which is dropped with the rest
+            *last = Cow::Owned(if self.accessibility == Accessibility::Verbose {
+                Accessibility::omitted(num_lines - limit + 1)
+            } else {
+                mark_end(last, column_width, &format.truncate_marker)
+            });
+        }
+        kept
+    }
+
+    /// Write a single row with the given role, which occupies as many lines as its tallest cell, optionally styling
+    /// each whole line and giving cells their own backgrounds.
+    fn write_row(
+        &self,
+        writer: &mut impl Write,
+        cells: &[String],
+        (role, style): (RowRole, Option<Style>),
+        columns: &[usize],
+        (column_widths, alignments): (&[usize], &[Alignment]),
+        cell_styles: &[String],
+    ) -> FmtResult {
+        let (sgr, reset) = style.map_or_else(
+            || (String::new(), ""),
+            |row_style| (row_style.render_sgr(), row_style.render_reset()),
+        );
+
+        let links: Vec<Option<Option<&Path>>> = columns
+            .iter()
+            .map(|&idx| {
+                self.linked_paths
+                    .iter()
+                    .find(|&&(column, _)| column == idx)
+                    .map(|linked| linked.1.as_deref())
+            })
+            .collect();
+        let linking = links.iter().any(Option::is_some) && self.color.enabled();
+
+        let cell_lines: Vec<Vec<Cow<'_, str>>> = columns
+            .iter()
+            .zip(column_widths)
+            .map(|(&idx, &column_width)| {
+                let source_lines = cells.get(idx).map_or_else(Vec::new, |cell| cell.lines().collect());
+                self.clipped_cell(source_lines, column_width, &self.formats[idx])
+            })
+            .collect();
+        let height = cell_lines.iter().map(Vec::len).max().unwrap_or(0).max(1);
+
+        for line_idx in 0..height {
+            write!(writer, "{sgr}")?;
+            for (pos, lines) in cell_lines.iter().enumerate() {
+                let format = &self.formats[columns[pos]];
+                let column_width = column_widths[pos];
+                let line = lines.get(line_idx).map_or_else(
+                    || blank_line(format, column_width, self.fill_blank_lines),
+                    |cell_line| {
+                        let line: &str = cell_line;
+                        let cut = self.accessibility.cut(line, column_width);
+                        let alignment = format.role_alignment(role).unwrap_or(alignments[columns[pos]]);
+                        let fitted = fit_line_aligned(cut.as_deref().unwrap_or(line), column_width, format, alignment);
+                        match links[pos] {
+                            Some(base) if linking => link_path(&fitted, line, base),
+                            _ => fitted,
+                        }
+                    },
+                );
+
+                match cell_styles.get(pos) {
+                    Some(cell_sgr) if !cell_sgr.is_empty() => write!(writer, "{cell_sgr}{line}{RESET}{sgr}")?,
+                    _ => write!(writer, "{line}")?,
+                }
+
+                // Add separator if not the last column
+                if pos < cell_lines.len() - 1
+                    && let Some(separator) = self.separator(columns, pos)
+                {
+                    let piece = format.positioned_separator.render(separator, line_idx, height);
+                    write!(writer, "{}", format.separator_fill.render(piece, line_idx))?;
+                }
+            }
+            writeln!(writer, "{reset}")?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Display for Table {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        let rows: Vec<&[String]> = self.rows.iter().map(Vec::as_slice).collect();
+        let indices: Vec<usize> = (0..rows.len()).collect();
+        let styles = self.row_styles(&indices, &rows);
+        self.write_rows(f, &rows, &styles, false)
+    }
+}
+
+/// End a line with the marker, cutting it so that both fit within the column width.
+#[expect(clippy::single_call_fn, reason = "Keeps the marking apart from the clipping of rows.")]
+fn mark_end(line: &str, column_width: usize, marker: &str) -> String {
+    let marker_width = visible_width(marker);
+    if column_width < marker_width {
+        return split(marker, column_width).0.to_owned();
+    }
+    let kept = split(line, column_width - marker_width).0;
+    let reset = if kept.contains(ESCAPE) { RESET } else { "" };
+    format!("{kept}{reset}{marker}")
+}
+
+/// Read the numeric value of a cell, ignoring surrounding whitespace and digit group separators.
+fn cell_value(cell: &str) -> Option<f64> {
+    cell.trim().replace([',', '_'], "").parse().ok()
+}
@@ -0,0 +1,212 @@
+//! Implementation of tables written row by row as the rows arrive.
+//!
+//! This module provides the `TableWriter` struct, which streams rows formatted with a shared
+//! column layout to an `io::Write`, without holding the whole table in memory like `Table`.
+//! As rows are written before later rows are known, column widths are fixed when the first row
+//! is written: explicit widths are kept, and every other column takes the width of its header or
+//! first cell, whichever is wider. Later cells are truncated or padded to those widths.
+//!
+//! Rows are collected in a buffer, and passed on and flushed following the `FlushPolicy`. With
+//! `FlushPolicy::Manual`, nothing reaches the writer until `TableWriter::flush` is called or the
+//! `TableWriter` is dropped.
+
+use std::io::{Result as IoResult, Write};
+
+use crate::{
+    ColumnFormatter, FlushPolicy, FormattableItem, LineEnding,
+    column_format::ColumnFormat,
+    column_formatter::fit_line,
+    row_role::RowRole,
+    slice::visible_width,
+    terminal,
+    width::{self, Width},
+};
+
+/// A table written to an `io::Write` row by row.
+pub struct TableWriter<W: Write> {
+    /// The writer receiving the rows.
+    writer: W,
+    /// The kind of format for each column.
+    formats: Vec<ColumnFormat>,
+    /// Optional header cells, written above the first row.
+    headers: Option<Vec<String>>,
+    /// The width of each column, once fixed by the first row.
+    column_widths: Option<Vec<usize>>,
+    /// When rows are flushed.
+    flush_policy: FlushPolicy,
+    /// The terminator of each written line.
+    line_ending: LineEnding,
+    /// Rows written since the last flush.
+    buffer: Vec<u8>,
+    /// Number of rows written since the last flush.
+    unflushed_rows: usize,
+}
+
+impl<W: Write> TableWriter<W> {
+    /// Construct a new `TableWriter` streaming rows to the writer, using the given format string for every row.
+    #[must_use]
+    #[inline]
+    pub fn new(format_str: &str, writer: W) -> Self {
+        Self {
+            writer,
+            formats: ColumnFormatter::parse_format_string(format_str),
+            headers: None,
+            column_widths: None,
+            flush_policy: FlushPolicy::EveryRow,
+            line_ending: LineEnding::Lf,
+            buffer: Vec::new(),
+            unflushed_rows: 0,
+        }
+    }
+
+    /// Set the header cells, written above the first row.
+    ///
+    /// Headers set after the first row has been written are ignored.
+    #[must_use]
+    #[inline]
+    pub fn with_headers<I, S>(mut self, headers: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.headers = Some(headers.into_iter().map(Into::into).collect());
+        self
+    }
+
+    /// Set when rows are flushed.
+    #[must_use]
+    #[inline]
+    pub const fn with_flush_policy(mut self, flush_policy: FlushPolicy) -> Self {
+        self.flush_policy = flush_policy;
+        self
+    }
+
+    /// Set how each written line ends, such as `LineEnding::CrLf` for files opened on Windows.
+    #[must_use]
+    #[inline]
+    pub const fn with_line_ending(mut self, line_ending: LineEnding) -> Self {
+        self.line_ending = line_ending;
+        self
+    }
+
+    /// A reference to the writer.
+    #[must_use]
+    #[inline]
+    pub const fn get_ref(&self) -> &W {
+        &self.writer
+    }
+
+    /// Format a row of items and write it, flushing if the flush policy requires it.
+    ///
+    /// The first row fixes the column widths, and is preceded by the headers. Items beyond the number of columns
+    /// are ignored.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the rows cannot be written or flushed.
+    #[inline]
+    pub fn write_row(&mut self, items: &[FormattableItem<'_>]) -> IoResult<()> {
+        let cells: Vec<String> = self.formats.iter().zip(items).map(|(fmt, item)| fmt.render(item)).collect();
+
+        if self.column_widths.is_none() {
+            let column_widths = self.first_widths(&cells);
+            if let Some(headers) = self.headers.take() {
+                self.buffer_row(&headers, &column_widths, RowRole::Header)?;
+            }
+            self.column_widths = Some(column_widths);
+        }
+        let column_widths = self.column_widths.take().unwrap_or_default();
+        let buffered = self.buffer_row(&cells, &column_widths, RowRole::Data);
+        self.column_widths = Some(column_widths);
+        buffered?;
+
+        self.unflushed_rows += 1;
+        let due = match self.flush_policy {
+            FlushPolicy::EveryRow => true,
+            FlushPolicy::EveryN(rows) => self.unflushed_rows >= rows,
+            FlushPolicy::Manual => false,
+        };
+        if due { self.flush() } else { Ok(()) }
+    }
+
+    /// Pass the rows written since the last flush on to the writer, and flush it.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the rows cannot be written or flushed.
+    #[inline]
+    pub fn flush(&mut self) -> IoResult<()> {
+        self.writer.write_all(&self.buffer)?;
+        self.buffer.clear();
+        self.unflushed_rows = 0;
+        self.writer.flush()
+    }
+
+    /// Fix the width of each column from the explicit widths, the headers, and the first row.
+    fn first_widths(&self, cells: &[String]) -> Vec<usize> {
+        let widths: Vec<Option<Width>> = self.formats.iter().map(|fmt| fmt.width).collect();
+        let separators: usize = self
+            .formats
+            .iter()
+            .take(self.formats.len().saturating_sub(1))
+            .filter_map(|fmt| fmt.separator.as_ref())
+            .map(|separator| separator.chars().count())
+            .sum();
+        let absolute: usize = widths.iter().filter_map(|width| width.and_then(Width::chars)).sum();
+
+        width::resolve(&widths, terminal::width(), separators + absolute)
+            .into_iter()
+            .enumerate()
+            .map(|(idx, explicit)| {
+                explicit.unwrap_or_else(|| {
+                    [cells.get(idx), self.headers.as_ref().and_then(|headers| headers.get(idx))]
+                        .into_iter()
+                        .flatten()
+                        .flat_map(|cell| cell.lines())
+                        .map(visible_width)
+                        .max()
+                        .unwrap_or(0)
+                })
+            })
+            .collect()
+    }
+
+    /// Write a row to the buffer, which occupies as many lines as its tallest cell.
+    fn buffer_row(&mut self, cells: &[String], column_widths: &[usize], role: RowRole) -> IoResult<()> {
+        let cell_lines: Vec<Vec<&str>> = (0..self.formats.len())
+            .map(|idx| cells.get(idx).map_or_else(Vec::new, |cell| cell.lines().collect()))
+            .collect();
+        let height = cell_lines.iter().map(Vec::len).max().unwrap_or(0).max(1);
+
+        for line_idx in 0..height {
+            for (idx, lines) in cell_lines.iter().enumerate() {
+                let format = &self.formats[idx];
+                let column_width = column_widths[idx];
+                let line = lines
+                    .get(line_idx)
+                    .map_or_else(|| " ".repeat(column_width), |line| fit_line(line, column_width, format, role));
+                write!(self.buffer, "{line}")?;
+
+                // Add separator if not the last column
+                if idx < cell_lines.len() - 1
+                    && let Some(separator) = format.separator.as_ref()
+                {
+                    write!(self.buffer, "{separator}")?;
+                }
+            }
+            write!(self.buffer, "{}", self.line_ending.as_str())?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<W: Write> Drop for TableWriter<W> {
+    /// Flush any rows still buffered, ignoring errors as there is no one left to report them to.
+    #[inline]
+    fn drop(&mut self) {
+        if self.flush().is_err() {
+            self.buffer.clear();
+        }
+    }
+}
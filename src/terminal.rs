@@ -0,0 +1,84 @@
+//! Detection of the terminal the output is written to.
+//!
+//! The terminal width is read from the `COLUMNS` environment variable, which shells set for
+//! interactive sessions.
+//!
+//! With the `terminal` feature on Windows, the width is instead read from the console screen
+//! buffer, and virtual terminal processing is enabled (once, on a best-effort basis) before any
+//! escape sequences are emitted. Consoles which refuse it receive uncoloured output.
+
+use std::env;
+
+/// The width of the terminal in characters, if it is known.
+pub fn width() -> Option<usize> {
+    #[cfg(all(windows, feature = "terminal"))]
+    if let Some(width) = windows::console_width() {
+        return Some(width);
+    }
+
+    let columns = env::var("COLUMNS").ok()?;
+    columns.trim().parse().ok()
+}
+
+/// Prepare the terminal for escape sequences, returning whether it can display them.
+#[expect(clippy::single_call_fn, reason = "Kept with the other terminal queries.")]
+#[cfg(all(windows, feature = "terminal"))]
+pub fn enable_styles() -> bool {
+    windows::enable_virtual_terminal()
+}
+
+/// Prepare the terminal for escape sequences, returning whether it can display them.
+#[expect(clippy::single_call_fn, reason = "Kept with the other terminal queries.")]
+#[cfg(not(all(windows, feature = "terminal")))]
+pub const fn enable_styles() -> bool {
+    true
+}
+
+/// Console queries for Windows.
+#[cfg(all(windows, feature = "terminal"))]
+mod windows {
+    use std::{mem::zeroed, sync::OnceLock};
+
+    use windows_sys::Win32::System::Console::{
+        CONSOLE_SCREEN_BUFFER_INFO, ENABLE_VIRTUAL_TERMINAL_PROCESSING, GetConsoleMode, GetConsoleScreenBufferInfo,
+        GetStdHandle, STD_OUTPUT_HANDLE, SetConsoleMode,
+    };
+
+    /// The width of the visible console window, if standard output is a console.
+    pub fn console_width() -> Option<usize> {
+        // SAFETY: `GetStdHandle` has no preconditions.
+        let handle = unsafe { GetStdHandle(STD_OUTPUT_HANDLE) };
+        // SAFETY: `CONSOLE_SCREEN_BUFFER_INFO` is plain data, for which all zeroes is a valid value.
+        let mut info: CONSOLE_SCREEN_BUFFER_INFO = unsafe { zeroed() };
+        // SAFETY: `info` is a valid, writable `CONSOLE_SCREEN_BUFFER_INFO`, and invalid handles are reported as failure.
+        if unsafe { GetConsoleScreenBufferInfo(handle, &raw mut info) } == 0 {
+            return None;
+        }
+
+        let width = i32::from(info.srWindow.Right) - i32::from(info.srWindow.Left) + 1;
+        usize::try_from(width).ok().filter(|&width| width > 0)
+    }
+
+    /// Enable virtual terminal processing on standard output, returning whether it is enabled.
+    ///
+    /// The console is only configured once; later calls return the first result.
+    pub fn enable_virtual_terminal() -> bool {
+        /// Whether virtual terminal processing was enabled.
+        static ENABLED: OnceLock<bool> = OnceLock::new();
+
+        *ENABLED.get_or_init(|| {
+            // SAFETY: `GetStdHandle` has no preconditions.
+            let handle = unsafe { GetStdHandle(STD_OUTPUT_HANDLE) };
+            let mut mode = 0;
+            // SAFETY: `mode` is a valid, writable `u32`, and invalid handles are reported as failure.
+            if unsafe { GetConsoleMode(handle, &raw mut mode) } == 0 {
+                return false;
+            }
+            if mode & ENABLE_VIRTUAL_TERMINAL_PROCESSING != 0 {
+                return true;
+            }
+            // SAFETY: `handle` is a console handle, as `GetConsoleMode` succeeded.
+            unsafe { SetConsoleMode(handle, mode | ENABLE_VIRTUAL_TERMINAL_PROCESSING) != 0 }
+        })
+    }
+}
@@ -0,0 +1,136 @@
+//! Rendering of timestamps with strftime-like patterns.
+//!
+//! This module provides the functions used by the `Time` format type to turn a `SystemTime` into text
+//! such as `2024-03-09 14:05` for the pattern `%Y-%m-%d %H:%M`. Times are shown in UTC, and the
+//! supported directives are:
+//! - `%Y`: The year, such as `2024`, and `%y` its last two digits.
+//! - `%m`: The month from `01` to `12`, and `%b` its abbreviated name, such as `Mar`.
+//! - `%d`: The day of the month from `01` to `31`, and `%j` the day of the year from `001` to `366`.
+//! - `%H`, `%M`, and `%S`: The hour, minute, and second, each with two digits.
+//! - `%F` and `%T`: Shorthands for `%Y-%m-%d` and `%H:%M:%S`.
+//! - `%%`: A literal `%`.
+//!
+//! Any other directive is invalid, and is rendered as it is written.
+
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// The pattern used for times in columns without one, giving ISO 8601 timestamps.
+pub const DEFAULT_PATTERN: &str = "%Y-%m-%dT%H:%M:%SZ";
+
+/// The letters which may follow a `%` in a pattern.
+const DIRECTIVES: &str = "YymbdjHMSFT%";
+
+/// Abbreviated month names.
+const MONTHS: [&str; 12] = [
+    "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+];
+
+/// Seconds in a day.
+const SECONDS_PER_DAY: i64 = 86_400;
+
+/// Days in a 400-year cycle of the Gregorian calendar.
+const DAYS_PER_ERA: i64 = 146_097;
+
+/// Days from 0000-03-01, the start of the calendar used by the conversions, to 1970-01-01.
+const EPOCH_OFFSET: i64 = 719_468;
+
+/// Check that every `%` in a pattern starts a supported directive.
+#[expect(clippy::single_call_fn, reason = "Kept alongside the renderer which shares its directives.")]
+#[must_use]
+#[inline]
+pub fn is_valid(pattern: &str) -> bool {
+    let mut chars = pattern.chars();
+    while let Some(c) = chars.next() {
+        if c == '%' && !chars.next().is_some_and(|directive| DIRECTIVES.contains(directive)) {
+            return false;
+        }
+    }
+    true
+}
+
+/// Render a time in UTC following a pattern.
+#[must_use]
+#[inline]
+pub fn format(time: SystemTime, pattern: &str) -> String {
+    let seconds = epoch_seconds(time);
+    let days = seconds.div_euclid(SECONDS_PER_DAY);
+    let second_of_day = seconds.rem_euclid(SECONDS_PER_DAY);
+    let (year, month, day) = civil_from_days(days);
+    let day_of_year = days - days_from_new_year(year) + 1;
+    let (hour, minute, second) = (
+        second_of_day.div_euclid(3600),
+        second_of_day.div_euclid(60).rem_euclid(60),
+        second_of_day.rem_euclid(60),
+    );
+
+    let mut text = String::new();
+    let mut chars = pattern.chars();
+    while let Some(c) = chars.next() {
+        if c != '%' {
+            text.push(c);
+            continue;
+        }
+        let directive = chars.next();
+        let rendered = match directive {
+            Some('Y') => format!("{year:04}"),
+            Some('y') => format!("{:02}", year.rem_euclid(100)),
+            Some('m') => format!("{month:02}"),
+            Some('b') => MONTHS[usize::try_from(month - 1).unwrap_or(0)].to_owned(),
+            Some('d') => format!("{day:02}"),
+            Some('j') => format!("{day_of_year:03}"),
+            Some('H') => format!("{hour:02}"),
+            Some('M') => format!("{minute:02}"),
+            Some('S') => format!("{second:02}"),
+            Some('F') => format!("{year:04}-{month:02}-{day:02}"),
+            Some('T') => format!("{hour:02}:{minute:02}:{second:02}"),
+            Some('%') | None => "%".to_owned(),
+            Some(other) => format!("%{other}"),
+        };
+        text.push_str(&rendered);
+    }
+    text
+}
+
+/// Whole seconds since the Unix epoch, rounded down for times before it.
+#[expect(clippy::single_call_fn, reason = "Keeps the calendar arithmetic readable.")]
+fn epoch_seconds(time: SystemTime) -> i64 {
+    match time.duration_since(UNIX_EPOCH) {
+        Ok(after) => i64::try_from(after.as_secs()).unwrap_or(i64::MAX),
+        Err(err) => {
+            let before = err.duration();
+            let rounded = before.as_secs() + u64::from(before.subsec_nanos() > 0);
+            i64::try_from(rounded).map_or(i64::MIN, |whole| -whole)
+        }
+    }
+}
+
+/// The year, month, and day of a number of days since the Unix epoch.
+#[expect(clippy::integer_division, reason = "Calendar arithmetic counts whole days.")]
+#[expect(clippy::single_call_fn, reason = "Keeps the calendar arithmetic readable.")]
+fn civil_from_days(days: i64) -> (i64, i64, i64) {
+    let shifted = days + EPOCH_OFFSET;
+    let era = shifted.div_euclid(DAYS_PER_ERA);
+    let day_of_era = shifted.rem_euclid(DAYS_PER_ERA);
+    let year_of_era = (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let shifted_month = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * shifted_month + 2) / 5 + 1;
+    let month = if shifted_month < 10 {
+        shifted_month + 3
+    } else {
+        shifted_month - 9
+    };
+    (year_of_era + era * 400 + i64::from(month <= 2), month, day)
+}
+
+/// The number of days from the Unix epoch to the first of January of a year.
+#[expect(clippy::integer_division, reason = "Calendar arithmetic counts whole days.")]
+#[expect(clippy::single_call_fn, reason = "Keeps the calendar arithmetic readable.")]
+const fn days_from_new_year(year: i64) -> i64 {
+    // January falls at the end of the previous year in the calendar starting in March
+    let shifted_year = year - 1;
+    let era = shifted_year.div_euclid(400);
+    let year_of_era = shifted_year.rem_euclid(400);
+    let day_of_era = 365 * year_of_era + year_of_era / 4 - year_of_era / 100 + 306;
+    era * DAYS_PER_ERA + day_of_era - EPOCH_OFFSET
+}
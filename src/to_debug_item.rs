@@ -0,0 +1,23 @@
+//! Provides conversions from `Debug` values into `FormattableItem`s.
+//!
+//! This module contains the `ToDebugItem` trait, which is implemented for every type
+//! implementing `Debug`, whether or not it also implements `Display`. Items built this way
+//! suit `{:?}` and `{:#?}` columns; in a `{}` column they fall back to their `Debug`
+//! representation, or are reported as a `FormatError::FormatMismatch` by strict formatters.
+
+use std::fmt::Debug;
+
+use crate::FormattableItem;
+
+/// Conversion of a value into a `FormattableItem` using its `Debug` representation.
+pub trait ToDebugItem {
+    /// Wrap the value for `Debug` formatting.
+    fn to_debug_item(&self) -> FormattableItem<'_>;
+}
+
+impl<T: Debug> ToDebugItem for T {
+    #[inline]
+    fn to_debug_item(&self) -> FormattableItem<'_> {
+        FormattableItem::DebugItem(self)
+    }
+}
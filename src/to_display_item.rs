@@ -0,0 +1,21 @@
+//! Provides conversions from `Display` values into `FormattableItem`s.
+//!
+//! This module contains the `ToDisplayItem` trait, which is implemented for every type
+//! implementing `Display`, whether or not it also implements `Debug`.
+
+use std::fmt::Display;
+
+use crate::FormattableItem;
+
+/// Conversion of a value into a `FormattableItem` using its `Display` representation.
+pub trait ToDisplayItem {
+    /// Wrap the value for `Display` formatting.
+    fn to_display_item(&self) -> FormattableItem<'_>;
+}
+
+impl<T: Display> ToDisplayItem for T {
+    #[inline]
+    fn to_display_item(&self) -> FormattableItem<'_> {
+        FormattableItem::DisplayItem(self)
+    }
+}
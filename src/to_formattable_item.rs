@@ -0,0 +1,27 @@
+//! Provides conversions from values into `FormattableItem`s.
+//!
+//! This module contains the `ToFormattableItem` trait, which is implemented for every type
+//! implementing both `Display` and `Debug`. It offers a concise way to build the items passed
+//! to `ColumnFormatter::new` or `Table::push_row`:
+//! - `to_formattable_item`: Wraps both representations, letting the column's format type decide.
+//!
+//! Wrapping a single representation is provided by the `ToDisplayItem` and `ToDebugItem`
+//! supertraits, which only need that representation, so types implementing only `Debug`
+//! can still be placed in `{:?}` and `{:#?}` columns with `to_debug_item`.
+
+use std::fmt::{Debug, Display};
+
+use crate::{FormattableItem, ToDebugItem, ToDisplayItem};
+
+/// Conversion of a value into a `FormattableItem`.
+pub trait ToFormattableItem: ToDisplayItem + ToDebugItem {
+    /// Wrap the value so that the column's format type chooses between `Display` and `Debug`.
+    fn to_formattable_item(&self) -> FormattableItem<'_>;
+}
+
+impl<T: Display + Debug> ToFormattableItem for T {
+    #[inline]
+    fn to_formattable_item(&self) -> FormattableItem<'_> {
+        FormattableItem::Both(self, self)
+    }
+}
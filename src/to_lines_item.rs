@@ -0,0 +1,51 @@
+//! Provides conversions from collections into line-backed `FormattableItem`s.
+//!
+//! This module contains the `ToLinesItem` trait, which is implemented for every `IntoIterator`.
+//! Each element is rendered on its own line, using `Display` with `as_display_lines` or `Debug`
+//! with `as_debug_lines`, so collections of either kind can sit side by side in one formatter.
+//! The column's automatic width spans the widest rendered element.
+
+use std::fmt::{Debug, Display};
+
+use crate::FormattableItem;
+
+/// Conversion of a collection into a `FormattableItem` holding one element per line.
+pub trait ToLinesItem: IntoIterator + Sized {
+    /// Render each element with `Display`, one per line.
+    #[expect(
+        clippy::wrong_self_convention,
+        reason = "Consumes the iterator, which for collections is usually a cheap reference."
+    )]
+    fn as_display_lines<'a>(self) -> FormattableItem<'a>
+    where
+        Self::Item: Display;
+
+    /// Render each element with `Debug`, one per line.
+    #[expect(
+        clippy::wrong_self_convention,
+        reason = "Consumes the iterator, which for collections is usually a cheap reference."
+    )]
+    fn as_debug_lines<'a>(self) -> FormattableItem<'a>
+    where
+        Self::Item: Debug;
+}
+
+impl<I: IntoIterator> ToLinesItem for I {
+    #[inline]
+    fn as_display_lines<'a>(self) -> FormattableItem<'a>
+    where
+        Self::Item: Display,
+    {
+        let lines: Vec<String> = self.into_iter().map(|element| format!("{element}")).collect();
+        FormattableItem::VerbatimString(lines.join("\n"))
+    }
+
+    #[inline]
+    fn as_debug_lines<'a>(self) -> FormattableItem<'a>
+    where
+        Self::Item: Debug,
+    {
+        let lines: Vec<String> = self.into_iter().map(|element| format!("{element:?}")).collect();
+        FormattableItem::VerbatimString(lines.join("\n"))
+    }
+}
@@ -0,0 +1,36 @@
+//! Defines which part of an over-long line is cut away.
+//!
+//! This module contains the `TruncateSide` enum, which controls how a line wider than its
+//! column is shortened:
+//! - `Right`: Keep the start of the line and cut the end (the default).
+//! - `Left`: Keep the end of the line, such as the file name of a path, behind a `…` marker.
+//! - `Middle`: Keep the start and the end of the line around a `…` marker.
+//!
+//! A side can be chosen per column with a `~r`, `~l`, or `~m` suffix in the format string,
+//! or with `ColumnFormatter::with_truncate_side`.
+
+/// The part of an over-long line which is removed.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum TruncateSide {
+    /// Cut the end of the line.
+    #[default]
+    Right,
+    /// Cut the start of the line, marking the cut with `…`.
+    Left,
+    /// Cut the middle of the line, marking the cut with `…`.
+    Middle,
+}
+
+impl TruncateSide {
+    /// Parse the letter of a `~` suffix in the format string.
+    #[expect(clippy::single_call_fn, reason = "Keeps the suffix letters next to the variants.")]
+    pub(crate) fn from_suffix(suffix: &str) -> Option<Self> {
+        match suffix {
+            "r" => Some(Self::Right),
+            "l" => Some(Self::Left),
+            "m" => Some(Self::Middle),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,193 @@
+//! Defines the `std::fmt` options given inside a specifier's braces.
+//!
+//! This module contains the `ValueSpec` struct. Inside the braces, a specifier follows the
+//! `std::fmt` grammar, `[[fill]align][sign]['#']['0'][width]['.' precision][type]`, where the type
+//! is empty for `Display` or `?` for `Debug`, e.g. `{:>8.3}` or `{:+.2?}`. These options apply to
+//! each value before it is laid out, so the inline width is a per-value minimum width, as in
+//! `format!`. The width of the whole column is the separate `:N` suffix after the closing brace, so
+//! `{:.3}:12` renders each value with three decimal places in a column twelve characters wide.
+//!
+//! Specifiers which do not follow the grammar, such as `{:dur}` or `{:money}`, are named formats.
+
+use std::fmt::{Debug, Display};
+
+use crate::{
+    Alignment, FormattableItem,
+    column_formatter::split_padding,
+    format_type::FormatType,
+    parser::{split_anchor, split_grouping},
+    slice::visible_width,
+};
+
+/// The `std::fmt` options of a specifier.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct ValueSpec {
+    /// Character used to pad values narrower than the width.
+    pub fill: char,
+    /// Optional alignment of values narrower than the width; numbers are right-aligned and other values
+    /// left-aligned by default.
+    pub align: Option<Alignment>,
+    /// Whether non-negative numbers are given a `+` sign.
+    pub plus: bool,
+    /// Whether numbers are padded with zeros after their sign instead of the fill character.
+    pub zero: bool,
+    /// Optional minimum width of each value.
+    pub width: Option<usize>,
+    /// Optional precision, such as the number of decimal places of floating point numbers.
+    pub precision: Option<usize>,
+    /// The format type, from the type and the alternate flag, such as `PrettyDebug` for `#?`.
+    pub format_type: FormatType,
+}
+
+impl ValueSpec {
+    /// Parse the options inside a specifier's braces, if they follow the `std::fmt` grammar.
+    ///
+    /// Any anchor and digit grouping flag at the end of the specifier are ignored.
+    #[must_use]
+    #[inline]
+    pub fn parse(spec: &str) -> Option<Self> {
+        let (anchored, _) = split_anchor(spec);
+        let (body, _) = split_grouping(anchored);
+        let mut rest = body.strip_prefix("{:").or_else(|| body.strip_prefix('{'))?;
+
+        let mut value_spec = Self {
+            fill: ' ',
+            align: None,
+            plus: false,
+            zero: false,
+            width: None,
+            precision: None,
+            format_type: FormatType::Display,
+        };
+
+        // An alignment, optionally preceded by a fill character
+        let mut chars = rest.chars();
+        let first = chars.next();
+        if let Some(align) = chars.next().and_then(align_char) {
+            value_spec.fill = first.unwrap_or(' ');
+            value_spec.align = Some(align);
+            rest = chars.as_str();
+        } else if let Some(align) = first.and_then(align_char) {
+            value_spec.align = Some(align);
+            rest = rest.get(1..)?;
+        }
+
+        // Flags, which std parses in this order
+        if let Some(unsigned) = rest.strip_prefix('+') {
+            value_spec.plus = true;
+            rest = unsigned;
+        } else if let Some(unsigned) = rest.strip_prefix('-') {
+            rest = unsigned;
+        }
+        let alternate = rest.strip_prefix('#');
+        rest = alternate.unwrap_or(rest);
+        if let Some(unpadded) = rest.strip_prefix('0') {
+            value_spec.zero = true;
+            rest = unpadded;
+        }
+
+        // Width, then precision
+        let (width, after_width) = leading_number(rest);
+        value_spec.width = width;
+        rest = after_width;
+        if let Some(after_dot) = rest.strip_prefix('.') {
+            let (precision, after_precision) = leading_number(after_dot);
+            value_spec.precision = Some(precision?);
+            rest = after_precision;
+        }
+
+        // Type
+        value_spec.format_type = match (rest, alternate.is_some()) {
+            ("", _) => FormatType::Display,
+            ("?", false) => FormatType::Debug,
+            ("?", true) => FormatType::PrettyDebug,
+            _ => return None,
+        };
+        Some(value_spec)
+    }
+
+    /// Check whether the options only select the format type, leaving values as plain `format!` renders them.
+    #[must_use]
+    #[inline]
+    pub const fn is_plain(&self) -> bool {
+        self.align.is_none() && !self.plus && !self.zero && self.width.is_none() && self.precision.is_none()
+    }
+
+    /// Render an item with these options, falling back to its plain rendering for items without traits to format.
+    #[must_use]
+    #[inline]
+    pub fn render(&self, item: &FormattableItem<'_>) -> String {
+        let text = match (item, self.format_type) {
+            (
+                &(FormattableItem::Both(_, debug) | FormattableItem::DebugItem(debug)),
+                FormatType::Debug | FormatType::PrettyDebug,
+            ) => self.debug(debug, self.format_type == FormatType::PrettyDebug),
+            (&(FormattableItem::Both(display, _) | FormattableItem::DisplayItem(display)), _) => self.display(display),
+            (&FormattableItem::DebugItem(debug), _) => self.debug(debug, false),
+            _ => item.render(self.format_type),
+        };
+        self.pad(text)
+    }
+
+    /// Render a value with its `Display` implementation, passing on the sign and precision.
+    fn display(&self, value: &dyn Display) -> String {
+        match (self.plus, self.precision) {
+            (false, None) => format!("{value}"),
+            (true, None) => format!("{value:+}"),
+            (false, Some(precision)) => format!("{value:.precision$}"),
+            (true, Some(precision)) => format!("{value:+.precision$}"),
+        }
+    }
+
+    /// Render a value with its `Debug` implementation, passing on the sign and precision.
+    fn debug(&self, value: &dyn Debug, pretty: bool) -> String {
+        match (pretty, self.plus, self.precision) {
+            (false, false, None) => format!("{value:?}"),
+            (false, true, None) => format!("{value:+?}"),
+            (false, false, Some(precision)) => format!("{value:.precision$?}"),
+            (false, true, Some(precision)) => format!("{value:+.precision$?}"),
+            (true, false, None) => format!("{value:#?}"),
+            (true, true, None) => format!("{value:+#?}"),
+            (true, false, Some(precision)) => format!("{value:#.precision$?}"),
+            (true, true, Some(precision)) => format!("{value:+#.precision$?}"),
+        }
+    }
+
+    /// Pad a rendered value to the minimum width.
+    #[must_use]
+    #[inline]
+    pub(crate) fn pad(&self, text: String) -> String {
+        let Some(width) = self.width else {
+            return text;
+        };
+        let padding = width.saturating_sub(visible_width(&text));
+        if padding == 0 {
+            return text;
+        }
+
+        let numeric = text.trim().parse::<f64>().is_ok();
+        if self.zero && numeric {
+            let sign_len = usize::from(text.starts_with(['+', '-']));
+            let (sign, digits) = text.split_at(sign_len);
+            return format!("{sign}{}{digits}", "0".repeat(padding));
+        }
+
+        let default_align = if numeric { Alignment::Right } else { Alignment::Left };
+        let (left, right) = split_padding(padding, self.align.unwrap_or(default_align));
+        let fill = self.fill.to_string();
+        format!("{}{text}{}", fill.repeat(left), fill.repeat(right))
+    }
+}
+
+/// Parse an alignment character of the `std::fmt` grammar.
+fn align_char(c: char) -> Option<Alignment> {
+    u8::try_from(c).ok().and_then(Alignment::from_suffix)
+}
+
+/// Split the leading digits off some text, parsing them as a number.
+fn leading_number(text: &str) -> (Option<usize>, &str) {
+    let end = text.find(|c: char| !c.is_ascii_digit()).unwrap_or(text.len());
+    let (digits, rest) = text.split_at(end);
+    (digits.parse().ok(), rest)
+}
@@ -0,0 +1,198 @@
+//! Integration with ratatui, behind the `ratatui` feature.
+//!
+//! This module contains the `ColprintWidget` wrapper, which draws a `ColumnFormatter` into a ratatui `Rect`
+//! using `ColumnFormatter::render_rect`, so the columns are fitted to the area's width and clipped or padded
+//! to its height. Styles in the output are mapped to ratatui `Style`s on each cell of the buffer instead of
+//! being written as raw SGR escape sequences, and other escape sequences, such as hyperlinks, are dropped.
+//! Every cell the widget writes is reset first, so styles already in the buffer do not carry over.
+//! A vertical scroll offset shows long output inside a viewport.
+//!
+//! The crate's `Style` and `Color` also convert into their ratatui counterparts, where `Color::White`, ANSI
+//! colour 7, becomes ratatui's `Gray`.
+
+use std::str::Chars;
+
+use ratatui::{
+    buffer::Buffer,
+    layout::Rect,
+    style::{Color as RatatuiColor, Modifier, Style as RatatuiStyle},
+    widgets::Widget,
+};
+
+use crate::{Color, ColumnFormatter, Style, slice::ESCAPE};
+
+/// The ratatui colours of the eight standard ANSI colours, in SGR order.
+const ANSI_COLORS: [RatatuiColor; 8] = [
+    RatatuiColor::Black,
+    RatatuiColor::Red,
+    RatatuiColor::Green,
+    RatatuiColor::Yellow,
+    RatatuiColor::Blue,
+    RatatuiColor::Magenta,
+    RatatuiColor::Cyan,
+    RatatuiColor::Gray,
+];
+
+/// A ratatui widget drawing the output of a `ColumnFormatter` into its area.
+#[derive(Debug, Clone, Copy)]
+pub struct ColprintWidget<'f, 'a> {
+    /// The formatter drawn into the area.
+    formatter: &'f ColumnFormatter<'a>,
+    /// The number of output lines scrolled past at the top of the area.
+    scroll: usize,
+}
+
+impl<'f, 'a> ColprintWidget<'f, 'a> {
+    /// Construct a new `ColprintWidget` drawing the given formatter from its first line.
+    #[must_use]
+    #[inline]
+    pub const fn new(formatter: &'f ColumnFormatter<'a>) -> Self {
+        Self { formatter, scroll: 0 }
+    }
+
+    /// Scroll the output up by `offset` lines, showing the lines from `offset` at the top of the area.
+    #[must_use]
+    #[inline]
+    pub const fn scroll(mut self, offset: usize) -> Self {
+        self.scroll = offset;
+        self
+    }
+}
+
+impl Widget for ColprintWidget<'_, '_> {
+    #[inline]
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let height = usize::from(area.height);
+        let lines = self.formatter.render_rect(usize::from(area.width), self.scroll + height);
+        for (y, line) in (area.y..area.y.saturating_add(area.height)).zip(lines.iter().skip(self.scroll)) {
+            let mut style = RatatuiStyle::new();
+            let mut columns = area.x..area.x.saturating_add(area.width);
+            let mut chars = line.chars();
+            while let Some(ch) = chars.next() {
+                if ch == ESCAPE {
+                    style = apply_escape(&mut chars, style);
+                    continue;
+                }
+                let Some(x) = columns.next() else {
+                    break;
+                };
+                if let Some(cell) = buf.cell_mut((x, y)) {
+                    cell.reset();
+                    cell.set_char(ch).set_style(style);
+                }
+            }
+        }
+    }
+}
+
+/// Consume an escape sequence after its escape character, returning the style after any SGR codes it holds.
+///
+/// Control sequences other than SGR, and operating system commands such as hyperlinks, are skipped.
+#[expect(clippy::single_call_fn, reason = "Keeps the widget's drawing loop readable.")]
+fn apply_escape(chars: &mut Chars<'_>, style: RatatuiStyle) -> RatatuiStyle {
+    match chars.next() {
+        Some('[') => {
+            let mut params = String::new();
+            for ch in chars.by_ref() {
+                if ('@'..='~').contains(&ch) {
+                    return if ch == 'm' { apply_sgr(style, &params) } else { style };
+                }
+                params.push(ch);
+            }
+            style
+        }
+        Some(']') => {
+            // Operating system commands end with a bell or with `ESC \`
+            while let Some(ch) = chars.next() {
+                if ch == '\x07' || (ch == ESCAPE && chars.next() == Some('\\')) {
+                    break;
+                }
+            }
+            style
+        }
+        _ => style,
+    }
+}
+
+/// Apply the codes of an SGR sequence, such as `1;31` or `48;5;196`, to a style.
+#[expect(clippy::single_call_fn, reason = "Keeps escape sequence parsing separate from SGR codes.")]
+fn apply_sgr(mut style: RatatuiStyle, params: &str) -> RatatuiStyle {
+    let mut codes = params.split(';').map(|code| code.parse::<u8>().unwrap_or(0));
+    while let Some(code) = codes.next() {
+        style = match code {
+            0 => RatatuiStyle::new(),
+            1 => style.add_modifier(Modifier::BOLD),
+            2 => style.add_modifier(Modifier::DIM),
+            3 => style.add_modifier(Modifier::ITALIC),
+            4 => style.add_modifier(Modifier::UNDERLINED),
+            22 => style.remove_modifier(Modifier::BOLD | Modifier::DIM),
+            23 => style.remove_modifier(Modifier::ITALIC),
+            24 => style.remove_modifier(Modifier::UNDERLINED),
+            30..=37 => style.fg(ANSI_COLORS[usize::from(code - 30)]),
+            39 => style.fg(RatatuiColor::Reset),
+            40..=47 => style.bg(ANSI_COLORS[usize::from(code - 40)]),
+            49 => style.bg(RatatuiColor::Reset),
+            38 | 48 => {
+                let extended = match codes.next() {
+                    Some(5) => codes.next().map(RatatuiColor::Indexed),
+                    Some(2) => match (codes.next(), codes.next(), codes.next()) {
+                        (Some(red), Some(green), Some(blue)) => Some(RatatuiColor::Rgb(red, green, blue)),
+                        _ => None,
+                    },
+                    _ => None,
+                };
+                match extended {
+                    Some(color) if code == 38 => style.fg(color),
+                    Some(color) => style.bg(color),
+                    None => style,
+                }
+            }
+            _ => style,
+        };
+    }
+    style
+}
+
+impl From<Color> for RatatuiColor {
+    #[inline]
+    fn from(color: Color) -> Self {
+        match color {
+            Color::Black => Self::Black,
+            Color::Red => Self::Red,
+            Color::Green => Self::Green,
+            Color::Yellow => Self::Yellow,
+            Color::Blue => Self::Blue,
+            Color::Magenta => Self::Magenta,
+            Color::Cyan => Self::Cyan,
+            Color::White => Self::Gray,
+            Color::Indexed(index) => Self::Indexed(index),
+            Color::Rgb(red, green, blue) => Self::Rgb(red, green, blue),
+        }
+    }
+}
+
+impl From<Style> for RatatuiStyle {
+    #[inline]
+    fn from(style: Style) -> Self {
+        let mut converted = Self::new();
+        if let Some(fg) = style.fg {
+            converted = converted.fg(fg.into());
+        }
+        if let Some(bg) = style.bg {
+            converted = converted.bg(bg.into());
+        }
+        if style.bold {
+            converted = converted.add_modifier(Modifier::BOLD);
+        }
+        if style.dim {
+            converted = converted.add_modifier(Modifier::DIM);
+        }
+        if style.italic {
+            converted = converted.add_modifier(Modifier::ITALIC);
+        }
+        if style.underline {
+            converted = converted.add_modifier(Modifier::UNDERLINED);
+        }
+        converted
+    }
+}
@@ -0,0 +1,73 @@
+//! Defines the widths a column can be given in the format string.
+//!
+//! This module contains the `Width` enum. A width is either an absolute number of characters,
+//! such as the `40` in `{:?}:40`, or a percentage of the available width, such as the `40%`
+//! in `{:?}:40%`.
+//!
+//! Percentages are resolved at render time, once the width available to the whole line (an
+//! explicit total or maximum width, or else the terminal width) is known. Each is rounded down,
+//! and if together they would leave too little room for the other columns, separators, and
+//! margins, they are all scaled down in proportion so the line still fits. Without any known
+//! width, percentage columns are sized to their content.
+
+/// The width given to a column in the format string.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Width {
+    /// An absolute number of characters.
+    Chars(usize),
+    /// A percentage of the width available to the line.
+    Percent(u8),
+}
+
+impl Width {
+    /// Parse the digits of a width suffix, with an optional trailing `%`.
+    pub(crate) fn parse(text: &str) -> Option<Self> {
+        text.strip_suffix('%').map_or_else(
+            || text.parse().ok().map(Self::Chars),
+            |percent| percent.parse().ok().map(Self::Percent),
+        )
+    }
+
+    /// The absolute number of characters, if this width is one.
+    pub(crate) const fn chars(self) -> Option<usize> {
+        match self {
+            Self::Chars(chars) => Some(chars),
+            Self::Percent(_) => None,
+        }
+    }
+}
+
+/// Resolve the widths of a row of columns, given the width available to the line.
+///
+/// `used` is the width taken by everything except the percentage columns: margins, separators,
+/// padding, and the other columns. Percentages are scaled down together if they would not fit in the rest.
+/// Columns without a width, and percentage columns when no width is available, resolve to `None`.
+#[must_use]
+#[inline]
+pub fn resolve(widths: &[Option<Width>], available_width: Option<usize>, used: usize) -> Vec<Option<usize>> {
+    let Some(available) = available_width else {
+        return widths.iter().map(|width| width.and_then(Width::chars)).collect();
+    };
+
+    let percent_of = |percent: u8| (available * usize::from(percent)).div_euclid(100);
+    let requested: usize = widths
+        .iter()
+        .filter_map(|&width| match width {
+            Some(Width::Percent(percent)) => Some(percent_of(percent)),
+            Some(Width::Chars(_)) | None => None,
+        })
+        .sum();
+    let room = available.saturating_sub(used);
+
+    widths
+        .iter()
+        .map(|&width| {
+            width.map(|resolved| match resolved {
+                Width::Chars(chars) => chars,
+                Width::Percent(percent) if requested > room => (percent_of(percent) * room).div_euclid(requested),
+                Width::Percent(percent) => percent_of(percent),
+            })
+        })
+        .collect()
+}
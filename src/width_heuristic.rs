@@ -0,0 +1,35 @@
+//! Defines how the automatic width of a column is derived from its lines.
+//!
+//! This module contains the `WidthHeuristic` enum, which controls the width of columns without
+//! an explicit width:
+//! - `Max`: Fit the longest line (the default).
+//! - `Percentile`: Fit the given percentile of line lengths, so a few outlying long lines do not
+//!   widen the whole column. The longer lines are truncated, or wrapped under `Overflow::Wrap`.
+//!
+//! Percentiles use the nearest-rank method: the width is the length of the shortest line which is
+//! at least as long as that percentage of the lines. `Percentile(100)` behaves like `Max`.
+
+/// Heuristic deriving a column's automatic width from the lengths of its lines.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum WidthHeuristic {
+    /// Fit the longest line.
+    #[default]
+    Max,
+    /// Fit the given percentile of line lengths, from 0 to 100.
+    Percentile(u8),
+}
+
+impl WidthHeuristic {
+    /// Derive the width of a column from the lengths of its lines.
+    pub(crate) fn width(self, mut lengths: Vec<usize>) -> usize {
+        match self {
+            Self::Max => lengths.into_iter().max().unwrap_or(0),
+            Self::Percentile(percentile) => {
+                lengths.sort_unstable();
+                let rank = (lengths.len() * usize::from(percentile.min(100))).div_ceil(100);
+                lengths.get(rank.saturating_sub(1)).copied().unwrap_or(0)
+            }
+        }
+    }
+}
@@ -0,0 +1,83 @@
+//! Records each step of resolving the column widths of a `ColumnFormatter`.
+//!
+//! This module contains the `WidthResolution` struct. The render and `ColumnFormatter::explain` both resolve
+//! widths through it, so the report of how each width was decided always matches the printed output.
+
+use crate::{column_format::ColumnFormat, width::Width};
+
+/// The widths of the columns after each step of the layout.
+pub struct WidthResolution<'f> {
+    /// The widths given in the format string, resolved against the available width.
+    pub explicit: Vec<Option<usize>>,
+    /// The explicit widths, or else the widths of the content.
+    pub natural: Vec<usize>,
+    /// The separator after each column, after any squeezing.
+    pub separators: Vec<Option<&'f str>>,
+    /// The indices of the columns left after any were dropped.
+    pub visible: Vec<usize>,
+    /// The widths after sharing out the total width.
+    pub distributed: Vec<usize>,
+    /// The final widths, after narrowing columns for wrapping.
+    pub widths: Vec<usize>,
+}
+
+impl WidthResolution<'_> {
+    /// Write a line for each column, describing its requested and resolved width and separator, and a line comparing
+    /// the width of the output with the budget it was fitted within.
+    #[must_use]
+    #[inline]
+    pub fn report(&self, formats: &[ColumnFormat], line_width: usize, budget: Option<(usize, &str)>) -> String {
+        let mut lines: Vec<String> = formats
+            .iter()
+            .enumerate()
+            .take(self.explicit.len())
+            .map(|(idx, format)| {
+                let requested = match format.width {
+                    Some(Width::Chars(chars)) => chars.to_string(),
+                    Some(Width::Percent(percent)) => format!("{percent}%"),
+                    None => "auto".to_owned(),
+                };
+                let resolved = if self.visible.contains(&idx) {
+                    format!("resolved {} ({})", self.widths[idx], self.rules(idx, format).join(", "))
+                } else {
+                    "dropped to fit".to_owned()
+                };
+                let separator = match (format.separator.as_deref(), self.separators[idx]) {
+                    _ if idx + 1 == self.explicit.len() => String::new(),
+                    (Some(given), Some(squeezed)) if given != squeezed => {
+                        format!(", separator {given:?} squeezed to {squeezed:?}")
+                    }
+                    (Some(given), _) => format!(", separator {given:?}"),
+                    (None, _) => ", no separator".to_owned(),
+                };
+                format!(
+                    "column {idx}: {:?}, requested {requested}, {resolved}{separator}\n",
+                    format.format_type
+                )
+            })
+            .collect();
+        lines.push(match budget {
+            Some((width, kind)) => format!("total width {line_width} of {width} ({kind})\n"),
+            None => format!("total width {line_width}, with no width limit\n"),
+        });
+        lines.concat()
+    }
+
+    /// The rules which decided the width of a visible column, in the order they were applied.
+    fn rules(&self, idx: usize, format: &ColumnFormat) -> Vec<&'static str> {
+        let mut rules = vec![match format.width {
+            Some(Width::Percent(_)) if self.explicit[idx].is_some() => "percentage",
+            _ if self.explicit[idx].is_some() => "explicit",
+            _ => "content",
+        }];
+        if self.distributed[idx] > self.natural[idx] {
+            rules.push("shared from total width");
+        } else if self.distributed[idx] < self.natural[idx] {
+            rules.push("shrunk to total width");
+        }
+        if self.widths[idx] < self.distributed[idx] {
+            rules.push("shrunk for wrapping");
+        }
+        rules
+    }
+}
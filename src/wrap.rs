@@ -0,0 +1,85 @@
+//! Word wrapping of lines to a column width.
+//!
+//! Lines are broken at spaces, keeping as many words on each line as fit. Words wider than
+//! the column are placed on lines of their own, then split following the `WrapPolicy`: after
+//! the last break point which fits, or at the column width, unless splitting is switched off.
+//!
+//! Widths are measured in visible characters, so ANSI escape sequences take no space and are never split. When a
+//! styled line is wrapped, each wrapped line which ends with styles in effect is reset, and the next line starts
+//! by replaying them, so padding and neighbouring columns never inherit a style.
+
+use crate::{
+    WrapPolicy,
+    slice::{ESCAPE, sgr_state, split, strip_escapes, visible_width},
+    style::RESET,
+};
+
+/// Wrap a line into lines no wider than the width, in characters, following the policy for long words.
+#[expect(clippy::single_call_fn, reason = "Keeps the wrapping rules in one module.")]
+#[must_use]
+#[inline]
+pub fn wrap(line: &str, width: usize, policy: WrapPolicy) -> Vec<String> {
+    if width == 0 || visible_width(line) <= width {
+        return vec![line.to_owned()];
+    }
+
+    let mut lines = Vec::new();
+    let mut current = String::new();
+    let mut current_len = 0;
+    for word in line.split(' ') {
+        let mut rest = word;
+        let mut word_len = visible_width(word);
+
+        // Start a new line if the word does not fit after the current one
+        if current_len > 0 && current_len + 1 + word_len > width {
+            lines.push(current);
+            current = String::new();
+            current_len = 0;
+        }
+        if current_len > 0 {
+            current.push(' ');
+            current_len += 1;
+        }
+
+        // Split words wider than the column, preferably after a break point
+        while policy.break_long_words && word_len > width - current_len {
+            let room = width - current_len;
+            let cut = strip_escapes(rest)
+                .chars()
+                .take(room)
+                .collect::<Vec<char>>()
+                .iter()
+                .rposition(|ch| policy.break_points.contains(ch))
+                .map_or(room, |pos| pos + 1);
+            let (head, tail) = split(rest, cut);
+            current.push_str(head);
+            lines.push(current);
+            current = String::new();
+            current_len = 0;
+            rest = tail;
+            word_len -= cut;
+        }
+        current_len += word_len;
+        current.push_str(rest);
+    }
+    lines.push(current);
+
+    if line.contains(ESCAPE) {
+        balance_styles(&mut lines);
+    }
+    lines
+}
+
+/// Reset the styles in effect at the end of each wrapped line, and replay them at the start of the next.
+#[expect(clippy::single_call_fn, reason = "Keeps style tracking apart from the wrapping rules.")]
+fn balance_styles(lines: &mut [String]) {
+    let mut state = String::new();
+    for line in lines {
+        let next_state = sgr_state(&state, line);
+        line.insert_str(0, &state);
+        if !next_state.is_empty() {
+            line.push_str(RESET);
+        }
+        state = next_state;
+    }
+}
@@ -0,0 +1,41 @@
+//! Defines how words wider than their column are wrapped.
+//!
+//! This module contains the `WrapPolicy` struct, which configures word wrapping under
+//! `Overflow::Wrap`. Lines are always broken at spaces first, and a word which does not fit
+//! after the words before it moves to a line of its own, so tokens such as URLs and paths are
+//! only split when they alone are wider than the column. The policy decides what happens then:
+//! - `break_long_words`: Whether such words are split at all, or left whole to be truncated.
+//! - `break_points`: Characters after which a split is preferred, such as the `/` of a path.
+//!
+//! Words are split after the last break point which fits on the line, or at the column width
+//! when none does.
+
+/// Configuration of the splitting of words wider than their column.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct WrapPolicy {
+    /// Whether words wider than the column are split across lines, rather than left whole.
+    pub break_long_words: bool,
+    /// Characters after which words wider than the column are preferably split.
+    pub break_points: &'static [char],
+}
+
+impl WrapPolicy {
+    /// Construct a new `WrapPolicy`.
+    #[must_use]
+    #[inline]
+    pub const fn new(break_long_words: bool, break_points: &'static [char]) -> Self {
+        Self {
+            break_long_words,
+            break_points,
+        }
+    }
+}
+
+impl Default for WrapPolicy {
+    /// Split long words, preferably after the `/`, `?`, `&`, and `-` of URLs and paths.
+    #[inline]
+    fn default() -> Self {
+        Self::new(true, &['/', '?', '&', '-'])
+    }
+}
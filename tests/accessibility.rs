@@ -0,0 +1,64 @@
+//! Tests for announcing omitted content in plain text with `Accessibility::Verbose`.
+
+use colprint::{Accessibility, ColorChoice, ColorScale, ColumnFormatter, FormattableItem, Overflow, Style, Table};
+
+/// The rendered lines, without trailing padding.
+fn lines(text: &str) -> Vec<String> {
+    text.lines().map(|line| line.trim_end().to_owned()).collect()
+}
+
+#[test]
+fn truncated_cells_end_with_an_annotation() {
+    let text = "the quick brown fox jumps";
+    let rendered = ColumnFormatter::new("{}:20|", [&text])
+        .with_accessibility(Accessibility::Verbose)
+        .to_string();
+    assert_eq!(lines(&rendered), ["the quic [truncated]"]);
+    assert_eq!(rendered.lines().next().map(str::len), Some(20));
+}
+
+#[test]
+fn capped_cells_announce_the_omitted_lines() {
+    let text = (1..=15).map(|n| n.to_string()).collect::<Vec<_>>().join("\n");
+    let rendered = ColumnFormatter::new("{}", [&text])
+        .with_max_lines_per_cell(3)
+        .with_accessibility(Accessibility::Verbose)
+        .to_string();
+    assert_eq!(lines(&rendered), ["1", "2", "[13 lines omitted]"]);
+}
+
+#[test]
+fn hidden_columns_are_announced() {
+    let (a, b) = ("a".repeat(10), "b".repeat(10));
+    let rendered = ColumnFormatter::new("{}!2 {}!1", [&a, &b])
+        .with_max_width(12)
+        .with_overflow(Overflow::DropColumns { notice: false })
+        .with_accessibility(Accessibility::Verbose)
+        .to_string();
+    assert_eq!(lines(&rendered), ["aaaaaaaaaa", "[column 1 hidden]"]);
+}
+
+#[test]
+fn tables_announce_collapsed_columns_by_header() {
+    let (name, notes) = ("Alice", "");
+    let mut table = Table::new("{} | {}")
+        .with_headers(["name", "notes"])
+        .collapse_empty_columns(true)
+        .with_accessibility(Accessibility::Verbose);
+    table.push_row(&[FormattableItem::from(&name), FormattableItem::from(&notes)]);
+    assert_eq!(lines(&table.to_string()), ["name", "Alice", "[column 'notes' hidden]"]);
+}
+
+#[test]
+fn colour_signals_gain_textual_markers() {
+    let values = [0, 5, 10];
+    let mut table = Table::new("{}")
+        .heatmap_column(0, ColorScale::GREEN_TO_RED)
+        .style_rows(|_, row| (row[0] == "10").then(|| Style::new().dim()))
+        .with_color(ColorChoice::Never)
+        .with_accessibility(Accessibility::Verbose);
+    for value in &values {
+        table.push_row(&[FormattableItem::from(value)]);
+    }
+    assert_eq!(lines(&table.to_string()), ["0 [0%]", "5 [50%]", "[highlighted] 10 [100%]"]);
+}
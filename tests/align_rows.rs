@@ -0,0 +1,49 @@
+//! Tests for aligning lines of the first two columns with `ColumnFormatter::align_rows`.
+
+use colprint::{ColumnFormatter, FormatError, FormattableItem};
+
+/// Format keys next to a taller listing, aligning the given pairs of lines.
+fn aligned(pairs: &[(usize, usize)]) -> Result<Vec<String>, FormatError> {
+    let keys = "foo\nbar";
+    let dump = "Config {\n    foo: 1,\n    baz: 2,\n    bar: 3,\n}";
+    let formatter = ColumnFormatter::new(
+        "{} | {}",
+        [FormattableItem::DisplayItem(&keys), FormattableItem::DisplayItem(&dump)],
+    )
+    .align_rows(pairs)?;
+    Ok(formatter.to_string().lines().map(|line| line.trim_end().to_owned()).collect())
+}
+
+#[test]
+fn pairs_land_on_the_same_row() {
+    let lines = aligned(&[(1, 3), (0, 1)]).unwrap();
+    assert_eq!(
+        lines,
+        [
+            "    | Config {",
+            "foo |     foo: 1,",
+            "    |     baz: 2,",
+            "bar |     bar: 3,",
+            "    | }",
+        ]
+    );
+}
+
+#[test]
+fn right_column_can_be_pushed_down() {
+    let lines = aligned(&[(1, 0)]).unwrap();
+    assert_eq!(lines.get(1).map(String::as_str), Some("bar | Config {"));
+    assert_eq!(lines.len(), 6);
+}
+
+#[test]
+fn crossing_pairs_are_rejected() {
+    assert_eq!(
+        aligned(&[(0, 3), (1, 1)]),
+        Err(FormatError::CrossingRows {
+            first: (0, 3),
+            second: (1, 1)
+        })
+    );
+    assert!(aligned(&[(0, 1), (0, 2)]).is_err());
+}
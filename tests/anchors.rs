@@ -0,0 +1,69 @@
+//! Tests for anchoring columns at an offset with `{@n}` and `with_anchor`, under each `AnchorPolicy`.
+
+use colprint::{AnchorPolicy, ColumnFormatter, EnvConfig, FormattableItem};
+
+/// A formatter for the given format string and items, independent of the environment.
+fn formatter<'a>(format_str: &str, items: &[&'a &'a str]) -> ColumnFormatter<'a> {
+    ColumnFormatter::new(format_str, items.iter().map(|&item| FormattableItem::from(item))).with_env(&EnvConfig::empty())
+}
+
+#[test]
+fn anchored_columns_start_at_their_offset() {
+    assert_eq!(formatter("{} {@8}", &[&"key", &"value"]).to_string(), "key     value\n");
+    assert_eq!(formatter("{}|{@6}", &[&"ab", &"x"]).to_string(), "ab|   x\n");
+}
+
+#[test]
+fn anchors_follow_the_type_and_width_of_the_column() {
+    assert_eq!(formatter("{} {:?@8}", &[&"key", &"value"]).to_string(), "key     \"value\"\n");
+    assert_eq!(formatter("{} {@8}:3", &[&"key", &"value"]).to_string(), "key     val\n");
+}
+
+#[test]
+fn every_line_of_a_row_is_anchored() {
+    assert_eq!(
+        formatter("{} {@6}", &[&"a\nlonger", &"1\n2"]).to_string(),
+        "a      1\nlonger 2\n"
+    );
+}
+
+#[test]
+fn anchors_are_measured_from_the_margin() {
+    let anchored = formatter("{} {@8}", &[&"key", &"value"]).with_margin_left(2);
+    assert_eq!(anchored.to_string(), "  key     value\n");
+}
+
+#[test]
+fn builder_anchors_override_the_format_string() {
+    let anchored = formatter("{} {@8}", &[&"key", &"value"]).with_anchor(1, 5);
+    assert_eq!(anchored.to_string(), "key  value\n");
+    let added = formatter("{} {}", &[&"key", &"value"]).with_anchor(1, 6);
+    assert_eq!(added.to_string(), "key   value\n");
+}
+
+#[test]
+fn push_starts_passed_columns_after_the_earlier_content() {
+    let pushed = formatter("{} {@4}", &[&"a long key", &"value"]);
+    assert_eq!(pushed.to_string(), "a long key value\n");
+    assert_eq!(
+        pushed.with_anchor_policy(AnchorPolicy::Push).to_string(),
+        "a long key value\n"
+    );
+}
+
+#[test]
+fn truncate_cuts_the_earlier_content_back_to_the_anchor() {
+    let truncated = formatter("{} {@4}", &[&"a long key", &"value"]).with_anchor_policy(AnchorPolicy::Truncate);
+    assert_eq!(truncated.to_string(), "a lovalue\n");
+}
+
+#[test]
+fn policies_only_apply_to_passed_anchors() {
+    let lines = [&"a long key\nk", &"1\n2"];
+    let pushed = formatter("{} {@4}", &lines).to_string();
+    let truncated = formatter("{} {@4}", &lines)
+        .with_anchor_policy(AnchorPolicy::Truncate)
+        .to_string();
+    assert_eq!(pushed, "a long key 1\nk          2\n");
+    assert_eq!(truncated, "a lo1\nk   2\n");
+}
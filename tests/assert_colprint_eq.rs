@@ -0,0 +1,55 @@
+//! Tests for comparing rendered output with `assert_colprint_eq!`.
+
+use std::panic;
+
+use colprint::{ColumnFormatter, FormattableItem, Table, assert_colprint_eq};
+
+/// The panic message of a failed assertion.
+fn failure(check: impl FnOnce() + panic::UnwindSafe) -> String {
+    let payload = panic::catch_unwind(check).unwrap_err();
+    payload.downcast_ref::<String>().cloned().unwrap_or_default()
+}
+
+#[test]
+fn equal_output_passes() {
+    let formatter = ColumnFormatter::new("{} | {}", [&"alpha\nbeta", &"1"]);
+    assert_colprint_eq!(formatter, "alpha | 1\nbeta  |  ");
+    let mut table = Table::new("{} {}");
+    table.push_row(&[FormattableItem::from(&"key"), FormattableItem::from(&"value")]);
+    assert_colprint_eq!(table, "key value");
+}
+
+#[test]
+fn changed_lines_are_marked_with_a_caret() {
+    let message = failure(|| {
+        let formatter = ColumnFormatter::new("{} | {}", [&"alpha\nbeta", &"1\n2"]);
+        assert_colprint_eq!(formatter, "alpha | 1\nbeta  | 3");
+    });
+    assert_eq!(
+        message,
+        "assertion failed: rendered output matches expected\n--- expected\n+++ actual\n  alpha | 1\n- beta  | 3\n+ beta  | 2\n          ^\n"
+    );
+}
+
+#[test]
+fn trailing_whitespace_is_called_out() {
+    let message = failure(|| {
+        let formatter = ColumnFormatter::new("{} | {}", [&"alpha\nbeta", &"1"]);
+        assert_colprint_eq!(formatter, "alpha | 1\nbeta  |");
+    });
+    assert!(message.contains("- beta  |\n+ beta  |··\n         ^ (only trailing whitespace differs)\n"));
+}
+
+#[test]
+fn tabs_and_escape_codes_are_called_out() {
+    let message = failure(|| assert_colprint_eq!("a\tb", "a b"));
+    assert!(message.contains("- a b\n+ a→b\n   ^ (tabs differ from spaces)\n"));
+    let message = failure(|| assert_colprint_eq!("\x1b[1mbold\x1b[0m", "bold"));
+    assert!(message.contains("- bold\n+ ␛[1mbold␛[0m\n  ^ (only ANSI escape codes differ)\n"));
+}
+
+#[test]
+fn missing_lines_are_counted() {
+    let message = failure(|| assert_colprint_eq!("a\nb", "a\nx\nb"));
+    assert!(message.ends_with("  a\n- x\n  b\nexpected 3 lines, found 2\n"));
+}
@@ -0,0 +1,69 @@
+//! Tests for right-aligning columns of numbers automatically with `Table::auto_align`.
+
+use colprint::{Alignment, OutputStyle, Table};
+
+/// A table of names, amounts, and a column mixing words and numbers, with a non-numeric header.
+fn table() -> Table {
+    Table::from_columns(
+        "{} | {} | {}",
+        (["apples", "pears", "plums"], ["1,200", "3.5", "75%"], ["many", "few", "12"]),
+    )
+    .with_headers(["Fruit", "Amount", "Count"])
+}
+
+#[test]
+fn numeric_columns_are_right_aligned() {
+    let rendered = table().auto_align(true).to_string();
+    let lines: Vec<&str> = rendered.lines().collect();
+    assert_eq!(
+        lines,
+        [
+            "Fruit  | Amount | Count",
+            "apples |  1,200 | many ",
+            "pears  |    3.5 | few  ",
+            "plums  |    75% | 12   ",
+        ]
+    );
+}
+
+#[test]
+fn auto_align_is_off_by_default() {
+    let rendered = table().to_string();
+    assert!(rendered.contains("apples | 1,200  | many"), "{rendered}");
+}
+
+#[test]
+fn threshold_is_configurable() {
+    let rendered = table().auto_align_threshold(0.3).to_string();
+    assert!(rendered.contains("plums  |    75% |    12"), "{rendered}");
+}
+
+#[test]
+fn explicit_alignment_wins() {
+    let from_suffix = Table::from_columns("{}=< | {}", (["1", "22"], ["3", "44"]))
+        .auto_align(true)
+        .to_string();
+    assert_eq!(from_suffix, "1  |  3\n22 | 44\n");
+
+    let from_builder = Table::from_columns("{} | {}", (["1", "22"], ["3", "44"]))
+        .align_data(1, Alignment::Center)
+        .auto_align(true)
+        .to_string();
+    assert_eq!(from_builder, " 1 | 3 \n22 | 44\n");
+}
+
+#[test]
+fn empty_cells_are_ignored() {
+    let rendered = Table::from_columns("{} |", (["1", "", "300"],)).auto_align(true).to_string();
+    assert_eq!(rendered, "  1\n   \n300\n");
+}
+
+#[test]
+fn markdown_alignment_follows_detection() {
+    let rendered = Table::from_columns("{} | {}", (["a", "b"], ["1", "2"]))
+        .with_headers(["k", "v"])
+        .with_style(OutputStyle::Markdown)
+        .auto_align(true)
+        .to_string();
+    assert!(rendered.contains("--:"), "{rendered}");
+}
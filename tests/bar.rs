@@ -0,0 +1,72 @@
+//! Tests for progress bar columns with `{:bar}`.
+
+use colprint::{BarGlyphs, ColumnFormatter, FormattableItem, Overflow, colformat};
+
+#[test]
+fn fractions_fill_the_bar() {
+    assert_eq!(colformat!("{:bar}", 0.6), "[██████----]  60%\n");
+    assert_eq!(colformat!("{:bar}", 0.0), "[----------]   0%\n");
+}
+
+#[test]
+fn percentages_use_the_scale() {
+    assert_eq!(colformat!("{:bar(100)}", 25), colformat!("{:bar}", 0.25));
+    assert_eq!(colformat!("{:bar(100)}", 25_u8), "[███-------]  25%\n");
+}
+
+#[test]
+fn out_of_range_values_clamp() {
+    assert_eq!(colformat!("{:bar}", 1.5), "[██████████] 100%\n");
+    assert_eq!(colformat!("{:bar}", -0.5_f32), "[----------]   0%\n");
+}
+
+#[test]
+fn nan_is_a_question_mark() {
+    assert_eq!(colformat!("{:bar}", f64::NAN), "[       ?       ]\n");
+    assert_eq!(colformat!("{:bar}:7", f64::NAN), "[  ?  ]\n");
+}
+
+#[test]
+fn bars_fill_explicit_widths() {
+    assert_eq!(colformat!("{:bar}:12|{}", 0.4, "x"), "[██---]  40%|x\n");
+    assert_eq!(colformat!("{:bar}:6|{}", 0.5, "x"), "[██--]|x\n");
+    assert_eq!(colformat!("{:bar}:2|{}", 0.5, "x"), "█-|x\n");
+    assert_eq!(colformat!("{:bar}:1|{}", 1.0, "x"), "█|x\n");
+    assert_eq!(colformat!("{:bar}:0|{}", 1.0, "x"), "|x\n");
+}
+
+/// A name followed by a bar half full, laid out without a width.
+fn named_bar() -> ColumnFormatter<'static> {
+    ColumnFormatter::new(
+        "{} {:bar}",
+        [FormattableItem::DisplayItem(&"name"), FormattableItem::BarItem(0.5)],
+    )
+}
+
+#[test]
+fn total_widths_resize_the_bar() {
+    assert_eq!(named_bar().to_string(), "name [█████-----]  50%\n");
+    assert_eq!(named_bar().with_total_width(28).to_string(), "name           [███---]  50%\n");
+}
+
+#[test]
+fn narrowed_layouts_resize_the_bar() {
+    let output = named_bar()
+        .with_max_width(16)
+        .with_overflow(Overflow::Wrap { min_width: 1 })
+        .to_string();
+    assert_eq!(output, "name [██--]  50%\n");
+}
+
+#[test]
+fn glyphs_are_configurable() {
+    let output = ColumnFormatter::new("{:bar}", [FormattableItem::BarItem(0.3)])
+        .with_bar_glyphs(0, BarGlyphs::new('=', ' ', '<', '>'))
+        .to_string();
+    assert_eq!(output, "<===       >  30%\n");
+}
+
+#[test]
+fn non_numbers_get_a_placeholder() {
+    assert_eq!(colformat!("{:bar}", "full"), "<not a number>\n");
+}
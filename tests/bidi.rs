@@ -0,0 +1,69 @@
+//! Tests for aligning and truncating right-to-left and mixed-direction text with `with_bidi`.
+#![cfg(feature = "bidi")]
+
+use colprint::{ColumnFormatter, EnvConfig, FormattableItem};
+
+/// `shalom` in Hebrew, four right-to-left characters.
+const SHALOM: &str = "\u{5e9}\u{5dc}\u{5d5}\u{5dd}";
+
+/// Render one column of the given width and format, with or without bidirectional fitting, then a separator.
+fn render(format_str: &str, text: &str, bidi: bool) -> String {
+    ColumnFormatter::new(format_str, [FormattableItem::from(&text), FormattableItem::from(&"x")])
+        .with_env(&EnvConfig::empty())
+        .with_bidi(bidi)
+        .to_string()
+}
+
+/// Wrap text in a first-strong isolate.
+fn isolated(text: &str) -> String {
+    format!("\u{2068}{text}\u{2069}")
+}
+
+#[test]
+fn right_to_left_lines_mirror_their_alignment() {
+    assert_eq!(render("{}:8|{}", SHALOM, true), format!("    {}|x\n", isolated(SHALOM)));
+    assert_eq!(render("{}:8=>|{}", SHALOM, true), format!("{}    |x\n", isolated(SHALOM)));
+    assert_eq!(render("{}:8=^|{}", SHALOM, true), format!("  {}  |x\n", isolated(SHALOM)));
+}
+
+#[test]
+fn left_to_right_lines_are_fitted_as_usual() {
+    assert_eq!(render("{}:8|{}", "hello", true), "hello   |x\n");
+    assert_eq!(render("{}:8=>|{}", "hello", true), "   hello|x\n");
+}
+
+#[test]
+fn mixed_lines_follow_their_first_strong_character() {
+    // Right-to-left first: mirrored
+    let rtl_first = format!("{SHALOM} ok");
+    assert_eq!(
+        render("{}:10|{}", &rtl_first, true),
+        format!("   {}|x\n", isolated(&rtl_first))
+    );
+
+    // Left-to-right first: aligned as usual, but still isolated from the separators
+    let ltr_first = format!("ok {SHALOM}");
+    assert_eq!(
+        render("{}:10|{}", &ltr_first, true),
+        format!("{}   |x\n", isolated(&ltr_first))
+    );
+}
+
+#[test]
+fn truncation_keeps_the_logical_start_of_right_to_left_lines() {
+    let long = format!("{SHALOM}{SHALOM}");
+    // The cut lands at the logical end, which is displayed on the left
+    assert_eq!(
+        render("{}:5|{}", &long, true),
+        format!("{}|x\n", isolated("\u{5e9}\u{5dc}\u{5d5}\u{5dd}\u{5e9}"))
+    );
+    assert_eq!(
+        render("{}:5~m|{}", &long, true),
+        format!("{}|x\n", isolated("\u{5e9}\u{5dc}\u{2026}\u{5d5}\u{5dd}"))
+    );
+}
+
+#[test]
+fn without_bidi_right_to_left_lines_are_fitted_in_logical_order() {
+    assert_eq!(render("{}:8|{}", SHALOM, false), format!("{SHALOM}    |x\n"));
+}
@@ -0,0 +1,86 @@
+//! Tests for the lines added below a cell to match a taller neighbour, across alignment, fill, and `fill_blank_lines`.
+
+use colprint::{ColumnFormatter, FormattableItem, Table};
+
+/// Render a short cell in a six-wide column with the given alignment beside a three-line cell.
+fn rendered(alignment: char, fill: char, fill_blank_lines: bool) -> Vec<String> {
+    let short = "ab";
+    let tall = "x\ny\nz";
+    let format = format!("{{}}:6={alignment}|{{}}");
+    ColumnFormatter::new(&format, [&short, &tall])
+        .with_fill(0, fill)
+        .fill_blank_lines(fill_blank_lines)
+        .to_string()
+        .lines()
+        .map(str::to_owned)
+        .collect()
+}
+
+#[test]
+fn left_with_spaces() {
+    assert_eq!(rendered('<', ' ', false), ["ab    |x", "      |y", "      |z"]);
+    assert_eq!(rendered('<', ' ', true), ["ab    |x", "      |y", "      |z"]);
+}
+
+#[test]
+fn right_with_spaces() {
+    assert_eq!(rendered('>', ' ', false), ["    ab|x", "      |y", "      |z"]);
+    assert_eq!(rendered('>', ' ', true), ["    ab|x", "      |y", "      |z"]);
+}
+
+#[test]
+fn center_with_spaces() {
+    assert_eq!(rendered('^', ' ', false), ["  ab  |x", "      |y", "      |z"]);
+    assert_eq!(rendered('^', ' ', true), ["  ab  |x", "      |y", "      |z"]);
+}
+
+#[test]
+fn left_with_fill_only_fills_content_lines() {
+    assert_eq!(rendered('<', '.', false), ["ab....|x", "      |y", "      |z"]);
+}
+
+#[test]
+fn right_with_fill_only_fills_content_lines() {
+    assert_eq!(rendered('>', '.', false), ["....ab|x", "      |y", "      |z"]);
+}
+
+#[test]
+fn center_with_fill_only_fills_content_lines() {
+    assert_eq!(rendered('^', '.', false), ["..ab..|x", "      |y", "      |z"]);
+}
+
+#[test]
+fn left_with_fill_fills_blank_lines_when_set() {
+    assert_eq!(rendered('<', '.', true), ["ab....|x", "......|y", "......|z"]);
+}
+
+#[test]
+fn right_with_fill_fills_blank_lines_when_set() {
+    assert_eq!(rendered('>', '.', true), ["....ab|x", "......|y", "......|z"]);
+}
+
+#[test]
+fn center_with_fill_fills_blank_lines_when_set() {
+    assert_eq!(rendered('^', '.', true), ["..ab..|x", "......|y", "......|z"]);
+}
+
+#[test]
+fn empty_lines_inside_a_cell_are_content() {
+    let gappy = "a\n\nb";
+    let short = "c";
+    let output = ColumnFormatter::new("{}:3|{}:3", [&gappy, &short])
+        .with_fill(1, '.')
+        .to_string();
+    assert_eq!(output, "a  |c..\n   |   \nb  |   \n");
+}
+
+#[test]
+fn tables_fill_blank_lines_only_when_set() {
+    let render = |fill_blank_lines: bool| {
+        let mut table = Table::new("{}:4|{}").with_fill(0, '-').fill_blank_lines(fill_blank_lines);
+        table.push_row(&[FormattableItem::from(&"ab"), FormattableItem::from(&"x\ny")]);
+        table.to_string()
+    };
+    assert_eq!(render(false), "ab--|x\n    |y\n");
+    assert_eq!(render(true), "ab--|x\n----|y\n");
+}
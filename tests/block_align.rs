@@ -0,0 +1,44 @@
+//! Tests for placing the whole rendered block within a target width with `with_block_align`.
+
+use colprint::{BlockAlign, ColumnFormatter, EnvConfig, FormattableItem};
+
+/// Render a two-line block, eight characters wide, aligned within the target width.
+fn render(block_align: BlockAlign, block_width: usize) -> String {
+    let (names, values) = ("ab\nc", "xyz");
+    ColumnFormatter::new("{} | {}", [FormattableItem::from(&names), FormattableItem::from(&values)])
+        .with_env(&EnvConfig::empty())
+        .with_block_align(block_align)
+        .with_block_width(block_width)
+        .to_string()
+}
+
+#[test]
+fn left_blocks_stay_against_the_left_edge() {
+    assert_eq!(render(BlockAlign::Left, 12), "ab | xyz\nc  |    \n");
+    assert_eq!(BlockAlign::default(), BlockAlign::Left);
+}
+
+#[test]
+fn centered_blocks_split_the_free_width() {
+    assert_eq!(render(BlockAlign::Center, 12), "  ab | xyz\n  c  |    \n");
+}
+
+#[test]
+fn centered_blocks_round_towards_the_left() {
+    assert_eq!(render(BlockAlign::Center, 11), " ab | xyz\n c  |    \n");
+}
+
+#[test]
+fn right_blocks_end_at_the_right_edge() {
+    let output = render(BlockAlign::Right, 12);
+    assert_eq!(output, "    ab | xyz\n    c  |    \n");
+    assert!(output.lines().all(|line| line.chars().count() == 12));
+}
+
+#[test]
+fn blocks_wider_than_the_target_are_left_where_they_are() {
+    for block_align in [BlockAlign::Center, BlockAlign::Right] {
+        assert_eq!(render(block_align, 8), "ab | xyz\nc  |    \n");
+        assert_eq!(render(block_align, 4), "ab | xyz\nc  |    \n");
+    }
+}
@@ -0,0 +1,61 @@
+//! Tests for `FormattableItem::Both`, which lets each column's format type choose between `Display` and `Debug`.
+
+use std::fmt::{self, Display, Formatter};
+
+use colprint::{ColumnFormatter, FormattableItem, ToDisplayItem as _, ToFormattableItem as _};
+
+/// A point with distinct `Display` and `Debug` representations.
+#[derive(Debug)]
+struct Point {
+    x: i32,
+    y: i32,
+}
+
+impl Display for Point {
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> fmt::Result {
+        write!(fmt, "({}, {})", self.x, self.y)
+    }
+}
+
+#[test]
+fn the_column_format_type_chooses_the_representation() {
+    let point = Point { x: 1, y: 2 };
+    let items = vec![FormattableItem::Both(&point, &point), FormattableItem::Both(&point, &point)];
+    assert_eq!(
+        ColumnFormatter::new("{} | {:?}", items).to_string(),
+        "(1, 2) | Point { x: 1, y: 2 }\n"
+    );
+}
+
+#[test]
+fn pretty_debug_columns_use_the_pretty_representation() {
+    let point = Point { x: 1, y: 2 };
+    let items = vec![FormattableItem::Both(&point, &point)];
+    let output = ColumnFormatter::new("{:#?}", items).to_string();
+    let lines: Vec<&str> = output.lines().map(str::trim_end).collect();
+    assert_eq!(lines, ["Point {", "    x: 1,", "    y: 2,", "}"]);
+}
+
+#[test]
+fn humanized_columns_fall_back_to_display() {
+    let point = Point { x: 1, y: 2 };
+    let items = vec![FormattableItem::Both(&point, &point), FormattableItem::Both(&point, &point)];
+    assert_eq!(
+        ColumnFormatter::new("{:dur} | {:size}", items).to_string(),
+        "(1, 2) | (1, 2)\n"
+    );
+}
+
+#[test]
+fn to_formattable_item_wraps_both_representations() {
+    let point = Point { x: 1, y: 2 };
+    let items = vec![
+        point.to_formattable_item(),
+        point.to_formattable_item(),
+        point.to_display_item(),
+    ];
+    assert_eq!(
+        ColumnFormatter::new("{} | {:?} | {:?}", items).to_string(),
+        "(1, 2) | Point { x: 1, y: 2 } | (1, 2)\n"
+    );
+}
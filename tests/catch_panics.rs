@@ -0,0 +1,61 @@
+use std::fmt::{Debug, Display, Formatter, Result as FmtResult};
+
+use colprint::{ColumnFormatter, FormattableItem, Table};
+
+/// A value whose `Display` implementation panics when it is negative.
+#[derive(Debug)]
+struct Fragile(i32);
+
+impl Display for Fragile {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        assert!(self.0 >= 0, "negative value");
+        write!(f, "{}", self.0)
+    }
+}
+
+fn item(value: &Fragile) -> FormattableItem<'_> {
+    FormattableItem::Both(value as &dyn Display, value as &dyn Debug)
+}
+
+#[test]
+fn formatter_renders_error_cell() {
+    let good = Fragile(1);
+    let bad = Fragile(-1);
+    let formatter = ColumnFormatter::new("{} | {}", [item(&good), item(&bad)]).catch_panics(true);
+    assert_eq!(formatter.to_string(), "1 | <format panicked: negative value>\n");
+    assert_eq!(formatter.panicked_cells(), 1);
+}
+
+#[test]
+fn formatter_counts_only_latest_render() {
+    let good = Fragile(1);
+    let formatter = ColumnFormatter::new("{} | {}", [item(&good), item(&good)]).catch_panics(true);
+    let _rendered = formatter.to_string();
+    assert_eq!(formatter.panicked_cells(), 0);
+}
+
+#[test]
+#[should_panic(expected = "negative value")]
+fn formatter_unwinds_by_default() {
+    let bad = Fragile(-1);
+    let _rendered = ColumnFormatter::new("{}", [item(&bad)]).to_string();
+}
+
+#[test]
+fn table_keeps_other_rows() {
+    let values = [Fragile(1), Fragile(-2), Fragile(3)];
+    let mut table = Table::new("{} |").catch_panics(true);
+    for value in &values {
+        table.push_row(&[item(value)]);
+    }
+    assert_eq!(
+        table.to_string(),
+        [
+            "1                                \n",
+            "<format panicked: negative value>\n",
+            "3                                \n"
+        ]
+        .concat()
+    );
+    assert_eq!(table.panicked_cells(), 1);
+}
@@ -0,0 +1,54 @@
+//! Tests for laying out cells rendered in advance with `Cell`.
+
+use colprint::{Cell, ColumnFormatter, FormatType, FormattableItem, Table, colformat};
+
+/// A value with distinct `Display` and `Debug` output.
+#[derive(Debug)]
+struct Point {
+    x: i32,
+    y: i32,
+}
+
+impl std::fmt::Display for Point {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({}, {})", self.x, self.y)
+    }
+}
+
+/// A cell of the pretty `Debug` output of a point.
+fn pretty() -> Cell {
+    Cell::render(&FormattableItem::from(&Point { x: 1, y: 2 }), FormatType::PrettyDebug)
+}
+
+#[test]
+fn renders_lines_and_width() {
+    let cell = pretty();
+    assert_eq!(cell.lines(), ["Point {", "    x: 1,", "    y: 2,", "}"]);
+    assert_eq!(cell.width(), 9);
+}
+
+#[test]
+fn is_placed_as_rendered_whatever_the_column() {
+    let cell = pretty();
+    let label = "point";
+    let once = ColumnFormatter::new("{} | {}", [FormattableItem::from(&label), FormattableItem::from(&cell)]);
+    let again = ColumnFormatter::new("{} | {:?}", [FormattableItem::from(&label), FormattableItem::from(&cell)]);
+    assert_eq!(once.to_string(), again.to_string());
+    assert_eq!(once.to_string().lines().next(), Some("point | Point {  "));
+}
+
+#[test]
+fn can_be_used_in_tables_and_macros() {
+    let cell = Cell::from_text("a\nbb");
+    let mut table = Table::new("{}|{}");
+    table.push_row(&[FormattableItem::from(&cell), FormattableItem::from(&"x")]);
+    assert_eq!(table.to_string(), "a |x\nbb| \n");
+
+    assert_eq!(colformat!("{}|{}", cell, "x"), "a |x\nbb| \n");
+}
+
+#[test]
+fn is_clone_and_send() {
+    fn assert_clone_send<T: Clone + Send>(_: &T) {}
+    assert_clone_send(&pretty());
+}
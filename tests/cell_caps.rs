@@ -0,0 +1,28 @@
+//! Tests for capping the width and height of cells before layout.
+
+use colprint::{ColumnFormatter, EnvConfig, FormattableItem, RenderOptions};
+
+#[test]
+fn long_lines_are_cut_at_the_cell_width() {
+    let wide = "x".repeat(100_000);
+    let formatter =
+        ColumnFormatter::new("{}|{}", [FormattableItem::from(&wide), FormattableItem::from(&"z")]).with_max_cell_width(8);
+    assert_eq!(formatter.to_string(), "xxxxxxxx|z\n");
+}
+
+#[test]
+fn tall_cells_keep_their_first_lines() {
+    let tall = "y\n".repeat(100_000);
+    let formatter = ColumnFormatter::new("{}|{}", [FormattableItem::from(&tall), FormattableItem::from(&"z")])
+        .with_env(&EnvConfig::empty())
+        .with_max_lines_per_cell(2);
+    assert_eq!(formatter.to_string(), "y|z\ny| \n");
+}
+
+#[test]
+fn default_caps_are_generous() {
+    let wide = "x".repeat(5000);
+    assert_eq!(ColumnFormatter::new("{}", [&wide]).measure().width, 4096);
+    let frozen = ColumnFormatter::new("{}", [&wide]).with_options(RenderOptions::v1());
+    assert_eq!(frozen.measure().width, 5000);
+}
@@ -0,0 +1,77 @@
+//! Tests for checking rendered output against a maximum width.
+
+use std::path::Path;
+
+use colprint::{ColorChoice, ColumnFormatter, FitError, FormattableItem, Table, assert_fits};
+
+/// A formatter of two lines, 13 characters wide.
+fn formatter() -> ColumnFormatter<'static> {
+    ColumnFormatter::new(
+        "{} | {}",
+        [
+            FormattableItem::DisplayItem(&"alpha\nbeta"),
+            FormattableItem::DisplayItem(&"1\n12345"),
+        ],
+    )
+}
+
+#[test]
+fn reports_the_first_overflowing_line() {
+    let err = formatter().check_fits(10).unwrap_err();
+    assert_eq!((err.line, err.width, err.max_width), (1, 13, 10));
+    assert_eq!(
+        err.to_string(),
+        "line 1 is 13 characters wide, exceeding the maximum of 10:\nalpha | 1    \n          ^"
+    );
+    assert_eq!(formatter().check_fits(13), Ok(()));
+}
+
+#[test]
+fn escape_sequences_take_no_width() {
+    let styled = "\x1b[1mbold\x1b[0m";
+    let formatter = ColumnFormatter::new("{:raw}", [FormattableItem::Verbatim(styled)]);
+    assert_eq!(formatter.check_fits(4), Ok(()));
+    assert_fits!(formatter, 4);
+}
+
+#[test]
+fn tables_can_be_checked() {
+    let mut table = Table::new("{} {}");
+    table.push_row(&[FormattableItem::from(&"key"), FormattableItem::from(&"value")]);
+    assert_fits!(table, 9);
+    assert!(matches!(table.check_fits(8), Err(FitError { line: 1, .. })));
+}
+
+#[test]
+#[should_panic(expected = "line 1 is 13 characters wide")]
+fn assert_fits_panics_on_overflow() {
+    assert_fits!(formatter(), 12);
+}
+
+#[test]
+fn hyperlinks_take_no_width() {
+    let linked = "\x1b]8;;file:///tmp/notes.txt\x1b\\notes\x1b]8;;\x1b\\";
+    let formatter = ColumnFormatter::new("{:raw} | {}", [FormattableItem::Verbatim(linked), FormattableItem::from(&1)]);
+    assert_eq!(formatter.measure().width, 9);
+    assert_eq!(formatter.check_fits(9), Ok(()));
+}
+
+#[test]
+fn hyperlinks_ended_by_bel_take_no_width() {
+    let linked = "\x1b]8;;file:///tmp/notes.txt\x07notes\x1b]8;;\x07";
+    let formatter = ColumnFormatter::new("{:raw}", [FormattableItem::Verbatim(linked)]);
+    assert_eq!(formatter.measure().width, 5);
+}
+
+#[test]
+fn linkified_tables_fit_their_plain_width() {
+    let base = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let mut table = Table::new("{} | {}")
+        .linkify_paths(0, Some(base))
+        .with_color(ColorChoice::Always);
+    table.push_row(&[FormattableItem::from(&"src/lib.rs"), FormattableItem::from(&1)]);
+    let output = table.to_string();
+    assert!(output.contains("\x1b]8;;file://"));
+    assert_eq!(table.check_fits(14), Ok(()));
+    assert!(table.check_fits(13).is_err());
+}
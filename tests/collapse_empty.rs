@@ -0,0 +1,71 @@
+//! Tests for leaving out columns whose every cell is blank with `collapse_empty_columns`.
+
+use colprint::{FormattableItem, Table};
+
+/// A three-column table whose middle column is blank in every row, collapsing blank columns or not.
+fn table(collapse: bool) -> Table {
+    let rows = [("Alice", "", "30"), ("Bob", "  ", "25")];
+    let mut table = Table::new("{} | {} | {}")
+        .with_headers(["name", "notes", "age"])
+        .collapse_empty_columns(collapse);
+    for (name, notes, age) in &rows {
+        table.push_row(&[
+            FormattableItem::from(name),
+            FormattableItem::from(notes),
+            FormattableItem::from(age),
+        ]);
+    }
+    table
+}
+
+/// The rendered lines, without trailing padding.
+fn lines(output: &str) -> Vec<String> {
+    output.lines().map(|line| line.trim_end().to_owned()).collect()
+}
+
+#[test]
+fn blank_columns_are_kept_by_default() {
+    assert_eq!(
+        lines(&table(false).to_string()),
+        ["name  | notes | age", "Alice |       | 30", "Bob   |       | 25"]
+    );
+}
+
+#[test]
+fn blank_columns_lose_their_header_and_separator() {
+    assert_eq!(lines(&table(true).to_string()), ["name  | age", "Alice | 30", "Bob   | 25"]);
+}
+
+#[test]
+fn columns_with_any_content_are_kept() {
+    let (name, notes) = ("Carol", "late");
+    let mut table = table(true);
+    table.push_row(&[FormattableItem::from(&name), FormattableItem::from(&notes)]);
+    assert_eq!(
+        lines(&table.to_string()),
+        [
+            "name  | notes | age",
+            "Alice |       | 30",
+            "Bob   |       | 25",
+            "Carol | late  |",
+        ]
+    );
+}
+
+#[test]
+fn filtered_renders_collapse_by_the_rows_they_keep() {
+    let (name, notes, age) = ("Carol", "late", "41");
+    let mut table = table(true);
+    table.push_row(&[
+        FormattableItem::from(&name),
+        FormattableItem::from(&notes),
+        FormattableItem::from(&age),
+    ]);
+
+    // The only non-blank note is filtered out, so the column collapses
+    let without_carol = table.render_filtered(|row| row[0] != "Carol");
+    assert_eq!(lines(&without_carol), ["name  | age", "Alice | 30", "Bob   | 25"]);
+
+    let only_carol = table.render_filtered(|row| row[0] == "Carol");
+    assert_eq!(lines(&only_carol), ["name  | notes | age", "Carol | late  | 41"]);
+}
@@ -0,0 +1,38 @@
+//! Tests for deciding whether styles are emitted, including consoles which refuse escape sequences.
+
+use colprint::ColorChoice;
+use std::cell::Cell;
+
+#[test]
+fn auto_falls_back_to_plain_text_when_the_terminal_refuses_styles() {
+    assert!(!ColorChoice::Auto.enabled_with(false, true, || false));
+    assert!(ColorChoice::Auto.enabled_with(false, true, || true));
+}
+
+#[test]
+fn auto_only_prepares_the_terminal_when_writing_to_it() {
+    let calls = Cell::new(0);
+    let enable = || {
+        calls.set(calls.get() + 1);
+        true
+    };
+    assert!(!ColorChoice::Auto.enabled_with(true, true, enable));
+    assert!(!ColorChoice::Auto.enabled_with(false, false, enable));
+    assert_eq!(calls.get(), 0);
+}
+
+#[test]
+fn always_styles_even_when_the_terminal_refuses_them() {
+    let calls = Cell::new(0);
+    let refuse = || {
+        calls.set(calls.get() + 1);
+        false
+    };
+    assert!(ColorChoice::Always.enabled_with(true, false, refuse));
+    assert_eq!(calls.get(), 1);
+}
+
+#[test]
+fn never_styles_and_leaves_the_terminal_alone() {
+    assert!(!ColorChoice::Never.enabled_with(false, true, || panic!("the terminal was prepared")));
+}
@@ -0,0 +1,62 @@
+//! Tests for printing key/value pairs with dotted leaders using `colprint_kv!`.
+//!
+//! The macro prints straight to standard output, so each test runs itself again in a child process and reads what
+//! the child printed.
+
+use std::{env, process::Command};
+
+use colprint::colprint_kv;
+
+/// Set in the child process, which prints instead of checking the output.
+const CHILD: &str = "COLPRINT_KV_CHILD";
+
+/// Run the named test in a child process without any `COLPRINT_*` configuration, returning its standard output.
+fn printed_by(test: &str) -> String {
+    let mut command = Command::new(env::current_exe().expect("the test binary has a path"));
+    command
+        .args([test, "--exact", "--nocapture", "--test-threads=1"])
+        .env(CHILD, "1");
+    for (name, _) in env::vars_os() {
+        if name.to_string_lossy().starts_with("COLPRINT_") {
+            command.env_remove(name);
+        }
+    }
+    let output = command.output().expect("the test binary runs");
+    String::from_utf8_lossy(&output.stdout).into_owned()
+}
+
+#[test]
+fn keys_are_followed_by_dotted_leaders() {
+    if env::var_os(CHILD).is_some() {
+        colprint_kv!([("host", "example.com"), ("port", 8080), ("user", "admin")]);
+        return;
+    }
+    let output = printed_by("keys_are_followed_by_dotted_leaders");
+    assert!(
+        output.contains("host ... example.com\nport ... 8080       \nuser ... admin      \n"),
+        "{output:?}"
+    );
+}
+
+#[test]
+fn leaders_reach_past_the_widest_key() {
+    if env::var_os(CHILD).is_some() {
+        colprint_kv!([("id", 7), ("hostname", "db1")]);
+        return;
+    }
+    let output = printed_by("leaders_reach_past_the_widest_key");
+    assert!(output.contains("id ......... 7  \nhostname ... db1\n"), "{output:?}");
+}
+
+#[test]
+fn values_take_the_given_specifier() {
+    if env::var_os(CHILD).is_some() {
+        colprint_kv!("{:?}", [("name", "Alice"), ("tags", vec!["a", "b"])]);
+        return;
+    }
+    let output = printed_by("values_take_the_given_specifier");
+    assert!(
+        output.contains("name ... \"Alice\"   \ntags ... [\"a\", \"b\"]\n"),
+        "{output:?}"
+    );
+}
@@ -0,0 +1,71 @@
+//! Tests for layouts parsed once with `ColumnTemplate` and reused across call sites.
+
+use std::{sync::LazyLock, thread, time::Duration};
+
+use colprint::{ColumnTemplate, FormatError, colformat, colformat_with};
+
+/// A layout shared by every test, as call sites would share it.
+static TEMPLATE: LazyLock<ColumnTemplate> = LazyLock::new(|| {
+    ColumnTemplate::parse("{:>8}:8 | {}:6 | {:.2}:6")
+        .unwrap()
+        .with_headers(["Count", "Name", "Ratio"])
+});
+
+#[test]
+fn rows_match_the_format_string() {
+    assert_eq!(
+        colformat_with!(TEMPLATE, 42, "answer", 0.5),
+        colformat!("{:>8}:8 | {}:6 | {:.2}:6", 42, "answer", 0.5)
+    );
+}
+
+#[test]
+fn methods_match_the_macro() {
+    assert_eq!(TEMPLATE.format([&"a", &"b", &"c"]), colformat_with!(TEMPLATE, "a", "b", "c"));
+}
+
+#[test]
+fn columns_ask_for_their_representation() {
+    let template = ColumnTemplate::parse("{:dur} | {:?}").unwrap();
+    assert_eq!(
+        colformat_with!(template, Duration::from_secs(90), "x"),
+        colformat!("{:dur} | {:?}", Duration::from_secs(90), "x")
+    );
+}
+
+#[test]
+fn headers_take_the_column_widths_and_ignore_value_options() {
+    assert_eq!(TEMPLATE.header(), "Count    | Name   | Ratio \n");
+    assert_eq!(TEMPLATE.headers().map(<[String]>::len), Some(3));
+}
+
+#[test]
+fn templates_without_headers_format_no_header() {
+    assert_eq!(ColumnTemplate::parse("{}").unwrap().header(), "");
+}
+
+#[test]
+fn formatters_lay_out_items_like_the_template() {
+    assert_eq!(
+        TEMPLATE.formatter([&"a", &"b", &"c"]).to_string(),
+        TEMPLATE.format([&"a", &"b", &"c"])
+    );
+    assert_eq!(TEMPLATE.format([&"a", &"b", &"c"]), "       a | b      | c     \n");
+}
+
+#[test]
+fn invalid_format_strings_are_rejected() {
+    assert_eq!(
+        ColumnTemplate::parse("{} | {:?").unwrap_err(),
+        FormatError::UnterminatedSpec { offset: 5 }
+    );
+}
+
+#[test]
+fn templates_are_shared_between_threads() {
+    let rows: Vec<String> = (0..4)
+        .map(|idx| thread::spawn(move || colformat_with!(TEMPLATE, idx, "row", 1.0)))
+        .map(|handle| handle.join().unwrap())
+        .collect();
+    assert_eq!(rows[3], "       3 | row    | 1.00  \n");
+}
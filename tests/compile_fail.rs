@@ -0,0 +1,6 @@
+//! Tests that items which do not fit their column of a literal format string fail to compile.
+
+#[test]
+fn values_which_cannot_be_humanized_are_rejected() {
+    trybuild::TestCases::new().compile_fail("tests/ui/*.rs");
+}
@@ -0,0 +1,63 @@
+//! Tests for columns left with little or no room for content once their truncation marker is drawn.
+
+use colprint::{ColumnFormatter, EnvConfig, Overflow, Style, TruncateSide, WrapPolicy};
+
+/// Format a long line in a column of the given width, cut on the given side with a two-character marker.
+fn truncated(width: usize, side: TruncateSide) -> String {
+    ColumnFormatter::new(&format!("{{}}:{width}|{{}}"), [&"abcdef", &"x"])
+        .with_env(&EnvConfig::empty())
+        .with_truncate_side(0, side)
+        .with_truncate_marker("..")
+        .to_string()
+}
+
+#[test]
+fn empty_columns_show_nothing() {
+    for side in [TruncateSide::Left, TruncateSide::Middle, TruncateSide::Right] {
+        assert_eq!(truncated(0, side), "|x\n");
+    }
+}
+
+#[test]
+fn columns_narrower_than_the_marker_keep_the_same_side() {
+    assert_eq!(truncated(1, TruncateSide::Left), "f|x\n");
+    assert_eq!(truncated(1, TruncateSide::Middle), "a|x\n");
+    assert_eq!(truncated(1, TruncateSide::Right), "a|x\n");
+}
+
+#[test]
+fn columns_as_wide_as_the_marker_show_only_the_marker() {
+    assert_eq!(truncated(2, TruncateSide::Left), "..|x\n");
+    assert_eq!(truncated(2, TruncateSide::Middle), "..|x\n");
+}
+
+#[test]
+fn one_character_of_content_follows_the_marker() {
+    assert_eq!(truncated(3, TruncateSide::Left), "..f|x\n");
+    assert_eq!(truncated(3, TruncateSide::Middle), "a..|x\n");
+}
+
+#[test]
+fn styled_lines_narrower_than_the_marker_draw_no_marker_style() {
+    let output = ColumnFormatter::new("{:raw}:1|{}", [&"\x1b[1mbold\x1b[0m", &"x"])
+        .with_truncate_side(0, TruncateSide::Left)
+        .with_truncate_marker("..")
+        .with_truncate_marker_style(Style::new().dim())
+        .to_string();
+    assert!(!output.contains("\x1b[2m"), "{output:?}");
+    assert!(output.ends_with("|x\n"), "{output:?}");
+}
+
+#[test]
+fn wrapping_keeps_room_for_the_marker() {
+    let output = ColumnFormatter::new("{}|{}", [&"abc defgh", &"ijk lmn"])
+        .with_max_width(3)
+        .with_overflow(Overflow::Wrap { min_width: 0 })
+        .with_truncate_side(0, TruncateSide::Left)
+        .with_truncate_marker("..")
+        .with_wrap_policy(WrapPolicy::new(false, &[]))
+        .to_string();
+    for line in output.lines() {
+        assert_eq!(line.split('|').next().map(str::len), Some(2), "{output:?}");
+    }
+}
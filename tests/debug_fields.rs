@@ -0,0 +1,101 @@
+//! Tests for dumping `Debug` values as tables of fields with `debug_fields`.
+
+use std::collections::BTreeMap;
+
+use colprint::debug_fields;
+
+#[expect(dead_code, reason = "Only read through Debug.")]
+#[derive(Debug)]
+struct Inner {
+    x: i32,
+    label: &'static str,
+}
+
+#[expect(dead_code, reason = "Only read through Debug.")]
+#[derive(Debug)]
+struct Outer {
+    id: u8,
+    inner: Inner,
+    tags: Vec<&'static str>,
+}
+
+#[expect(dead_code, reason = "Only read through Debug.")]
+#[derive(Debug)]
+struct Point(i32, i32);
+
+#[expect(dead_code, reason = "Only read through Debug.")]
+#[derive(Debug)]
+enum Shape {
+    Circle { radius: f64 },
+    Square(u32),
+    Empty,
+}
+
+/// The lines of a dump, without their trailing padding.
+fn lines(dump: &str) -> Vec<&str> {
+    dump.lines().map(str::trim_end).collect()
+}
+
+#[test]
+fn structs_are_titled_by_their_name() {
+    let dump = debug_fields(&Inner { x: 1, label: "one" });
+    assert_eq!(lines(&dump), ["Inner", "x      1", "label  \"one\""]);
+}
+
+#[test]
+fn nested_structures_are_multi_line_cells() {
+    let outer = Outer {
+        id: 7,
+        inner: Inner { x: -2, label: "a: {b}" },
+        tags: vec!["t"],
+    };
+    assert_eq!(
+        lines(&debug_fields(&outer)),
+        [
+            "Outer",
+            "id     7",
+            "inner  Inner {",
+            "           x: -2,",
+            "           label: \"a: {b}\",",
+            "       }",
+            "tags   [",
+            "           \"t\",",
+            "       ]",
+        ]
+    );
+}
+
+#[test]
+fn tuple_structs_are_named_by_index() {
+    assert_eq!(lines(&debug_fields(&Point(3, -4))), ["Point", "0  3", "1  -4"]);
+}
+
+#[test]
+fn enum_variants_are_titled_by_the_variant() {
+    assert_eq!(
+        lines(&debug_fields(&Shape::Circle { radius: 1.5 })),
+        ["Circle", "radius  1.5"]
+    );
+    assert_eq!(lines(&debug_fields(&Shape::Square(2))), ["Square", "0  2"]);
+}
+
+#[test]
+fn maps_use_their_keys() {
+    let map = BTreeMap::from([("a: 1", 1), ("b", 2)]);
+    assert_eq!(lines(&debug_fields(&map)), ["\"a: 1\"  1", "\"b\"     2"]);
+}
+
+#[test]
+fn other_values_are_dumped_whole() {
+    assert_eq!(debug_fields(&Shape::Empty), "Empty\n");
+    assert_eq!(debug_fields(&42), "42\n");
+    assert_eq!(debug_fields(&[1, 2]), format!("{:#?}\n", [1, 2]));
+    assert_eq!(debug_fields("text"), "\"text\"\n");
+}
+
+#[test]
+fn empty_structs_have_only_a_title() {
+    #[derive(Debug)]
+    struct Empty {}
+    assert_eq!(debug_fields(&Empty {}), "Empty\n");
+}
@@ -0,0 +1,36 @@
+//! Tests for laying out items which implement only `Debug`, or which do not fit their column.
+
+use colprint::colformat;
+
+/// A type implementing only `Debug`.
+#[derive(Debug)]
+#[expect(dead_code, reason = "Only read through Debug.")]
+struct Point(i32, i32);
+
+/// A type implementing neither `Display` nor `Debug`.
+struct Opaque;
+
+#[test]
+fn debug_only_items_fit_debug_columns() {
+    assert_eq!(
+        colformat!("{:?} | {:>14?}", Point(1, 2), Point(3, 4)),
+        "Point(1, 2) |    Point(3, 4)\n"
+    );
+}
+
+#[test]
+fn runtime_format_strings_fall_back_when_items_do_not_fit() {
+    let format_str = String::from("{} | {} | {:dur}");
+    assert_eq!(
+        colformat!(format_str.as_str(), Point(1, 2), Opaque, "soon"),
+        "Point(1, 2) | <not formattable> | <not a duration>\n"
+    );
+}
+
+#[test]
+fn items_past_the_last_column_are_left_out() {
+    assert_eq!(colformat!("{} | {}", 1, 2, Point(1, 2), Opaque), "1 | 2\n");
+
+    let format_str = "{} | {}";
+    assert_eq!(colformat!(format_str, 1, 2, Point(1, 2), Opaque), "1 | 2\n");
+}
@@ -0,0 +1,35 @@
+//! Tests for separating adjacent columns with `ColumnFormatter::with_default_gap`.
+
+use colprint::ColumnFormatter;
+
+#[test]
+fn columns_are_jammed_together_by_default() {
+    let (a, b) = ("ab", "cd");
+    assert_eq!(ColumnFormatter::new("{}{}", [&a, &b]).to_string(), "abcd\n");
+}
+
+#[test]
+fn gap_separates_adjacent_columns() {
+    let (a, b, c) = ("ab", "cd", "ef");
+    let output = ColumnFormatter::new("{}{}{}", [&a, &b, &c]).with_default_gap(2).to_string();
+    assert_eq!(output, "ab  cd  ef\n");
+}
+
+#[test]
+fn explicit_separators_are_kept() {
+    let (a, b, c) = ("ab", "cd", "ef");
+    let output = ColumnFormatter::new("{} | {}{}", [&a, &b, &c])
+        .with_default_gap(1)
+        .to_string();
+    assert_eq!(output, "ab | cd ef\n");
+}
+
+#[test]
+fn no_gap_keeps_columns_concatenated() {
+    let (a, b, c) = ("ab", "cd", "ef");
+    let output = ColumnFormatter::new("{}{}{}", [&a, &b, &c])
+        .with_no_gap(0)
+        .with_default_gap(2)
+        .to_string();
+    assert_eq!(output, "abcd  ef\n");
+}
@@ -0,0 +1,60 @@
+//! Tests for the machine-parsable `OutputStyle::Delimited` style.
+
+use colprint::{FormatError, OutputStyle, Strictness, Table};
+
+/// A table of names and notes in the given delimited style.
+fn table(delimiter: &'static str) -> Table {
+    Table::from_columns("{} | {}", (vec!["Alice", "Bob"], vec!["short", "a much longer note"]))
+        .with_headers(["Name", "Note"])
+        .with_style(OutputStyle::Delimited { delimiter })
+}
+
+#[test]
+fn fields_are_joined_without_padding() {
+    assert_eq!(table("\t").to_string(), "Name\tNote\nAlice\tshort\nBob\ta much longer note\n");
+}
+
+#[test]
+fn multi_line_cells_are_flattened() {
+    let table = Table::from_columns("{} {}", (vec!["one\ntwo"], vec![1])).with_style(OutputStyle::Delimited { delimiter: ";" });
+    assert_eq!(table.to_string(), "one\\ntwo;1\n");
+    let joined = Table::from_columns("{} {}", (vec!["one\ntwo"], vec![1]))
+        .with_style(OutputStyle::Delimited { delimiter: ";" })
+        .with_line_join(" / ");
+    assert_eq!(joined.to_string(), "one / two;1\n");
+}
+
+#[test]
+fn lenient_tables_replace_the_delimiter_in_cells() {
+    let table = Table::from_columns("{} {}", (vec!["a;b"], vec!["c d"]));
+    assert_eq!(
+        table.with_style(OutputStyle::Delimited { delimiter: ";" }).to_string(),
+        "a b;c d\n"
+    );
+    let table = Table::from_columns("{} {}", (vec!["a;b"], vec!["c d"]));
+    assert_eq!(
+        table.with_style(OutputStyle::Delimited { delimiter: " " }).to_string(),
+        "a;b c_d\n"
+    );
+}
+
+#[test]
+fn strict_tables_reject_cells_containing_the_delimiter() {
+    let table = Table::from_columns("{} {}", (vec!["a", "b"], vec!["c", "d|e"]))
+        .with_style(OutputStyle::Delimited { delimiter: "|" })
+        .with_strictness(Strictness::Strict);
+    let mut output = Vec::new();
+    assert_eq!(table.write_to(&mut output), Err(FormatError::DelimiterInCell { column: 1 }));
+    assert!(output.is_empty());
+
+    let mut output = Vec::new();
+    table.with_strictness(Strictness::Lenient).write_to(&mut output).unwrap();
+    assert_eq!(output, b"a|c\nb|d e\n");
+}
+
+#[test]
+fn strict_tables_write_clean_cells() {
+    let mut output = Vec::new();
+    table("\t").with_strictness(Strictness::Strict).write_to(&mut output).unwrap();
+    assert_eq!(String::from_utf8(output).unwrap(), table("\t").to_string());
+}
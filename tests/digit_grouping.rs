@@ -0,0 +1,28 @@
+//! Tests for the `n` digit grouping flag, and for format names which end in `n`.
+
+use colprint::ColumnFormatter;
+
+#[test]
+fn n_groups_the_digits_of_plain_specifiers() {
+    assert_eq!(ColumnFormatter::new("{:n}", [&1234567]).to_string(), "1,234,567\n");
+    assert_eq!(ColumnFormatter::new("{:?n}", [&1234567]).to_string(), "1,234,567\n");
+}
+
+#[test]
+fn n_groups_after_std_fmt_options() {
+    assert_eq!(ColumnFormatter::new("{:.2n}", [&1234.5]).to_string(), "1,234.50\n");
+}
+
+#[test]
+fn registered_names_ending_in_n_are_kept_whole() {
+    let output = ColumnFormatter::new("{:json} | {:bin}", [&42, &5])
+        .register_format("json", |text| format!("{{\"v\":{text}}}"))
+        .register_format("bin", |text| format!("{:b}", text.parse::<u8>().unwrap_or(0)))
+        .to_string();
+    assert_eq!(output, "{\"v\":42} | 101\n");
+}
+
+#[test]
+fn unregistered_names_ending_in_n_are_not_grouped() {
+    assert_eq!(ColumnFormatter::new("{:json}", [&1234567]).to_string(), "1234567\n");
+}
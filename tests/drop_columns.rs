@@ -0,0 +1,51 @@
+//! Tests for hiding the lowest-priority columns with `Overflow::DropColumns`.
+
+use colprint::{ColumnFormatter, EnvConfig, Overflow};
+
+/// Format three columns of eight characters, with the given priority suffixes, within the maximum width.
+fn dropped(format_str: &str, max_width: usize, notice: bool) -> String {
+    ColumnFormatter::new(format_str, [&"one", &"two", &"three"])
+        .with_env(&EnvConfig::empty())
+        .with_overflow(Overflow::DropColumns { notice })
+        .with_max_width(max_width)
+        .to_string()
+}
+
+#[test]
+fn columns_which_fit_are_all_kept() {
+    assert_eq!(dropped("{}:5 | {}:5 | {}:5", 80, true), "one   | two   | three\n");
+}
+
+#[test]
+fn the_rightmost_column_is_dropped_on_ties() {
+    assert_eq!(dropped("{}:5 | {}:5 | {}:5", 14, false), "one   | two  \n");
+}
+
+#[test]
+fn the_lowest_priority_column_is_dropped_first() {
+    assert_eq!(dropped("{}:5!1 | {}:5 | {}:5!2", 14, false), "one   | three\n");
+    assert_eq!(dropped("{}:5!1 | {}:5 | {}:5!2", 6, false), "three\n");
+}
+
+#[test]
+fn priorities_can_be_set_by_builder() {
+    let output = ColumnFormatter::new("{}:5 | {}:5 | {}:5", [&"one", &"two", &"three"])
+        .with_env(&EnvConfig::empty())
+        .with_overflow(Overflow::DropColumns { notice: false })
+        .with_max_width(14)
+        .with_priority(1, 3)
+        .with_priority(2, 2)
+        .to_string();
+    assert_eq!(output, "two   | three\n");
+}
+
+#[test]
+fn the_notice_counts_the_hidden_columns() {
+    assert_eq!(dropped("{}:5 | {}:5 | {}:5", 14, true), "one   | two  \n(1 column hidden)\n");
+    assert_eq!(dropped("{}:5 | {}:5 | {}:5", 6, true), "one  \n(2 columns hidden)\n");
+}
+
+#[test]
+fn one_column_is_always_kept() {
+    assert_eq!(dropped("{}:5 | {}:5 | {}:5", 2, false).lines().count(), 1);
+}
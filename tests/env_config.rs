@@ -0,0 +1,192 @@
+//! Tests for reading defaults from `COLPRINT_*` variables, and for keeping output independent of them.
+
+use std::{collections::HashMap, time::Duration};
+
+use colprint::{Accessibility, BarGlyphs, ColorChoice, ColumnFormatter, EnvConfig, FormattableItem, Overflow, RenderOptions};
+
+/// A line of four words, which only wraps under a maximum width.
+const WORDS: &str = "one two three four";
+
+/// Read the defaults from the given variables only.
+fn env(vars: &[(&str, &str)]) -> EnvConfig {
+    let vars: HashMap<String, String> = vars
+        .iter()
+        .map(|&(name, value)| (name.to_owned(), value.to_owned()))
+        .collect();
+    EnvConfig::from_lookup(|name| vars.get(name).cloned())
+}
+
+/// The output of a formatter wrapping its lines to the maximum width in force.
+fn wrapped(formatter: ColumnFormatter<'_>) -> String {
+    formatter.with_overflow(Overflow::Wrap { min_width: 4 }).to_string()
+}
+
+#[test]
+fn no_variables_give_the_empty_defaults() {
+    assert_eq!(env(&[]), EnvConfig::empty());
+    assert_eq!(EnvConfig::empty().truncate_marker, "\u{2026}");
+}
+
+#[test]
+fn width_is_parsed_and_trimmed() {
+    assert_eq!(env(&[("COLPRINT_WIDTH", " 40 ")]).width, Some(40));
+    assert_eq!(env(&[("COLPRINT_WIDTH", "wide")]).width, None);
+    assert_eq!(env(&[("COLPRINT_WIDTH", "")]).width, None);
+}
+
+#[test]
+fn color_is_parsed_case_insensitively() {
+    assert_eq!(env(&[("COLPRINT_COLOR", "Always")]).color, Some(ColorChoice::Always));
+    assert_eq!(env(&[("COLPRINT_COLOR", "never")]).color, Some(ColorChoice::Never));
+    assert_eq!(env(&[("COLPRINT_COLOR", "auto")]).color, Some(ColorChoice::Auto));
+    assert_eq!(env(&[("COLPRINT_COLOR", "sometimes")]).color, None);
+}
+
+#[test]
+fn accessibility_is_parsed() {
+    assert_eq!(
+        env(&[("COLPRINT_ACCESSIBILITY", "VERBOSE")]).accessibility,
+        Some(Accessibility::Verbose)
+    );
+    assert_eq!(
+        env(&[("COLPRINT_ACCESSIBILITY", "visual")]).accessibility,
+        Some(Accessibility::Visual)
+    );
+    assert_eq!(env(&[("COLPRINT_ACCESSIBILITY", "loud")]).accessibility, None);
+}
+
+#[test]
+fn truncate_marker_follows_the_ascii_flag() {
+    assert_eq!(env(&[("COLPRINT_TRUNCATE_MARKER", ">")]).truncate_marker, ">");
+    assert_eq!(env(&[("COLPRINT_ASCII", "1")]).truncate_marker, "...");
+    assert_eq!(env(&[("COLPRINT_ASCII", "0")]).truncate_marker, "\u{2026}");
+    assert_eq!(
+        env(&[("COLPRINT_ASCII", "1"), ("COLPRINT_TRUNCATE_MARKER", "\u{2192}")]).truncate_marker,
+        "..."
+    );
+}
+
+#[test]
+fn injected_defaults_replace_the_environment() {
+    let injected = env(&[("COLPRINT_WIDTH", "12"), ("COLPRINT_TRUNCATE_MARKER", ">")]);
+    let formatter = ColumnFormatter::new("{}:4~m", [&"abcdef"]).with_env(&injected);
+    assert_eq!(formatter.to_string(), "ab>f\n");
+    assert_eq!(
+        wrapped(ColumnFormatter::new("{}", [&WORDS]).with_env(&injected)),
+        "one two     \nthree four  \n"
+    );
+    assert_eq!(
+        wrapped(ColumnFormatter::new("{}", [&WORDS]).with_env(&EnvConfig::empty())),
+        "one two three four\n"
+    );
+}
+
+#[test]
+fn the_frozen_preset_matches_empty_defaults() {
+    let pinned = ColumnFormatter::new("{}:4~m | {}", [&"abcdef", &"x"])
+        .with_options(RenderOptions::v1())
+        .to_string();
+    let empty = ColumnFormatter::new("{}:4~m | {}", [&"abcdef", &"x"])
+        .with_env(&EnvConfig::empty())
+        .with_options(RenderOptions::v1())
+        .to_string();
+    assert_eq!(pinned, empty);
+}
+
+#[test]
+fn the_frozen_preset_undoes_injected_defaults() {
+    let injected = env(&[("COLPRINT_WIDTH", "12"), ("COLPRINT_TRUNCATE_MARKER", ">")]);
+    let formatter = ColumnFormatter::new("{}:4~m", [&"abcdef"])
+        .with_env(&injected)
+        .with_options(RenderOptions::v1());
+    assert_eq!(formatter.to_string(), "ab\u{2026}f\n");
+    let words = ColumnFormatter::new("{}", [&WORDS])
+        .with_env(&injected)
+        .with_options(RenderOptions::v1());
+    assert_eq!(wrapped(words), "one two three four\n");
+}
+
+#[test]
+fn the_frozen_preset_undoes_injected_accessibility() {
+    let injected = env(&[("COLPRINT_ACCESSIBILITY", "verbose")]);
+    let verbose = ColumnFormatter::new("{}:14", [&"abcdefghijklmnop"]).with_env(&injected);
+    assert_eq!(verbose.to_string(), "ab [truncated]\n");
+    let frozen = ColumnFormatter::new("{}:14", [&"abcdefghijklmnop"])
+        .with_env(&injected)
+        .with_options(RenderOptions::v1());
+    assert_eq!(frozen.to_string(), "abcdefghijklmn\n");
+}
+
+#[test]
+fn the_frozen_preset_keeps_explicit_settings_equal_to_the_seeds() {
+    let injected = env(&[("COLPRINT_WIDTH", "12"), ("COLPRINT_TRUNCATE_MARKER", ">")]);
+    let formatter = ColumnFormatter::new("{}:4~m", [&"abcdef"])
+        .with_env(&injected)
+        .with_truncate_marker(">")
+        .with_options(RenderOptions::v1());
+    assert_eq!(formatter.to_string(), "ab>f\n");
+    let words = ColumnFormatter::new("{}", [&WORDS])
+        .with_env(&injected)
+        .with_max_width(12)
+        .with_options(RenderOptions::v1());
+    assert_eq!(wrapped(words), "one two     \nthree four  \n");
+}
+
+#[test]
+fn ascii_is_parsed() {
+    assert!(env(&[("COLPRINT_ASCII", "1")]).ascii);
+    assert!(!env(&[("COLPRINT_ASCII", "0")]).ascii);
+    assert!(!env(&[]).ascii);
+}
+
+#[test]
+fn ascii_replaces_bar_glyphs_and_micro_signs() {
+    let ascii = env(&[("COLPRINT_ASCII", "1")]);
+    let items = [
+        FormattableItem::BarItem(0.6),
+        FormattableItem::DurationItem(Duration::from_micros(3)),
+    ];
+    let formatter = ColumnFormatter::new("{:bar} {:dur}", items.clone()).with_env(&ascii);
+    assert_eq!(formatter.to_string(), "[######----]  60% 3 us\n");
+
+    let plain = ColumnFormatter::new("{:bar} {:dur}", items).with_env(&EnvConfig::empty());
+    assert_eq!(
+        plain.to_string(),
+        "[\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}----]  60% 3 \u{b5}s\n"
+    );
+}
+
+#[test]
+fn ascii_keeps_ascii_bar_glyphs() {
+    let formatter = ColumnFormatter::new("{:bar}", [FormattableItem::BarItem(0.5)])
+        .with_env(&EnvConfig::empty())
+        .with_bar_glyphs(0, BarGlyphs::new('=', '.', '|', '|'))
+        .with_ascii(true);
+    assert_eq!(formatter.to_string(), "|=====.....|  50%\n");
+}
+
+#[test]
+fn ascii_marks_capped_hexdumps_with_the_ascii_marker() {
+    let bytes = [b'x'; 24];
+    let formatter = ColumnFormatter::new("{:hex(8)}", [FormattableItem::Bytes(&bytes)])
+        .with_env(&env(&[("COLPRINT_ASCII", "1")]))
+        .with_max_lines_per_cell(2);
+    assert_eq!(
+        formatter.to_string().lines().last().map(str::trim_end),
+        Some("... (+16 bytes)")
+    );
+}
+
+#[test]
+fn the_frozen_preset_keeps_an_explicit_ascii_choice() {
+    let items = [FormattableItem::DurationItem(Duration::from_micros(3))];
+    let seeded = ColumnFormatter::new("{:dur}", items.clone())
+        .with_env(&env(&[("COLPRINT_ASCII", "1")]))
+        .with_options(RenderOptions::v1());
+    assert_eq!(seeded.to_string(), "3 \u{b5}s\n");
+
+    let explicit = ColumnFormatter::new("{:dur}", items)
+        .with_ascii(true)
+        .with_options(RenderOptions::v1());
+    assert_eq!(explicit.to_string(), "3 us\n");
+}
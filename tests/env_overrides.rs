@@ -0,0 +1,36 @@
+//! Tests that settings given by builders win over the `COLPRINT_*` variables, in a binary of their own so that no
+//! other test runs while the variables are set.
+
+use std::env;
+
+use colprint::{ColumnFormatter, Overflow, RenderOptions};
+
+/// A line of four words, wrapped to the maximum width in force.
+fn wrapped(formatter: ColumnFormatter<'_>) -> String {
+    formatter.with_overflow(Overflow::Wrap { min_width: 4 }).to_string()
+}
+
+#[test]
+fn builder_settings_survive_default_options_while_the_environment_is_set() {
+    // SAFETY: This is the only test in this binary, so no other thread reads the environment while it is changed.
+    unsafe {
+        env::set_var("COLPRINT_WIDTH", "12");
+        env::set_var("COLPRINT_TRUNCATE_MARKER", ">");
+    }
+    let words = "one two three four";
+
+    let given = ColumnFormatter::new("{}:4~m", [&"abcdef"])
+        .with_truncate_marker("~")
+        .with_options(RenderOptions::default());
+    assert_eq!(given.to_string(), "ab~f\n");
+    let wide = ColumnFormatter::new("{}", [&words])
+        .with_max_width(30)
+        .with_options(RenderOptions::default());
+    assert_eq!(wrapped(wide), "one two three four\n");
+
+    // Settings left to the environment still follow it
+    let seeded = ColumnFormatter::new("{}:4~m", [&"abcdef"]).with_options(RenderOptions::default());
+    assert_eq!(seeded.to_string(), "ab>f\n");
+    let narrow = ColumnFormatter::new("{}", [&words]).with_options(RenderOptions::default());
+    assert_eq!(wrapped(narrow), "one two     \nthree four  \n");
+}
@@ -0,0 +1,59 @@
+//! Tests for keeping cells on one line with `escape_newlines`.
+
+use colprint::{ColumnFormatter, FormattableItem, Table};
+
+#[test]
+fn line_breaks_are_written_as_escapes() {
+    let message = "disk full\nretrying";
+    let output = ColumnFormatter::new("{} | {}", [&"warn", &message])
+        .escape_newlines(1, true)
+        .to_string();
+    assert_eq!(output, "warn | disk full\\nretrying\n");
+}
+
+#[test]
+fn carriage_returns_are_escaped_too() {
+    let message = "a\r\nb";
+    let output = ColumnFormatter::new("{}", [&message]).escape_newlines(0, true).to_string();
+    assert_eq!(output, "a\\r\\nb\n");
+}
+
+#[test]
+fn line_breaks_are_kept_by_default() {
+    let message = "disk full\nretrying";
+    let output = ColumnFormatter::new("{} | {}", [&"warn", &message]).to_string();
+    assert_eq!(output, "warn | disk full\n     | retrying \n");
+}
+
+#[test]
+fn only_the_chosen_column_is_escaped() {
+    let left = "a\nb";
+    let right = "c\nd";
+    let output = ColumnFormatter::new("{} | {}", [&left, &right])
+        .escape_newlines(1, true)
+        .to_string();
+    assert_eq!(output, "a | c\\nd\nb |     \n");
+}
+
+#[test]
+fn width_counts_the_escaped_form() {
+    let message = "ab\ncd";
+    let formatter = ColumnFormatter::new("{}", [&message]).escape_newlines(0, true);
+    assert_eq!(formatter.measure().width, 6);
+    assert_eq!(formatter.measure().lines, 1);
+}
+
+#[test]
+fn debug_output_is_unchanged() {
+    let message = "ab\ncd";
+    let escaped = ColumnFormatter::new("{:?}", [&message]).escape_newlines(0, true).to_string();
+    let plain = ColumnFormatter::new("{:?}", [&message]).to_string();
+    assert_eq!(escaped, plain);
+}
+
+#[test]
+fn tables_escape_rows_pushed_after_it_is_set() {
+    let mut table = Table::new("{} | {}").escape_newlines(1, true);
+    table.push_row(&[FormattableItem::from(&"warn"), FormattableItem::from(&"disk full\nretrying")]);
+    assert_eq!(table.to_string(), "warn | disk full\\nretrying\n");
+}
@@ -0,0 +1,60 @@
+//! Tests for the report of how column widths were resolved, from `ColumnFormatter::explain`.
+
+use colprint::{ColumnFormatter, EnvConfig, Overflow, Squeeze};
+
+#[test]
+fn explicit_and_content_widths_are_explained() {
+    let formatter = ColumnFormatter::new("{}:8 | {:?}", [&"name", &"value"]).with_env(&EnvConfig::empty());
+    assert_eq!(
+        formatter.explain(),
+        "column 0: Display, requested 8, resolved 8 (explicit), separator \" | \"\n\
+         column 1: Debug, requested auto, resolved 7 (content)\n\
+         total width 18, with no width limit\n"
+    );
+}
+
+#[test]
+fn fitting_steps_are_explained() {
+    let formatter = ColumnFormatter::new("{}  |  {}", [&"a long cell", &"another long cell"])
+        .with_squeeze(Squeeze::Separators)
+        .with_overflow(Overflow::Wrap { min_width: 4 })
+        .with_max_width(20);
+    assert_eq!(
+        formatter.explain(),
+        "column 0: Display, requested auto, resolved 11 (content), separator \"  |  \" squeezed to \"|\"\n\
+         column 1: Display, requested auto, resolved 8 (content, shrunk for wrapping)\n\
+         total width 20 of 20 (maximum width)\n"
+    );
+}
+
+#[test]
+fn shared_and_dropped_columns_are_explained() {
+    let shared = ColumnFormatter::new("{}|{}:30%", [&"a", &"b"]).with_total_width(10);
+    assert_eq!(
+        shared.explain(),
+        "column 0: Display, requested auto, resolved 6 (content, shared from total width), separator \"|\"\n\
+         column 1: Display, requested 30%, resolved 3 (percentage)\n\
+         total width 10 of 10 (total width)\n"
+    );
+    let dropped = ColumnFormatter::new("{}|{}!0", [&"kept", &"dropped"])
+        .with_overflow(Overflow::DropColumns { notice: false })
+        .with_max_width(6);
+    assert!(
+        dropped
+            .explain()
+            .contains("column 1: Display, requested auto, dropped to fit\n")
+    );
+}
+
+#[test]
+fn the_report_matches_the_render() {
+    let formatter = ColumnFormatter::new("{} | {}", [&"alpha\nbeta", &"1234567"])
+        .with_overflow(Overflow::Wrap { min_width: 2 })
+        .with_max_width(12);
+    let width = formatter.measure().width;
+    assert!(
+        formatter
+            .explain()
+            .ends_with(&format!("total width {width} of 12 (maximum width)\n"))
+    );
+}
@@ -0,0 +1,23 @@
+//! Tests for passing `format_args!` values directly into columns.
+
+use colprint::{colformat, colprint};
+
+#[test]
+fn format_args_fill_a_column() {
+    let host = "example.com";
+    let port = 8080;
+    let output = colformat!("{} | {}", format_args!("{host}:{port}"), "up");
+    assert_eq!(output, "example.com:8080 | up\n");
+}
+
+#[test]
+fn format_args_can_span_lines() {
+    let output = colformat!("{} | {:?}", format_args!("{}\n{}", "first", "second"), 7);
+    assert_eq!(output, "first  | 7\nsecond |  \n");
+}
+
+#[test]
+fn format_args_can_be_printed() {
+    let status = "up";
+    colprint!("{} | {}", format_args!("{}:{}", "localhost", 80), status);
+}
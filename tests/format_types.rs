@@ -0,0 +1,99 @@
+//! Tests that the macros read the column types of a format string the same way `ColumnFormatter` parses it.
+
+use std::iter;
+
+use colprint::{
+    ColumnFormatter, EnvConfig, FormatType, FormattableItem, colformat,
+    macro_support::{column_format_type, format_types},
+};
+
+/// The format type of each column, as parsed by `ColumnFormatter`.
+fn parsed_types(format_str: &str) -> Vec<FormatType> {
+    ColumnFormatter::new(format_str, iter::empty::<FormattableItem<'_>>())
+        .formats()
+        .iter()
+        .map(|format| format.format_type)
+        .collect()
+}
+
+/// Check that every parser, including the one run when the macros are compiled, finds the same columns with the
+/// same types.
+fn assert_agree(format_str: &str) {
+    let parsed = parsed_types(format_str);
+    assert_eq!(format_types(format_str), parsed, "{format_str:?}");
+
+    let constant: Vec<_> = (0..=parsed.len())
+        .map(|column| column_format_type::<256>(format_str, column))
+        .collect();
+    let expected: Vec<_> = parsed.into_iter().map(Some).chain([None]).collect();
+    assert_eq!(constant, expected, "{format_str:?}");
+}
+
+#[test]
+fn plain_specifiers_agree() {
+    assert_agree("{} | {:?} | {:#?}");
+    assert_agree("{:>8.2} | {:x} | {:<6?}");
+}
+
+#[test]
+fn escaped_braces_agree() {
+    assert_agree("{{}} {} | {:?}");
+    assert_agree("{} {{x}} {:?} }}");
+    assert_agree("{{{}}} | {:?}");
+}
+
+#[test]
+fn unterminated_specifiers_agree() {
+    assert_agree("{} | {:?");
+    assert_agree("{:?} | {");
+    assert_agree("{:#?");
+}
+
+#[test]
+fn suffixed_widths_agree() {
+    assert_agree("{}:8 | {:?}:12~m | {:#?}:80");
+    assert_agree("{:?}:6=^ | {}:30% | {:?}:5!2");
+}
+
+#[test]
+fn named_and_grouped_specifiers_agree() {
+    assert_agree("{:raw}:5 | {:dur} | {:size}");
+    assert_agree("{:.2n} | {:?n} | {:json}");
+    assert_agree("{:time(%H:%M)} | {:hex(8)} | {:bar}");
+}
+
+#[test]
+fn whitespace_anchors_and_fills_agree() {
+    assert_agree("{ :? : 40 } | { :#? } | {: >8} | { }");
+    assert_agree("{ :time(%H %M) } | { :dur } | {:lines ?} | { : size }");
+    assert_agree("{:?@40} | {@12} | {:?n} | {:.2n} | {:nn} | {:json}");
+    assert_agree("{:\u{e9}^8?} | {:\u{fc}<3} | {:.99999999999999999999999} | {:?@99999999999999999999999}");
+}
+
+#[test]
+fn long_specifiers_with_whitespace_are_read_when_compiled() {
+    let output = colformat!(
+        "{ :? } | { :time(\
+         %Y-%m-%d %H:%M:%S %Y-%m-%d %H:%M:%S %Y-%m-%d %H:%M:%S %Y-%m-%d %H:%M:%S %Y-%m-%d %H:%M:%S \
+         %Y-%m-%d %H:%M:%S %Y-%m-%d %H:%M:%S %Y-%m-%d %H:%M:%S %Y-%m-%d %H:%M:%S %Y-%m-%d %H:%M:%S \
+         %Y-%m-%d %H:%M:%S %Y-%m-%d %H:%M:%S %Y-%m-%d %H:%M:%S %Y-%m-%d %H:%M:%S %Y-%m-%d %H:%M:%S) }",
+        "a",
+        0
+    );
+    assert!(
+        output.starts_with("\"a\" | 1970-01-01 00:00:00 1970-01-01 00:00:00"),
+        "{output:?}"
+    );
+}
+
+#[test]
+fn macros_render_as_the_formatter_does() {
+    let text = "a{b}";
+    let cases = ["{{}} {} | {:?}", "{}:8 | {:?}:6~m", "{} | {:?"];
+    for format_str in cases {
+        let expected = ColumnFormatter::new(format_str, [FormattableItem::from(&text), FormattableItem::from(&text)])
+            .with_env(&EnvConfig::empty())
+            .to_string();
+        assert_eq!(colformat!(format_str, text, text), expected, "{format_str:?}");
+    }
+}
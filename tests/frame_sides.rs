@@ -0,0 +1,31 @@
+//! Tests for framing every line between a left and a right side with `frame_sides`.
+
+use colprint::{ColumnFormatter, EnvConfig};
+
+#[test]
+fn sides_frame_every_line() {
+    let output = ColumnFormatter::new("{} | {}", [&"a\nb", &"c"])
+        .with_env(&EnvConfig::empty())
+        .frame_sides("| ", " |")
+        .to_string();
+    assert_eq!(output, "| a | c |\n| b |   |\n");
+}
+
+#[test]
+fn total_widths_narrower_than_the_right_side_do_not_panic() {
+    let formatter = ColumnFormatter::new("{} | {}", [&"abc", &"def"])
+        .with_env(&EnvConfig::empty())
+        .frame_sides("[", " <<<<|")
+        .with_total_width(3);
+    let output = formatter.to_string();
+    assert!(output.ends_with(" <<<<|\n"), "{output:?}");
+}
+
+#[test]
+fn total_widths_of_zero_do_not_panic() {
+    let formatter = ColumnFormatter::new("{}", [&"abc"])
+        .with_env(&EnvConfig::empty())
+        .frame_sides("|", "  |")
+        .with_total_width(0);
+    assert_eq!(formatter.to_string().lines().count(), 1);
+}
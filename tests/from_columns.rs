@@ -0,0 +1,41 @@
+use colprint::{FormatError, Table};
+
+#[test]
+fn zips_columns_into_rows() {
+    let names = vec!["Alice".to_owned(), "Bob".to_owned()];
+    let ages = vec![30_u32, 4];
+    let scores = vec![91.25_f64, 7.5];
+    let table = Table::from_columns("{} | {} | {:.1}", (names, ages, scores));
+    assert_eq!(table.to_string(), "Alice | 30 | 91.2\nBob   | 4  | 7.5 \n");
+}
+
+#[test]
+fn pads_short_columns_with_default_cells() {
+    let table = Table::from_columns("{} | {}", (["a", "b", "c"], [1, 2]));
+    assert_eq!(table.to_string(), "a | 1\nb | 2\nc |  \n");
+}
+
+#[test]
+fn rejects_mismatched_lengths() {
+    let result = Table::try_from_columns("{} | {} | {}", (["a", "b"], [1, 2], [true]));
+    assert_eq!(
+        result.err(),
+        Some(FormatError::ColumnLengthMismatch {
+            column: 2,
+            expected: 2,
+            found: 1
+        })
+    );
+}
+
+#[test]
+fn rejects_extra_columns() {
+    let result = Table::try_from_columns("{}", (["a"], [1]));
+    assert_eq!(result.err(), Some(FormatError::TooManyItems { columns: 1, items: 2 }));
+}
+
+#[test]
+fn accepts_equal_lengths() {
+    let table = Table::try_from_columns("{}-{}", (vec!['x', 'y'], vec![1, 2])).unwrap();
+    assert_eq!(table.to_string(), "x-1\ny-2\n");
+}
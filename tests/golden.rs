@@ -0,0 +1,87 @@
+//! Golden-output tests for the frozen `RenderOptions::v1` preset.
+//!
+//! Each case renders a fixed layout with `RenderOptions::v1` and compares it byte for byte with
+//! a file under `tests/golden/`. These files must never change: a failure here means the output
+//! of the frozen preset has drifted.
+
+use std::time::Duration;
+
+use colprint::{ColumnFormatter, FormattableItem, RenderOptions};
+
+/// A value with multi-line `Display` output and distinct `Debug` output.
+#[derive(Debug)]
+struct Person {
+    name: &'static str,
+    age: u32,
+}
+
+impl std::fmt::Display for Person {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Name: {}\nAge: {}", self.name, self.age)
+    }
+}
+
+const ALICE: Person = Person { name: "Alice", age: 30 };
+
+/// Render the format string and items with the frozen preset.
+fn render(format_str: &str, items: Vec<FormattableItem<'_>>) -> String {
+    ColumnFormatter::new(format_str, items)
+        .with_options(RenderOptions::v1())
+        .to_string()
+}
+
+#[test]
+fn display_columns() {
+    let output = render(
+        "{} | {}",
+        vec![FormattableItem::Both(&ALICE, &ALICE), FormattableItem::DisplayItem(&"short")],
+    );
+    assert_eq!(output, include_str!("golden/display_columns.txt"));
+}
+
+#[test]
+fn debug_columns() {
+    let output = render(
+        "{:?} -> {:#?}",
+        vec![FormattableItem::Both(&ALICE, &ALICE), FormattableItem::Both(&ALICE, &ALICE)],
+    );
+    assert_eq!(output, include_str!("golden/debug_columns.txt"));
+}
+
+#[test]
+fn fixed_widths() {
+    let output = render(
+        "{}:4 | {}:12 | {}",
+        vec![
+            FormattableItem::DisplayItem(&"truncated"),
+            FormattableItem::DisplayItem(&"padded"),
+            FormattableItem::DisplayItem(&"end"),
+        ],
+    );
+    assert_eq!(output, include_str!("golden/fixed_widths.txt"));
+}
+
+#[test]
+fn humanized_columns() {
+    let output = render(
+        "{:dur} | {:size} | {:n}",
+        vec![
+            FormattableItem::DurationItem(Duration::from_millis(1500)),
+            FormattableItem::SizeItem(1_048_576),
+            FormattableItem::DisplayItem(&"count=6789012"),
+        ],
+    );
+    assert_eq!(output, include_str!("golden/humanized_columns.txt"));
+}
+
+#[test]
+fn total_width() {
+    let output = ColumnFormatter::new(
+        "{} | {}",
+        vec![FormattableItem::DisplayItem(&"left"), FormattableItem::DisplayItem(&"right")],
+    )
+    .with_options(RenderOptions::v1())
+    .with_total_width(24)
+    .to_string();
+    assert_eq!(output, include_str!("golden/total_width.txt"));
+}
@@ -0,0 +1,71 @@
+//! Tests for grouping table rows under group header lines.
+
+use colprint::{ColorChoice, FormattableItem, Style, Table};
+
+/// A table of services, endpoints and request counts.
+fn table() -> Table {
+    Table::from_columns(
+        "{} | {} | {}",
+        (
+            vec!["api", "db", "api", "db"],
+            vec!["/users", "read", "/orders", "write"],
+            vec![12, 7, 30, 1],
+        ),
+    )
+}
+
+#[test]
+fn groups_follow_first_appearance() {
+    let table = table().group_by(0).select_columns(&[1, 2]).unwrap();
+    assert_eq!(
+        table.to_string(),
+        "api\n/users  | 12\n/orders | 30\ndb\nread    | 7 \nwrite   | 1 \n"
+    );
+}
+
+#[test]
+fn grouped_rows_can_be_indented() {
+    let table = table().group_by(0).group_indent(2).select_columns(&[1, 2]).unwrap();
+    assert_eq!(
+        table.to_string(),
+        "api\n  /users  | 12\n  /orders | 30\ndb\n  read    | 7 \n  write   | 1 \n"
+    );
+}
+
+#[test]
+fn group_headers_do_not_widen_columns() {
+    let mut table = Table::new("{} {}").group_by(0);
+    table.push_row(&[FormattableItem::from(&"a very long group label"), FormattableItem::from(&"x")]);
+    let table = table.select_columns(&[1]).unwrap();
+    assert_eq!(table.to_string(), "a\nx\n");
+}
+
+#[test]
+fn subtotals_follow_each_group() {
+    let table = table()
+        .group_by(0)
+        .group_subtotals(|label, rows| {
+            let total: u32 = rows.iter().map(|row| row[2].parse::<u32>().unwrap()).sum();
+            vec![String::new(), format!("{label} total"), total.to_string()]
+        })
+        .select_columns(&[1, 2])
+        .unwrap();
+    assert_eq!(
+        table.to_string(),
+        "api\n/users    | 12\n/orders   | 30\napi total | 42\ndb\nread      | 7 \nwrite     | 1 \ndb total  | 8 \n"
+    );
+}
+
+#[test]
+fn group_headers_are_styled() {
+    let table = table()
+        .group_by(0)
+        .group_style(Style::new().bold())
+        .with_color(ColorChoice::Always)
+        .select_columns(&[1])
+        .unwrap();
+    assert_eq!(
+        table.to_string(),
+        "\x1b[1mapi\x1b[0m\n/users \n/orders\n\x1b[1mdb\x1b[0m\nread   \nwrite  \n"
+    );
+}
@@ -0,0 +1,43 @@
+//! Tests for which numbers within mixed text are grouped by the `n` flag.
+
+use colprint::ColumnFormatter;
+
+/// Format the text in a single column with digit grouping enabled.
+fn grouped(text: &str) -> String {
+    ColumnFormatter::new("{:n}", [&text]).to_string().trim_end().to_owned()
+}
+
+#[test]
+fn standalone_numbers_in_text_are_grouped() {
+    assert_eq!(grouped("id=12345 count=6789012"), "id=12,345 count=6,789,012");
+    assert_eq!(grouped("total 1000000 bytes"), "total 1,000,000 bytes");
+}
+
+#[test]
+fn digits_in_identifiers_are_left_alone() {
+    assert_eq!(grouped("a1b2c3d4e5 build_20240101"), "a1b2c3d4e5 build_20240101");
+}
+
+#[test]
+fn decimals_group_only_their_integer_part() {
+    assert_eq!(grouped("1234.5678"), "1,234.5678");
+    assert_eq!(grouped("cost 98765.4321."), "cost 98,765.4321.");
+}
+
+#[test]
+fn negative_numbers_are_grouped() {
+    assert_eq!(grouped("-12345"), "-12,345");
+}
+
+#[test]
+fn dates_and_times_are_left_alone() {
+    assert_eq!(grouped("2024-01-01"), "2024-01-01");
+    assert_eq!(grouped("on 2024-01-01 at 12:30:45"), "on 2024-01-01 at 12:30:45");
+    assert_eq!(grouped("20240101-1234"), "20240101-1234");
+}
+
+#[test]
+fn versions_are_left_alone() {
+    assert_eq!(grouped("v10.2.1234"), "v10.2.1234");
+    assert_eq!(grouped("1234.5678.9"), "1234.5678.9");
+}
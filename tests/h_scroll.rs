@@ -0,0 +1,65 @@
+//! Tests for scrolling a window across a wide layout with `HScroll`.
+
+use colprint::{ColumnFormatter, HScroll};
+
+/// A key column followed by a column wider than the window.
+fn formatter() -> ColumnFormatter<'static> {
+    ColumnFormatter::new("{} | {}", [&"key", &"0123456789"])
+}
+
+#[test]
+fn windows_start_at_the_left() {
+    let mut scroll = HScroll::new(10);
+    assert_eq!(formatter().render_window(&mut scroll, 1), "key | 0123");
+    assert!(!scroll.can_scroll_left());
+    assert!(scroll.can_scroll_right());
+}
+
+#[test]
+fn scrolling_right_stops_at_the_last_column() {
+    let mut scroll = HScroll::new(10);
+    let _ = formatter().render_window(&mut scroll, 1);
+    scroll.scroll_right(4);
+    assert_eq!(formatter().render_window(&mut scroll, 1), "key | 4567");
+    scroll.scroll_right(10);
+    assert_eq!(scroll.offset(), 6);
+    assert_eq!(formatter().render_window(&mut scroll, 1), "key | 6789");
+    assert!(scroll.can_scroll_left());
+    assert!(!scroll.can_scroll_right());
+}
+
+#[test]
+fn scrolling_left_stops_at_the_start() {
+    let mut scroll = HScroll::new(10);
+    scroll.scroll_right(3);
+    scroll.scroll_left(5);
+    assert_eq!(scroll.offset(), 0);
+    assert!(!scroll.can_scroll_left());
+}
+
+#[test]
+fn renders_clamp_offsets_set_before_measuring() {
+    let mut scroll = HScroll::new(10);
+    scroll.scroll_right(50);
+    assert!(scroll.can_scroll_right());
+    assert_eq!(formatter().render_window(&mut scroll, 1), "key | 6789");
+    assert_eq!(scroll.offset(), 6);
+}
+
+#[test]
+fn wider_viewports_clamp_the_offset_again() {
+    let mut scroll = HScroll::new(10);
+    let _ = formatter().render_window(&mut scroll, 1);
+    scroll.scroll_right(6);
+    scroll.set_viewport(14);
+    assert_eq!(formatter().render_window(&mut scroll, 1), "key | 23456789");
+    assert_eq!(scroll.offset(), 2);
+}
+
+#[test]
+fn layouts_which_fit_cannot_scroll() {
+    let mut scroll = HScroll::new(40);
+    assert_eq!(formatter().render_window(&mut scroll, 0), "key | 0123456789");
+    assert!(!scroll.can_scroll_left());
+    assert!(!scroll.can_scroll_right());
+}
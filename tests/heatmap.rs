@@ -0,0 +1,82 @@
+//! Tests for shading numeric columns by their values with `heatmap_column`.
+
+use colprint::{ColorChoice, ColorScale, FormattableItem, Table};
+
+/// The scale used by every test.
+const SCALE: ColorScale = ColorScale::GREEN_TO_RED;
+
+/// A two-column table shading the values of its second column, with a header.
+fn table(values: &[&str]) -> Table {
+    let mut table = Table::new("{} | {}")
+        .with_headers(["key", "value"])
+        .heatmap_column(1, SCALE)
+        .with_color(ColorChoice::Always);
+    for value in values {
+        table.push_row(&[FormattableItem::from(&"k"), FormattableItem::from(value)]);
+    }
+    table
+}
+
+/// A shaded cell padded to the given width, as rendered.
+fn shaded(sgr: &str, text: &str, width: usize) -> String {
+    format!("{sgr}{text:width$}\x1b[0m")
+}
+
+#[test]
+fn values_are_scaled_between_the_lowest_and_highest() {
+    let output = table(&["10", "30", "20"]).to_string();
+    let expected = [
+        "key | value".to_owned(),
+        format!("k   | {}", shaded(&SCALE.render_sgr(0.0), "10", 5)),
+        format!("k   | {}", shaded(&SCALE.render_sgr(1.0), "30", 5)),
+        format!("k   | {}", shaded(&SCALE.render_sgr(0.5), "20", 5)),
+    ];
+    assert_eq!(output.lines().collect::<Vec<_>>(), expected);
+    assert_eq!(SCALE.render_sgr(0.5), "\x1b[48;2;110;85;50m");
+}
+
+#[test]
+fn constant_columns_are_shaded_as_the_lowest_value() {
+    let output = table(&["7", "7"]).to_string();
+    let low = format!("k   | {}", shaded(&SCALE.render_sgr(0.0), "7", 5));
+    assert_eq!(output.lines().skip(1).collect::<Vec<_>>(), [low.as_str(), low.as_str()]);
+}
+
+#[test]
+fn non_finite_values_get_the_non_finite_style_and_do_not_stretch_the_range() {
+    let output = table(&["NaN", "1", "inf", "3", "-inf"]).to_string();
+    let non_finite = SCALE.non_finite.render_sgr();
+    let expected = [
+        "key | value".to_owned(),
+        format!("k   | {}", shaded(&non_finite, "NaN", 5)),
+        format!("k   | {}", shaded(&SCALE.render_sgr(0.0), "1", 5)),
+        format!("k   | {}", shaded(&non_finite, "inf", 5)),
+        format!("k   | {}", shaded(&SCALE.render_sgr(1.0), "3", 5)),
+        format!("k   | {}", shaded(&non_finite, "-inf", 5)),
+    ];
+    assert_eq!(output.lines().collect::<Vec<_>>(), expected);
+}
+
+#[test]
+fn digit_group_separators_are_ignored() {
+    let output = table(&["1,000", "3_000", "2000"]).to_string();
+    let expected = [
+        "key | value".to_owned(),
+        format!("k   | {}", shaded(&SCALE.render_sgr(0.0), "1,000", 5)),
+        format!("k   | {}", shaded(&SCALE.render_sgr(1.0), "3_000", 5)),
+        format!("k   | {}", shaded(&SCALE.render_sgr(0.5), "2000", 5)),
+    ];
+    assert_eq!(output.lines().collect::<Vec<_>>(), expected);
+}
+
+#[test]
+fn cells_which_are_not_numbers_are_left_unstyled() {
+    let output = table(&["1", "n/a", "2"]).to_string();
+    assert_eq!(output.lines().nth(2), Some("k   | n/a  "));
+}
+
+#[test]
+fn nothing_is_shaded_without_color() {
+    let output = table(&["1", "2"]).with_color(ColorChoice::Never).to_string();
+    assert_eq!(output, "key | value\nk   | 1    \nk   | 2    \n");
+}
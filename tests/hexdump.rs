@@ -0,0 +1,54 @@
+//! Tests for hexdump columns of bytes with `{:hex}` and `FormattableItem::Bytes`.
+
+use colprint::{ColumnFormatter, FormattableItem, colformat};
+
+#[derive(Debug)]
+#[expect(dead_code, reason = "Only read through Debug.")]
+struct Header {
+    kind: u8,
+    length: u16,
+}
+
+#[test]
+fn bytes_render_as_a_classic_hexdump() {
+    let packet = b"Hello, world!\n\x00\x7fAB";
+    assert_eq!(
+        colformat!("{:hex}", packet.as_slice()),
+        concat!(
+            "00000000  48 65 6c 6c 6f 2c 20 77  6f 72 6c 64 21 0a 00 7f  |Hello, world!...|\n",
+            "00000010  41 42                                             |AB|              \n",
+        )
+    );
+}
+
+#[test]
+fn bytes_per_row_sets_the_natural_width() {
+    let bytes: Vec<u8> = (0..10).collect();
+    assert_eq!(
+        colformat!("{:hex(4)} | {:?}", bytes.as_slice(), Header { kind: 1, length: 10 }),
+        concat!(
+            "00000000  00 01 02 03  |....| | Header { kind: 1, length: 10 }\n",
+            "00000004  04 05 06 07  |....| |                               \n",
+            "00000008  08 09        |..|   |                               \n",
+        )
+    );
+}
+
+#[test]
+fn capped_dumps_announce_the_remaining_bytes() {
+    let bytes = [b'x'; 40];
+    let formatter = ColumnFormatter::new("{:hex(8)}", vec![FormattableItem::Bytes(&bytes)]).with_max_lines_per_cell(3);
+    assert_eq!(
+        formatter.to_string(),
+        concat!(
+            "00000000  78 78 78 78 78 78 78 78  |xxxxxxxx|\n",
+            "00000008  78 78 78 78 78 78 78 78  |xxxxxxxx|\n",
+            "\u{2026} (+24 bytes)                                \n",
+        )
+    );
+}
+
+#[test]
+fn items_which_are_not_bytes_show_a_placeholder() {
+    assert_eq!(colformat!("{:hex}", 5), "<not bytes>\n");
+}
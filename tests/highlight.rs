@@ -0,0 +1,80 @@
+//! Tests for syntax highlighting `Debug` columns with `with_highlight`.
+
+use colprint::{Color, ColorChoice, ColumnFormatter, EnvConfig, Style, ToDebugItem as _};
+
+/// A record dumped with `Debug`.
+#[expect(dead_code, reason = "Only read through Debug.")]
+#[derive(Debug)]
+struct Record {
+    /// The name of the record.
+    name: &'static str,
+    /// The number of the record.
+    id: u32,
+}
+
+/// Format the records in two `{:?}` columns, the first highlighted as given.
+fn formatted(records: &[Record; 2], highlight: bool, color: ColorChoice) -> String {
+    ColumnFormatter::new("{:?}:12 | {:?}", [records[0].to_debug_item(), records[1].to_debug_item()])
+        .with_env(&EnvConfig::empty())
+        .with_color(color)
+        .with_highlight(0, highlight)
+        .to_string()
+}
+
+/// The token wrapped in the escape sequences of its colour.
+fn painted(token: &str, color: Color) -> String {
+    let style = Style::new().fg(color);
+    format!("{}{token}{}", style.render_sgr(), style.render_reset())
+}
+
+/// Remove the escape sequences from the text.
+fn strip_escapes(text: &str) -> String {
+    let mut stripped = String::new();
+    let mut in_escape = false;
+    for ch in text.chars() {
+        if ch == '\x1b' {
+            in_escape = true;
+        } else if in_escape {
+            in_escape = !ch.is_ascii_alphabetic();
+        } else {
+            stripped.push(ch);
+        }
+    }
+    stripped
+}
+
+/// Two short records.
+const RECORDS: [Record; 2] = [Record { name: "Bob", id: 7 }, Record { name: "Al", id: 1 }];
+
+#[test]
+fn tokens_are_coloured_by_kind() {
+    let record = Record { name: "Bob", id: 7 };
+    let output = ColumnFormatter::new("{:?}", [record.to_debug_item()])
+        .with_env(&EnvConfig::empty())
+        .with_color(ColorChoice::Always)
+        .with_highlight(0, true)
+        .to_string();
+    assert!(output.starts_with("Record "), "{output:?}");
+    assert!(output.contains(&painted("name", Color::Cyan)), "{output:?}");
+    assert!(output.contains(&painted("\"Bob\"", Color::Green)), "{output:?}");
+    assert!(output.contains(&painted("7", Color::Yellow)), "{output:?}");
+    assert!(output.contains(&painted("{", Color::Blue)), "{output:?}");
+}
+
+#[test]
+fn highlighting_does_not_change_the_layout() {
+    let plain = formatted(&RECORDS, false, ColorChoice::Never);
+    let coloured = formatted(&RECORDS, true, ColorChoice::Always);
+    assert_ne!(coloured, plain);
+    assert_eq!(strip_escapes(&coloured), plain);
+}
+
+#[test]
+fn highlighting_is_off_by_default() {
+    assert!(!formatted(&RECORDS, false, ColorChoice::Always).contains('\x1b'));
+}
+
+#[test]
+fn highlighting_needs_colour() {
+    assert!(!formatted(&RECORDS, true, ColorChoice::Never).contains('\x1b'));
+}
@@ -0,0 +1,49 @@
+//! Tests for indenting forwarded output with `IndentWriter`.
+
+use std::io::Write as _;
+
+use colprint::{ColumnFormatter, IndentWriter};
+
+/// Write each chunk in turn through a writer indenting with the prefix.
+fn indented(chunks: &[&str], indent_first_line: bool) -> String {
+    let mut writer = IndentWriter::new(Vec::new(), "> ").with_indent_first_line(indent_first_line);
+    for chunk in chunks {
+        writer.write_all(chunk.as_bytes()).unwrap();
+    }
+    String::from_utf8(writer.into_inner()).unwrap()
+}
+
+#[test]
+fn indents_lines_after_the_first() {
+    assert_eq!(indented(&["a\nb\nc"], false), "a\n> b\n> c");
+}
+
+#[test]
+fn can_indent_the_first_line() {
+    assert_eq!(indented(&["a\nb"], true), "> a\n> b");
+}
+
+#[test]
+fn leaves_no_prefix_after_the_final_newline() {
+    assert_eq!(indented(&["a\nb\n"], false), "a\n> b\n");
+}
+
+#[test]
+fn indents_lines_split_across_writes() {
+    assert_eq!(indented(&["a", "\n", "", "b", "c\n", "d"], false), "a\n> bc\n> d");
+}
+
+#[test]
+fn indents_blank_lines() {
+    assert_eq!(indented(&["a\n\nb"], false), "a\n> \n> b");
+}
+
+#[test]
+fn composes_with_write_to() {
+    let mut writer = IndentWriter::new(Vec::new(), "    ");
+    writer.write_all(b"- ").unwrap();
+    ColumnFormatter::new("{} | {}", [&"one\ntwo", &"1\n2"])
+        .write_to(&mut writer)
+        .unwrap();
+    assert_eq!(String::from_utf8(writer.into_inner()).unwrap(), "- one | 1\n    two | 2\n");
+}
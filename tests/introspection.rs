@@ -0,0 +1,36 @@
+//! Tests for inspecting formatters through their `Debug` output and accessors.
+
+use colprint::{Alignment, ColumnFormatter, FormattableItem};
+
+#[test]
+fn formats_and_len_describe_the_layout() {
+    let name = "Alice";
+    let age = 30;
+    let formatter = ColumnFormatter::new("{}:10=> | {:?}", [FormattableItem::from(&name), FormattableItem::from(&age)]);
+    assert_eq!(formatter.len(), 2);
+    assert!(!formatter.is_empty());
+
+    let formats = formatter.formats();
+    assert_eq!(formats.len(), 2);
+    assert_eq!(formats[0].alignment, Alignment::Right);
+    assert_eq!(formats[0].separator.as_deref(), Some(" | "));
+    assert_eq!(formats[1].separator, None);
+}
+
+#[test]
+fn item_debug_shows_the_variant_and_a_preview() {
+    let short = "short";
+    assert_eq!(format!("{:?}", FormattableItem::from(&short)), r#"Both("short")"#);
+
+    let long = "x".repeat(50);
+    let preview = format!("{:?}", FormattableItem::Verbatim(&long));
+    assert_eq!(preview, format!("Verbatim(\"{}\u{2026}\")", "x".repeat(40)));
+}
+
+#[test]
+fn formatter_debug_lists_its_formats_and_items() {
+    let value = 42;
+    let debug = format!("{:?}", ColumnFormatter::new("{}", [&value]));
+    assert!(debug.starts_with("ColumnFormatter { formats: [ColumnFormat {"));
+    assert!(debug.contains(r#"items: [Both("42")]"#));
+}
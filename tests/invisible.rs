@@ -0,0 +1,49 @@
+//! Tests for measuring non-breaking spaces, zero-width characters, and soft hyphens copied from web pages.
+
+use colprint::{ColumnFormatter, FormattableItem, Overflow, colformat};
+
+/// A formatter wrapping a raw line into a column of `width` characters, followed by a second column.
+fn wrapped(line: &str, width: usize) -> ColumnFormatter<'_> {
+    ColumnFormatter::new("{:raw}|{}", [FormattableItem::Verbatim(line), FormattableItem::from(&"x")])
+        .with_overflow(Overflow::Wrap { min_width: 1 })
+        .with_max_width(width + 2)
+}
+
+#[cfg(feature = "unicode")]
+#[test]
+fn zero_width_characters_do_not_widen_columns() {
+    assert_eq!(colformat!("{}|{}", "a\u{200b}b\ncd", "x"), "a\u{200b}b|x\ncd| \n");
+    assert_eq!(colformat!("{}|{}", "co\u{ad}op\nteam", "x"), "co\u{ad}op|x\nteam| \n");
+    assert_eq!(colformat!("{}|{}", "a\u{200d}b\ncd", "x"), "a\u{200d}b|x\ncd| \n");
+}
+
+#[cfg(feature = "unicode")]
+#[test]
+fn truncation_counts_only_visible_characters() {
+    use colprint::EnvConfig;
+
+    let formatter = ColumnFormatter::new("{}:3|{}", [&"ab\u{200b}cdef", &"x"]).with_env(&EnvConfig::empty());
+    assert_eq!(formatter.to_string(), "ab\u{200b}c|x\n");
+}
+
+#[test]
+fn non_breaking_spaces_take_one_column_and_never_wrap() {
+    assert_eq!(colformat!("{}|{}", "a\u{a0}b\ncd", "x"), "a\u{a0}b|x\ncd | \n");
+    assert_eq!(
+        wrapped("one two\u{a0}three", 9).to_string(),
+        "one      |x\ntwo\u{a0}three| \n"
+    );
+}
+
+#[test]
+fn cleaning_replaces_non_breaking_spaces_and_removes_zero_width_spaces() {
+    assert_eq!(
+        wrapped("one two\u{a0}three", 9).clean_invisible(true).to_string(),
+        "one two  |x\nthree    | \n"
+    );
+    let formatter = ColumnFormatter::new(
+        "{}|{}",
+        vec![FormattableItem::from(&"a\u{200b}b"), FormattableItem::from(&"x")],
+    );
+    assert_eq!(formatter.clean_invisible(true).to_string(), "ab|x\n");
+}
@@ -0,0 +1,65 @@
+//! Tests for exporting tables as JSON with `to_json` and `to_porcelain`.
+#![cfg(feature = "serde_json")]
+
+use std::io::{IsTerminal as _, stdout};
+
+use colprint::{FormattableItem, Table};
+use serde_json::{Value, json};
+
+/// A two-column table with one single-line and one multi-line row.
+fn table() -> Table {
+    let (name, notes) = ("Alice", "first line\nsecond line");
+    let mut table = Table::new("{}:4 | {:?}");
+    table.push_row(&[FormattableItem::from(&"Bob"), FormattableItem::from(&"ok")]);
+    table.push_row(&[FormattableItem::from(&name), FormattableItem::from(&notes)]);
+    table
+}
+
+/// Parse exported JSON.
+fn parse(json: &str) -> Value {
+    serde_json::from_str(json).expect("the export should be valid JSON")
+}
+
+#[test]
+fn headers_and_rows_are_exported() {
+    let table = table().with_headers(["Name", "Notes"]);
+    assert_eq!(
+        parse(&table.to_json()),
+        json!({
+            "headers": ["Name", "Notes"],
+            "rows": [["Bob", "\"ok\""], ["Alice", "\"first line\\nsecond line\""]],
+        })
+    );
+}
+
+#[test]
+fn tables_without_headers_export_an_empty_array() {
+    assert_eq!(parse(&table().to_json())["headers"], json!([]));
+}
+
+#[test]
+fn footers_are_left_out() {
+    let exported = parse(&table().with_footers(["Total", "2"]).to_json());
+    assert_eq!(exported.as_object().map(|object| object.len()), Some(2));
+    assert_eq!(exported["rows"].as_array().map(Vec::len), Some(2));
+    assert!(!table().with_footers(["Total", "2"]).to_json().contains("Total"));
+}
+
+#[test]
+fn multi_line_cells_stay_whole_and_untruncated() {
+    let (text, long) = ("one\ntwo\nthree", "a value wider than its column");
+    let mut table = Table::new("{}:4 | {}:4");
+    table.push_row(&[FormattableItem::from(&text), FormattableItem::from(&long)]);
+    assert_eq!(parse(&table.to_json())["rows"], json!([["one\ntwo\nthree", long]]));
+}
+
+#[test]
+fn porcelain_output_is_json_unless_writing_to_a_terminal() {
+    let table = table().with_headers(["Name", "Notes"]);
+    let expected = if stdout().is_terminal() {
+        table.to_string()
+    } else {
+        table.to_json()
+    };
+    assert_eq!(table.to_porcelain(), expected);
+}
@@ -0,0 +1,73 @@
+//! Tests for exporting and applying table layouts with `LayoutSpec`.
+
+use colprint::{Alignment, ColumnSpec, EnvConfig, FormatError, LayoutSpec, Table};
+
+fn table() -> Table {
+    Table::from_columns("{} | {}", (vec!["Alice", "Bob"], vec![30, 4])).with_env(&EnvConfig::empty())
+}
+
+#[test]
+fn layouts_set_headers_widths_and_alignment() {
+    let layout = LayoutSpec::new()
+        .column(ColumnSpec::new().header("Name").width("6"))
+        .column(ColumnSpec::new().header("Age").align("right"));
+    let table = table().with_layout(&layout).unwrap();
+    assert_eq!(table.to_string(), "Name   | Age\nAlice  |  30\nBob    |   4\n");
+}
+
+#[test]
+fn layouts_set_separators_order_and_truncation() {
+    let layout = LayoutSpec::new()
+        .order(&[1, 0])
+        .column(ColumnSpec::new().width("3").truncate("left").truncate_marker("~"))
+        .column(ColumnSpec::new().separator(" : "));
+    let table = table().with_layout(&layout).unwrap();
+    assert_eq!(table.to_string(), "30 : ~ce\n4  : Bob\n");
+}
+
+#[test]
+fn exported_layouts_round_trip() {
+    let original = table().with_headers(["Name", "Age"]).align_data(1, Alignment::Right);
+    let layout = original.layout_spec();
+    assert_eq!(layout.columns[0].header.as_deref(), Some("Name"));
+    assert_eq!(layout.columns[1].align.as_deref(), Some("right"));
+    assert_eq!(layout.columns[0].separator.as_deref(), Some(" | "));
+    assert_eq!(layout.order, None);
+
+    let reapplied = table().with_layout(&layout).unwrap();
+    assert_eq!(reapplied.to_string(), original.to_string());
+    assert_eq!(reapplied.layout_spec(), layout);
+}
+
+#[test]
+fn invalid_settings_name_the_field() {
+    let layout = LayoutSpec::new()
+        .column(ColumnSpec::new())
+        .column(ColumnSpec::new().align("middle"));
+    let error = table().with_layout(&layout).err().unwrap();
+    assert_eq!(
+        error.to_string(),
+        "layout field `columns[1].align` is `middle`, expected `left`, `right`, or `center`"
+    );
+
+    let layout = LayoutSpec::new().column(ColumnSpec::new().width("wide"));
+    assert!(matches!(
+        table().with_layout(&layout),
+        Err(FormatError::InvalidLayout { field, .. }) if field == "columns[0].width"
+    ));
+
+    let layout = LayoutSpec::new().order(&[0, 2]);
+    assert!(matches!(
+        table().with_layout(&layout),
+        Err(FormatError::InvalidLayout { field, .. }) if field == "order[1]"
+    ));
+
+    let layout = LayoutSpec::new()
+        .column(ColumnSpec::new())
+        .column(ColumnSpec::new())
+        .column(ColumnSpec::new());
+    assert!(matches!(
+        table().with_layout(&layout),
+        Err(FormatError::InvalidLayout { field, .. }) if field == "columns[2]"
+    ));
+}
@@ -0,0 +1,71 @@
+//! Tests for writing output with `LineEnding::CrLf` line endings.
+
+use colprint::{ColumnFormatter, FormattableItem, LineEnding, Table, TableWriter};
+
+/// A table with a header and two rows.
+fn table() -> Table {
+    Table::from_columns("{} | {}", (vec!["Alice", "Bob"], vec![30, 25])).with_headers(["Name", "Age"])
+}
+
+#[test]
+fn line_feeds_are_the_default() {
+    let mut output = Vec::new();
+    table().write_to(&mut output).unwrap();
+    assert_eq!(String::from_utf8(output).unwrap(), table().to_string());
+}
+
+#[test]
+fn tables_write_crlf_on_every_line() {
+    let table = table().with_line_ending(LineEnding::CrLf);
+    let mut output = Vec::new();
+    table.write_to(&mut output).unwrap();
+    let text = String::from_utf8(output).unwrap();
+    assert_eq!(text, table.to_string().replace('\n', "\r\n"));
+    assert_eq!(text.matches("\r\n").count(), text.matches('\n').count());
+    assert_eq!(text.lines().count(), 3);
+    assert_eq!(table.to_text(), text);
+}
+
+#[test]
+fn display_keeps_line_feeds() {
+    let table = table().with_line_ending(LineEnding::CrLf);
+    assert!(!table.to_string().contains('\r'));
+    assert!(!format!("{table}").contains('\r'));
+}
+
+#[test]
+fn formatters_write_crlf() {
+    let formatter = ColumnFormatter::new("{} | {}", [&"one\ntwo", &"x"]).with_line_ending(LineEnding::CrLf);
+    let mut output = Vec::new();
+    formatter.write_to(&mut output).unwrap();
+    assert_eq!(output, b"one | x\r\ntwo |  \r\n");
+    assert_eq!(formatter.to_text().as_bytes(), output);
+    assert_eq!(formatter.to_string(), "one | x\ntwo |  \n");
+}
+
+#[test]
+fn streamed_rows_write_crlf() {
+    let mut output = Vec::new();
+    {
+        let mut writer = TableWriter::new("{}:5 | {}", &mut output)
+            .with_headers(["name", "n"])
+            .with_line_ending(LineEnding::CrLf);
+        writer
+            .write_row(&[FormattableItem::DisplayItem(&"Alice"), FormattableItem::DisplayItem(&1)])
+            .unwrap();
+    }
+    assert_eq!(String::from_utf8(output).unwrap(), "name  | n\r\nAlice | 1\r\n");
+}
+
+#[test]
+fn output_has_no_byte_order_mark() {
+    let mut output = Vec::new();
+    table().with_line_ending(LineEnding::CrLf).write_to(&mut output).unwrap();
+    assert!(!output.starts_with(b"\xef\xbb\xbf"));
+}
+
+#[test]
+fn line_endings_name_their_text() {
+    assert_eq!(LineEnding::default().as_str(), "\n");
+    assert_eq!(LineEnding::CrLf.as_str(), "\r\n");
+}
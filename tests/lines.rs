@@ -0,0 +1,52 @@
+//! Tests for `{:lines}` and `{:lines?}` columns and the `ToLinesItem` adapters.
+
+use colprint::{ColumnFormatter, ToLinesItem as _, colformat};
+
+#[derive(Debug)]
+#[expect(dead_code, reason = "Only read through Debug.")]
+struct Point {
+    x: i32,
+    y: i32,
+}
+
+fn points() -> Vec<Point> {
+    vec![Point { x: 1, y: 2 }, Point { x: -3, y: 40 }]
+}
+
+#[test]
+fn display_and_debug_collections_share_one_call() {
+    let names = vec![String::from("alpha"), String::from("b")];
+    let points = points();
+    assert_eq!(
+        colformat!("{:lines} | {:lines?}", &names, &points),
+        "alpha | Point { x: 1, y: 2 }  \nb     | Point { x: -3, y: 40 }\n"
+    );
+}
+
+#[test]
+fn adapters_match_the_macro() {
+    let names = vec!["alpha", "b"];
+    let points = points();
+    let formatter = ColumnFormatter::new(
+        "{:lines} | {:lines?}",
+        vec![(&names).as_display_lines(), (&points).as_debug_lines()],
+    );
+    assert_eq!(formatter.to_string(), colformat!("{:lines} | {:lines?}", &names, &points));
+}
+
+#[test]
+fn auto_width_spans_every_element() {
+    let numbers = [1, 22_222, 3];
+    assert_eq!(colformat!("{:lines}|{}", &numbers, &"x"), "1    |x\n22222| \n3    | \n");
+}
+
+#[test]
+fn items_which_are_not_collections_show_a_placeholder() {
+    assert_eq!(colformat!("{:lines}", &5), "<not a collection>\n");
+}
+
+#[test]
+fn elements_are_not_reformatted_as_a_whole() {
+    let words = vec!["a", "b"];
+    assert_eq!(colformat!("{:lines?}", &words), "\"a\"\n\"b\"\n");
+}
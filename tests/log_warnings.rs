@@ -0,0 +1,130 @@
+//! Tests for the warnings emitted through the `log` facade when a formatter or table recovers from a problem.
+#![cfg(feature = "log")]
+
+use std::cell::RefCell;
+
+use colprint::{ColumnFormatter, FormattableItem, Table};
+use log::{Level, LevelFilter, Log, Metadata, Record};
+
+/// A logger keeping the messages logged on each thread, so tests running in parallel see only their own.
+struct CapturingLogger;
+
+thread_local! {
+    static MESSAGES: RefCell<Vec<(Level, String)>> = const { RefCell::new(Vec::new()) };
+}
+
+impl Log for CapturingLogger {
+    fn enabled(&self, _metadata: &Metadata<'_>) -> bool {
+        true
+    }
+
+    fn log(&self, record: &Record<'_>) {
+        MESSAGES.with(|messages| messages.borrow_mut().push((record.level(), record.args().to_string())));
+    }
+
+    fn flush(&self) {}
+}
+
+/// The logger installed for every test.
+static LOGGER: CapturingLogger = CapturingLogger;
+
+/// The messages logged on this thread while running `f`.
+fn captured(f: impl FnOnce()) -> Vec<(Level, String)> {
+    // Only the first test to run installs the logger
+    let _installed = log::set_logger(&LOGGER);
+    log::set_max_level(LevelFilter::Trace);
+    MESSAGES.with(|messages| messages.borrow_mut().clear());
+    f();
+    MESSAGES.with(|messages| messages.take())
+}
+
+#[test]
+fn formatters_warn_about_items_and_columns_they_drop() {
+    let (a, b, c) = ("a", "b", "c");
+    let surplus = captured(|| {
+        let output = ColumnFormatter::new(
+            "{} | {}",
+            [
+                FormattableItem::from(&a),
+                FormattableItem::from(&b),
+                FormattableItem::from(&c),
+            ],
+        )
+        .to_string();
+        assert_eq!(output, "a | b\n");
+    });
+    assert_eq!(
+        surplus,
+        [(
+            Level::Warn,
+            "colprint: dropping 1 item(s) beyond the 2 specifier(s) in the format string".to_owned()
+        )]
+    );
+
+    let missing = captured(|| {
+        let _output = ColumnFormatter::new("{} | {}", [FormattableItem::from(&a)]).to_string();
+    });
+    assert_eq!(
+        missing,
+        [(
+            Level::Warn,
+            "colprint: dropping 1 column(s) with no item, as only 1 item(s) were given".to_owned()
+        )]
+    );
+}
+
+#[test]
+fn formatters_warn_about_mismatched_items() {
+    let text = "ab";
+    let messages = captured(|| {
+        let _output = ColumnFormatter::new("{:?}", [FormattableItem::DisplayItem(&text)]).to_string();
+    });
+    assert_eq!(
+        messages,
+        [(
+            Level::Warn,
+            "colprint: column 0 requests Debug formatting, which its item lacks; falling back".to_owned()
+        )]
+    );
+}
+
+#[test]
+fn matching_items_do_not_warn() {
+    let (a, b) = ("a", "b");
+    let messages = captured(|| {
+        let _output = ColumnFormatter::new("{} | {}", [FormattableItem::from(&a), FormattableItem::from(&b)]).to_string();
+    });
+    assert_eq!(messages, []);
+}
+
+#[test]
+fn pushing_too_many_items_warns() {
+    let (a, b, c) = ("a", "b", "c");
+    let mut table = Table::new("{} | {}");
+    let messages = captured(|| {
+        table.push_row(&[
+            FormattableItem::from(&a),
+            FormattableItem::from(&b),
+            FormattableItem::from(&c),
+        ]);
+    });
+    assert_eq!(
+        messages,
+        [(
+            Level::Warn,
+            "colprint: dropping 1 item(s) beyond the 2 column(s) of the table".to_owned()
+        )]
+    );
+    assert_eq!(table.to_string().lines().next().map(str::trim_end), Some("a | b"));
+}
+
+#[test]
+fn pushing_full_or_short_rows_does_not_warn() {
+    let (a, b) = ("a", "b");
+    let mut table = Table::new("{} | {}");
+    let messages = captured(|| {
+        table.push_row(&[FormattableItem::from(&a), FormattableItem::from(&b)]);
+        table.push_row(&[FormattableItem::from(&a)]);
+    });
+    assert_eq!(messages, []);
+}
@@ -0,0 +1,69 @@
+//! Tests for limiting the number of output lines of each table row with `Table::max_row_height`.
+
+use colprint::{Accessibility, EnvConfig, FormattableItem, Table};
+
+/// A table of two text columns with the given rows.
+fn table(rows: &[[&str; 2]], height: Option<usize>) -> Table {
+    let mut table = Table::new("{} | {}").with_env(&EnvConfig::empty());
+    if let Some(limit) = height {
+        table = table.max_row_height(limit);
+    }
+    for row in rows {
+        let items: Vec<FormattableItem<'_>> = row.iter().map(FormattableItem::from).collect();
+        table.push_row(&items);
+    }
+    table
+}
+
+#[test]
+fn rows_are_unlimited_by_default() {
+    let output = table(&[["a\nb\nc\nd", "x"]], None).to_string();
+    assert_eq!(output.lines().count(), 4);
+}
+
+#[test]
+fn tall_cells_are_clipped_with_a_marker() {
+    let output = table(&[["a\nb\nc\nd", "x"], ["e", "y"]], Some(2)).to_string();
+    assert_eq!(output, "a  | x\nb… |  \ne  | y\n");
+}
+
+#[test]
+fn rows_which_fit_are_untouched() {
+    let rows = [["a\nb", "x"], ["c", "y\nz"]];
+    assert_eq!(table(&rows, Some(2)).to_string(), table(&rows, None).to_string());
+}
+
+#[test]
+fn dropped_lines_do_not_widen_the_column() {
+    let output = table(&[["a\nb\na very long dropped line", "x"]], Some(2)).to_string();
+    assert_eq!(output, "a  | x\nb… |  \n");
+}
+
+#[test]
+fn marked_lines_are_cut_to_the_column() {
+    let mut table = Table::new("{}:3 | {}").with_env(&EnvConfig::empty()).max_row_height(2);
+    table.push_row(&[FormattableItem::from(&"abc\ndef\nghi"), FormattableItem::from(&"x")]);
+    assert_eq!(table.to_string(), "abc | x\nde… |  \n");
+}
+
+#[test]
+fn every_clipped_cell_is_marked() {
+    let output = table(&[["a\nb\nc", "x\ny\nz"]], Some(1)).to_string();
+    assert_eq!(output, "a… | x…\n");
+}
+
+#[test]
+fn zero_is_treated_as_one() {
+    let output = table(&[["a\nb", "x"]], Some(0)).to_string();
+    assert_eq!(output, "a… | x\n");
+}
+
+#[test]
+fn verbose_accessibility_announces_dropped_lines() {
+    let mut table = Table::new("{} | {}")
+        .with_env(&EnvConfig::empty())
+        .with_accessibility(Accessibility::Verbose)
+        .max_row_height(2);
+    table.push_row(&[FormattableItem::from(&"a\nb\nc\nd"), FormattableItem::from(&"x")]);
+    assert_eq!(table.to_string(), "a                 | x\n[3 lines omitted] |  \n");
+}
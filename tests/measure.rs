@@ -0,0 +1,60 @@
+//! Tests for measuring the output of a formatter with `measure`, without writing it.
+
+use colprint::{BlockAlign, ColumnFormatter, EnvConfig, FormattableItem, Overflow};
+
+/// The dimensions of rendered output, counted from its lines.
+fn dimensions_of(output: &str) -> (usize, usize) {
+    let widths: Vec<usize> = output.lines().map(|line| line.chars().count()).collect();
+    (widths.len(), widths.iter().copied().max().unwrap_or(0))
+}
+
+/// Check that a formatter measures the output it renders.
+fn assert_measures_its_output(formatter: &ColumnFormatter<'_>) {
+    let dimensions = formatter.measure();
+    assert_eq!((dimensions.lines, dimensions.width), dimensions_of(&formatter.to_string()));
+}
+
+#[test]
+fn wrapped_multi_line_cells_measure_as_rendered() {
+    let formatter = ColumnFormatter::new(
+        "{} | {}",
+        [
+            FormattableItem::from(&"a cell wrapped over\nseveral lines of text"),
+            FormattableItem::from(&"short\nnotes"),
+        ],
+    )
+    .with_env(&EnvConfig::empty())
+    .with_overflow(Overflow::Wrap { min_width: 4 })
+    .with_max_width(20);
+
+    let output = formatter.to_string();
+    assert_eq!(
+        output,
+        concat!(
+            "a cell       | short\n",
+            "wrapped over | notes\n",
+            "several      |      \n",
+            "lines of     |      \n",
+            "text         |      \n",
+        )
+    );
+    assert_eq!(dimensions_of(&output), (5, 20));
+    assert_measures_its_output(&formatter);
+}
+
+#[test]
+fn block_alignment_is_measured() {
+    let formatter = ColumnFormatter::new("{}|{}", [&"one\ntwo\nthree", &"x"])
+        .with_env(&EnvConfig::empty())
+        .with_block_align(BlockAlign::Right)
+        .with_block_width(12);
+    assert_measures_its_output(&formatter);
+    assert_eq!(formatter.measure().width, 12);
+}
+
+#[test]
+fn empty_output_measures_nothing() {
+    let formatter = ColumnFormatter::new("", Vec::<FormattableItem<'_>>::new()).with_env(&EnvConfig::empty());
+    let dimensions = formatter.measure();
+    assert_eq!((dimensions.lines, dimensions.width), (0, 0));
+}
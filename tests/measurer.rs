@@ -0,0 +1,99 @@
+//! Tests for measuring line widths with the `CharCount` and `SkipZeroWidth` measurers.
+
+use colprint::{CharCount, Measurer, colformat};
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    cell::Cell,
+};
+
+/// The system allocator, counting the allocations made on each thread.
+struct CountingAllocator;
+
+thread_local! {
+    static ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+}
+
+// SAFETY: Every call is forwarded to the system allocator unchanged.
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.with(|count| count.set(count.get() + 1));
+        // SAFETY: The caller upholds the contract of `GlobalAlloc::alloc`.
+        unsafe { System.alloc(layout) }
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        // SAFETY: The caller upholds the contract of `GlobalAlloc::dealloc`.
+        unsafe { System.dealloc(ptr, layout) }
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+/// The number of allocations made on this thread while running `f`.
+fn allocations(f: impl FnOnce()) -> usize {
+    let before = ALLOCATIONS.with(Cell::get);
+    f();
+    ALLOCATIONS.with(Cell::get) - before
+}
+
+#[test]
+fn char_count_counts_chars_and_skips_escape_sequences() {
+    assert_eq!(CharCount.width("plain"), 5);
+    assert_eq!(CharCount.width("caf\u{e9}"), 4);
+    assert_eq!(CharCount.width("\x1b[1mbold\x1b[0m"), 4);
+    assert_eq!(CharCount.width("\x1b]8;;https://example.com\x1b\\link\x1b]8;;\x1b\\"), 4);
+    assert_eq!(CharCount.width("a\u{200b}b"), 3);
+}
+
+#[test]
+fn char_count_never_allocates() {
+    let lines = [
+        "plain",
+        "caf\u{e9}",
+        "\x1b[1;31mstyled\x1b[0m",
+        "\x1b]8;;https://example.com\x07link\x1b]8;;\x07",
+    ];
+    let mut total = 0;
+    assert_eq!(
+        allocations(|| total = lines.iter().map(|line| CharCount.width(line)).sum()),
+        0
+    );
+    assert_eq!(total, 19);
+}
+
+#[cfg(feature = "unicode")]
+#[test]
+fn skip_zero_width_gives_zero_width_characters_no_width() {
+    use colprint::SkipZeroWidth;
+
+    assert_eq!(SkipZeroWidth.width("a\u{200b}b"), 2);
+    assert_eq!(SkipZeroWidth.width("co\u{ad}op"), 4);
+    assert_eq!(SkipZeroWidth.width("\x1b[1ma\u{200d}b\x1b[0m"), 2);
+    assert_eq!(
+        allocations(|| assert_eq!(SkipZeroWidth.width("\x1b[1ma\u{200d}b\x1b[0m"), 2)),
+        0
+    );
+}
+
+#[cfg(feature = "unicode")]
+#[test]
+fn skip_zero_width_counts_wide_characters_as_one_column() {
+    use colprint::SkipZeroWidth;
+
+    assert_eq!(SkipZeroWidth.width("\u{65e5}\u{672c}"), 2);
+    assert_eq!(SkipZeroWidth.width("\u{1f600}!"), 2);
+}
+
+#[cfg(not(feature = "unicode"))]
+#[test]
+fn without_unicode_every_char_takes_a_column() {
+    assert_eq!(colformat!("{}|{}", "a\u{200b}b\ncd", "x"), "a\u{200b}b|x\ncd | \n");
+    assert_eq!(colformat!("{}:3|{}", "ab\u{200b}cdef", "x"), "ab\u{200b}|x\n");
+}
+
+#[cfg(feature = "unicode")]
+#[test]
+fn with_unicode_zero_width_characters_take_no_column() {
+    assert_eq!(colformat!("{}|{}", "a\u{200b}b\ncd", "x"), "a\u{200b}b|x\ncd| \n");
+}
@@ -0,0 +1,63 @@
+//! Tests for the gap that squeezing always leaves between adjacent columns.
+
+use colprint::{ColumnFormatter, Overflow, Squeeze};
+
+/// A small linear congruential generator, so the randomized layouts are the same on every run.
+struct Lcg(u64);
+
+impl Lcg {
+    /// The next number below `bound`.
+    fn below(&mut self, bound: usize) -> usize {
+        self.0 = self
+            .0
+            .wrapping_mul(6_364_136_223_846_793_005)
+            .wrapping_add(1_442_695_040_888_963_407);
+        usize::try_from(self.0 >> 33).unwrap() % bound
+    }
+}
+
+#[test]
+fn squeezing_stops_at_the_minimum_gap() {
+    let formatter = ColumnFormatter::new("{}  {}", [&"aaa", &"bbb"])
+        .with_squeeze(Squeeze::Separators)
+        .with_max_width(6);
+    assert_eq!(formatter.to_string(), "aaa bbb\n");
+    let fused = ColumnFormatter::new("{}  {}", [&"aaa", &"bbb"])
+        .with_squeeze(Squeeze::Separators)
+        .with_max_width(6)
+        .with_min_gap(0);
+    assert_eq!(fused.to_string(), "aaabbb\n");
+}
+
+#[test]
+fn adjacent_columns_never_fuse() {
+    let mut rng = Lcg(7);
+    for _ in 0..200 {
+        let separators = ["", " ", "  ", "   ", " | ", "  :  "];
+        let letters = ["a", "b", "c", "d"];
+        let num_columns = 2 + rng.below(3);
+        let mut format_str = String::new();
+        let mut cells = Vec::new();
+        for (idx, letter) in letters.iter().take(num_columns).enumerate() {
+            format_str.push_str("{}");
+            if idx + 1 < num_columns {
+                // Skip empty separators, which the format string asks for explicitly
+                format_str.push_str(separators[1 + rng.below(separators.len() - 1)]);
+            }
+            cells.push(letter.repeat(1 + rng.below(6)));
+        }
+        let max_width = 1 + rng.below(30);
+        let formatter = ColumnFormatter::new(&format_str, &cells)
+            .with_squeeze(Squeeze::Separators)
+            .with_overflow(Overflow::Wrap { min_width: 1 })
+            .with_max_width(max_width);
+        for line in formatter.to_string().lines() {
+            for pair in letters.windows(2) {
+                assert!(
+                    !line.contains(&format!("{}{}", pair[0], pair[1])),
+                    "columns fused in {line:?} for {format_str:?} within {max_width}"
+                );
+            }
+        }
+    }
+}
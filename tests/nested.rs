@@ -0,0 +1,41 @@
+//! Tests for composing formatters with `FormattableItem::Nested`.
+
+use colprint::{ColumnFormatter, FormattableItem, colformat};
+
+/// The keys and values of a two-column child layout, whose last column is padded past its widest value.
+const CHILD: (&str, &str, &str) = ("{} {}:6", "a\nlonger", "1\n22");
+
+#[test]
+fn parent_auto_width_is_the_trimmed_width_of_the_child() {
+    let (format_str, keys, values) = CHILD;
+    let child = ColumnFormatter::new(format_str, [&keys, &values]);
+    assert_eq!(child.to_string(), "a      1     \nlonger 22    \n");
+
+    let parent = ColumnFormatter::new("{}|{}", [FormattableItem::Nested(&child), FormattableItem::from(&"x")]);
+    assert_eq!(parent.to_string(), "a      1 |x\nlonger 22| \n");
+    assert_eq!(parent.measure().width, "longer 22".len() + 2);
+}
+
+#[test]
+fn nested_items_drop_the_padding_of_the_child_which_display_items_keep() {
+    let (format_str, keys, values) = CHILD;
+    let child = ColumnFormatter::new(format_str, [&keys, &values]);
+
+    let nested = ColumnFormatter::new("{}|{}", [FormattableItem::Nested(&child), FormattableItem::from(&"x")])
+        .with_trim_trailing_whitespace(false);
+    assert_eq!(nested.to_string(), "a      1 |x\nlonger 22| \n");
+
+    let display = ColumnFormatter::new("{}|{}", [FormattableItem::DisplayItem(&child), FormattableItem::from(&"x")])
+        .with_trim_trailing_whitespace(false);
+    assert_eq!(display.to_string(), "a      1     |x\nlonger 22    | \n");
+}
+
+#[test]
+fn macros_pick_nested_for_formatter_items() {
+    let (format_str, keys, values) = CHILD;
+    let child = ColumnFormatter::new(format_str, [&keys, &values]);
+    let nested = ColumnFormatter::new("{}|{}", [FormattableItem::Nested(&child), FormattableItem::from(&"x")]);
+    assert_eq!(colformat!("{}|{}", child, "x"), nested.to_string());
+    // A `Debug` item would print the fields of the formatter instead of its lines
+    assert_eq!(colformat!("{:?}|{}", child, "x"), nested.to_string());
+}
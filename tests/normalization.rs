@@ -0,0 +1,41 @@
+//! Tests for normalizing cell text before it is measured with `with_normalization`.
+#![cfg(feature = "unicode")]
+
+use colprint::{ColumnFormatter, Normalization};
+
+/// `café` with the accent as a combining mark.
+const DECOMPOSED: &str = "cafe\u{301}";
+
+/// `café` with the accent precomposed.
+const COMPOSED: &str = "caf\u{e9}";
+
+#[test]
+fn combining_marks_widen_columns_without_normalization() {
+    assert_eq!(ColumnFormatter::new("{}", [&DECOMPOSED]).measure().width, 5);
+}
+
+#[test]
+fn nfc_composes_characters_before_measurement() {
+    let formatter = ColumnFormatter::new("{}|{}", [&DECOMPOSED, &"x"]).with_normalization(Normalization::Nfc);
+    assert_eq!(formatter.measure().width, 6);
+    assert_eq!(formatter.to_string(), format!("{COMPOSED}|x\n"));
+}
+
+#[test]
+fn nfd_decomposes_characters() {
+    let formatter = ColumnFormatter::new("{}", [&COMPOSED]).with_normalization(Normalization::Nfd);
+    assert_eq!(formatter.to_string(), format!("{DECOMPOSED}\n"));
+}
+
+#[test]
+fn mixed_sources_share_one_width() {
+    let (cell, next) = (format!("{COMPOSED}\n{DECOMPOSED}"), "x".to_owned());
+    let formatter = ColumnFormatter::new("{}|{}", [&cell, &next]).with_normalization(Normalization::Nfc);
+    assert_eq!(formatter.to_string(), format!("{COMPOSED}|x\n{COMPOSED}| \n"));
+}
+
+#[test]
+fn compatibility_forms_replace_ligatures() {
+    assert_eq!(Normalization::Nfkc.apply("\u{fb01}le"), "file");
+    assert_eq!(Normalization::Nfc.apply("\u{fb01}le"), "\u{fb01}le");
+}
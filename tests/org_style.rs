@@ -0,0 +1,48 @@
+//! Tests for the Markdown and Org styles drawn by the shared delimited-table backend.
+
+use colprint::{Alignment, OutputStyle, Table};
+
+/// A table of names and ages with the given style, the ages aligned right.
+fn table(style: OutputStyle) -> Table {
+    Table::from_columns("{} | {}", (vec!["Alice", "Bob|Rob"], vec![30, 4]))
+        .with_headers(["Name", "Age"])
+        .align_data(1, Alignment::Right)
+        .with_style(style)
+}
+
+#[test]
+fn org_tables_draw_a_rule_under_the_header() {
+    assert_eq!(
+        table(OutputStyle::Org { alignment_row: false }).to_string(),
+        "| Name          | Age |\n\
+         |---------------+-----|\n\
+         | Alice         |  30 |\n\
+         | Bob\\vert{}Rob |   4 |\n"
+    );
+}
+
+#[test]
+fn org_alignment_rows_follow_the_data_alignment() {
+    let output = table(OutputStyle::Org { alignment_row: true }).to_string();
+    assert_eq!(output.lines().nth(2), Some("| <l>           | <r> |"));
+}
+
+#[test]
+fn markdown_tables_encode_alignment_in_the_rule() {
+    assert_eq!(
+        table(OutputStyle::Markdown).to_string(),
+        "| Name     | Age |\n\
+         | -------- | --: |\n\
+         | Alice    |  30 |\n\
+         | Bob\\|Rob |   4 |\n"
+    );
+}
+
+#[test]
+fn multi_line_cells_are_joined_onto_one_row() {
+    let cells = (vec!["one\ntwo"], vec![1]);
+    let org = Table::from_columns("{} {}", cells.clone()).with_style(OutputStyle::Org { alignment_row: false });
+    assert_eq!(org.to_string(), "| one two | 1 |\n");
+    let markdown = Table::from_columns("{} {}", cells).with_style(OutputStyle::Markdown);
+    assert_eq!(markdown.to_string(), "| one<br>two | 1   |\n");
+}
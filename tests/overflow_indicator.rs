@@ -0,0 +1,51 @@
+//! Tests for marking lines clipped on the right with `ColumnFormatter::with_overflow_indicator`.
+
+use colprint::{ColorChoice, ColumnFormatter, HScroll, Style};
+
+/// A formatter with a long second column and a `>` indicator.
+fn formatter<'a>(left: &'a &'a str, right: &'a &'a str) -> ColumnFormatter<'a> {
+    ColumnFormatter::new("{} | {}", [left, right]).with_overflow_indicator('>', Style::new().dim())
+}
+
+#[test]
+fn clipped_lines_end_with_the_indicator() {
+    let (left, right) = ("a\nb", "long text\nx");
+    let lines = formatter(&left, &right).with_color(ColorChoice::Never).render_rect(8, 2);
+    assert_eq!(lines, ["a | lon>", "b | x   "]);
+}
+
+#[test]
+fn total_width_clips_with_the_indicator() {
+    let (left, right) = ("abcdef", "ghijkl");
+    let output = ColumnFormatter::new("{}:6 | {}:6", [&left, &right])
+        .with_overflow_indicator('>', Style::new())
+        .with_total_width(10)
+        .with_color(ColorChoice::Never)
+        .to_string();
+    assert!(output.lines().all(|line| line.chars().count() <= 10), "{output:?}");
+}
+
+#[test]
+fn window_marks_content_to_the_right() {
+    let (left, right) = ("key", "0123456789");
+    let mut scroll = HScroll::new(10);
+    scroll.scroll_right(2);
+    let window = formatter(&left, &right)
+        .with_color(ColorChoice::Never)
+        .render_window(&mut scroll, 1);
+    assert_eq!(window, "key | 234>");
+}
+
+#[test]
+fn indicator_is_styled_when_colour_is_enabled() {
+    let (left, right) = ("a", "long text");
+    let lines = formatter(&left, &right).with_color(ColorChoice::Always).render_rect(6, 1);
+    assert_eq!(lines, ["a | l\x1b[2m>\x1b[0m"]);
+}
+
+#[test]
+fn lines_which_fit_are_unchanged() {
+    let (left, right) = ("a", "b");
+    let lines = formatter(&left, &right).render_rect(6, 1);
+    assert_eq!(lines, ["a | b "]);
+}
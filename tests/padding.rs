@@ -0,0 +1,64 @@
+//! Tests for the left margin and the padding around separators, set with `with_margin_left` and `with_padding`.
+
+use colprint::{ColumnFormatter, EnvConfig, FormattableItem};
+
+/// Format the cells in columns of the given widths, with the given separators, margin, and padding.
+fn padded(widths: &[usize], separators: &[&str], margin: usize, padding: usize) -> String {
+    let mut format_str = String::new();
+    for (column, width) in widths.iter().enumerate() {
+        format_str.push_str(&format!("{{}}:{width}"));
+        if let Some(separator) = separators.get(column) {
+            format_str.push_str(separator);
+        }
+    }
+    let cells: Vec<String> = widths.iter().map(|&width| "x".repeat(width)).collect();
+    ColumnFormatter::new(&format_str, cells.iter().map(FormattableItem::from))
+        .with_env(&EnvConfig::empty())
+        .with_margin_left(margin)
+        .with_padding(padding)
+        .to_string()
+}
+
+/// The length of a line: the margin, the widths, and each separator with padding on both sides, then the padding
+/// after the last column.
+fn expected_length(widths: &[usize], separators: &[&str], margin: usize, padding: usize) -> usize {
+    let separated: usize = separators.iter().map(|separator| separator.len() + 2 * padding).sum();
+    margin + widths.iter().sum::<usize>() + separated + padding
+}
+
+#[test]
+fn lines_are_as_long_as_margin_widths_separators_and_padding() {
+    let cases: [(&[usize], &[&str], usize, usize); 5] = [
+        (&[4, 6], &["|"], 0, 0),
+        (&[4, 6], &["|"], 3, 2),
+        (&[1, 2, 3], &[" | ", ":"], 1, 1),
+        (&[5], &[], 4, 3),
+        (&[2, 2, 2, 2], &["", "|", "||"], 0, 2),
+    ];
+    for (widths, separators, margin, padding) in cases {
+        let output = padded(widths, separators, margin, padding);
+        let expected = expected_length(widths, separators, margin, padding);
+        for line in output.lines() {
+            assert_eq!(line.chars().count(), expected, "{output:?}");
+        }
+    }
+}
+
+#[test]
+fn the_first_column_starts_at_the_margin() {
+    assert_eq!(padded(&[3, 2], &["|"], 2, 1), "  xxx | xx \n");
+}
+
+#[test]
+fn padding_follows_the_last_column() {
+    assert_eq!(padded(&[3], &[], 0, 2), "xxx  \n");
+    assert_eq!(padded(&[1, 1], &["|"], 0, 2), "x  |  x  \n");
+}
+
+#[test]
+fn padding_is_not_counted_in_column_widths() {
+    let formatter = ColumnFormatter::new("{}:4|{}:4", [&"abcdef", &"ghijkl"])
+        .with_env(&EnvConfig::empty())
+        .with_padding(1);
+    assert_eq!(formatter.to_string(), "abcd | ghij \n");
+}
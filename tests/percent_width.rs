@@ -0,0 +1,43 @@
+//! Tests for column widths given as percentages of the available width, such as `{}:40%`.
+
+use colprint::{ColumnFormatter, EnvConfig};
+
+/// The resolved column widths of the format string laid out within the maximum width.
+fn widths(format_str: &str, max_width: usize) -> Vec<usize> {
+    ColumnFormatter::new(format_str, [&"left", &"right"])
+        .with_env(&EnvConfig::empty())
+        .with_max_width(max_width)
+        .prepare()
+        .widths()
+        .to_vec()
+}
+
+#[test]
+fn percentages_are_of_the_maximum_width() {
+    assert_eq!(widths("{}:50% | {}", 40), [20, 5]);
+    assert_eq!(widths("{}:25% | {}:10%", 80), [20, 8]);
+}
+
+#[test]
+fn percentages_are_rounded_down() {
+    assert_eq!(widths("{}:33% | {}", 10), [3, 5]);
+}
+
+#[test]
+fn percentages_are_of_the_total_width() {
+    let formatter = ColumnFormatter::new("{}:50% | {}", [&"left", &"right"])
+        .with_env(&EnvConfig::empty())
+        .with_total_width(30);
+    assert_eq!(formatter.prepare().widths(), [15, 12]);
+    assert_eq!(formatter.to_string(), format!("{:15} | {:12}\n", "left", "right"));
+}
+
+#[test]
+fn percentages_which_do_not_fit_are_scaled_down_together() {
+    assert_eq!(widths("{}:60% | {}:60%", 40), [18, 18]);
+}
+
+#[test]
+fn absolute_widths_and_separators_are_left_room() {
+    assert_eq!(widths("{}:10 | {}:90%", 43), [10, 30]);
+}
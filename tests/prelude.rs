@@ -0,0 +1,32 @@
+//! Tests for reaching the common items through the prelude and the crate root.
+
+use colprint::prelude::*;
+
+#[test]
+fn prelude_brings_in_the_macros_and_types() {
+    let formatter = ColumnFormatter::new("{}:5 | {:?}", [FormattableItem::from(&"Alice"), FormattableItem::from(&30)]);
+    assert_eq!(formatter.to_string(), colformat!("{}:5 | {:?}", "Alice", 30));
+}
+
+#[test]
+fn prelude_brings_in_the_item_traits() {
+    let value = 42;
+    let item: FormattableItem<'_> = value.to_formattable_item();
+    assert_eq!(ColumnFormatter::new("{}", [item]).to_string(), "42\n");
+}
+
+#[test]
+fn format_types_are_named_at_the_root() {
+    let formatter = ColumnFormatter::new("{}:8 | {:?}", [&1, &2]);
+    let format: &colprint::ColumnFormat = &formatter.formats()[0];
+    assert_eq!(format.format_type, colprint::FormatType::Display);
+    assert_eq!(format.width, Some(colprint::Width::Chars(8)));
+    assert_eq!(formatter.formats()[1].format_type, FormatType::Debug);
+}
+
+#[test]
+fn value_specs_are_named_at_the_root() {
+    let formatter = ColumnFormatter::new("{:>6.2}", [&1.5]);
+    let spec: Option<colprint::ValueSpec> = formatter.formats()[0].value_spec;
+    assert_eq!(spec.map(|spec| spec.precision), Some(Some(2)));
+}
@@ -0,0 +1,74 @@
+//! Tests for preparing the cells of a `ColumnFormatter` and assembling them with `Prepared`.
+
+use std::iter;
+
+use colprint::{ColumnFormatter, FormattableItem, Overflow};
+
+#[test]
+fn lines_match_the_display_output() {
+    let notes = "first\nsecond";
+    let formatter = ColumnFormatter::new("{}:6 | {:?} | {}", [&"alpha", &"beta", &notes]).with_max_width(80);
+    let prepared = formatter.prepare();
+    let joined: String = prepared.lines().iter().map(|line| format!("{line}\n")).collect();
+    assert_eq!(joined, formatter.to_string());
+}
+
+#[test]
+fn cells_hold_the_formatted_lines_before_padding() {
+    let notes = "first\nsecond";
+    let formatter = ColumnFormatter::new("{}:6 | {:?} | {}", [&"alpha", &"beta", &notes]).with_max_width(80);
+    let prepared = formatter.prepare();
+    assert_eq!(prepared.cells().len(), 3);
+    assert_eq!(prepared.cell(0), Some(&["alpha".to_owned()][..]));
+    assert_eq!(prepared.cell(1), Some(&["\"beta\"".to_owned()][..]));
+    assert_eq!(prepared.cell(2), Some(&["first".to_owned(), "second".to_owned()][..]));
+    assert_eq!(prepared.cell(3), None);
+}
+
+#[test]
+fn widths_separators_and_height_are_resolved() {
+    let notes = "first\nsecond";
+    let formatter = ColumnFormatter::new("{}:6 | {:?} | {}", [&"alpha", &"beta", &notes]).with_max_width(80);
+    let prepared = formatter.prepare();
+    assert_eq!(prepared.widths(), [6, 6, 6]);
+    assert_eq!(prepared.visible_columns(), [0, 1, 2]);
+    assert_eq!(prepared.separator(0), Some(" | "));
+    assert_eq!(prepared.separator(2), None);
+    assert_eq!(prepared.height(), 2);
+}
+
+#[test]
+fn render_lines_match_the_full_lines() {
+    let tall = "a\nb\nc\nd";
+    let formatter = ColumnFormatter::new("{} | {}", [&tall, &"x"]).with_max_width(80);
+    let prepared = formatter.prepare();
+    assert_eq!(prepared.render_lines(1..3), prepared.lines()[1..3]);
+    assert_eq!(prepared.render_lines(1..3), formatter.render_lines(1..3));
+    assert!(prepared.render_lines(10..12).is_empty());
+}
+
+#[test]
+fn measure_matches_the_formatter() {
+    let formatter = ColumnFormatter::new("{}:10 | {}", [&"name", &"value"]).with_max_width(80);
+    assert_eq!(formatter.prepare().measure(), formatter.measure());
+}
+
+#[test]
+fn dropped_columns_are_not_visible() {
+    let formatter = ColumnFormatter::new("{}:8 | {}:8 | {}:8", [&"one", &"two", &"three"])
+        .with_overflow(Overflow::DropColumns { notice: false })
+        .with_max_width(20);
+    let prepared = formatter.prepare();
+    assert_eq!(prepared.visible_columns(), [0, 1]);
+    let joined: String = prepared.lines().iter().map(|line| format!("{line}\n")).collect();
+    assert_eq!(joined, formatter.to_string());
+}
+
+#[test]
+fn empty_formatters_prepare_nothing() {
+    let formatter = ColumnFormatter::new("", iter::empty::<FormattableItem<'_>>());
+    let prepared = formatter.prepare();
+    assert!(prepared.cells().is_empty());
+    assert_eq!(prepared.height(), 0);
+    assert!(prepared.lines().is_empty());
+}
@@ -0,0 +1,55 @@
+//! Tests for writing whole blocks, as the printing macros do, with `write_locked`.
+
+use std::{
+    io::{self, Write},
+    iter,
+};
+
+use colprint::{ColumnFormatter, EnvConfig, FormattableItem, macro_support::write_locked};
+
+/// A writer failing every write.
+struct Failing;
+
+impl Write for Failing {
+    fn write(&mut self, _: &[u8]) -> io::Result<usize> {
+        Err(io::Error::other("closed"))
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+/// Write the formatter as the macros print it, returning the text written.
+fn written(formatter: &ColumnFormatter<'_>) -> String {
+    let mut buffer = Vec::new();
+    assert!(write_locked(&mut buffer, formatter).is_ok());
+    String::from_utf8(buffer).unwrap_or_default()
+}
+
+#[test]
+fn every_line_ends_in_a_newline() {
+    let tall = "a\nb\nc";
+    let formatter = ColumnFormatter::new("{} | {}", [&tall, &"x"]).with_env(&EnvConfig::empty());
+    let output = written(&formatter);
+    assert_eq!(output, "a | x\nb |  \nc |  \n");
+    assert_eq!(output.matches('\n').count(), output.lines().count());
+}
+
+#[test]
+fn the_block_matches_the_display_output() {
+    let formatter = ColumnFormatter::new("{}:6 | {:?}", [&"name", &"value"]).with_env(&EnvConfig::empty());
+    assert_eq!(written(&formatter), formatter.to_string());
+}
+
+#[test]
+fn empty_blocks_write_nothing() {
+    let formatter = ColumnFormatter::new("", iter::empty::<FormattableItem<'_>>());
+    assert_eq!(written(&formatter), "");
+}
+
+#[test]
+fn write_errors_are_returned() {
+    let formatter = ColumnFormatter::new("{}", [&"a"]);
+    assert!(write_locked(&mut Failing, &formatter).is_err());
+}
@@ -0,0 +1,78 @@
+//! Tests for drawing a formatter into a ratatui buffer with `ColprintWidget`.
+#![cfg(feature = "ratatui")]
+
+use colprint::{Color, ColorChoice, ColprintWidget, ColumnFormatter, Style};
+use ratatui::{
+    buffer::Buffer,
+    layout::Rect,
+    style::{Color as RatatuiColor, Modifier, Style as RatatuiStyle},
+    widgets::Widget,
+};
+
+/// The symbols of a row of the buffer, joined into a string.
+fn row(buf: &Buffer, y: u16) -> String {
+    (buf.area.x..buf.area.x + buf.area.width)
+        .map(|x| buf.cell((x, y)).unwrap().symbol().to_owned())
+        .collect()
+}
+
+#[test]
+fn draws_into_the_area() {
+    let left = "a\nb";
+    let right = "x";
+    let formatter = ColumnFormatter::new("{} | {}", [&left, &right]);
+    let area = Rect::new(0, 0, 7, 3);
+    let mut buf = Buffer::empty(area);
+    ColprintWidget::new(&formatter).render(area, &mut buf);
+    assert_eq!(row(&buf, 0), "a | x  ");
+    assert_eq!(row(&buf, 1), "b |    ");
+    assert_eq!(row(&buf, 2), "       ");
+}
+
+#[test]
+fn scrolls_through_long_output() {
+    let left = "1\n2\n3\n4";
+    let formatter = ColumnFormatter::new("{}", [&left]);
+    let area = Rect::new(2, 1, 2, 2);
+    let mut buf = Buffer::empty(area);
+    ColprintWidget::new(&formatter).scroll(2).render(area, &mut buf);
+    assert_eq!(row(&buf, 1), "3 ");
+    assert_eq!(row(&buf, 2), "4 ");
+}
+
+#[test]
+fn maps_sgr_to_styles() {
+    let text = "\x1b[1;31mhi\x1b[0m!";
+    let formatter = ColumnFormatter::new("{}", [&text]).with_color(ColorChoice::Always);
+    let area = Rect::new(0, 0, 3, 1);
+    let mut buf = Buffer::empty(area);
+    ColprintWidget::new(&formatter).render(area, &mut buf);
+    assert_eq!(row(&buf, 0), "hi!");
+    let plain = RatatuiStyle::new().fg(RatatuiColor::Reset).bg(RatatuiColor::Reset);
+    let bold_red = plain.fg(RatatuiColor::Red).add_modifier(Modifier::BOLD);
+    assert_eq!(buf.cell((0, 0)).unwrap().style(), bold_red);
+    assert_eq!(buf.cell((2, 0)).unwrap().style(), plain);
+}
+
+#[test]
+fn clears_styles_already_in_the_buffer() {
+    let text = "\x1b[31mhi\x1b[0m!";
+    let formatter = ColumnFormatter::new("{}", [&text]).with_color(ColorChoice::Always);
+    let area = Rect::new(0, 0, 3, 1);
+    let mut buf = Buffer::empty(area);
+    buf.set_style(area, RatatuiStyle::new().bg(RatatuiColor::Blue).add_modifier(Modifier::ITALIC));
+    ColprintWidget::new(&formatter).render(area, &mut buf);
+    let plain = RatatuiStyle::new().fg(RatatuiColor::Reset).bg(RatatuiColor::Reset);
+    assert_eq!(buf.cell((0, 0)).unwrap().style(), plain.fg(RatatuiColor::Red));
+    assert_eq!(buf.cell((2, 0)).unwrap().style(), plain);
+}
+
+#[test]
+fn converts_styles() {
+    let style = Style::new().fg(Color::Green).bg(Color::White).dim();
+    let expected = RatatuiStyle::new()
+        .fg(RatatuiColor::Green)
+        .bg(RatatuiColor::Gray)
+        .add_modifier(Modifier::DIM);
+    assert_eq!(RatatuiStyle::from(style), expected);
+}
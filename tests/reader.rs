@@ -0,0 +1,80 @@
+//! Tests for streaming cells from `BufRead` sources with `FormattableItem::Reader`.
+
+use std::{
+    cell::RefCell,
+    io::{self, BufRead, BufReader, Cursor, Read},
+};
+
+use colprint::{ColumnFormatter, EnvConfig, FormattableItem};
+
+/// A source of endless lines, which would never finish if read to the end.
+struct Endless;
+
+impl Read for Endless {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        for (idx, byte) in buf.iter_mut().enumerate() {
+            *byte = if idx % 4 == 3 { b'\n' } else { b'x' };
+        }
+        Ok(buf.len())
+    }
+}
+
+/// A source which fails after its first line.
+struct Failing(bool);
+
+impl Read for Failing {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        if self.0 {
+            return Err(io::Error::other("disk on fire"));
+        }
+        self.0 = true;
+        buf[..3].copy_from_slice(b"ok\n");
+        Ok(3)
+    }
+}
+
+#[test]
+fn reader_lines_fill_the_column() {
+    let source = RefCell::new(Cursor::new("one\ntwo\r\nthree"));
+    let label = "log";
+    let items = [FormattableItem::from(&label), FormattableItem::Reader(&source)];
+    let output = ColumnFormatter::new("{} | {}", items).to_string();
+    assert_eq!(output, "log | one  \n    | two  \n    | three\n");
+}
+
+#[test]
+fn endless_sources_stop_at_the_line_cap() {
+    let source: RefCell<BufReader<Endless>> = RefCell::new(BufReader::new(Endless));
+    let items = [FormattableItem::Reader(&source)];
+    let output = ColumnFormatter::new("{}", items)
+        .with_env(&EnvConfig::empty())
+        .with_max_lines_per_cell(3)
+        .to_string();
+    assert_eq!(output, "xxx\nxxx\nxxx\n");
+    assert!(source.borrow_mut().fill_buf().is_ok());
+}
+
+#[test]
+fn long_lines_are_cut_while_reading() {
+    let source = RefCell::new(Cursor::new(format!("{}\nend", "y".repeat(100_000))));
+    let items = [FormattableItem::Reader(&source)];
+    let output = ColumnFormatter::new("{}", items).with_max_cell_width(5).to_string();
+    assert_eq!(output, "yyyyy\nend  \n");
+}
+
+#[test]
+fn errors_end_the_column_with_a_marker() {
+    let source = RefCell::new(BufReader::new(Failing(false)));
+    let items = [FormattableItem::Reader(&source)];
+    let output = ColumnFormatter::new("{}", items).to_string();
+    let lines: Vec<&str> = output.lines().map(str::trim_end).collect();
+    assert_eq!(lines, ["ok", "<read error: other error>"]);
+}
+
+#[test]
+fn debug_does_not_consume_the_reader() {
+    let source = RefCell::new(Cursor::new("kept"));
+    let item = FormattableItem::Reader(&source);
+    assert_eq!(format!("{item:?}"), "Reader(..)");
+    assert_eq!(ColumnFormatter::new("{}", [item]).to_string(), "kept\n");
+}
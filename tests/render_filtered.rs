@@ -0,0 +1,49 @@
+//! Tests for rendering the rows of a `Table` which match a predicate with `render_filtered`.
+
+use colprint::{EnvConfig, FilterWidths, FormattableItem, Table};
+
+/// A table of services and their states, with a header.
+fn services() -> Table {
+    let mut table = Table::new("{} | {}")
+        .with_env(&EnvConfig::empty())
+        .with_headers(["Service", "State"]);
+    for (service, state) in [("api", "up"), ("database", "down"), ("cache", "up")] {
+        table.push_row(&[FormattableItem::from(&service), FormattableItem::from(&state)]);
+    }
+    table
+}
+
+/// Whether the row reports a service which is up.
+fn is_up(row: &[String]) -> bool {
+    row.get(1).is_some_and(|state| state == "up")
+}
+
+#[test]
+fn only_matching_rows_are_rendered_and_measured() {
+    let output = services().render_filtered(is_up);
+    assert_eq!(output, "Service | State\napi     | up   \ncache   | up   \n");
+}
+
+#[test]
+fn widths_can_be_measured_from_every_row() {
+    let output = services().with_filter_widths(FilterWidths::AllRows).render_filtered(is_up);
+    assert_eq!(output, "Service  | State\napi      | up   \ncache    | up   \n");
+}
+
+#[test]
+fn the_count_line_reports_rows_shown() {
+    let output = services().with_row_count(true).render_filtered(is_up);
+    assert_eq!(output.lines().last(), Some("(showing 2 of 3 rows)"));
+    assert_eq!(output.lines().count(), 4);
+}
+
+#[test]
+fn the_count_line_is_off_by_default() {
+    assert!(!services().render_filtered(is_up).contains("showing"));
+}
+
+#[test]
+fn headers_are_kept_when_no_rows_match() {
+    let output = services().with_row_count(true).render_filtered(|_| false);
+    assert_eq!(output, "Service | State\n(showing 0 of 3 rows)\n");
+}
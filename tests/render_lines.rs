@@ -0,0 +1,35 @@
+//! Tests for rendering a range of output lines with `ColumnFormatter::render_lines`.
+
+use colprint::{ColumnFormatter, Overflow};
+
+#[test]
+fn lines_match_the_full_output() {
+    let left = (0..10).map(|n| n.to_string()).collect::<Vec<_>>().join("\n");
+    let right = "top".to_owned();
+    let formatter = ColumnFormatter::new("{} | {}", [&left, &right]);
+    let full = formatter.to_string();
+    let all: Vec<&str> = full.lines().collect();
+    assert_eq!(formatter.render_lines(3..6), all[3..6]);
+    assert_eq!(formatter.render_lines(0..10), all);
+}
+
+#[test]
+fn out_of_range_returns_fewer_lines() {
+    let left = "a\nb\nc".to_owned();
+    let formatter = ColumnFormatter::new("{}", [&left]);
+    assert_eq!(formatter.render_lines(2..8), ["c"]);
+    assert!(formatter.render_lines(5..8).is_empty());
+}
+
+#[test]
+fn hidden_column_notice_is_a_line_of_its_own() {
+    let (left, right) = ("a\nb".to_owned(), "wide column".to_owned());
+    let formatter = ColumnFormatter::new("{} | {}!0", [&left, &right])
+        .with_max_width(5)
+        .with_overflow(Overflow::DropColumns { notice: true });
+    let full = formatter.to_string();
+    let all: Vec<&str> = full.lines().collect();
+    assert_eq!(all.len(), 3);
+    assert_eq!(formatter.render_lines(2..3), all[2..3]);
+    assert_eq!(formatter.render_lines(1..2), all[1..2]);
+}
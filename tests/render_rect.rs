@@ -0,0 +1,74 @@
+//! Tests for rendering into a fixed rectangle with `ColumnFormatter::render_rect`.
+
+use colprint::{ColorChoice, ColumnFormatter, EnvConfig, Overflow};
+
+/// Check that every line of a rectangle has exactly the given width.
+fn assert_dimensions(lines: &[String], width: usize, height: usize) {
+    assert_eq!(lines.len(), height);
+    for line in lines {
+        let visible: String = strip_escapes(line);
+        assert_eq!(visible.chars().count(), width, "{line:?}");
+    }
+}
+
+/// Remove the escape sequences from a line.
+fn strip_escapes(line: &str) -> String {
+    let mut stripped = String::new();
+    let mut in_escape = false;
+    for ch in line.chars() {
+        if ch == '\x1b' {
+            in_escape = true;
+        } else if in_escape {
+            in_escape = !ch.is_ascii_alphabetic();
+        } else {
+            stripped.push(ch);
+        }
+    }
+    stripped
+}
+
+#[test]
+fn pads_to_width_and_height() {
+    let left = "a\nb";
+    let right = "x";
+    let lines = ColumnFormatter::new("{} | {}", [&left, &right]).render_rect(8, 4);
+    assert_dimensions(&lines, 8, 4);
+    assert_eq!(lines, ["a | x   ", "b |     ", "        ", "        "]);
+}
+
+#[test]
+fn clips_to_height() {
+    let left = "1\n2\n3\n4";
+    let lines = ColumnFormatter::new("{}", [&left]).render_rect(3, 2);
+    assert_eq!(lines, ["1  ", "2  "]);
+}
+
+#[test]
+fn fits_columns_to_width() {
+    let left = "the quick brown fox";
+    let right = "jumps";
+    let lines = ColumnFormatter::new("{} | {}", [&left, &right])
+        .with_env(&EnvConfig::empty())
+        .with_overflow(Overflow::Wrap { min_width: 3 })
+        .render_rect(16, 3);
+    assert_dimensions(&lines, 16, 3);
+    assert!(lines.iter().any(|line| line.contains("jumps")), "{lines:?}");
+}
+
+#[test]
+fn clips_lines_wider_than_the_rectangle() {
+    let left = "abcdefghij";
+    let lines = ColumnFormatter::new("{}", [&left]).render_rect(4, 1);
+    assert_eq!(lines, ["abcd"]);
+}
+
+#[test]
+fn balances_styles_per_line() {
+    let left = "\x1b[31mred text that is long\x1b[0m";
+    let lines = ColumnFormatter::new("{}", [&left])
+        .with_color(ColorChoice::Always)
+        .render_rect(5, 1);
+    assert_dimensions(&lines, 5, 1);
+    assert!(lines[0].starts_with("\x1b[31mred t"), "{:?}", lines[0]);
+    assert!(lines[0].ends_with("\x1b[0m"), "{:?}", lines[0]);
+}
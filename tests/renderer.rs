@@ -0,0 +1,62 @@
+//! Tests for writing tables through `Renderer` backends selected with `OutputStyle::Custom`.
+
+use std::fmt::{Result, Write};
+
+use colprint::{Alignment, Cell, OutputStyle, PlainRenderer, RenderColumn, Renderer, Table};
+
+/// A table with headers, multi-line cells, and a right-aligned column.
+fn table() -> Table {
+    Table::from_columns("{} | {}", (vec!["Alice\nSmith", "Bob"], vec![30, 4]))
+        .with_headers(["Name", "Age"])
+        .align_data(1, Alignment::Right)
+}
+
+/// A renderer writing AsciiDoc tables.
+struct AsciiDoc;
+
+impl Renderer for AsciiDoc {
+    fn begin_table(&self, out: &mut dyn Write, _columns: &[RenderColumn<'_>]) -> Result {
+        writeln!(out, "|===")
+    }
+
+    fn data_row(&self, out: &mut dyn Write, _columns: &[RenderColumn<'_>], cells: &[Cell]) -> Result {
+        for cell in cells {
+            write!(out, "|{} ", cell.lines().join(" +\n"))?;
+        }
+        writeln!(out)
+    }
+
+    fn end_table(&self, out: &mut dyn Write, _columns: &[RenderColumn<'_>]) -> Result {
+        writeln!(out, "|===")
+    }
+}
+
+#[test]
+fn plain_output_is_unchanged() {
+    assert_eq!(table().to_string(), include_str!("golden/plain_table.txt"));
+}
+
+#[test]
+fn plain_renderer_matches_the_plain_style() {
+    let rendered = table()
+        .with_renderer("plain", PlainRenderer::new())
+        .with_style(OutputStyle::Custom("plain"));
+    assert_eq!(rendered.to_string(), include_str!("golden/plain_table.txt"));
+}
+
+#[test]
+fn third_party_renderers_are_dispatched_by_name() {
+    let rendered = table()
+        .with_renderer("asciidoc", AsciiDoc)
+        .with_style(OutputStyle::Custom("asciidoc"));
+    assert_eq!(
+        rendered.to_string(),
+        "|===\n|Name |Age \n|Alice +\nSmith |30 \n|Bob |4 \n|===\n"
+    );
+}
+
+#[test]
+fn unregistered_renderers_fall_back_to_plain() {
+    let rendered = table().with_style(OutputStyle::Custom("missing"));
+    assert_eq!(rendered.to_string(), include_str!("golden/plain_table.txt"));
+}
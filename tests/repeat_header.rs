@@ -0,0 +1,44 @@
+//! Tests for repeating the header of long tables with `repeat_header_every`.
+
+use colprint::{EnvConfig, FormattableItem, OutputStyle, Table};
+
+/// A table of five numbered rows with a header, repeated after every `rows` rows.
+fn numbered(rows: usize) -> Table {
+    let mut table = Table::new("{} | {}")
+        .with_env(&EnvConfig::empty())
+        .with_headers(["N", "Square"])
+        .repeat_header_every(rows);
+    for n in 1..=5 {
+        table.push_row(&[FormattableItem::from(&n), FormattableItem::from(&(n * n))]);
+    }
+    table
+}
+
+#[test]
+fn headers_are_repeated_between_groups_of_rows() {
+    assert_eq!(
+        numbered(2).to_string(),
+        "N | Square\n1 | 1     \n2 | 4     \n\
+         N | Square\n3 | 9     \n4 | 16    \n\
+         N | Square\n5 | 25    \n"
+    );
+}
+
+#[test]
+fn headers_are_not_repeated_after_the_last_row() {
+    let output = numbered(5).to_string();
+    assert_eq!(output.lines().filter(|line| line.starts_with("N ")).count(), 1);
+    assert_eq!(output.lines().last(), Some("5 | 25    "));
+}
+
+#[test]
+fn zero_turns_repetition_off() {
+    assert_eq!(numbered(0).to_string(), numbered(10).to_string());
+}
+
+#[test]
+fn markdown_repeats_only_the_header_row() {
+    let output = numbered(3).with_style(OutputStyle::Markdown).to_string();
+    assert_eq!(output.lines().filter(|line| line.starts_with("| N ")).count(), 2);
+    assert_eq!(output.lines().filter(|line| line.starts_with("| ---")).count(), 1);
+}
@@ -0,0 +1,38 @@
+//! Tests for aligning the header, data, and footer of a table column separately.
+
+use colprint::{Alignment, EnvConfig, Table};
+
+/// A table of items and prices, with a header and a footer narrower than the prices.
+fn prices() -> Table {
+    Table::from_columns("{} | {}", (vec!["tea", "coffee"], vec![3, 12500]))
+        .with_env(&EnvConfig::empty())
+        .with_headers(["Item", "$"])
+        .with_footers(["Total", "12k"])
+}
+
+#[test]
+fn headers_and_footers_follow_the_data_by_default() {
+    let output = prices().align_data(1, Alignment::Right).to_string();
+    assert_eq!(output, "Item   |     $\ntea    |     3\ncoffee | 12500\nTotal  |   12k\n");
+}
+
+#[test]
+fn headers_can_be_aligned_apart_from_the_data() {
+    let output = prices()
+        .align_data(1, Alignment::Right)
+        .align_header(1, Alignment::Center)
+        .to_string();
+    assert_eq!(output.lines().next(), Some("Item   |   $  "));
+    assert_eq!(output.lines().nth(1), Some("tea    |     3"));
+    assert_eq!(output.lines().last(), Some("Total  |   12k"));
+}
+
+#[test]
+fn footers_can_be_aligned_apart_from_the_data() {
+    let output = prices()
+        .align_data(1, Alignment::Right)
+        .align_footer(1, Alignment::Left)
+        .to_string();
+    assert_eq!(output.lines().next(), Some("Item   |     $"));
+    assert_eq!(output.lines().last(), Some("Total  | 12k  "));
+}
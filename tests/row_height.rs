@@ -0,0 +1,71 @@
+//! Tests for the height of table rows mixing empty, single-line, and multi-line cells.
+
+use colprint::{FormattableItem, OutputStyle, PlainRenderer, Table};
+
+/// A table of three text columns with the given rows.
+fn table(rows: &[[&str; 3]]) -> Table {
+    let mut table = Table::new("{} | {} | {}");
+    for row in rows {
+        let items: Vec<FormattableItem<'_>> = row.iter().map(FormattableItem::from).collect();
+        table.push_row(&items);
+    }
+    table
+}
+
+#[test]
+fn empty_cells_are_padded_to_the_tallest_cell() {
+    let table = table(&[["a\nb\nc", "", "x"], ["d", "e", "f"]]);
+    assert_eq!(table.to_string(), "a |   | x\nb |   |  \nc |   |  \nd | e | f\n");
+}
+
+#[test]
+fn an_empty_middle_cell_keeps_its_neighbours_aligned() {
+    let table = table(&[["1", "", "one\ntwo\nthree"], ["2", "x", "four"]]);
+    assert_eq!(
+        table.to_string(),
+        "1 |   | one  \n  |   | two  \n  |   | three\n2 | x | four \n"
+    );
+}
+
+#[test]
+fn rows_of_only_empty_cells_take_one_line() {
+    let table = table(&[["a", "b", "c"], ["", "", ""], ["d", "e", "f"]]);
+    assert_eq!(table.to_string(), "a | b | c\n  |   |  \nd | e | f\n");
+}
+
+#[test]
+fn each_row_starts_below_the_tallest_cell_of_the_previous_row() {
+    let table = table(&[["a\nb", "", "c\nd\ne"], ["f", "g\nh", ""], ["", "", "i"]]);
+    assert_eq!(
+        table.to_string(),
+        concat!(
+            "a |   | c\n",
+            "b |   | d\n",
+            "  |   | e\n",
+            "f | g |  \n",
+            "  | h |  \n",
+            "  |   | i\n",
+        )
+    );
+}
+
+#[test]
+fn trailing_newlines_do_not_add_lines() {
+    let table = table(&[["a\n", "b", "c\nd"]]);
+    assert_eq!(table.to_string(), "a | b | c\n  |   | d\n");
+}
+
+#[test]
+fn grouped_and_rendered_tables_use_the_same_row_heights() {
+    let plain = table(&[["g", "", "a\nb"], ["g", "c", ""]]);
+    let expected = "g |   | a\n  |   | b\ng | c |  \n";
+    assert_eq!(plain.to_string(), expected);
+
+    let rendered = table(&[["g", "", "a\nb"], ["g", "c", ""]])
+        .with_renderer("plain", PlainRenderer::new())
+        .with_style(OutputStyle::Custom("plain"));
+    assert_eq!(rendered.to_string(), expected);
+
+    let grouped = table(&[["g", "", "a\nb"], ["g", "c", ""]]).group_by(0);
+    assert_eq!(grouped.to_string(), "g\ng |   | a\n  |   | b\ng | c |  \n");
+}
@@ -0,0 +1,37 @@
+//! Tests for horizontal rules matching the width of the output.
+
+use colprint::{BlockAlign, ColumnFormatter, Overflow};
+
+#[test]
+fn rules_span_columns_and_separators() {
+    let formatter = ColumnFormatter::new("{} | {}", [&"name", &"Alice"]);
+    assert_eq!(formatter.rule('-'), "------------");
+}
+
+#[test]
+fn rules_span_frames_and_skip_the_margin() {
+    let formatter = ColumnFormatter::new("{} {}", [&"a", &"b"])
+        .with_margin_left(2)
+        .frame_sides("| ", " |");
+    let width = formatter.to_string().lines().map(|line| line.chars().count()).max();
+    let rule = formatter.rule('=');
+    assert_eq!(rule, "  =======");
+    assert_eq!(Some(rule.chars().count()), width);
+}
+
+#[test]
+fn rules_match_the_fitted_width() {
+    let (long, end) = ("x".repeat(50), "end".to_owned());
+    let formatter = ColumnFormatter::new("{} {}", [&long, &end])
+        .with_max_width(20)
+        .with_overflow(Overflow::Wrap { min_width: 5 });
+    assert_eq!(formatter.rule('\u{2500}').chars().count(), 20);
+}
+
+#[test]
+fn rules_are_placed_like_the_block() {
+    let formatter = ColumnFormatter::new("{}", [&"abcd"])
+        .with_block_align(BlockAlign::Right)
+        .with_block_width(10);
+    assert_eq!(formatter.rule('-'), "      ----");
+}
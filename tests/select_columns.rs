@@ -0,0 +1,45 @@
+//! Tests for rendering a selection of a table's columns.
+
+use colprint::{FormatError, Table};
+
+/// A table of names, ages and cities, with headers.
+fn table() -> Table {
+    Table::from_columns("{} | {}, {}", (vec!["Alice", "Bob"], vec![30, 4], vec!["Paris", "Oslo"]))
+        .with_headers(["Name", "Age", "City"])
+}
+
+#[test]
+fn columns_are_reordered_with_their_own_separators() {
+    let table = table().select_columns(&[2, 0]).unwrap();
+    assert_eq!(table.to_string(), "City  | Name \nParis | Alice\nOslo  | Bob  \n");
+}
+
+#[test]
+fn widths_come_from_the_selected_columns() {
+    let table = table().select_columns(&[1]).unwrap();
+    assert_eq!(table.to_string(), "Age\n30 \n4  \n");
+}
+
+#[test]
+fn columns_can_be_repeated() {
+    let table = table().select_columns(&[1, 1]).unwrap();
+    assert_eq!(table.to_string(), "Age, Age\n30 , 30 \n4  , 4  \n");
+}
+
+#[test]
+fn columns_can_be_selected_by_header() {
+    let table = table().select_headers(&["City", "Age"]).unwrap();
+    assert_eq!(table.to_string(), "City  | Age\nParis | 30 \nOslo  | 4  \n");
+}
+
+#[test]
+fn unknown_columns_are_errors() {
+    assert_eq!(
+        table().select_columns(&[0, 3]).err(),
+        Some(FormatError::ColumnOutOfRange { column: 3, columns: 3 })
+    );
+    assert_eq!(
+        table().select_headers(&["Name", "Country"]).err(),
+        Some(FormatError::UnknownHeader { position: 1 })
+    );
+}
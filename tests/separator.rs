@@ -0,0 +1,45 @@
+//! Tests for separators whose piece depends on the position of the line with `Separator::PerPosition`.
+
+use colprint::{ColumnFormatter, FormattableItem, Separator, Table};
+
+/// A bracket-like gutter separator.
+fn bracket() -> Separator {
+    Separator::per_position(" \u{250c} ", " \u{2502} ", " \u{2514} ").unwrap()
+}
+
+#[test]
+fn pieces_follow_the_line_position() {
+    let left = "a\nb\nc";
+    let right = "x";
+    let lines: Vec<String> = ColumnFormatter::new("{} | {}", [&left, &right])
+        .with_separator(0, bracket())
+        .to_string()
+        .lines()
+        .map(str::to_owned)
+        .collect();
+    assert_eq!(lines, ["a \u{250c} x", "b \u{2502}  ", "c \u{2514}  "]);
+}
+
+#[test]
+fn single_line_uses_middle() {
+    let left = "a";
+    let right = "x";
+    let output = ColumnFormatter::new("{}|{}", [&left, &right])
+        .with_separator(0, bracket())
+        .to_string();
+    assert_eq!(output, "a \u{2502} x\n");
+}
+
+#[test]
+fn pieces_of_different_widths_are_rejected() {
+    assert_eq!(Separator::per_position("+", "|", "--"), None);
+}
+
+#[test]
+fn table_rows_restart_the_pieces() {
+    let mut table = Table::new("{} | {}").with_separator(0, bracket());
+    let (tall, short, one) = ("a\nb", "x", "c");
+    table.push_row(&[FormattableItem::from(&tall), FormattableItem::from(&short)]);
+    table.push_row(&[FormattableItem::from(&one), FormattableItem::from(&short)]);
+    assert_eq!(table.to_string(), "a \u{250c} x\nb \u{2514}  \nc \u{2502} x\n");
+}
@@ -0,0 +1,46 @@
+//! Tests for showing separators only on the first line of a row with `SeparatorFill`.
+
+use colprint::{ColumnFormatter, FormattableItem, SeparatorFill, Table};
+
+/// Render two multi-line items separated by the given separator, with the given fill.
+fn rendered(separator: &str, separator_fill: SeparatorFill) -> Vec<String> {
+    let left = "a\nb\nc";
+    let right = "x\ny";
+    let format = format!("{{}}{separator}{{}}");
+    ColumnFormatter::new(&format, [&left, &right])
+        .with_separator_fill(0, separator_fill)
+        .to_string()
+        .lines()
+        .map(str::to_owned)
+        .collect()
+}
+
+#[test]
+fn repeat_writes_the_separator_on_every_line() {
+    let lines = rendered(" | ", SeparatorFill::Repeat);
+    assert_eq!(lines, ["a | x", "b | y", "c |  "]);
+}
+
+#[test]
+fn first_line_styled_fills_later_lines() {
+    let lines = rendered(" | ", SeparatorFill::FirstLineStyled { rest: ' ' });
+    assert_eq!(lines, ["a | x", "b   y", "c    "]);
+}
+
+#[test]
+fn fill_matches_the_visible_width_of_styled_separators() {
+    let separator = "\x1b[2m ─┼─ \x1b[0m";
+    let lines = rendered(separator, SeparatorFill::FirstLineStyled { rest: '.' });
+    assert_eq!(lines[0], format!("a{separator}x"));
+    assert_eq!(lines[1], "b.....y");
+}
+
+#[test]
+fn tables_fill_separators_of_multi_line_cells() {
+    let left = "a\nb";
+    let right = "x";
+    let mut table = Table::new("{} | {}").with_separator_fill(0, SeparatorFill::FirstLineStyled { rest: ' ' });
+    table.push_row(&[FormattableItem::from(&left), FormattableItem::from(&right)]);
+    let lines: Vec<String> = table.to_string().lines().map(|line| line.trim_end().to_owned()).collect();
+    assert_eq!(lines, ["a | x", "b"]);
+}
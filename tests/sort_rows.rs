@@ -0,0 +1,45 @@
+//! Tests for sorting table rows with `Table::sort_rows_by`.
+
+use colprint::{FormattableItem, Table};
+
+/// Build a table of team, name, and score rows.
+fn scores() -> Table {
+    let mut table = Table::new("{} {} {}");
+    for (team, name, score) in [("red", "Cleo", 7), ("blue", "Ari", 9), ("red", "Abe", 3), ("blue", "Bea", 9)] {
+        table.push_row(&[
+            FormattableItem::from(&team),
+            FormattableItem::from(&name),
+            FormattableItem::from(&score),
+        ]);
+    }
+    table
+}
+
+#[test]
+fn sorts_by_secondary_column_when_primary_ties() {
+    let mut table = scores();
+    table.sort_rows_by(|a, b| a[0].cmp(&b[0]).then_with(|| a[1].cmp(&b[1])));
+    assert_eq!(table.to_string(), "blue Ari  9\nblue Bea  9\nred  Abe  3\nred  Cleo 7\n");
+}
+
+#[test]
+fn keeps_tied_rows_in_order() {
+    let mut table = scores();
+    table.sort_rows_by(|a, b| a[2].parse::<u32>().unwrap().cmp(&b[2].parse::<u32>().unwrap()).reverse());
+    assert_eq!(table.to_string(), "blue Ari  9\nblue Bea  9\nred  Cleo 7\nred  Abe  3\n");
+}
+
+#[test]
+fn does_not_resort_rows_pushed_later() {
+    let mut table = scores();
+    table.sort_rows_by(|a, b| a[1].cmp(&b[1]));
+    table.push_row(&[
+        FormattableItem::from(&"blue"),
+        FormattableItem::from(&"Aaron"),
+        FormattableItem::from(&1),
+    ]);
+    assert_eq!(
+        table.to_string(),
+        "red  Abe   3\nblue Ari   9\nblue Bea   9\nred  Cleo  7\nblue Aaron 1\n"
+    );
+}
@@ -0,0 +1,51 @@
+//! Tests for tolerating whitespace between the tokens of a specifier.
+
+use colprint::{ColumnFormatter, FormatType, colformat};
+
+/// The format types of a format string's columns.
+fn format_types(format_str: &str) -> Vec<FormatType> {
+    let (a, b) = ("a", "b");
+    ColumnFormatter::new(format_str, [&a, &b])
+        .formats()
+        .iter()
+        .map(|format| format.format_type)
+        .collect()
+}
+
+#[test]
+fn whitespace_after_the_opening_brace() {
+    assert_eq!(format_types("{ :?} | { }"), [FormatType::Debug, FormatType::Display]);
+}
+
+#[test]
+fn whitespace_before_the_closing_brace() {
+    assert_eq!(format_types("{:#? } | {: }"), [FormatType::PrettyDebug, FormatType::Display]);
+}
+
+#[test]
+fn whitespace_around_an_inner_width() {
+    let spaced = ColumnFormatter::new("{ :? : 40 }", [&"a"]);
+    let compact = ColumnFormatter::new("{:?:40}", [&"a"]);
+    assert_eq!(format!("{:?}", spaced.formats()), format!("{:?}", compact.formats()));
+}
+
+#[test]
+fn whitespace_before_an_anchor() {
+    assert_eq!(colformat!("{} | { @8}", "a", "b"), colformat!("{} | {@8}", "a", "b"));
+}
+
+#[test]
+fn spaces_used_as_fill_are_kept() {
+    assert_eq!(colformat!("{: >4}|{}", 7, "x"), "   7|x\n");
+}
+
+#[test]
+fn spaces_inside_time_patterns_are_kept() {
+    let spaced = ColumnFormatter::new("{ :time(%H %M) }", [&"a"]);
+    assert_eq!(spaced.formats()[0].time_pattern.as_deref(), Some("%H %M"));
+}
+
+#[test]
+fn macro_agrees_with_the_formatter() {
+    assert_eq!(colformat!("{ :? } | { }", "a", "b"), "\"a\" | b\n");
+}
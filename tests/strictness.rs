@@ -0,0 +1,80 @@
+//! Tests for reporting mismatched items and malformed format strings with `Strictness::Strict`.
+
+use colprint::{ColumnFormatter, FormatError, FormattableItem, Strictness};
+
+/// Render the format string over a `Display`-only number and a `Debug`-only string, returning the text written
+/// and the result of writing it.
+fn write(format_str: &str, strictness: Strictness) -> (String, Result<(), FormatError>) {
+    let (number, text) = (5, "ab");
+    let items = [FormattableItem::DisplayItem(&number), FormattableItem::DebugItem(&text)];
+    let formatter = ColumnFormatter::new(format_str, items).with_strictness(strictness);
+    let mut output = Vec::new();
+    let result = formatter.write_to(&mut output);
+    (String::from_utf8(output).unwrap(), result)
+}
+
+/// The error `try_new` reports for the format string over the same items as `write`.
+fn try_new(format_str: &str) -> Option<FormatError> {
+    let (number, text) = (5, "ab");
+    let items = [FormattableItem::DisplayItem(&number), FormattableItem::DebugItem(&text)];
+    ColumnFormatter::try_new(format_str, items).err()
+}
+
+#[test]
+fn matching_items_are_accepted() {
+    assert_eq!(try_new("{} | {:?}"), None);
+    assert_eq!(write("{} | {:?}", Strictness::Strict), ("5 | \"ab\"\n".to_owned(), Ok(())));
+}
+
+#[test]
+fn mismatched_items_are_marked_and_reported() {
+    assert_eq!(try_new("{:?} | {}"), Some(FormatError::FormatMismatch { column: 0 }));
+    assert_eq!(
+        write("{:?} | {}", Strictness::Strict),
+        (
+            "<format mismatch> | <format mismatch>\n".to_owned(),
+            Err(FormatError::FormatMismatch { column: 0 })
+        )
+    );
+    assert_eq!(try_new("{} | {}"), Some(FormatError::FormatMismatch { column: 1 }));
+}
+
+#[test]
+fn unterminated_specifiers_are_reported() {
+    assert_eq!(try_new("{} | {:?"), Some(FormatError::UnterminatedSpec { offset: 5 }));
+    assert_eq!(
+        write("{} | {:?", Strictness::Strict),
+        ("5 | {:?\n".to_owned(), Err(FormatError::UnterminatedSpec { offset: 5 }))
+    );
+}
+
+#[test]
+fn unsupported_time_directives_are_reported() {
+    assert_eq!(try_new("{} {:time(%Q)}"), Some(FormatError::InvalidTimePattern { column: 1 }));
+    assert_eq!(
+        write("{} {:time(%Q)}", Strictness::Strict),
+        (
+            "5 <format mismatch>\n".to_owned(),
+            Err(FormatError::InvalidTimePattern { column: 1 })
+        )
+    );
+}
+
+#[test]
+fn lenient_formatters_fall_back_without_errors() {
+    assert_eq!(write("{:?} | {}", Strictness::Lenient), ("5 | \"ab\"\n".to_owned(), Ok(())));
+    assert_eq!(write("{} | {:?", Strictness::Lenient), ("5 | {:?\n".to_owned(), Ok(())));
+    assert_eq!(
+        write("{} {:time(%Q)}", Strictness::Lenient),
+        ("5 \"ab\"\n".to_owned(), Ok(()))
+    );
+}
+
+#[test]
+fn formatters_are_lenient_by_default() {
+    let (number, text) = (5, "ab");
+    let items = [FormattableItem::DisplayItem(&number), FormattableItem::DebugItem(&text)];
+    let formatter = ColumnFormatter::new("{:?} | {}", items);
+    assert_eq!(formatter.to_string(), write("{:?} | {}", Strictness::Lenient).0);
+    assert_eq!(Strictness::default(), Strictness::Lenient);
+}
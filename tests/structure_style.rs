@@ -0,0 +1,80 @@
+//! Tests for styling separators, frames, and blank lines with `separator_style` and `padding_style`.
+
+use colprint::{ColorChoice, ColumnFormatter, Style};
+
+/// Render a short cell beside a two-line cell, with the given styles and color choice.
+fn rendered(separator_style: Style, padding_style: Style, color: ColorChoice) -> String {
+    let short = "a";
+    let tall = "x\ny";
+    ColumnFormatter::new("{}:2 | {}", [&short, &tall])
+        .with_fill(0, '.')
+        .fill_blank_lines(true)
+        .separator_style(separator_style)
+        .padding_style(padding_style)
+        .with_color(color)
+        .to_string()
+}
+
+#[test]
+fn separators_are_styled_and_reset() {
+    let output = rendered(Style::new().dim(), Style::new(), ColorChoice::Always);
+    assert_eq!(output, "a.\x1b[2m | \x1b[0mx\n..\x1b[2m | \x1b[0my\n");
+}
+
+#[test]
+fn blank_lines_are_styled_and_reset() {
+    let output = rendered(Style::new(), Style::new().italic(), ColorChoice::Always);
+    assert_eq!(output, "a. | x\n\x1b[3m..\x1b[0m | y\n");
+}
+
+#[test]
+fn content_lines_are_not_padding() {
+    let output = rendered(Style::new(), Style::new().dim(), ColorChoice::Always);
+    assert!(output.starts_with("a. | x\n"));
+}
+
+#[test]
+fn styles_are_a_no_op_without_color() {
+    let plain = rendered(Style::new(), Style::new(), ColorChoice::Never);
+    assert_eq!(rendered(Style::new().dim(), Style::new().dim(), ColorChoice::Never), plain);
+    assert_eq!(plain, "a. | x\n.. | y\n");
+}
+
+#[test]
+fn frame_sides_take_the_separator_style() {
+    let cell = "a";
+    let output = ColumnFormatter::new("{}", [&cell])
+        .frame_sides("|", "|")
+        .separator_style(Style::new().dim())
+        .with_color(ColorChoice::Always)
+        .to_string();
+    assert_eq!(output, "\x1b[2m|\x1b[0ma\x1b[2m|\x1b[0m\n");
+}
+
+#[test]
+fn frame_sides_are_plain_without_color() {
+    let cell = "a";
+    let output = ColumnFormatter::new("{}", [&cell])
+        .frame_sides("|", "|")
+        .separator_style(Style::new().dim())
+        .with_color(ColorChoice::Never)
+        .to_string();
+    assert_eq!(output, "|a|\n");
+}
+
+#[test]
+fn styles_are_listed_in_debug_output() {
+    let cell = "a";
+    let formatter = ColumnFormatter::new("{}", [&cell])
+        .separator_style(Style::new().dim())
+        .padding_style(Style::new().bold());
+    let debug = format!("{formatter:?}");
+    assert!(
+        debug.contains(&format!("separator_style: {:?}", Style::new().dim())),
+        "{debug}"
+    );
+    assert!(
+        debug.contains(&format!("padding_style: {:?}", Style::new().bold())),
+        "{debug}"
+    );
+}
@@ -0,0 +1,40 @@
+//! Tests for composing styles and parsing colours.
+
+use colprint::{Color, Style};
+
+#[test]
+fn attributes_and_colours_render_as_one_sequence() {
+    let style = Style::new().bold().italic().underline().fg(Color::Red).bg(Color::Blue);
+    assert_eq!(style.render_sgr(), "\x1b[1;3;4;31;44m");
+    assert_eq!(style.render_reset(), "\x1b[0m");
+    assert_eq!(Style::new().render_sgr(), "");
+    assert_eq!(Style::new().render_reset(), "");
+}
+
+#[test]
+fn palette_and_24_bit_colours_use_extended_codes() {
+    assert_eq!(Style::new().fg(Color::Indexed(208)).render_sgr(), "\x1b[38;5;208m");
+    assert_eq!(Style::new().bg(Color::Rgb(1, 2, 3)).render_sgr(), "\x1b[48;2;1;2;3m");
+}
+
+#[test]
+fn later_styles_override_colours_and_add_attributes() {
+    let zebra = Style::new().bg(Color::Black).dim();
+    let failed = Style::new().fg(Color::Red).bold();
+    assert_eq!(zebra.patch(failed), Style::new().fg(Color::Red).bg(Color::Black).dim().bold());
+    assert_eq!(zebra.patch(Style::new().bg(Color::White)).bg, Some(Color::White));
+    assert_eq!(zebra.patch(Style::new()), zebra);
+}
+
+#[test]
+fn colours_parse_from_names_and_hex_strings() {
+    assert_eq!(Color::parse("red"), Some(Color::Red));
+    assert_eq!(Color::parse(" Magenta "), Some(Color::Magenta));
+    assert_eq!(Color::parse("grey"), Some(Color::Indexed(8)));
+    assert_eq!(Color::parse("#ff8800"), Some(Color::Rgb(255, 136, 0)));
+    assert_eq!(Color::parse("#f80"), Some(Color::Rgb(255, 136, 0)));
+    assert_eq!(Color::from_hex("0a0B0c"), Some(Color::Rgb(10, 11, 12)));
+    assert_eq!(Color::parse("#ff88"), None);
+    assert_eq!(Color::parse("#gg0000"), None);
+    assert_eq!(Color::parse("mauve"), None);
+}
@@ -0,0 +1,49 @@
+//! Tests for styling whole rows based on their contents with `style_rows`.
+
+use colprint::{Color, ColorChoice, FormattableItem, Style, Table};
+
+/// A two-column table with a multi-line row, styling the rows the callback picks.
+fn table<F>(row_style: F) -> Table
+where
+    F: Fn(usize, &[String]) -> Option<Style> + 'static,
+{
+    let rows = [("Alice", "a\nb"), ("Bob", "ok")];
+    let mut table = Table::new("{} | {}").style_rows(row_style).with_color(ColorChoice::Always);
+    for (name, notes) in &rows {
+        table.push_row(&[FormattableItem::from(name), FormattableItem::from(notes)]);
+    }
+    table
+}
+
+#[test]
+fn the_style_spans_every_line_of_the_row_including_padding_and_separators() {
+    let styled = table(|_, row| (row[0] == "Alice").then(|| Style::new().fg(Color::Red).bold()));
+    assert_eq!(
+        styled.to_string(),
+        "\x1b[1;31mAlice | a \x1b[0m\n\x1b[1;31m      | b \x1b[0m\nBob   | ok\n"
+    );
+}
+
+#[test]
+fn the_callback_receives_the_row_index_and_formatted_cells() {
+    let styled = table(|idx, row| (idx == 1 && row[1] == "ok").then(|| Style::new().bold()));
+    assert_eq!(styled.to_string(), "Alice | a \n      | b \n\x1b[1mBob   | ok\x1b[0m\n");
+}
+
+#[test]
+fn headers_and_footers_are_never_styled() {
+    let styled = table(|_, _| Some(Style::new().fg(Color::Red).bold()))
+        .with_headers(["name", "notes"])
+        .with_footers(["total", "2"]);
+    let output = styled.to_string();
+    let lines: Vec<&str> = output.lines().collect();
+    assert_eq!(lines.first(), Some(&"name  | notes"));
+    assert_eq!(lines.last(), Some(&"total | 2    "));
+    assert!(lines[1..lines.len() - 1].iter().all(|line| line.starts_with("\x1b[1;31m")));
+}
+
+#[test]
+fn rows_are_left_plain_without_color() {
+    let plain = table(|_, _| Some(Style::new().bold())).with_color(ColorChoice::Never);
+    assert_eq!(plain.to_string(), "Alice | a \n      | b \nBob   | ok\n");
+}
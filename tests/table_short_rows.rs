@@ -0,0 +1,65 @@
+//! Tests for pushing rows with fewer or more items than a table has columns.
+
+use colprint::{FormatError, FormattableItem, Table};
+
+/// A three-column table with a default for the last column.
+fn table() -> Table {
+    Table::new("{} | {} | {}").with_default_cell(2, "-")
+}
+
+/// The rendered lines of a table, without trailing padding.
+fn lines(table: &Table) -> Vec<String> {
+    table.to_string().lines().map(|line| line.trim_end().to_owned()).collect()
+}
+
+#[test]
+fn short_rows_take_the_default_cells() {
+    let (a, b, c) = ("a", "b", "c");
+    let mut table = table();
+    table.push_row(&[FormattableItem::from(&a), FormattableItem::from(&b)]);
+    table.push_row(&[
+        FormattableItem::from(&a),
+        FormattableItem::from(&b),
+        FormattableItem::from(&c),
+    ]);
+    assert_eq!(lines(&table), ["a | b | -", "a | b | c"]);
+}
+
+#[test]
+fn missing_cells_without_a_default_are_empty() {
+    let a = "a";
+    let mut table = Table::new("{} | {} | {}");
+    table.push_row(&[FormattableItem::from(&a)]);
+    assert_eq!(lines(&table), ["a |  |"]);
+}
+
+#[test]
+fn long_rows_are_truncated_by_push_row() {
+    let (a, b, c, d) = ("a", "b", "c", "d");
+    let mut table = table();
+    table.push_row(&[
+        FormattableItem::from(&a),
+        FormattableItem::from(&b),
+        FormattableItem::from(&c),
+        FormattableItem::from(&d),
+    ]);
+    assert_eq!(lines(&table), ["a | b | c"]);
+}
+
+#[test]
+fn long_rows_are_rejected_by_try_push_row() {
+    let (a, b, c, d) = ("a", "b", "c", "d");
+    let mut table = table();
+    assert_eq!(
+        table.try_push_row(&[FormattableItem::from(&a), FormattableItem::from(&b)]),
+        Ok(())
+    );
+    let result = table.try_push_row(&[
+        FormattableItem::from(&a),
+        FormattableItem::from(&b),
+        FormattableItem::from(&c),
+        FormattableItem::from(&d),
+    ]);
+    assert_eq!(result, Err(FormatError::TooManyItems { columns: 3, items: 4 }));
+    assert_eq!(table.len(), 1);
+}
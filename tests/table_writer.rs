@@ -0,0 +1,100 @@
+//! Tests for streaming rows with `TableWriter` under each `FlushPolicy`.
+
+use std::io::{Result as IoResult, Write};
+
+use colprint::{FlushPolicy, FormattableItem, TableWriter};
+
+/// A writer recording what reached it and how many times it was flushed.
+#[derive(Default)]
+struct Counting {
+    written: Vec<u8>,
+    flushes: usize,
+}
+
+impl Counting {
+    /// The text written so far.
+    fn text(&self) -> String {
+        String::from_utf8(self.written.clone()).unwrap()
+    }
+}
+
+impl Write for Counting {
+    fn write(&mut self, buf: &[u8]) -> IoResult<usize> {
+        self.written.extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> IoResult<()> {
+        self.flushes += 1;
+        Ok(())
+    }
+}
+
+/// Write the numbered rows, recording the flushes after each one.
+fn stream(policy: FlushPolicy, rows: usize) -> (Counting, Vec<usize>) {
+    let mut counting = Counting::default();
+    let mut flushes = Vec::new();
+    {
+        let mut writer = TableWriter::new("{} | {}", &mut counting).with_flush_policy(policy);
+        for row in 0..rows {
+            writer
+                .write_row(&[FormattableItem::from(&"row"), FormattableItem::from(&row)])
+                .unwrap();
+            flushes.push(writer.get_ref().flushes);
+        }
+    }
+    (counting, flushes)
+}
+
+#[test]
+fn every_row_flushes_each_row() {
+    let (counting, flushes) = stream(FlushPolicy::EveryRow, 3);
+    assert_eq!(flushes, [1, 2, 3]);
+    assert_eq!(counting.flushes, 4);
+    assert_eq!(counting.text(), "row | 0\nrow | 1\nrow | 2\n");
+}
+
+#[test]
+fn every_n_flushes_in_batches() {
+    let (counting, flushes) = stream(FlushPolicy::EveryN(2), 5);
+    assert_eq!(flushes, [0, 1, 1, 2, 2]);
+    assert_eq!(counting.flushes, 3);
+    assert_eq!(counting.text().lines().count(), 5);
+}
+
+#[test]
+fn manual_writes_nothing_until_dropped() {
+    let mut counting = Counting::default();
+    {
+        let mut writer = TableWriter::new("{} | {}", &mut counting).with_flush_policy(FlushPolicy::Manual);
+        writer
+            .write_row(&[FormattableItem::from(&"a"), FormattableItem::from(&1)])
+            .unwrap();
+        assert_eq!(writer.get_ref().flushes, 0);
+        assert!(writer.get_ref().written.is_empty());
+
+        writer.flush().unwrap();
+        assert_eq!(writer.get_ref().text(), "a | 1\n");
+
+        writer
+            .write_row(&[FormattableItem::from(&"b"), FormattableItem::from(&2)])
+            .unwrap();
+        assert_eq!(writer.get_ref().flushes, 1);
+    }
+    assert_eq!(counting.flushes, 2);
+    assert_eq!(counting.text(), "a | 1\nb | 2\n");
+}
+
+#[test]
+fn first_row_fixes_the_widths() {
+    let mut counting = Counting::default();
+    {
+        let mut writer = TableWriter::new("{}:6 | {}", &mut counting).with_headers(["name", "n"]);
+        for (name, n) in [("short", 100), ("much longer", 2)] {
+            writer
+                .write_row(&[FormattableItem::from(&name), FormattableItem::from(&n)])
+                .unwrap();
+        }
+    }
+    assert_eq!(counting.text(), "name   | n  \nshort  | 100\nmuch l | 2  \n");
+}
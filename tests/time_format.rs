@@ -0,0 +1,47 @@
+//! Tests for rendering timestamps with `{:time(pattern)}` columns.
+
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use colprint::{ColumnFormatter, FormatError, FormattableItem, colformat};
+
+/// 2024-03-09 14:05:07 UTC, in seconds since the Unix epoch.
+const SECONDS: i64 = 1_709_993_107;
+
+#[test]
+fn epoch_seconds_follow_the_pattern() {
+    assert_eq!(colformat!("{:time(%Y-%m-%d %H:%M)}", SECONDS), "2024-03-09 14:05\n");
+    assert_eq!(colformat!("{:time(%d %b %y, %j)}", SECONDS), "09 Mar 24, 069\n");
+}
+
+#[test]
+fn system_times_default_to_iso_8601() {
+    let time = UNIX_EPOCH + Duration::from_secs(SECONDS.unsigned_abs());
+    assert_eq!(colformat!("{:time}", time), "2024-03-09T14:05:07Z\n");
+    assert_eq!(colformat!("{:time(%F %T)}", time), "2024-03-09 14:05:07\n");
+}
+
+#[test]
+fn times_before_the_epoch_round_down() {
+    assert_eq!(colformat!("{:time(%F %T)}", -1), "1969-12-31 23:59:59\n");
+}
+
+#[test]
+fn time_columns_are_right_aligned() {
+    assert_eq!(colformat!("{:time(%H:%M)}:8 | {}", SECONDS, "x"), "   14:05 | x\n");
+}
+
+#[test]
+fn values_which_are_not_times_render_a_placeholder() {
+    assert_eq!(colformat!("{:time(%Y)}", "yesterday"), "<not a time>\n");
+}
+
+#[test]
+fn invalid_patterns_are_rejected_by_strict_formatters() {
+    let time = SystemTime::UNIX_EPOCH;
+    let items = [FormattableItem::TimeItem(time)];
+    let result = ColumnFormatter::try_new("{:time(%Y %Q)}", items.iter());
+    assert_eq!(result.err(), Some(FormatError::InvalidTimePattern { column: 0 }));
+
+    let lenient = ColumnFormatter::new("{:time(%Y %Q)}", items.iter());
+    assert_eq!(lenient.to_string(), "1970 %Q\n");
+}
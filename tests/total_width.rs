@@ -0,0 +1,58 @@
+//! Tests for laying columns out in an exact number of characters with `with_total_width`.
+
+use colprint::{ColumnFormatter, EnvConfig, FormatError};
+
+#[test]
+fn automatic_columns_share_the_remaining_width() {
+    let output = ColumnFormatter::new("{}:4|{}|{}", [&"ab", &"cd", &"ef"])
+        .with_env(&EnvConfig::empty())
+        .with_total_width(15)
+        .to_string();
+    assert_eq!(output, "ab  |cd   |ef  \n");
+}
+
+#[test]
+fn the_remainder_goes_to_the_leftmost_columns() {
+    let output = ColumnFormatter::new("{} {} {}", [&"a", &"b", &"c"])
+        .with_env(&EnvConfig::empty())
+        .with_total_width(10)
+        .to_string();
+    assert_eq!(output, "a   b   c \n");
+}
+
+#[test]
+fn lines_are_never_wider_than_the_total() {
+    let long = "x".repeat(40);
+    let output = ColumnFormatter::new("{}:30 | {}:30", [&long, &long])
+        .with_env(&EnvConfig::empty())
+        .with_total_width(20)
+        .to_string();
+    for line in output.lines() {
+        assert!(line.chars().count() <= 20, "{output:?}");
+    }
+}
+
+#[test]
+fn budgets_which_fit_are_accepted() {
+    let formatter = ColumnFormatter::new("{}:4 | {}", [&"ab", &"cd"])
+        .with_env(&EnvConfig::empty())
+        .try_with_total_width(12);
+    assert_eq!(
+        formatter.map(|formatter| formatter.to_string()),
+        Ok("ab   | cd   \n".to_owned())
+    );
+}
+
+#[test]
+fn budgets_narrower_than_the_explicit_widths_are_rejected() {
+    let result = ColumnFormatter::new("{}:8 | {}:6", [&"ab", &"cd"])
+        .with_env(&EnvConfig::empty())
+        .try_with_total_width(12);
+    assert_eq!(
+        result.map(|formatter| formatter.to_string()),
+        Err(FormatError::WidthBudgetExceeded {
+            required: 17,
+            available: 12
+        })
+    );
+}
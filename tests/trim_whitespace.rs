@@ -0,0 +1,34 @@
+//! Tests for removing trailing whitespace from cell lines before measuring them.
+
+use colprint::{ColumnFormatter, RenderOptions};
+
+/// Text whose lines end with spaces, as written by `writeln!` with padding.
+const PADDED: &str = "one   \ntwo          ";
+
+#[test]
+fn trailing_whitespace_does_not_widen_columns() {
+    let rendered = ColumnFormatter::new("{}|{}", [&PADDED, &"x"]).to_string();
+    assert_eq!(rendered, "one|x\ntwo| \n");
+}
+
+#[test]
+fn trimming_can_be_turned_off() {
+    let rendered = ColumnFormatter::new("{}|{}", [&PADDED, &"x"])
+        .with_trim_trailing_whitespace(false)
+        .to_string();
+    assert_eq!(rendered, "one          |x\ntwo          | \n");
+}
+
+#[test]
+fn the_v1_preset_keeps_trailing_whitespace() {
+    let rendered = ColumnFormatter::new("{}|{}", [&PADDED, &"x"])
+        .with_options(RenderOptions::v1())
+        .to_string();
+    assert_eq!(rendered, "one          |x\ntwo          | \n");
+}
+
+#[test]
+fn values_padded_by_their_specifier_keep_their_padding() {
+    let rendered = ColumnFormatter::new("{:<6}|{}", [&"ab", &"x"]).to_string();
+    assert_eq!(rendered, "ab    |x\n");
+}
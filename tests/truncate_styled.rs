@@ -0,0 +1,45 @@
+//! Tests for truncating lines styled with ANSI escape sequences.
+
+use colprint::{ColumnFormatter, EnvConfig, Style, TruncateSide};
+
+/// Bold text, turning red part way through, then reset.
+const STYLED: &str = "\x1b[1mbold \x1b[31mand red text\x1b[0m";
+
+/// Format the styled text in a column eight characters wide, followed by a plain column.
+fn truncated(side: TruncateSide, marker_style: Style) -> String {
+    ColumnFormatter::new("{:raw}:8|{}", [&STYLED, &"next"])
+        .with_env(&EnvConfig::empty())
+        .with_truncate_side(0, side)
+        .with_truncate_marker("…")
+        .with_truncate_marker_style(marker_style)
+        .to_string()
+}
+
+#[test]
+fn marker_is_drawn_after_a_reset() {
+    let output = truncated(TruncateSide::Middle, Style::new());
+    assert_eq!(output, "\x1b[1mbold\x1b[0m…\x1b[1m\x1b[31mext\x1b[0m\x1b[0m|next\n");
+}
+
+#[test]
+fn marker_takes_its_own_style() {
+    let output = truncated(TruncateSide::Left, Style::new().dim());
+    assert_eq!(output, "\x1b[0m\x1b[2m…\x1b[0m\x1b[1m\x1b[31med text\x1b[0m\x1b[0m|next\n");
+}
+
+#[test]
+fn cut_ends_are_reset_before_the_separator() {
+    let output = truncated(TruncateSide::Right, Style::new().dim());
+    assert_eq!(output, "\x1b[1mbold \x1b[31mand\x1b[0m|next\n");
+}
+
+#[test]
+fn plain_lines_keep_a_plain_marker() {
+    let output = ColumnFormatter::new("{}:8|{}", [&"plain text here", &"next"])
+        .with_env(&EnvConfig::empty())
+        .with_truncate_side(0, TruncateSide::Middle)
+        .with_truncate_marker("…")
+        .with_truncate_marker_style(Style::new().dim())
+        .to_string();
+    assert_eq!(output, "plai…ere|next\n");
+}
@@ -0,0 +1,41 @@
+//! Tests for replacing over-long lines with the text computed by a truncation callback.
+
+use colprint::{ColumnFormatter, EnvConfig, FormattableItem, Table};
+
+#[test]
+fn callback_computes_the_replacement() {
+    let formatter = ColumnFormatter::new("{}:10|{}", [&"a line which is far too long", &"x"])
+        .with_env(&EnvConfig::empty())
+        .with_truncator(0, |line, width| {
+            let dropped = line.chars().count() - (width - 6);
+            format!("{}…(+{dropped})", &line[..width - 6])
+        });
+    assert_eq!(formatter.to_string(), "a li…(+24)|x\n");
+}
+
+#[test]
+fn lines_that_fit_are_left_alone() {
+    let formatter = ColumnFormatter::new("{}:10|{}", [&"short", &"x"]).with_truncator(0, |_, _| "replaced".to_owned());
+    assert_eq!(formatter.to_string(), "short     |x\n");
+}
+
+#[test]
+fn replacements_are_padded_and_cut() {
+    let short = ColumnFormatter::new("{}:6|{}", [&"much too long", &"x"])
+        .with_env(&EnvConfig::empty())
+        .with_truncator(0, |_, _| "…".to_owned());
+    assert_eq!(short.to_string(), "…     |x\n");
+    let long = ColumnFormatter::new("{}:6|{}", [&"much too long", &"x"])
+        .with_env(&EnvConfig::empty())
+        .with_truncator(0, |line, _| line.repeat(2));
+    assert_eq!(long.to_string(), "much t|x\n");
+}
+
+#[test]
+fn tables_use_the_callback() {
+    let mut table = Table::new("{}:4|{}")
+        .with_env(&EnvConfig::empty())
+        .with_truncator(0, |line, width| line[line.len() - width..].to_owned());
+    table.push_row(&[FormattableItem::from(&"deadbeef"), FormattableItem::from(&"hash")]);
+    assert_eq!(table.to_string(), "beef|hash\n");
+}
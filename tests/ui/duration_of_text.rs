@@ -0,0 +1,6 @@
+use colprint::colformat;
+
+fn main() {
+    // Text cannot be humanized as a duration
+    colformat!("{} | {:dur}", "build", "soon");
+}
@@ -0,0 +1,6 @@
+use colprint::colformat;
+
+fn main() {
+    // Only integer numbers of bytes can be humanized as a size
+    colformat!("{} | {:size}", "cache", 1.5);
+}
@@ -0,0 +1,51 @@
+//! Tests for `std::fmt` options inside specifiers, alongside the column width suffix.
+
+use colprint::{ColumnFormatter, EnvConfig, FormattableItem, colformat};
+
+/// Format values on a single line with the given format string, without the trailing newline.
+macro_rules! single {
+    ($fmt:expr, $($value:expr),+) => {
+        colformat!($fmt, $($value),+).trim_end_matches('\n').to_owned()
+    };
+}
+
+#[test]
+fn precision_is_passed_to_the_value() {
+    assert_eq!(single!("{:.3}", 1.23456), "1.235");
+    assert_eq!(single!("{:.0}", 2.5_f32), "2");
+    assert_eq!(single!("{:.2}", "abcdef"), "ab");
+    assert_eq!(single!("{:.1?}", 0.25), "0.2");
+}
+
+#[test]
+fn inline_width_is_a_per_value_minimum() {
+    assert_eq!(single!("{:6}|{}", 42, "x"), "    42|x");
+    assert_eq!(single!("{:6}|{}", "ab", "x"), "ab    |x");
+    assert_eq!(single!("{:2}", "abcd"), "abcd");
+}
+
+#[test]
+fn fill_alignment_sign_and_zero_flags_follow_std() {
+    assert_eq!(single!("{:*^7}", "mid"), format!("{:*^7}", "mid"));
+    assert_eq!(single!("{:<5}|{}", 42, "x"), "42   |x");
+    assert_eq!(single!("{:+}", 7), "+7");
+    assert_eq!(single!("{:+.1}", 2.0), "+2.0");
+    assert_eq!(single!("{:06.2}", -1.5), "-01.50");
+    assert_eq!(single!("{:>8.3?}", 1.0), "   1.000");
+}
+
+#[test]
+fn suffix_width_sizes_the_column_around_the_values() {
+    assert_eq!(colformat!("{:.3}:12| {}", 1.23456, "x"), "1.235       | x\n");
+    assert_eq!(colformat!("{:>6.1}:10| {}", 2.25, "x"), "   2.2    | x\n");
+    let cut = ColumnFormatter::new("{:<6}:4| {}", [FormattableItem::from(&42), FormattableItem::from(&"x")])
+        .with_env(&EnvConfig::empty());
+    assert_eq!(cut.to_string(), "42  | x\n");
+}
+
+#[test]
+fn named_formats_are_not_std_specifiers() {
+    assert_eq!(single!("{:?}", "a"), "\"a\"");
+    assert_eq!(single!("{:#?}", (1,)), "(     \n    1,\n)     ");
+    assert_eq!(single!("{:raw}", "a"), "a");
+}
@@ -0,0 +1,36 @@
+//! Tests for deriving automatic column widths with `WidthHeuristic`.
+
+use colprint::{ColumnFormatter, EnvConfig, WidthHeuristic};
+
+/// A cell of nine short lines and one outlying long line.
+fn skewed() -> String {
+    let mut lines: Vec<String> = (1..=9).map(|len| "x".repeat(len)).collect();
+    lines.push("y".repeat(300));
+    lines.join("\n")
+}
+
+#[test]
+fn max_fits_the_longest_line() {
+    let cell = skewed();
+    let formatter = ColumnFormatter::new("{}", [&cell]);
+    assert_eq!(formatter.measure().width, 300);
+}
+
+#[test]
+fn percentile_ignores_outlying_lines() {
+    let cell = skewed();
+    let formatter = ColumnFormatter::new("{}", [&cell])
+        .with_env(&EnvConfig::empty())
+        .with_width_heuristic(0, WidthHeuristic::Percentile(90));
+    assert_eq!(formatter.measure().width, 9);
+
+    let output = formatter.to_string();
+    assert_eq!(output.lines().last(), Some("yyyyyyyyy"));
+}
+
+#[test]
+fn percentile_of_one_hundred_matches_max() {
+    let cell = skewed();
+    let formatter = ColumnFormatter::new("{}", [&cell]).with_width_heuristic(0, WidthHeuristic::Percentile(100));
+    assert_eq!(formatter.measure().width, 300);
+}
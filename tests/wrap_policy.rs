@@ -0,0 +1,71 @@
+//! Tests for splitting words wider than their column with `WrapPolicy`.
+
+use colprint::{ColumnFormatter, EnvConfig, Overflow, WrapPolicy};
+
+/// A URL of 120 characters.
+fn url() -> String {
+    let url = format!(
+        "https://example.com/{}/{}?query={}&page=2-{}",
+        "a".repeat(30),
+        "b".repeat(25),
+        "c".repeat(12),
+        "d".repeat(17)
+    );
+    assert_eq!(url.chars().count(), 120);
+    url
+}
+
+/// Wrap the text into a column 40 characters wide with the given policy.
+fn wrapped(text: &str, policy: WrapPolicy) -> Vec<String> {
+    ColumnFormatter::new("{}:40", [&text])
+        .with_env(&EnvConfig::empty())
+        .with_overflow(Overflow::Wrap { min_width: 10 })
+        .with_wrap_policy(policy)
+        .to_string()
+        .lines()
+        .map(|line| line.trim_end().to_owned())
+        .collect()
+}
+
+#[test]
+fn long_urls_break_after_break_points() {
+    let lines = wrapped(&url(), WrapPolicy::default());
+    assert_eq!(
+        lines,
+        [
+            "https://example.com/".to_owned(),
+            format!("{}/", "a".repeat(30)),
+            format!("{}?", "b".repeat(25)),
+            format!("query={}&page=2-", "c".repeat(12)),
+            "d".repeat(17),
+        ]
+    );
+}
+
+#[test]
+fn urls_move_to_their_own_line_before_breaking() {
+    let text = format!("see {}", url());
+    let lines = wrapped(&text, WrapPolicy::default());
+    assert_eq!(lines.first().map(String::as_str), Some("see"));
+    assert_eq!(lines.concat(), text.replace(' ', ""));
+}
+
+#[test]
+fn tokens_that_fit_are_never_broken() {
+    let text = "open /usr/local/share/doc/colprint/README.md now";
+    let lines = wrapped(text, WrapPolicy::default());
+    assert_eq!(lines, ["open", "/usr/local/share/doc/colprint/README.md", "now"]);
+}
+
+#[test]
+fn without_break_points_words_break_at_the_width() {
+    let lines = wrapped(&url(), WrapPolicy::new(true, &[]));
+    assert!(lines.iter().take(2).all(|line| line.chars().count() == 40));
+    assert_eq!(lines.concat(), url());
+}
+
+#[test]
+fn long_words_can_be_left_whole() {
+    let lines = wrapped(&url(), WrapPolicy::new(false, &[]));
+    assert_eq!(lines.len(), 1);
+}
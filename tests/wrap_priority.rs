@@ -0,0 +1,64 @@
+//! Tests for choosing which columns absorb wrapping under `Overflow::Wrap` with `with_wrap_priority`.
+
+use colprint::{ColumnFormatter, EnvConfig, Overflow};
+
+/// Three columns of ten characters, with the given wrap priorities, wrapped to the maximum width.
+fn widths(priorities: &[(usize, u8)], max_width: usize) -> Vec<usize> {
+    let mut formatter = ColumnFormatter::new("{} | {} | {}", [&"aaaa aaaaa", &"bbbb bbbbb", &"cccc ccccc"])
+        .with_env(&EnvConfig::empty())
+        .with_overflow(Overflow::Wrap { min_width: 4 })
+        .with_max_width(max_width);
+    for &(column, priority) in priorities {
+        formatter = formatter.with_wrap_priority(column, priority);
+    }
+    formatter.prepare().widths().to_vec()
+}
+
+#[test]
+fn columns_keep_their_width_when_they_fit() {
+    assert_eq!(widths(&[(0, 1)], 36), [10, 10, 10]);
+}
+
+#[test]
+fn the_highest_priority_column_is_narrowed_first() {
+    assert_eq!(widths(&[(0, 1), (2, 2)], 32), [10, 10, 6]);
+}
+
+#[test]
+fn narrowing_moves_on_once_the_minimum_is_reached() {
+    assert_eq!(widths(&[(0, 1), (2, 2)], 26), [6, 10, 4]);
+}
+
+#[test]
+fn columns_of_equal_priority_are_narrowed_widest_first() {
+    let formatter = ColumnFormatter::new("{} | {} | {}", [&"aaaa aa", &"bbbb bbbbb", &"cccc ccccc"])
+        .with_env(&EnvConfig::empty())
+        .with_overflow(Overflow::Wrap { min_width: 4 })
+        .with_max_width(29)
+        .with_wrap_priority(0, 1)
+        .with_wrap_priority(1, 1);
+    assert_eq!(formatter.prepare().widths(), [7, 6, 10]);
+}
+
+#[test]
+fn columns_of_equal_priority_and_width_are_narrowed_leftmost_first() {
+    assert_eq!(widths(&[(0, 1), (2, 1)], 32), [6, 10, 10]);
+}
+
+#[test]
+fn columns_without_a_priority_are_narrowed_last() {
+    let narrowed = widths(&[(1, 1)], 24);
+    assert_eq!(narrowed[1], 4);
+    assert_eq!(narrowed.iter().sum::<usize>(), 18);
+}
+
+#[test]
+fn narrowed_columns_wrap_their_words() {
+    let output = ColumnFormatter::new("{} | {}", [&"aaaa aaaaa", &"bbbb bbbbb"])
+        .with_env(&EnvConfig::empty())
+        .with_overflow(Overflow::Wrap { min_width: 4 })
+        .with_max_width(18)
+        .with_wrap_priority(1, 1)
+        .to_string();
+    assert_eq!(output, "aaaa aaaaa | bbbb \n           | bbbbb\n");
+}
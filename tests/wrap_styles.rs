@@ -0,0 +1,32 @@
+//! Tests for keeping ANSI styles balanced when styled lines are wrapped.
+
+use colprint::{ColumnFormatter, FormattableItem, Overflow};
+
+/// A formatter wrapping a raw line into a column of `width` characters, followed by a second column.
+fn wrapped(line: &str, width: usize) -> String {
+    ColumnFormatter::new("{:raw}|{}", [FormattableItem::Verbatim(line), FormattableItem::from(&"x")])
+        .with_overflow(Overflow::Wrap { min_width: 1 })
+        .with_max_width(width + 2)
+        .to_string()
+}
+
+#[test]
+fn styles_are_replayed_on_continuation_lines() {
+    let output = wrapped("plain then \x1b[1mbold words here\x1b[0m", 10);
+    assert_eq!(
+        output,
+        "plain then|x\n\x1b[1mbold words\x1b[0m| \n\x1b[1mhere\x1b[0m      | \n"
+    );
+}
+
+#[test]
+fn escape_sequences_take_no_width() {
+    let output = wrapped("\x1b[31mred\x1b[0m and more", 8);
+    assert_eq!(output, "\x1b[31mred\x1b[0m and |x\nmore    | \n");
+}
+
+#[test]
+fn long_words_are_split_outside_escape_sequences() {
+    let output = wrapped("\x1b[4munderlined\x1b[0m", 5);
+    assert_eq!(output, "\x1b[4munder\x1b[0m|x\n\x1b[4mlined\x1b[0m| \n");
+}